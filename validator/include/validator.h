@@ -0,0 +1,31 @@
+/* The C functions of the `ffi` feature of the validator crate */
+
+#ifndef VALIDATOR_H
+#define VALIDATOR_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The values are part of the ABI and never change */
+typedef enum ValidatorResult {
+    VALIDATOR_VALID = 0,
+    VALIDATOR_INVALID = 1,
+    /* The value is a null pointer */
+    VALIDATOR_NULL_POINTER = 2,
+    /* The value isn't UTF-8 */
+    VALIDATOR_INVALID_UTF8 = 3,
+} ValidatorResult;
+
+/* The values are UTF-8 strings ending with a nul byte */
+ValidatorResult validator_validate_email(const char *value);
+ValidatorResult validator_validate_url(const char *value);
+ValidatorResult validator_validate_ip(const char *value);
+ValidatorResult validator_validate_ip_v4(const char *value);
+ValidatorResult validator_validate_ip_v6(const char *value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
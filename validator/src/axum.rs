@@ -0,0 +1,213 @@
+use std::ops::{Deref, DerefMut};
+
+use axum::extract::rejection::{FormRejection, JsonRejection, PathRejection, QueryRejection};
+use axum::extract::{Form, FromRequest, FromRequestParts, Path, Query, Request};
+use axum::http::header::CONTENT_TYPE;
+use axum::http::request::Parts;
+use axum::http::{Extensions, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::de::DeserializeOwned;
+
+use crate::problem::{ProblemDetails, PROBLEM_JSON_CONTENT_TYPE};
+use crate::serialization::ErrorsShape;
+use crate::traits::Validate;
+use crate::types::ValidationErrors;
+
+/// The response of invalid input: the errors serialized as JSON in a shape, with a status. See
+/// [`ValidationErrors::into_response_with`].
+#[derive(Debug, Clone)]
+pub struct ErrorsResponse {
+    errors: ValidationErrors,
+    status: StatusCode,
+    shape: ErrorsShape,
+}
+
+impl ValidationErrors {
+    /// Returns a response with the errors serialized as JSON in that shape and that status, the
+    /// `IntoResponse` implementation of `ValidationErrors` being a 422 with the `Nested` shape
+    pub fn into_response_with(self, status: StatusCode, shape: ErrorsShape) -> ErrorsResponse {
+        ErrorsResponse { errors: self, status, shape }
+    }
+}
+
+impl IntoResponse for ErrorsResponse {
+    fn into_response(self) -> Response {
+        (self.status, Json(self.errors.with_shape(self.shape))).into_response()
+    }
+}
+
+impl IntoResponse for ValidationErrors {
+    fn into_response(self) -> Response {
+        self.into_response_with(StatusCode::UNPROCESSABLE_ENTITY, ErrorsShape::Nested)
+            .into_response()
+    }
+}
+
+/// An `application/problem+json` response with the status of the problem
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::UNPROCESSABLE_ENTITY);
+        let mut response = (status, Json(self)).into_response();
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE));
+        response
+    }
+}
+
+/// The response of the validated extractors when the payload is invalid, added to the requests
+/// with an `Extension` layer. The default is a 422 with the `Nested` shape.
+///
+/// ```ignore
+/// let app = Router::new()
+///     .route("/signup", post(signup))
+///     .layer(Extension(RejectionConfig::new(StatusCode::BAD_REQUEST, ErrorsShape::List)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RejectionConfig {
+    pub status: StatusCode,
+    pub shape: ErrorsShape,
+}
+
+impl RejectionConfig {
+    pub fn new(status: StatusCode, shape: ErrorsShape) -> RejectionConfig {
+        RejectionConfig { status, shape }
+    }
+
+    fn of(extensions: &Extensions) -> RejectionConfig {
+        extensions.get::<RejectionConfig>().copied().unwrap_or_default()
+    }
+}
+
+impl Default for RejectionConfig {
+    fn default() -> RejectionConfig {
+        RejectionConfig::new(StatusCode::UNPROCESSABLE_ENTITY, ErrorsShape::Nested)
+    }
+}
+
+/// Why a validated extractor rejected a request: the payload couldn't be extracted, with the
+/// rejection of the axum extractor, or it is invalid
+#[derive(Debug)]
+pub enum ValidationRejection<R> {
+    Extract(R),
+    Invalid(ErrorsResponse),
+}
+
+impl<R: IntoResponse> IntoResponse for ValidationRejection<R> {
+    fn into_response(self) -> Response {
+        match self {
+            ValidationRejection::Extract(rejection) => rejection.into_response(),
+            ValidationRejection::Invalid(response) => response.into_response(),
+        }
+    }
+}
+
+fn validated<T: Validate, R>(
+    value: T,
+    config: RejectionConfig,
+) -> Result<T, ValidationRejection<R>> {
+    match value.validate() {
+        Ok(()) => Ok(value),
+        Err(errors) => Err(ValidationRejection::Invalid(
+            errors.into_response_with(config.status, config.shape),
+        )),
+    }
+}
+
+/// A JSON body that is valid, see [`RejectionConfig`] for the response when it isn't
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedJson<T>(pub T);
+
+/// A query string that is valid, see [`RejectionConfig`] for the response when it isn't
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedQuery<T>(pub T);
+
+/// A form, in the query string or the body, that is valid, see [`RejectionConfig`] for the
+/// response when it isn't
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedForm<T>(pub T);
+
+/// Path parameters that are valid, see [`RejectionConfig`] for the response when they aren't
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedPath<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = ValidationRejection<JsonRejection>;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let config = RejectionConfig::of(req.extensions());
+        let Json(value) =
+            Json::<T>::from_request(req, state).await.map_err(ValidationRejection::Extract)?;
+        validated(value, config).map(ValidatedJson)
+    }
+}
+
+impl<T, S> FromRequest<S> for ValidatedForm<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = ValidationRejection<FormRejection>;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let config = RejectionConfig::of(req.extensions());
+        let Form(value) =
+            Form::<T>::from_request(req, state).await.map_err(ValidationRejection::Extract)?;
+        validated(value, config).map(ValidatedForm)
+    }
+}
+
+impl<T, S> FromRequestParts<S> for ValidatedQuery<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = ValidationRejection<QueryRejection>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(value) = Query::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(ValidationRejection::Extract)?;
+        validated(value, RejectionConfig::of(&parts.extensions)).map(ValidatedQuery)
+    }
+}
+
+impl<T, S> FromRequestParts<S> for ValidatedPath<T>
+where
+    T: DeserializeOwned + Validate + Send,
+    S: Send + Sync,
+{
+    type Rejection = ValidationRejection<PathRejection>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(value) = Path::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(ValidationRejection::Extract)?;
+        validated(value, RejectionConfig::of(&parts.extensions)).map(ValidatedPath)
+    }
+}
+
+macro_rules! impl_deref {
+    ($($extractor:ident),*) => {$(
+        impl<T> Deref for $extractor<T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> DerefMut for $extractor<T> {
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+    )*};
+}
+
+impl_deref!(ValidatedJson, ValidatedQuery, ValidatedForm, ValidatedPath);
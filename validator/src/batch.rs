@@ -0,0 +1,124 @@
+use std::fmt::Display;
+
+use serde_derive::Serialize;
+
+use crate::traits::Validate;
+use crate::types::ValidationErrors;
+
+/// The code of the errors of the rows that couldn't be deserialized
+pub const DESERIALIZE_CODE: &str = "deserialize";
+
+/// An error of a row of a batch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RowError {
+    /// The index of the row in the batch, starting at 0
+    pub row: usize,
+    /// The path of the field, like `addresses[2].street`, empty for the rows that couldn't be
+    /// deserialized
+    pub path: String,
+    pub code: String,
+    pub message: Option<String>,
+}
+
+/// The result of the validation of a batch of rows by [`BatchValidator`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BatchReport {
+    /// The number of rows validated, which doesn't include the rows after the threshold
+    pub rows: usize,
+    pub invalid_rows: usize,
+    /// The errors of the invalid rows, in the order of the rows
+    pub errors: Vec<RowError>,
+    /// Whether the validation stopped because more rows than the threshold were invalid
+    pub aborted: bool,
+}
+
+impl BatchReport {
+    /// Whether all the rows were valid
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.invalid_rows == 0
+    }
+
+    fn add(&mut self, row: usize, errors: &ValidationErrors) {
+        self.invalid_rows += 1;
+        self.errors.extend(errors.iter().map(|(path, error)| RowError {
+            row,
+            path: path.to_string(),
+            code: error.code.to_string(),
+            message: error.message.as_ref().map(|message| message.to_string()),
+        }));
+    }
+}
+
+/// Validates the rows of a data import, like the records of a CSV file or the lines of an NDJSON
+/// one once deserialized, and reports the errors of all the rows instead of stopping at the first
+/// invalid one:
+///
+/// ```ignore
+/// let rows = csv::Reader::from_path("users.csv")?.into_deserialize::<User>();
+/// let (users, report) = BatchValidator::new().max_invalid_rows(100).validate_results(rows);
+/// if !report.is_valid() {
+///     serde_json::to_writer(std::io::stderr(), &report)?;
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchValidator {
+    max_invalid_rows: Option<usize>,
+}
+
+impl BatchValidator {
+    pub fn new() -> BatchValidator {
+        BatchValidator::default()
+    }
+
+    /// Stops the validation once more than that number of rows are invalid, as the file is
+    /// likely not the right one
+    pub fn max_invalid_rows(mut self, max: usize) -> BatchValidator {
+        self.max_invalid_rows = Some(max);
+        self
+    }
+
+    /// Validates the rows, returning the valid ones with the report
+    pub fn validate<T, I>(&self, rows: I) -> (Vec<T>, BatchReport)
+    where
+        T: Validate,
+        I: IntoIterator<Item = T>,
+    {
+        self.validate_results(rows.into_iter().map(Ok::<T, std::convert::Infallible>))
+    }
+
+    /// Validates the results of the deserialization of the rows, the rows that couldn't be
+    /// deserialized being invalid with a [`DESERIALIZE_CODE`] error with the error as message
+    pub fn validate_results<T, E, I>(&self, rows: I) -> (Vec<T>, BatchReport)
+    where
+        T: Validate,
+        E: Display,
+        I: IntoIterator<Item = Result<T, E>>,
+    {
+        let mut valid = vec![];
+        let mut report = BatchReport::default();
+        for (row, value) in rows.into_iter().enumerate() {
+            report.rows += 1;
+            match value {
+                Ok(value) => match value.validate() {
+                    Ok(()) => valid.push(value),
+                    Err(errors) => report.add(row, &errors),
+                },
+                Err(error) => {
+                    report.invalid_rows += 1;
+                    report.errors.push(RowError {
+                        row,
+                        path: String::new(),
+                        code: DESERIALIZE_CODE.to_string(),
+                        message: Some(error.to_string()),
+                    });
+                }
+            }
+            if self.max_invalid_rows.is_some_and(|max| report.invalid_rows > max) {
+                report.aborted = true;
+                break;
+            }
+        }
+        (valid, report)
+    }
+}
@@ -0,0 +1,149 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::types::ValidationError;
+
+/// The codes of the errors of the built-in validators, to match on them instead of comparing
+/// strings. Other codes, like the ones set with `code = "..."`, prefixed with `code_prefix` or
+/// returned by custom functions, are `Custom`.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    Email,
+    Url,
+    Length,
+    Range,
+    Percentage,
+    Ratio,
+    BitsWithin,
+    MustMatch,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    Before,
+    After,
+    Contains,
+    DoesNotContain,
+    Regex,
+    CreditCard,
+    Phone,
+    NonControlCharacter,
+    Required,
+    Rule,
+    /// The maximum nesting depth was reached, see `set_max_nesting_depth`
+    MaxDepth,
+    Custom(Cow<'static, str>),
+}
+
+impl ErrorCode {
+    /// Returns the code for that string, `Custom` if it isn't the code of a built-in validator
+    pub fn from_code(code: &str) -> ErrorCode {
+        match code {
+            "email" => ErrorCode::Email,
+            "url" => ErrorCode::Url,
+            "length" => ErrorCode::Length,
+            "range" => ErrorCode::Range,
+            "percentage" => ErrorCode::Percentage,
+            "ratio" => ErrorCode::Ratio,
+            "bits_within" => ErrorCode::BitsWithin,
+            "must_match" => ErrorCode::MustMatch,
+            "greater_than" => ErrorCode::GreaterThan,
+            "greater_than_or_equal" => ErrorCode::GreaterThanOrEqual,
+            "less_than" => ErrorCode::LessThan,
+            "less_than_or_equal" => ErrorCode::LessThanOrEqual,
+            "before" => ErrorCode::Before,
+            "after" => ErrorCode::After,
+            "contains" => ErrorCode::Contains,
+            "does_not_contain" => ErrorCode::DoesNotContain,
+            "regex" => ErrorCode::Regex,
+            "credit_card" => ErrorCode::CreditCard,
+            "phone" => ErrorCode::Phone,
+            "non_control_character" => ErrorCode::NonControlCharacter,
+            "required" => ErrorCode::Required,
+            "rule" => ErrorCode::Rule,
+            "max_depth" => ErrorCode::MaxDepth,
+            _ => ErrorCode::Custom(Cow::Owned(code.to_string())),
+        }
+    }
+
+    /// The code as stored in `ValidationError::code`
+    pub fn as_str(&self) -> &str {
+        match self {
+            ErrorCode::Custom(code) => code,
+            builtin => builtin.builtin_code().unwrap(),
+        }
+    }
+
+    /// The code of a built-in validator, `None` for `Custom`
+    fn builtin_code(&self) -> Option<&'static str> {
+        match self {
+            ErrorCode::Email => Some("email"),
+            ErrorCode::Url => Some("url"),
+            ErrorCode::Length => Some("length"),
+            ErrorCode::Range => Some("range"),
+            ErrorCode::Percentage => Some("percentage"),
+            ErrorCode::Ratio => Some("ratio"),
+            ErrorCode::BitsWithin => Some("bits_within"),
+            ErrorCode::MustMatch => Some("must_match"),
+            ErrorCode::GreaterThan => Some("greater_than"),
+            ErrorCode::GreaterThanOrEqual => Some("greater_than_or_equal"),
+            ErrorCode::LessThan => Some("less_than"),
+            ErrorCode::LessThanOrEqual => Some("less_than_or_equal"),
+            ErrorCode::Before => Some("before"),
+            ErrorCode::After => Some("after"),
+            ErrorCode::Contains => Some("contains"),
+            ErrorCode::DoesNotContain => Some("does_not_contain"),
+            ErrorCode::Regex => Some("regex"),
+            ErrorCode::CreditCard => Some("credit_card"),
+            ErrorCode::Phone => Some("phone"),
+            ErrorCode::NonControlCharacter => Some("non_control_character"),
+            ErrorCode::Required => Some("required"),
+            ErrorCode::Rule => Some("rule"),
+            ErrorCode::MaxDepth => Some("max_depth"),
+            ErrorCode::Custom(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+impl From<ErrorCode> for Cow<'static, str> {
+    fn from(code: ErrorCode) -> Cow<'static, str> {
+        match (code.builtin_code(), code) {
+            (Some(builtin), _) => Cow::Borrowed(builtin),
+            (None, ErrorCode::Custom(code)) => code,
+            (None, _) => unreachable!("only `Custom` isn't a built-in code"),
+        }
+    }
+}
+
+impl PartialEq<&str> for ErrorCode {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<ErrorCode> for Cow<'static, str> {
+    fn eq(&self, other: &ErrorCode) -> bool {
+        self.as_ref() == other.as_str()
+    }
+}
+
+impl ValidationError {
+    /// Creates an error with that code, see [`ErrorCode`]
+    pub fn with_code(code: ErrorCode) -> ValidationError {
+        let mut error = ValidationError::new("");
+        error.code = code.into();
+        error
+    }
+
+    /// The code of the error as an [`ErrorCode`], to match on it
+    pub fn error_code(&self) -> ErrorCode {
+        ErrorCode::from_code(&self.code)
+    }
+}
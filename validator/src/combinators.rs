@@ -0,0 +1,210 @@
+//! Rules composing the standalone validators, to reuse a combination of them outside of the
+//! derive, or in a `custom` validator:
+//!
+//! ```
+//! use validator::combinators::{all_of, map_err_code, not, predicate, Rule};
+//! use validator::validate_length;
+//!
+//! fn safe_display_name() -> impl Rule<str> {
+//!     map_err_code(
+//!         all_of((
+//!             predicate("length", |name: &str| validate_length(name, Some(1), Some(32), None)),
+//!             predicate("ascii", |name: &str| name.is_ascii()),
+//!             not("reserved", predicate("admin", |name: &str| name.eq_ignore_ascii_case("admin"))),
+//!         )),
+//!         "display_name",
+//!     )
+//! }
+//!
+//! assert!(safe_display_name().check("Bob").is_ok());
+//! assert_eq!(safe_display_name().check("ADMIN").unwrap_err().code, "display_name");
+//! ```
+//!
+//! The trait is unrelated to [`crate::Rule`], which describes the derived validators as data.
+
+use std::borrow::Cow;
+
+use crate::types::ValidationError;
+
+/// Checks a value, failing with a single error. It is implemented by the functions and closures
+/// with the signature of the `custom` validators, `Fn(&T) -> Result<(), ValidationError>`.
+pub trait Rule<T: ?Sized> {
+    fn check(&self, value: &T) -> Result<(), ValidationError>;
+}
+
+impl<T: ?Sized, F> Rule<T> for F
+where
+    F: Fn(&T) -> Result<(), ValidationError>,
+{
+    fn check(&self, value: &T) -> Result<(), ValidationError> {
+        self(value)
+    }
+}
+
+impl<T: ?Sized> Rule<T> for Box<dyn Rule<T> + Send + Sync> {
+    fn check(&self, value: &T) -> Result<(), ValidationError> {
+        (**self).check(value)
+    }
+}
+
+/// Several rules checked in order, as a tuple or a `Vec` of boxed rules
+pub trait Rules<T: ?Sized> {
+    /// The first error of the rules, if any
+    fn check_all(&self, value: &T) -> Result<(), ValidationError>;
+
+    /// Whether one of the rules passes, the error of the first one otherwise
+    fn check_any(&self, value: &T) -> Result<(), ValidationError>;
+}
+
+macro_rules! impl_rules_for_tuple {
+    ($first:ident $($rule:ident)*) => {
+        #[allow(non_snake_case)]
+        impl<T: ?Sized, $first: Rule<T>, $($rule: Rule<T>),*> Rules<T> for ($first, $($rule,)*) {
+            fn check_all(&self, value: &T) -> Result<(), ValidationError> {
+                let ($first, $($rule,)*) = self;
+                $first.check(value)?;
+                $($rule.check(value)?;)*
+                Ok(())
+            }
+
+            fn check_any(&self, value: &T) -> Result<(), ValidationError> {
+                let ($first, $($rule,)*) = self;
+                let error = match $first.check(value) {
+                    Ok(()) => return Ok(()),
+                    Err(error) => error,
+                };
+                $(if $rule.check(value).is_ok() {
+                    return Ok(());
+                })*
+                Err(error)
+            }
+        }
+    };
+}
+
+impl_rules_for_tuple!(A);
+impl_rules_for_tuple!(A B);
+impl_rules_for_tuple!(A B C);
+impl_rules_for_tuple!(A B C D);
+impl_rules_for_tuple!(A B C D E);
+impl_rules_for_tuple!(A B C D E F);
+impl_rules_for_tuple!(A B C D E F G);
+impl_rules_for_tuple!(A B C D E F G H);
+
+impl<T: ?Sized, R: Rule<T>> Rules<T> for Vec<R> {
+    fn check_all(&self, value: &T) -> Result<(), ValidationError> {
+        self.iter().try_for_each(|rule| rule.check(value))
+    }
+
+    /// An empty list passes
+    fn check_any(&self, value: &T) -> Result<(), ValidationError> {
+        let mut first_error = None;
+        for rule in self {
+            match rule.check(value) {
+                Ok(()) => return Ok(()),
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+/// A rule from a function returning whether the value is valid, like the `validate_*` ones,
+/// failing with an error with that code
+pub fn predicate<T: ?Sized, F>(code: &'static str, valid: F) -> impl Rule<T>
+where
+    F: Fn(&T) -> bool,
+{
+    move |value: &T| match valid(value) {
+        true => Ok(()),
+        false => Err(ValidationError::new(code)),
+    }
+}
+
+/// Passes when all the rules pass, failing with the first error
+pub fn all_of<T: ?Sized, R: Rules<T>>(rules: R) -> impl Rule<T> {
+    move |value: &T| rules.check_all(value)
+}
+
+/// Passes when one of the rules passes, failing with the error of the first rule
+pub fn any_of<T: ?Sized, R: Rules<T>>(rules: R) -> impl Rule<T> {
+    move |value: &T| rules.check_any(value)
+}
+
+/// Passes when the rule fails, failing with an error with that code
+pub fn not<T: ?Sized, R: Rule<T>>(code: &'static str, rule: R) -> impl Rule<T> {
+    move |value: &T| match rule.check(value) {
+        Ok(()) => Err(ValidationError::new(code)),
+        Err(_) => Ok(()),
+    }
+}
+
+/// Checks the value of an `Option` with the rule, `None` passing
+pub fn optional<T, R: Rule<T>>(rule: R) -> impl Rule<Option<T>> {
+    move |value: &Option<T>| match value {
+        Some(value) => rule.check(value),
+        None => Ok(()),
+    }
+}
+
+/// Replaces the code of the errors of the rule, keeping their message and params
+pub fn map_err_code<T: ?Sized, R: Rule<T>>(
+    rule: R,
+    code: impl Into<Cow<'static, str>>,
+) -> impl Rule<T> {
+    let code = code.into();
+    move |value: &T| {
+        rule.check(value).map_err(|mut error| {
+            error.code = code.clone();
+            error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate_email;
+
+    fn not_empty(value: &str) -> Result<(), ValidationError> {
+        match value.is_empty() {
+            true => Err(ValidationError::new("empty")),
+            false => Ok(()),
+        }
+    }
+
+    #[test]
+    fn test_all_of_fails_with_the_first_error() {
+        let rule = all_of((not_empty, predicate("email", |value: &str| validate_email(value))));
+        assert!(rule.check("bob@example.com").is_ok());
+        assert_eq!(rule.check("").unwrap_err().code, "empty");
+        assert_eq!(rule.check("bob").unwrap_err().code, "email");
+    }
+
+    #[test]
+    fn test_any_of_passes_when_one_rule_passes() {
+        let rule = any_of((
+            predicate("email", |value: &str| validate_email(value)),
+            predicate("phone", |value: &str| value.starts_with('+')),
+        ));
+        assert!(rule.check("bob@example.com").is_ok());
+        assert!(rule.check("+33").is_ok());
+        assert_eq!(rule.check("bob").unwrap_err().code, "email");
+
+        let rules: Vec<Box<dyn Rule<str> + Send + Sync>> = vec![];
+        assert!(any_of(rules).check("bob").is_ok());
+    }
+
+    #[test]
+    fn test_not_optional_and_map_err_code() {
+        let rule = optional(not("reserved", predicate("admin", |name: &String| name == "admin")));
+        assert!(rule.check(&None).is_ok());
+        assert!(rule.check(&Some("bob".to_string())).is_ok());
+        assert_eq!(rule.check(&Some("admin".to_string())).unwrap_err().code, "reserved");
+
+        let rule = map_err_code(not_empty, "name");
+        assert_eq!(rule.check("").unwrap_err().code, "name");
+    }
+}
@@ -0,0 +1,102 @@
+use std::cell::Cell;
+
+use crate::types::ValidationErrors;
+
+/// Limits on a validation run with `Validate::validate_with_config`, for large payloads where
+/// collecting every error would build a huge `ValidationErrors`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ValidationConfig {
+    /// The maximum number of errors collected, nested ones included. The validation stops as soon
+    /// as it is reached, a limit of 0 is treated as 1.
+    pub max_errors: Option<usize>,
+    /// Stops the validation at the first nested struct, or element of a nested list or map, that
+    /// is invalid
+    pub stop_on_first_nested_failure: bool,
+}
+
+impl ValidationConfig {
+    pub fn new() -> ValidationConfig {
+        ValidationConfig::default()
+    }
+
+    pub fn max_errors(mut self, max_errors: usize) -> ValidationConfig {
+        self.max_errors = Some(max_errors);
+        self
+    }
+
+    pub fn stop_on_first_nested_failure(mut self, stop: bool) -> ValidationConfig {
+        self.stop_on_first_nested_failure = stop;
+        self
+    }
+}
+
+thread_local! {
+    static CONFIG: Cell<Option<ValidationConfig>> = const { Cell::new(None) };
+    static ERROR_COUNT: Cell<usize> = const { Cell::new(0) };
+    static STOPPED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether the validation run with a config has reached one of its limits, the generated code
+/// then returns the errors found so far
+pub fn should_stop() -> bool {
+    STOPPED.with(|stopped| stopped.get())
+}
+
+/// Whether the validation is run with a config
+#[cfg(feature = "parallel")]
+pub(crate) fn is_configured() -> bool {
+    CONFIG.with(|config| config.get()).is_some()
+}
+
+/// Counts an error added to a `ValidationErrors`, returns whether it is kept: once `max_errors`
+/// is reached the other errors are dropped
+pub(crate) fn count_error() -> bool {
+    let max_errors = match CONFIG.with(|config| config.get()).and_then(|c| c.max_errors) {
+        Some(max_errors) => max_errors.max(1),
+        None => return true,
+    };
+    let count = ERROR_COUNT.with(|count| count.get());
+    if count >= max_errors {
+        return false;
+    }
+    ERROR_COUNT.with(|c| c.set(count + 1));
+    if count + 1 == max_errors {
+        STOPPED.with(|stopped| stopped.set(true));
+    }
+    true
+}
+
+/// Records the result of a nested validation, which stops the validation if it failed and the
+/// config has `stop_on_first_nested_failure`
+pub(crate) fn nested_done(result: &Result<(), ValidationErrors>) {
+    if result.as_ref().is_err_and(ValidationErrors::is_blocking)
+        && CONFIG.with(|config| config.get()).is_some_and(|c| c.stop_on_first_nested_failure)
+    {
+        STOPPED.with(|stopped| stopped.set(true));
+    }
+}
+
+/// Restores the state of the enclosing validation when the one with a config is done, even if it
+/// panics
+struct ConfigGuard(Option<ValidationConfig>, usize, bool);
+
+impl Drop for ConfigGuard {
+    fn drop(&mut self) {
+        CONFIG.with(|config| config.set(self.0));
+        ERROR_COUNT.with(|count| count.set(self.1));
+        STOPPED.with(|stopped| stopped.set(self.2));
+    }
+}
+
+/// Runs a validation with the limits of the config
+pub(crate) fn with_config<F>(config: &ValidationConfig, validate: F) -> Result<(), ValidationErrors>
+where
+    F: FnOnce() -> Result<(), ValidationErrors>,
+{
+    let _guard = ConfigGuard(
+        CONFIG.with(|c| c.replace(Some(*config))),
+        ERROR_COUNT.with(|count| count.replace(0)),
+        STOPPED.with(|stopped| stopped.replace(false)),
+    );
+    validate()
+}
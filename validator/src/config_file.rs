@@ -0,0 +1,95 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+
+use crate::display_impl::display_error;
+use crate::traits::Validate;
+use crate::types::ValidationErrors;
+
+/// Why a config file couldn't be loaded by [`load_config`]
+#[derive(Debug)]
+pub enum ConfigFileError {
+    /// The file couldn't be read
+    Io { file: PathBuf, error: io::Error },
+    /// The file isn't valid JSON or TOML, or doesn't have the shape of the config
+    Parse { file: PathBuf, message: String },
+    /// The config was deserialized but is invalid
+    Invalid { file: PathBuf, errors: ValidationErrors },
+}
+
+impl ConfigFileError {
+    /// The file the error is about
+    pub fn file(&self) -> &Path {
+        match self {
+            ConfigFileError::Io { file, .. }
+            | ConfigFileError::Parse { file, .. }
+            | ConfigFileError::Invalid { file, .. } => file,
+        }
+    }
+}
+
+/// Shows the errors of an invalid config one per line with the file and the key they are about,
+/// like `config.toml: servers[0].port: Not a port (range)`
+impl fmt::Display for ConfigFileError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigFileError::Io { file, error } => {
+                write!(fmt, "{}: can't be read: {}", file.display(), error)
+            }
+            ConfigFileError::Parse { file, message } => {
+                write!(fmt, "{}: {}", file.display(), message)
+            }
+            ConfigFileError::Invalid { file, errors } => {
+                for (idx, (path, error)) in errors.iter().enumerate() {
+                    if idx > 0 {
+                        writeln!(fmt)?;
+                    }
+                    display_error(fmt, &format_args!("{}: {}", file.display(), path), error)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for ConfigFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigFileError::Io { error, .. } => Some(error),
+            ConfigFileError::Invalid { errors, .. } => Some(errors),
+            ConfigFileError::Parse { .. } => None,
+        }
+    }
+}
+
+/// Reads a config file, deserializes it as a `T` and validates it. The format is picked from the
+/// extension of the file: `.toml` for TOML and anything else for JSON.
+///
+/// ```ignore
+/// let config: ServerConfig = load_config("config.toml")?;
+/// ```
+pub fn load_config<T>(file: impl AsRef<Path>) -> Result<T, ConfigFileError>
+where
+    T: DeserializeOwned + Validate,
+{
+    let file = file.as_ref().to_path_buf();
+    let source = match std::fs::read_to_string(&file) {
+        Ok(source) => source,
+        Err(error) => return Err(ConfigFileError::Io { file, error }),
+    };
+    let config: Result<T, String> = match file.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => toml::from_str(&source).map_err(|error| error.to_string()),
+        _ => serde_json::from_str(&source).map_err(|error| error.to_string()),
+    };
+    let config = match config {
+        Ok(config) => config,
+        Err(message) => return Err(ConfigFileError::Parse { file, message }),
+    };
+    match config.validate() {
+        Ok(()) => Ok(config),
+        Err(errors) => Err(ConfigFileError::Invalid { file, errors }),
+    }
+}
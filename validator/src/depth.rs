@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::code::ErrorCode;
+use crate::types::{ValidationError, ValidationErrors};
+
+/// The default maximum nesting depth, the same as the recursion limit of `serde_json`
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 128;
+
+static MAX_NESTING_DEPTH: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_NESTING_DEPTH);
+
+thread_local! {
+    static NESTING_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Sets how many levels of nested structs are validated before giving up with a `max_depth`
+/// error, so recursive types like `Option<Box<Self>>` trees can't overflow the stack
+pub fn set_max_nesting_depth(depth: usize) {
+    MAX_NESTING_DEPTH.store(depth, Ordering::Relaxed);
+}
+
+/// The maximum nesting depth of the validation, `DEFAULT_MAX_NESTING_DEPTH` unless changed with
+/// `set_max_nesting_depth`
+#[must_use]
+pub fn max_nesting_depth() -> usize {
+    MAX_NESTING_DEPTH.load(Ordering::Relaxed)
+}
+
+/// How many nested structs deep the current validation is, 0 for the value being validated
+pub(crate) fn nesting_depth() -> usize {
+    NESTING_DEPTH.with(|depth| depth.get())
+}
+
+/// Decrements the depth when leaving a nested validation, even if it panics
+struct DepthGuard;
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        NESTING_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Restores the depth of the thread when a validation run at another depth is done
+#[cfg(feature = "parallel")]
+struct RestoreDepthGuard(usize);
+
+#[cfg(feature = "parallel")]
+impl Drop for RestoreDepthGuard {
+    fn drop(&mut self) {
+        NESTING_DEPTH.with(|depth| depth.set(self.0));
+    }
+}
+
+/// Runs a validation at the depth of the one that started it on another thread
+#[cfg(feature = "parallel")]
+pub(crate) fn at_depth<F, R>(depth: usize, validate: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    let _guard = RestoreDepthGuard(NESTING_DEPTH.with(|d| d.replace(depth)));
+    validate()
+}
+
+/// Runs the validation of a nested value one level deeper. When the maximum depth is reached the
+/// value isn't validated and a `max_depth` error is returned in its `__all__` instead.
+pub(crate) fn validate_deeper<F>(validate: F) -> Result<(), ValidationErrors>
+where
+    F: FnOnce() -> Result<(), ValidationErrors>,
+{
+    let max_depth = max_nesting_depth();
+    let depth = NESTING_DEPTH.with(|depth| depth.get());
+    if depth >= max_depth {
+        let mut err = ValidationError::with_code(ErrorCode::MaxDepth);
+        err.add_param(Cow::from("max_depth"), &max_depth);
+        let mut errors = ValidationErrors::new();
+        errors.add("__all__", err);
+        return Err(errors);
+    }
+
+    NESTING_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let _guard = DepthGuard;
+    validate()
+}
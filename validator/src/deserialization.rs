@@ -0,0 +1,102 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+
+use crate::types::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+/// The serialized errors before knowing which kind each object is, keeping the order of the keys
+enum Serialized {
+    Errors(Vec<ValidationError>),
+    Object(Vec<(String, Serialized)>),
+}
+
+impl<'de> Deserialize<'de> for Serialized {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SerializedVisitor)
+    }
+}
+
+struct SerializedVisitor;
+
+impl<'de> Visitor<'de> for SerializedVisitor {
+    type Value = Serialized;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map of fields to their errors or a list of errors")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Serialized, A::Error> {
+        let mut errors = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(error) = seq.next_element()? {
+            errors.push(error);
+        }
+        Ok(Serialized::Errors(errors))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Serialized, A::Error> {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Serialized::Object(entries))
+    }
+}
+
+fn into_errors<E: de::Error>(entries: Vec<(String, Serialized)>) -> Result<ValidationErrors, E> {
+    let mut errors = ValidationErrors::new();
+    let mut seen = HashSet::new();
+    for (field, serialized) in entries {
+        if !seen.insert(field.clone()) {
+            return Err(E::custom(format_args!("duplicate field `{}`", field)));
+        }
+        errors.insert_kind(field, into_kind(serialized)?);
+    }
+    Ok(errors)
+}
+
+/// The kinds serialize the same way except the fields: an object with a list of errors is a
+/// struct, one with only indices as keys is a list and the others are structs too, since a map
+/// of nested structs can't be told apart from a struct of nested structs
+fn into_kind<E: de::Error>(serialized: Serialized) -> Result<ValidationErrorsKind, E> {
+    let entries = match serialized {
+        Serialized::Errors(errors) => return Ok(ValidationErrorsKind::Field(errors)),
+        Serialized::Object(entries) => entries,
+    };
+
+    let has_errors = entries.iter().any(|(_, value)| matches!(value, Serialized::Errors(_)));
+    let is_list = !has_errors
+        && !entries.is_empty()
+        && entries.iter().all(|(key, _)| key.parse::<usize>().is_ok());
+    if !is_list {
+        return Ok(ValidationErrorsKind::Struct(Box::new(into_errors(entries)?)));
+    }
+
+    let mut list = BTreeMap::new();
+    for (key, value) in entries {
+        let index = key.parse::<usize>().map_err(E::custom)?;
+        let entry = match value {
+            Serialized::Object(entries) => into_errors(entries)?,
+            Serialized::Errors(_) => unreachable!("the lists only have nested errors"),
+        };
+        if list.insert(index, Box::new(entry)).is_some() {
+            return Err(E::custom(format_args!("duplicate index `{}`", index)));
+        }
+    }
+    Ok(ValidationErrorsKind::List(list))
+}
+
+/// Deserializes what the `Serialize` implementation produces, in the shape
+/// [`ErrorsShape::Nested`](crate::ErrorsShape::Nested). Serializing the deserialized errors gives
+/// back the same output, but the errors of nested maps are deserialized as the errors of nested
+/// structs since they can't be told apart.
+impl<'de> Deserialize<'de> for ValidationErrors {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Serialized::deserialize(deserializer)? {
+            Serialized::Object(entries) => into_errors(entries),
+            Serialized::Errors(_) => {
+                Err(de::Error::custom("expected a map of fields to their errors, found a list"))
+            }
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+
+use crate::types::ValidationErrors;
+
+/// An error of [`Diagnostics`], with the part of the source it is about when it could be found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+    /// The JSON Pointer of the field, like `/servers/0/port`
+    pub pointer: String,
+    /// The message of the error, or its code if it doesn't have one
+    pub message: String,
+    pub code: String,
+    /// The byte range of the value in the source. A missing field points at the object it
+    /// should be in.
+    pub span: Option<Range<usize>>,
+}
+
+/// The errors of a validated JSON document located in its source, to show them like a compiler
+/// would: the line of each error with its value underlined. See [`ValidationErrors::diagnostics`].
+#[derive(Debug, Clone)]
+pub struct Diagnostics<'a> {
+    errors: &'a ValidationErrors,
+    source: &'a str,
+    name: Option<&'a str>,
+}
+
+impl ValidationErrors {
+    /// Returns the diagnostics of the errors of a value deserialized from that JSON source, whose
+    /// `Display` shows each error with the line of the source it is about
+    pub fn diagnostics<'a>(&'a self, source: &'a str) -> Diagnostics<'a> {
+        Diagnostics { errors: self, source, name: None }
+    }
+}
+
+impl<'a> Diagnostics<'a> {
+    /// Sets the name of the source shown with the positions, like the path of the file
+    pub fn with_name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Returns the errors in the order of [`ValidationErrors::iter`] with their spans
+    pub fn labels(&self) -> Vec<Label> {
+        let spans = locate_values(self.source);
+        self.errors
+            .iter()
+            .map(|(path, error)| {
+                let pointer = path.to_pointer();
+                // A missing field points at the closest parent that is in the source
+                let mut span = None;
+                let mut parent = pointer.as_str();
+                loop {
+                    if let Some(found) = spans.get(parent) {
+                        span = Some(found.clone());
+                        break;
+                    }
+                    match parent.rfind('/') {
+                        Some(index) if !parent.is_empty() => parent = &parent[..index],
+                        _ => break,
+                    }
+                }
+                Label {
+                    message: error.message.as_deref().unwrap_or(&error.code).to_string(),
+                    code: error.code.to_string(),
+                    pointer,
+                    span,
+                }
+            })
+            .collect()
+    }
+
+    /// The line and column of a byte offset, both starting at 1, and the offset of its line
+    fn position(&self, offset: usize) -> (usize, usize, usize) {
+        let before = &self.source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        (line, before[line_start..].chars().count() + 1, line_start)
+    }
+}
+
+/// Shows each error like `error: Not a port (port)` followed by its position and the line of
+/// the source with the value underlined
+impl fmt::Display for Diagnostics<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.name.unwrap_or("<input>");
+        for (idx, label) in self.labels().iter().enumerate() {
+            if idx > 0 {
+                writeln!(fmt)?;
+            }
+            if label.message == label.code {
+                writeln!(fmt, "error: {}", label.code)?;
+            } else {
+                writeln!(fmt, "error: {} ({})", label.message, label.code)?;
+            }
+
+            let span = match label.span {
+                Some(ref span) => span,
+                None => {
+                    writeln!(fmt, " --> {}#{}", name, label.pointer)?;
+                    continue;
+                }
+            };
+            let (line, column, line_start) = self.position(span.start);
+            let text = self.source[line_start..].lines().next().unwrap_or("");
+            // Multiline values are underlined until the end of their first line
+            let underlined = self.source[span.clone()].lines().next().unwrap_or("").chars().count();
+            let gutter = " ".repeat(line.to_string().len());
+
+            writeln!(fmt, "{}--> {}:{}:{}", gutter, name, line, column)?;
+            writeln!(fmt, "{} |", gutter)?;
+            writeln!(fmt, "{} | {}", line, text)?;
+            writeln!(
+                fmt,
+                "{} | {}{}",
+                gutter,
+                " ".repeat(column - 1),
+                "^".repeat(underlined.max(1))
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Finds the span of every value of a JSON document, keyed by JSON Pointer. It stops at the
+/// first syntax error, the values after it are not located.
+fn locate_values(source: &str) -> HashMap<String, Range<usize>> {
+    let mut locator = Locator { source: source.as_bytes(), position: 0, spans: HashMap::new() };
+    let _ = locator.value(&mut String::new());
+    locator.spans
+}
+
+struct Locator<'a> {
+    source: &'a [u8],
+    position: usize,
+    spans: HashMap<String, Range<usize>>,
+}
+
+impl Locator<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.source.get(self.position).is_some_and(u8::is_ascii_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.source.get(self.position).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        if self.peek()? == byte {
+            self.position += 1;
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    /// Records the span of the value at the pointer, and of all the values inside of it
+    fn value(&mut self, pointer: &mut String) -> Option<()> {
+        let start = match self.peek() {
+            Some(_) => self.position,
+            None => return None,
+        };
+        match self.source[start] {
+            b'{' => self.object(pointer)?,
+            b'[' => self.array(pointer)?,
+            b'"' => {
+                self.string()?;
+            }
+            _ => {
+                while self
+                    .source
+                    .get(self.position)
+                    .is_some_and(|b| !b",]}".contains(b) && !b.is_ascii_whitespace())
+                {
+                    self.position += 1;
+                }
+                // Numbers, booleans and null
+                serde_json::from_slice::<serde_json::Value>(&self.source[start..self.position])
+                    .ok()?;
+            }
+        }
+        self.spans.insert(pointer.clone(), start..self.position);
+        Some(())
+    }
+
+    fn object(&mut self, pointer: &mut String) -> Option<()> {
+        self.expect(b'{')?;
+        if self.peek()? == b'}' {
+            self.position += 1;
+            return Some(());
+        }
+        loop {
+            let key = self.string()?;
+            let key = serde_json::from_slice::<String>(&self.source[key]).ok()?;
+            self.expect(b':')?;
+            let len = pointer.len();
+            pointer.push('/');
+            pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+            self.value(pointer)?;
+            pointer.truncate(len);
+            match self.peek()? {
+                b',' => self.position += 1,
+                b'}' => {
+                    self.position += 1;
+                    return Some(());
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    fn array(&mut self, pointer: &mut String) -> Option<()> {
+        self.expect(b'[')?;
+        if self.peek()? == b']' {
+            self.position += 1;
+            return Some(());
+        }
+        for index in 0.. {
+            let len = pointer.len();
+            pointer.push('/');
+            pointer.push_str(&index.to_string());
+            self.value(pointer)?;
+            pointer.truncate(len);
+            match self.peek()? {
+                b',' => self.position += 1,
+                b']' => {
+                    self.position += 1;
+                    return Some(());
+                }
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    /// Skips a string, returning its span with the quotes
+    fn string(&mut self) -> Option<Range<usize>> {
+        self.expect(b'"')?;
+        let start = self.position - 1;
+        loop {
+            match *self.source.get(self.position)? {
+                b'\\' => self.position += 2,
+                b'"' => {
+                    self.position += 1;
+                    return Some(start..self.position);
+                }
+                _ => self.position += 1,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::locate_values;
+
+    #[test]
+    fn can_locate_values() {
+        let source = r#"{"a": [1, {"b~/c": "x\"y"}], "d": null}"#;
+        let spans = locate_values(source);
+        assert_eq!(&source[spans[""].clone()], source);
+        assert_eq!(&source[spans["/a"].clone()], r#"[1, {"b~/c": "x\"y"}]"#);
+        assert_eq!(&source[spans["/a/0"].clone()], "1");
+        assert_eq!(&source[spans["/a/1/b~0~1c"].clone()], r#""x\"y""#);
+        assert_eq!(&source[spans["/d"].clone()], "null");
+    }
+
+    #[test]
+    fn stops_at_syntax_errors() {
+        let spans = locate_values(r#"{"a": 1, "b": }"#);
+        assert!(spans.contains_key("/a"));
+        assert!(!spans.contains_key("/b"));
+    }
+}
@@ -1,6 +1,6 @@
-use std::fmt::{self, Write};
+use std::fmt;
 
-use crate::{ValidationError, ValidationErrors, ValidationErrorsKind};
+use crate::{ValidationError, ValidationErrors};
 
 impl fmt::Display for ValidationError {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12,63 +12,69 @@ impl fmt::Display for ValidationError {
     }
 }
 
-fn display_errors(
+/// Writes an error of a field as `path: message (code)`, or `path: code (name: value, ...)` with
+/// its params sorted by name when it has no message
+pub(crate) fn display_error(
     fmt: &mut fmt::Formatter<'_>,
-    errs: &ValidationErrorsKind,
-    path: &str,
+    path: &dyn fmt::Display,
+    err: &ValidationError,
 ) -> fmt::Result {
-    fn display_struct(
-        fmt: &mut fmt::Formatter<'_>,
-        errs: &ValidationErrors,
-        path: &str,
-    ) -> fmt::Result {
-        let mut full_path = String::new();
-        write!(&mut full_path, "{}.", path)?;
-        let base_len = full_path.len();
-        for (path, err) in errs.errors() {
-            write!(&mut full_path, "{}", path)?;
-            display_errors(fmt, err, &full_path)?;
-            full_path.truncate(base_len);
-        }
-        Ok(())
+    if let Some(msg) = err.message.as_ref() {
+        return write!(fmt, "{}: {} ({})", path, msg, err.code);
     }
 
-    match errs {
-        ValidationErrorsKind::Field(errs) => {
-            write!(fmt, "{}: ", path)?;
-            let len = errs.len();
-            for (idx, err) in errs.iter().enumerate() {
-                if idx + 1 == len {
-                    write!(fmt, "{}", err)?;
-                } else {
-                    write!(fmt, "{}, ", err)?;
-                }
+    write!(fmt, "{}: {}", path, err.code)?;
+    if !err.params.is_empty() {
+        let mut params = err.params.iter().collect::<Vec<_>>();
+        params.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        write!(fmt, " (")?;
+        for (idx, (name, value)) in params.into_iter().enumerate() {
+            if idx > 0 {
+                write!(fmt, ", ")?;
             }
-            Ok(())
+            write!(fmt, "{}: {}", name, value)?;
         }
-        ValidationErrorsKind::Struct(errs) => display_struct(fmt, errs, path),
-        ValidationErrorsKind::List(errs) => {
-            let mut full_path = String::new();
-            write!(&mut full_path, "{}", path)?;
-            let base_len = full_path.len();
-            for (idx, err) in errs.iter() {
-                write!(&mut full_path, "[{}]", idx)?;
-                display_struct(fmt, err, &full_path)?;
-                full_path.truncate(base_len);
-            }
-            Ok(())
+        write!(fmt, ")")?;
+    }
+    Ok(())
+}
+
+fn display_errors(
+    fmt: &mut fmt::Formatter<'_>,
+    errs: &ValidationErrors,
+    separator: &str,
+) -> fmt::Result {
+    for (idx, (path, err)) in errs.iter().enumerate() {
+        if idx > 0 {
+            write!(fmt, "{}", separator)?;
         }
+        display_error(fmt, &path, err)?;
     }
+    Ok(())
 }
 
+/// Shows one error per line with the path of its field, like `address.city: Too short (length)`.
+/// The fields are in the order of `ValidationErrors::iter_fields`.
 impl fmt::Display for ValidationErrors {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (idx, (path, err)) in self.errors().iter().enumerate() {
-            display_errors(fmt, err, path)?;
-            if idx + 1 < self.errors().len() {
-                writeln!(fmt)?;
-            }
-        }
-        Ok(())
+        display_errors(fmt, self, "\n")
+    }
+}
+
+/// Shows all the errors on a single line separated by `; `, see
+/// [`ValidationErrors::display_compact`]
+#[derive(Debug, Clone, Copy)]
+pub struct CompactDisplay<'a>(&'a ValidationErrors);
+
+impl fmt::Display for CompactDisplay<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_errors(fmt, self.0, "; ")
+    }
+}
+
+impl ValidationErrors {
+    /// Returns a value displaying the errors on a single line, for logs
+    pub fn display_compact(&self) -> CompactDisplay<'_> {
+        CompactDisplay(self)
     }
 }
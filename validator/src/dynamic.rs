@@ -0,0 +1,664 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use crate::json_schema::as_integer;
+#[cfg(any(feature = "forms", feature = "test-strategies"))]
+use crate::rules::Rule;
+use crate::types::{ValidationError, ValidationErrors};
+#[cfg(feature = "card")]
+use crate::validation::cards::validate_credit_card;
+use crate::validation::check::Numeric;
+use crate::validation::email::validate_email;
+#[cfg(feature = "unic")]
+use crate::validation::non_control_character::validate_non_control_character;
+#[cfg(feature = "phone")]
+use crate::validation::phone::validate_phone;
+use crate::validation::range::{validate_range, validate_range_step};
+use crate::validation::urls::validate_url;
+use crate::{validate_bits_within, validate_percentage, validate_ratio};
+
+/// Why the rules of a [`DynamicValidator`] couldn't be loaded
+#[derive(Debug)]
+pub enum DynamicRulesError {
+    /// The rules aren't valid JSON
+    Json(serde_json::Error),
+    /// A rule isn't one of the built-in validators
+    UnknownRule { field: String, kind: String },
+    /// A rule is missing an argument or has one of the wrong type, or the rules of a field aren't
+    /// a list of rules
+    InvalidRule { field: String, reason: String },
+}
+
+impl fmt::Display for DynamicRulesError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynamicRulesError::Json(error) => write!(fmt, "The rules aren't valid JSON: {}", error),
+            DynamicRulesError::UnknownRule { field, kind } => {
+                write!(fmt, "Unknown rule `{}` on `{}`", kind, field)
+            }
+            DynamicRulesError::InvalidRule { field, reason } => {
+                write!(fmt, "Invalid rule on `{}`: {}", field, reason)
+            }
+        }
+    }
+}
+
+impl Error for DynamicRulesError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DynamicRulesError::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// What a rule checks, with its arguments
+#[derive(Debug, Clone)]
+enum Check {
+    Required,
+    Email,
+    Url,
+    Length {
+        min: Option<u64>,
+        max: Option<u64>,
+        equal: Option<u64>,
+    },
+    Range {
+        min: Option<f64>,
+        max: Option<f64>,
+        exclusive_min: Option<f64>,
+        exclusive_max: Option<f64>,
+        step: Option<f64>,
+    },
+    Percentage {
+        exclusive: bool,
+    },
+    Ratio {
+        exclusive: bool,
+    },
+    BitsWithin(u64),
+    Contains(String),
+    DoesNotContain(String),
+    Regex(Regex),
+    MustMatch(String),
+    #[cfg(feature = "card")]
+    CreditCard,
+    #[cfg(feature = "phone")]
+    Phone,
+    #[cfg(feature = "unic")]
+    NonControlCharacter,
+    Nested(DynamicValidator),
+}
+
+#[derive(Debug, Clone)]
+struct DynamicRule {
+    check: Check,
+    code: Cow<'static, str>,
+    message: Option<Cow<'static, str>>,
+    /// The arguments of the rule, added to the params of its errors
+    params: Map<String, Value>,
+}
+
+/// Validates JSON documents with rules loaded at runtime instead of derived, for the forms whose
+/// rules are defined by the users of an application. The rules are an object of fields, each
+/// with a list of the built-in validators and their arguments as in the derive:
+///
+/// ```
+/// use serde_json::json;
+/// use validator::DynamicValidator;
+///
+/// let validator = DynamicValidator::from_json(r#"{
+///     "name": [{"kind": "required"}, {"kind": "length", "min": 1, "max": 50}],
+///     "email": [{"kind": "email", "message": "Not an email"}],
+///     "address": [{"kind": "nested", "rules": {"zip": [{"kind": "regex", "pattern": "^[0-9]{5}$"}]}}]
+/// }"#).unwrap();
+///
+/// let errors = validator.validate(&json!({"email": "bob", "address": {"zip": "1"}})).unwrap_err();
+/// assert_eq!(errors.to_string(), "address.zip: regex (pattern: \"^[0-9]{5}$\", value: \"1\")\n\
+///     email: Not an email (email)\nname: required");
+/// ```
+///
+/// The validators are `required`, `email`, `url`, `length`, `range`, `percentage`, `ratio`,
+/// `bits_within`, `contains`, `does_not_contain`, `regex` with a `pattern`, `must_match` with
+/// the `other` field and, with their features, `credit_card`, `phone` and
+/// `non_control_character`. `nested` validates an object, or each object of a list, with its own
+/// `rules`; it only runs when the other rules of the field pass. Each rule can have a `code` and a
+/// `message`.
+///
+/// Like the `Option` fields of a struct, the missing fields and the nulls are only checked by
+/// `required`. A value of the wrong type for a validator, like a number for `email`, fails it.
+///
+/// The rules can also be built in code, for the documents whose shape is only known at runtime
+/// like the settings of plugins, see [`StructValidator`].
+#[derive(Debug, Clone, Default)]
+pub struct DynamicValidator {
+    fields: Vec<(String, Vec<DynamicRule>)>,
+}
+
+/// A [`DynamicValidator`] whose rules are built in code rather than loaded, with the constructors
+/// of the [`field`](crate::field) module:
+///
+/// ```
+/// use serde_json::json;
+/// use validator::field::{email, range};
+/// use validator::StructValidator;
+///
+/// let validator = StructValidator::new()
+///     .field("email", email().required())
+///     .field("age", range(18..=120));
+///
+/// assert!(validator.validate(&json!({"email": "bob@example.com", "age": 30})).is_ok());
+/// let errors = validator.validate_map([("age", 12)]).unwrap_err();
+/// assert_eq!(errors.to_string(), "email: required\nage: range (max: 120, min: 18, value: 12)");
+/// ```
+pub type StructValidator = DynamicValidator;
+
+/// The rules of a field of a [`StructValidator`], run in the order they are added. Each rule has
+/// the code and the params it has when loaded from JSON, the code and the message of the last
+/// rule added can be changed with [`FieldValidator::code`] and [`FieldValidator::message`].
+#[derive(Debug, Clone, Default)]
+pub struct FieldValidator(Vec<DynamicRule>);
+
+impl FieldValidator {
+    pub fn new() -> FieldValidator {
+        FieldValidator::default()
+    }
+
+    fn rule(mut self, kind: &'static str, check: Check, params: &[(&str, Option<Value>)]) -> Self {
+        let params = params
+            .iter()
+            .filter_map(|(name, param)| Some((name.to_string(), as_integer(param.clone()?))))
+            .collect();
+        self.0.push(DynamicRule { check, code: Cow::Borrowed(kind), message: None, params });
+        self
+    }
+
+    pub fn required(self) -> FieldValidator {
+        self.rule("required", Check::Required, &[])
+    }
+
+    pub fn email(self) -> FieldValidator {
+        self.rule("email", Check::Email, &[])
+    }
+
+    pub fn url(self) -> FieldValidator {
+        self.rule("url", Check::Url, &[])
+    }
+
+    /// The length of a string, in characters, or of a list or an object
+    pub fn length<R: RangeBounds<u64>>(self, length: R) -> FieldValidator {
+        let min = match length.start_bound() {
+            Bound::Included(min) => Some(*min),
+            Bound::Excluded(min) => Some(min + 1),
+            Bound::Unbounded => None,
+        };
+        let max = match length.end_bound() {
+            Bound::Included(max) => Some(*max),
+            Bound::Excluded(max) => Some(max.saturating_sub(1)),
+            Bound::Unbounded => None,
+        };
+        self.rule(
+            "length",
+            Check::Length { min, max, equal: None },
+            &[("min", min.map(Value::from)), ("max", max.map(Value::from))],
+        )
+    }
+
+    /// The length of a string, in characters, or of a list or an object
+    pub fn length_equal(self, equal: u64) -> FieldValidator {
+        self.rule(
+            "length",
+            Check::Length { min: None, max: None, equal: Some(equal) },
+            &[("equal", Some(Value::from(equal)))],
+        )
+    }
+
+    /// A number in the range, whose excluded bounds are the `exclusive_min` and `exclusive_max`
+    /// of the validator
+    pub fn range<T, R>(self, range: R) -> FieldValidator
+    where
+        T: Numeric,
+        R: RangeBounds<T>,
+    {
+        let (mut min, mut exclusive_min, mut max, mut exclusive_max) = (None, None, None, None);
+        match range.start_bound() {
+            Bound::Included(start) => min = Some((*start).to_f64()),
+            Bound::Excluded(start) => exclusive_min = Some((*start).to_f64()),
+            Bound::Unbounded => (),
+        }
+        match range.end_bound() {
+            Bound::Included(end) => max = Some((*end).to_f64()),
+            Bound::Excluded(end) => exclusive_max = Some((*end).to_f64()),
+            Bound::Unbounded => (),
+        }
+        self.rule(
+            "range",
+            Check::Range { min, max, exclusive_min, exclusive_max, step: None },
+            &[
+                ("min", min.map(Value::from)),
+                ("max", max.map(Value::from)),
+                ("exclusive_min", exclusive_min.map(Value::from)),
+                ("exclusive_max", exclusive_max.map(Value::from)),
+            ],
+        )
+    }
+
+    pub fn percentage(self, exclusive: bool) -> FieldValidator {
+        self.rule(
+            "percentage",
+            Check::Percentage { exclusive },
+            &[("exclusive", Some(Value::from(exclusive)))],
+        )
+    }
+
+    pub fn ratio(self, exclusive: bool) -> FieldValidator {
+        self.rule(
+            "ratio",
+            Check::Ratio { exclusive },
+            &[("exclusive", Some(Value::from(exclusive)))],
+        )
+    }
+
+    pub fn bits_within(self, mask: u64) -> FieldValidator {
+        self.rule("bits_within", Check::BitsWithin(mask), &[("mask", Some(Value::from(mask)))])
+    }
+
+    /// A string containing the needle, or an object with that key
+    pub fn contains(self, needle: &str) -> FieldValidator {
+        let param = Some(Value::from(needle));
+        self.rule("contains", Check::Contains(needle.to_string()), &[("needle", param)])
+    }
+
+    /// A string not containing the needle, or an object without that key
+    pub fn does_not_contain(self, needle: &str) -> FieldValidator {
+        let param = Some(Value::from(needle));
+        self.rule(
+            "does_not_contain",
+            Check::DoesNotContain(needle.to_string()),
+            &[("needle", param)],
+        )
+    }
+
+    pub fn regex(self, regex: Regex) -> FieldValidator {
+        let param = Some(Value::from(regex.as_str()));
+        self.rule("regex", Check::Regex(regex), &[("pattern", param)])
+    }
+
+    /// Equal to the `other` field of the document
+    pub fn must_match(self, other: &str) -> FieldValidator {
+        let param = Some(Value::from(other));
+        self.rule("must_match", Check::MustMatch(other.to_string()), &[("other", param)])
+    }
+
+    #[cfg(feature = "card")]
+    pub fn credit_card(self) -> FieldValidator {
+        self.rule("credit_card", Check::CreditCard, &[])
+    }
+
+    #[cfg(feature = "phone")]
+    pub fn phone(self) -> FieldValidator {
+        self.rule("phone", Check::Phone, &[])
+    }
+
+    #[cfg(feature = "unic")]
+    pub fn non_control_character(self) -> FieldValidator {
+        self.rule("non_control_character", Check::NonControlCharacter, &[])
+    }
+
+    /// An object, or each object of a list, valid according to its own rules. They only run when
+    /// the other rules of the field pass.
+    pub fn nested(self, validator: StructValidator) -> FieldValidator {
+        self.rule("nested", Check::Nested(validator), &[])
+    }
+
+    /// Sets the code of the errors of the last rule added
+    pub fn code(mut self, code: impl Into<Cow<'static, str>>) -> FieldValidator {
+        if let Some(rule) = self.0.last_mut() {
+            rule.code = code.into();
+        }
+        self
+    }
+
+    /// Sets the message of the errors of the last rule added
+    pub fn message(mut self, message: impl Into<Cow<'static, str>>) -> FieldValidator {
+        if let Some(rule) = self.0.last_mut() {
+            rule.message = Some(message.into());
+        }
+        self
+    }
+}
+
+impl DynamicValidator {
+    /// A validator without rules, to build in code like a [`StructValidator`]
+    pub fn new() -> DynamicValidator {
+        DynamicValidator::default()
+    }
+
+    /// Adds rules to a field, after the ones it already has
+    pub fn field(mut self, field: &str, rules: FieldValidator) -> DynamicValidator {
+        match self.fields.iter_mut().find(|(name, _)| *name == field) {
+            Some((_, existing)) => existing.extend(rules.0),
+            None => self.fields.push((field.to_string(), rules.0)),
+        }
+        self
+    }
+
+    /// Validates a document given as its fields and their values, like the fields of a form
+    pub fn validate_map<K, V, I>(&self, fields: I) -> Result<(), ValidationErrors>
+    where
+        K: Into<String>,
+        V: Into<Value>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let document =
+            fields.into_iter().map(|(name, value)| (name.into(), value.into())).collect();
+        self.validate(&Value::Object(document))
+    }
+
+    /// Loads the rules from a JSON document
+    pub fn from_json(rules: &str) -> Result<DynamicValidator, DynamicRulesError> {
+        let rules: Value = serde_json::from_str(rules).map_err(DynamicRulesError::Json)?;
+        DynamicValidator::from_value(&rules)
+    }
+
+    /// Loads the rules from a JSON value, for rules stored in another format like YAML and
+    /// deserialized as a `serde_json::Value`
+    pub fn from_value(rules: &Value) -> Result<DynamicValidator, DynamicRulesError> {
+        let invalid = |field: &str, reason: &str| DynamicRulesError::InvalidRule {
+            field: field.to_string(),
+            reason: reason.to_string(),
+        };
+        let rules = rules.as_object().ok_or_else(|| invalid("", "The rules must be an object"))?;
+        let mut fields = Vec::with_capacity(rules.len());
+        for (field, field_rules) in rules {
+            let field_rules = match field_rules {
+                Value::Array(field_rules) => field_rules,
+                _ => return Err(invalid(field, "The rules of a field must be a list")),
+            };
+            let field_rules = field_rules
+                .iter()
+                .map(|rule| match rule {
+                    Value::Object(rule) => parse_rule(field, rule),
+                    _ => Err(invalid(field, "A rule must be an object with a `kind`")),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            fields.push((field.clone(), field_rules));
+        }
+        Ok(DynamicValidator { fields })
+    }
+
+    /// Validates a document, a value that isn't an object having none of the fields
+    pub fn validate(&self, document: &Value) -> Result<(), ValidationErrors> {
+        let empty = Map::new();
+        let document = document.as_object().unwrap_or(&empty);
+        let mut errors = ValidationErrors::new();
+        for (field, rules) in &self.fields {
+            let value = document.get(field).filter(|value| !value.is_null());
+            let value = match value {
+                Some(value) => value,
+                None => {
+                    for rule in rules.iter().filter(|rule| matches!(rule.check, Check::Required)) {
+                        errors.add(field.clone(), rule.error(&Value::Null));
+                    }
+                    continue;
+                }
+            };
+            let mut failed = false;
+            for rule in rules {
+                if !rule.check.passes(value, document) {
+                    failed = true;
+                    errors.add(field.clone(), rule.error(value));
+                }
+            }
+            // A field can't have both its errors and the errors of its fields
+            if failed {
+                continue;
+            }
+            for rule in rules {
+                if let Check::Nested(validator) = &rule.check {
+                    if let Err(nested) = validator.validate_nested(field, value) {
+                        errors.extend(nested);
+                    }
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validates an object, or each object of a list, as the field of a document
+    fn validate_nested(&self, field: &str, value: &Value) -> Result<(), ValidationErrors> {
+        match value {
+            Value::Array(values) => ValidationErrors::merge_list(
+                Ok(()),
+                field.to_string(),
+                values.iter().map(|value| self.validate(value)).collect(),
+            ),
+            _ => ValidationErrors::merge(Ok(()), field.to_string(), self.validate(value)),
+        }
+    }
+}
+
+impl DynamicRule {
+    fn error(&self, value: &Value) -> ValidationError {
+        let mut error = ValidationError::new("");
+        error.code = self.code.clone();
+        error.message = self.message.clone();
+        for (name, param) in &self.params {
+            error.add_param(Cow::from(name.clone()), param);
+        }
+        if !value.is_null() {
+            error.add_param(Cow::from("value"), value);
+        }
+        error
+    }
+}
+
+impl Check {
+    fn passes(&self, value: &Value, document: &Map<String, Value>) -> bool {
+        let string = value.as_str();
+        let number = value.as_f64();
+        match self {
+            Check::Required | Check::Nested(_) => true,
+            Check::Email => string.is_some_and(validate_email),
+            Check::Url => string.is_some_and(validate_url),
+            Check::Length { min, max, equal } => {
+                let length = match value {
+                    Value::String(string) => string.chars().count(),
+                    Value::Array(values) => values.len(),
+                    Value::Object(values) => values.len(),
+                    _ => return false,
+                } as u64;
+                match equal {
+                    Some(equal) => length == *equal,
+                    None => {
+                        min.is_none_or(|min| length >= min) && max.is_none_or(|max| length <= max)
+                    }
+                }
+            }
+            Check::Range { min, max, exclusive_min, exclusive_max, step } => {
+                number.is_some_and(|number| {
+                    validate_range(number, *min, *max, *exclusive_min, *exclusive_max)
+                        && step.is_none_or(|step| {
+                            let origin = min.or(*exclusive_min).unwrap_or(0.0);
+                            validate_range_step(number, origin, step)
+                        })
+                })
+            }
+            Check::Percentage { exclusive } => {
+                number.is_some_and(|number| validate_percentage(number, *exclusive))
+            }
+            Check::Ratio { exclusive } => {
+                number.is_some_and(|number| validate_ratio(number, *exclusive))
+            }
+            Check::BitsWithin(mask) => value
+                .as_u64()
+                .or_else(|| value.as_i64().map(|number| number as u64))
+                .is_some_and(|number| validate_bits_within(number, *mask)),
+            Check::Contains(needle) => match value {
+                Value::String(string) => string.contains(needle.as_str()),
+                Value::Object(values) => values.contains_key(needle),
+                _ => false,
+            },
+            Check::DoesNotContain(needle) => match value {
+                Value::String(string) => !string.contains(needle.as_str()),
+                Value::Object(values) => !values.contains_key(needle),
+                _ => false,
+            },
+            Check::Regex(regex) => string.is_some_and(|string| regex.is_match(string)),
+            Check::MustMatch(other) => document.get(other) == Some(value),
+            #[cfg(feature = "card")]
+            Check::CreditCard => string.is_some_and(validate_credit_card),
+            #[cfg(feature = "phone")]
+            Check::Phone => string.is_some_and(validate_phone),
+            #[cfg(feature = "unic")]
+            Check::NonControlCharacter => string.is_some_and(validate_non_control_character),
+        }
+    }
+}
+
+fn parse_rule(field: &str, rule: &Map<String, Value>) -> Result<DynamicRule, DynamicRulesError> {
+    let invalid =
+        |reason: String| DynamicRulesError::InvalidRule { field: field.to_string(), reason };
+    let kind = match rule.get("kind") {
+        Some(Value::String(kind)) => kind.as_str(),
+        _ => return Err(invalid("A rule must be an object with a `kind`".to_string())),
+    };
+    let number = |name: &str| match rule.get(name) {
+        None => Ok(None),
+        Some(value) => value
+            .as_f64()
+            .map(Some)
+            .ok_or_else(|| invalid(format!("`{}` of `{}` must be a number", name, kind))),
+    };
+    let integer = |name: &str| match rule.get(name) {
+        None => Ok(None),
+        Some(value) => value.as_u64().map(Some).ok_or_else(|| {
+            invalid(format!("`{}` of `{}` must be a non-negative integer", name, kind))
+        }),
+    };
+    let string = |name: &str| match rule.get(name) {
+        Some(Value::String(value)) => Ok(value.clone()),
+        _ => Err(invalid(format!("`{}` needs a `{}` string", kind, name))),
+    };
+    let exclusive = match rule.get("exclusive") {
+        None => false,
+        Some(Value::Bool(exclusive)) => *exclusive,
+        Some(_) => return Err(invalid(format!("`exclusive` of `{}` must be a boolean", kind))),
+    };
+
+    let check = match kind {
+        "required" => Check::Required,
+        "email" => Check::Email,
+        "url" => Check::Url,
+        "length" => {
+            Check::Length { min: integer("min")?, max: integer("max")?, equal: integer("equal")? }
+        }
+        "range" => {
+            let step = number("step")?;
+            // The values would never be on the grid of such a step
+            if step.is_some_and(|step| !step.is_finite() || step <= 0.0) {
+                return Err(invalid("`step` of `range` must be greater than 0".to_string()));
+            }
+            Check::Range {
+                min: number("min")?,
+                max: number("max")?,
+                exclusive_min: number("exclusive_min")?,
+                exclusive_max: number("exclusive_max")?,
+                step,
+            }
+        }
+        "percentage" => Check::Percentage { exclusive },
+        "ratio" => Check::Ratio { exclusive },
+        "bits_within" => match integer("mask")? {
+            Some(mask) => Check::BitsWithin(mask),
+            None => return Err(invalid("`bits_within` needs a `mask`".to_string())),
+        },
+        "contains" => Check::Contains(string("needle")?),
+        "does_not_contain" => Check::DoesNotContain(string("needle")?),
+        "regex" => {
+            let pattern = string("pattern")?;
+            match Regex::new(&pattern) {
+                Ok(regex) => Check::Regex(regex),
+                Err(error) => return Err(invalid(format!("Invalid `pattern`: {}", error))),
+            }
+        }
+        "must_match" => Check::MustMatch(string("other")?),
+        #[cfg(feature = "card")]
+        "credit_card" => Check::CreditCard,
+        #[cfg(feature = "phone")]
+        "phone" => Check::Phone,
+        #[cfg(feature = "unic")]
+        "non_control_character" => Check::NonControlCharacter,
+        "nested" => match rule.get("rules") {
+            Some(rules) => Check::Nested(
+                DynamicValidator::from_value(rules).map_err(|error| nested_error(field, error))?,
+            ),
+            None => return Err(invalid("`nested` needs `rules`".to_string())),
+        },
+        _ => {
+            return Err(DynamicRulesError::UnknownRule {
+                field: field.to_string(),
+                kind: kind.to_string(),
+            })
+        }
+    };
+
+    let code = match rule.get("code") {
+        None => Cow::Owned(kind.to_string()),
+        Some(Value::String(code)) => Cow::Owned(code.clone()),
+        Some(_) => return Err(invalid("`code` must be a string".to_string())),
+    };
+    let message = match rule.get("message") {
+        None => None,
+        Some(Value::String(message)) => Some(Cow::Owned(message.clone())),
+        Some(_) => return Err(invalid("`message` must be a string".to_string())),
+    };
+    let params = rule
+        .iter()
+        .filter(|(name, _)| !matches!(name.as_str(), "kind" | "code" | "message" | "rules"))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+    Ok(DynamicRule { check, code, message, params })
+}
+
+#[cfg(any(feature = "forms", feature = "test-strategies"))]
+/// Whether a value passes a derived rule, `None` for the rules that can't be checked on a value
+/// alone, like `custom`, `must_match` or `nested`
+pub(crate) fn rule_passes(rule: &Rule, value: &Value) -> Option<bool> {
+    let mut spec: Map<String, Value> = rule
+        .params
+        .iter()
+        .map(|(name, param)| (name.to_string(), as_integer(param.clone())))
+        .collect();
+    spec.insert("kind".to_string(), Value::from(rule.kind));
+    match parse_rule(rule.kind, &spec).ok()?.check {
+        Check::MustMatch(_) | Check::Nested(_) => None,
+        check => Some(check.passes(value, &Map::new())),
+    }
+}
+
+/// The fields of the errors of nested rules are paths from the document
+fn nested_error(field: &str, error: DynamicRulesError) -> DynamicRulesError {
+    let path = |nested: String| match nested.is_empty() {
+        true => field.to_string(),
+        false => format!("{}.{}", field, nested),
+    };
+    match error {
+        DynamicRulesError::UnknownRule { field, kind } => {
+            DynamicRulesError::UnknownRule { field: path(field), kind }
+        }
+        DynamicRulesError::InvalidRule { field, reason } => {
+            DynamicRulesError::InvalidRule { field: path(field), reason }
+        }
+        error => error,
+    }
+}
@@ -0,0 +1,133 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+
+use crate::types::{ErrorSource, Severity, ValidationError};
+
+/// Builds a [`ValidationError`] one part at a time, see [`ValidationError::builder`]
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ValidationErrorBuilder {
+    error: ValidationError,
+}
+
+impl ValidationError {
+    /// Starts building an error with that code:
+    ///
+    /// ```
+    /// use validator::ValidationError;
+    ///
+    /// let error = ValidationError::builder("too_short")
+    ///     .message("Must have at least {min} characters")
+    ///     .param("min", 3)
+    ///     .build();
+    /// assert_eq!(error.param_i64("min"), Some(3));
+    /// ```
+    pub fn builder(code: impl Into<Cow<'static, str>>) -> ValidationErrorBuilder {
+        let mut error = ValidationError::new("");
+        error.code = code.into();
+        ValidationErrorBuilder { error }
+    }
+}
+
+impl ValidationErrorBuilder {
+    pub fn message(mut self, message: impl Into<Cow<'static, str>>) -> Self {
+        self.error.message = Some(message.into());
+        self
+    }
+
+    /// Adds a param, replacing the one of the same name if there is one
+    pub fn param<T: Serialize>(mut self, name: impl Into<Cow<'static, str>>, value: T) -> Self {
+        self.error.add_param(name.into(), &value);
+        self
+    }
+
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.error.severity = severity;
+        self
+    }
+
+    /// Sets the error that caused this one, see [`ValidationError::with_source`]
+    pub fn source<E>(mut self, source: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        self.error.source = Some(ErrorSource::new(source));
+        self
+    }
+
+    pub fn build(self) -> ValidationError {
+        self.error
+    }
+}
+
+impl From<ValidationErrorBuilder> for ValidationError {
+    fn from(builder: ValidationErrorBuilder) -> ValidationError {
+        builder.build()
+    }
+}
+
+/// Returns an error from a custom validator function, with a code and optionally a message and
+/// params:
+///
+/// ```
+/// use validator::{bail_validation, ValidationError};
+///
+/// fn validate_even(value: i64) -> Result<(), ValidationError> {
+///     if value % 2 != 0 {
+///         bail_validation!("even", message = "Must be even, not {value}", value = value);
+///     }
+///     Ok(())
+/// }
+///
+/// assert_eq!(validate_even(3).unwrap_err().param_i64("value"), Some(3));
+/// ```
+///
+/// The error is converted with `Into`, so the function can return any error a `ValidationError`
+/// converts to.
+#[macro_export]
+macro_rules! bail_validation {
+    ($($error:tt)+) => {
+        return ::std::result::Result::Err(::std::convert::Into::into($crate::__validation_error!($($error)+)))
+    };
+}
+
+/// Returns an error from a custom validator function if the condition is true, taking the same
+/// arguments as [`bail_validation!`] after the condition:
+///
+/// ```
+/// use validator::{err_if, ValidationError};
+///
+/// fn validate_username(value: &str) -> Result<(), ValidationError> {
+///     err_if!(value.len() < 3, "too_short", min = 3);
+///     err_if!(value == "admin", "reserved", message = "This username is reserved");
+///     Ok(())
+/// }
+///
+/// assert_eq!(validate_username("admin").unwrap_err().code, "reserved");
+/// ```
+#[macro_export]
+macro_rules! err_if {
+    ($condition:expr, $($error:tt)+) => {
+        if $condition {
+            $crate::bail_validation!($($error)+);
+        }
+    };
+}
+
+// Builds the error of `bail_validation!` and `err_if!`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __validation_error {
+    ($code:expr, message = $message:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::ValidationError::builder($code)
+            .message($message)
+            $(.param(::std::stringify!($name), $value))*
+            .build()
+    };
+    ($code:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::ValidationError::builder($code)
+            $(.param(::std::stringify!($name), $value))*
+            .build()
+    };
+}
@@ -0,0 +1,354 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::Index;
+use std::{iter, option, slice, vec};
+
+use crate::types::ValidationErrorsKind;
+
+type Entry = (Cow<'static, str>, ValidationErrorsKind);
+
+/// The errors of the fields of a [`ValidationErrors`](crate::ValidationErrors), in the order the
+/// fields got them. The names of the fields are borrowed, except the ones only known at runtime
+/// like the deserialized ones.
+///
+/// Most values have no invalid field or a single one, which are stored inline without allocating.
+/// The fields are then searched one by one, until there are more than `INDEX_THRESHOLD` of them
+/// and a map of their positions is built.
+#[derive(Clone, Default)]
+pub struct ErrorMap {
+    entries: Entries,
+    /// The position of each field in `entries`, once there are enough of them. Boxed since it's
+    /// rarely built, to keep the errors small.
+    #[allow(clippy::box_collection)]
+    index: Option<Box<HashMap<Cow<'static, str>, usize>>>,
+}
+
+#[derive(Clone)]
+enum Entries {
+    Inline(Option<Entry>),
+    Heap(Vec<Entry>),
+}
+
+impl Default for Entries {
+    fn default() -> Entries {
+        Entries::Inline(None)
+    }
+}
+
+impl ErrorMap {
+    const INDEX_THRESHOLD: usize = 16;
+
+    pub const fn new() -> ErrorMap {
+        ErrorMap { entries: Entries::Inline(None), index: None }
+    }
+
+    fn as_slice(&self) -> &[Entry] {
+        match &self.entries {
+            Entries::Inline(entry) => entry.as_slice(),
+            Entries::Heap(entries) => entries,
+        }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [Entry] {
+        match &mut self.entries {
+            Entries::Inline(entry) => entry.as_mut_slice(),
+            Entries::Heap(entries) => entries,
+        }
+    }
+
+    fn position(&self, field: &str) -> Option<usize> {
+        match &self.index {
+            Some(index) => index.get(field).copied(),
+            None => self.as_slice().iter().position(|(f, _)| *f == field),
+        }
+    }
+
+    /// Rebuilds the index after the positions of the fields changed
+    fn reindex(&mut self) {
+        self.index = match self.len() > Self::INDEX_THRESHOLD {
+            true => Some(Box::new(
+                self.as_slice().iter().enumerate().map(|(i, (f, _))| (f.clone(), i)).collect(),
+            )),
+            false => None,
+        };
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, field: &str) -> bool {
+        self.position(field).is_some()
+    }
+
+    pub fn get(&self, field: &str) -> Option<&ValidationErrorsKind> {
+        self.position(field).map(|i| &self.as_slice()[i].1)
+    }
+
+    pub fn get_mut(&mut self, field: &str) -> Option<&mut ValidationErrorsKind> {
+        let i = self.position(field)?;
+        Some(&mut self.as_mut_slice()[i].1)
+    }
+
+    /// Sets the errors of a field, returning its previous errors. A new field comes after the
+    /// others.
+    pub fn insert(
+        &mut self,
+        field: impl Into<Cow<'static, str>>,
+        kind: ValidationErrorsKind,
+    ) -> Option<ValidationErrorsKind> {
+        let field = field.into();
+        if let Some(existing) = self.get_mut(&field) {
+            return Some(std::mem::replace(existing, kind));
+        }
+
+        let position = self.len();
+        match &mut self.entries {
+            Entries::Inline(entry @ None) => *entry = Some((field.clone(), kind)),
+            Entries::Inline(entry) => {
+                let mut entries = Vec::with_capacity(4);
+                entries.extend(entry.take());
+                entries.push((field.clone(), kind));
+                self.entries = Entries::Heap(entries);
+            }
+            Entries::Heap(entries) => entries.push((field.clone(), kind)),
+        }
+        match &mut self.index {
+            Some(index) => {
+                index.insert(field, position);
+            }
+            None if position == Self::INDEX_THRESHOLD => self.reindex(),
+            None => (),
+        }
+        None
+    }
+
+    /// Removes the errors of a field, keeping the order of the others
+    pub fn remove(&mut self, field: &str) -> Option<ValidationErrorsKind> {
+        let i = self.position(field)?;
+        let (_, kind) = match &mut self.entries {
+            Entries::Inline(entry) => entry.take()?,
+            Entries::Heap(entries) => entries.remove(i),
+        };
+        if self.index.is_some() {
+            self.reindex();
+        }
+        Some(kind)
+    }
+
+    /// Keeps only the fields for which `f` returns `true`
+    pub fn retain(&mut self, mut f: impl FnMut(&str, &mut ValidationErrorsKind) -> bool) {
+        match &mut self.entries {
+            Entries::Inline(entry) => {
+                if let Some((field, kind)) = entry {
+                    if !f(field, kind) {
+                        *entry = None;
+                    }
+                }
+            }
+            Entries::Heap(entries) => entries.retain_mut(|(field, kind)| f(field, kind)),
+        }
+        if self.index.is_some() {
+            self.reindex();
+        }
+    }
+
+    /// Iterates over the fields and their errors, in order
+    pub fn iter(&self) -> Iter<'_> {
+        let entry: fn(&Entry) -> (&str, &ValidationErrorsKind) = |(field, kind)| (field, kind);
+        self.as_slice().iter().map(entry)
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_> {
+        let entry: fn(&mut Entry) -> (&str, &mut ValidationErrorsKind) =
+            |(field, kind)| (field, kind);
+        self.as_mut_slice().iter_mut().map(entry)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.as_slice().iter().map(|(field, _)| field.as_ref())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &ValidationErrorsKind> {
+        self.as_slice().iter().map(|(_, kind)| kind)
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut ValidationErrorsKind> {
+        self.as_mut_slice().iter_mut().map(|(_, kind)| kind)
+    }
+
+    /// Moves the fields in `fields` first in that order, the others come after them in their
+    /// current order
+    pub(crate) fn reorder(&mut self, fields: &[&str]) {
+        if let Entries::Heap(entries) = &mut self.entries {
+            entries.sort_by_key(|(field, _)| {
+                fields.iter().position(|f| *f == field).unwrap_or(fields.len())
+            });
+            if self.index.is_some() {
+                self.reindex();
+            }
+        }
+    }
+}
+
+pub type Iter<'a> =
+    iter::Map<slice::Iter<'a, Entry>, fn(&'a Entry) -> (&'a str, &'a ValidationErrorsKind)>;
+
+pub type IterMut<'a> = iter::Map<
+    slice::IterMut<'a, Entry>,
+    fn(&'a mut Entry) -> (&'a str, &'a mut ValidationErrorsKind),
+>;
+
+/// The owned iterator of an [`ErrorMap`]
+pub enum IntoIter {
+    Inline(option::IntoIter<Entry>),
+    Heap(vec::IntoIter<Entry>),
+}
+
+impl Iterator for IntoIter {
+    type Item = Entry;
+
+    fn next(&mut self) -> Option<Entry> {
+        match self {
+            IntoIter::Inline(entry) => entry.next(),
+            IntoIter::Heap(entries) => entries.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            IntoIter::Inline(entry) => entry.size_hint(),
+            IntoIter::Heap(entries) => entries.size_hint(),
+        }
+    }
+}
+
+impl IntoIterator for ErrorMap {
+    type Item = Entry;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        match self.entries {
+            Entries::Inline(entry) => IntoIter::Inline(entry.into_iter()),
+            Entries::Heap(entries) => IntoIter::Heap(entries.into_iter()),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a ErrorMap {
+    type Item = (&'a str, &'a ValidationErrorsKind);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut ErrorMap {
+    type Item = (&'a str, &'a mut ValidationErrorsKind);
+    type IntoIter = IterMut<'a>;
+
+    fn into_iter(self) -> IterMut<'a> {
+        self.iter_mut()
+    }
+}
+
+impl<F: Into<Cow<'static, str>>> FromIterator<(F, ValidationErrorsKind)> for ErrorMap {
+    fn from_iter<I: IntoIterator<Item = (F, ValidationErrorsKind)>>(entries: I) -> ErrorMap {
+        let mut map = ErrorMap::new();
+        for (field, kind) in entries {
+            map.insert(field, kind);
+        }
+        map
+    }
+}
+
+impl From<ErrorMap> for HashMap<Cow<'static, str>, ValidationErrorsKind> {
+    fn from(map: ErrorMap) -> HashMap<Cow<'static, str>, ValidationErrorsKind> {
+        map.into_iter().collect()
+    }
+}
+
+impl Index<&str> for ErrorMap {
+    type Output = ValidationErrorsKind;
+
+    /// # Panics
+    ///
+    /// If the field has no errors
+    fn index(&self, field: &str) -> &ValidationErrorsKind {
+        self.get(field).unwrap_or_else(|| panic!("the field `{}` has no errors", field))
+    }
+}
+
+/// The order of the fields doesn't matter for equality
+impl PartialEq for ErrorMap {
+    fn eq(&self, other: &ErrorMap) -> bool {
+        self.len() == other.len() && self.iter().all(|(field, kind)| other.get(field) == Some(kind))
+    }
+}
+
+impl fmt::Debug for ErrorMap {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ValidationError;
+
+    const FIELDS: [&str; 20] = [
+        "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r",
+        "s", "t",
+    ];
+
+    fn kind(code: &'static str) -> ValidationErrorsKind {
+        ValidationErrorsKind::Field(vec![ValidationError::new(code)])
+    }
+
+    #[test]
+    fn test_keeps_the_order_of_the_fields() {
+        for len in &[0, 1, 2, 16, 17, 20] {
+            let mut map: ErrorMap = FIELDS[..*len].iter().map(|f| (*f, kind(f))).collect();
+            assert_eq!(map.len(), *len);
+            assert_eq!(map.index.is_some(), *len > ErrorMap::INDEX_THRESHOLD);
+            assert_eq!(map.keys().collect::<Vec<_>>(), &FIELDS[..*len]);
+            for field in &FIELDS[..*len] {
+                assert_eq!(map[*field], kind(field));
+            }
+            assert_eq!(map.get("z"), None);
+
+            if *len > 0 {
+                assert_eq!(map.remove("a"), Some(kind("a")));
+                assert_eq!(map.keys().collect::<Vec<_>>(), &FIELDS[1..*len]);
+                assert!(FIELDS[1..*len].iter().all(|field| map.contains_key(field)));
+                assert!(!map.contains_key("a"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_insert_replaces_in_place() {
+        let mut map: ErrorMap = FIELDS.iter().map(|f| (*f, kind(f))).collect();
+        assert_eq!(map.insert("b", kind("other")), Some(kind("b")));
+        assert_eq!(map.keys().nth(1), Some("b"));
+        assert_eq!(map["b"], kind("other"));
+
+        map.retain(|field, _| field > "j");
+        assert_eq!(map.keys().collect::<Vec<_>>(), &FIELDS[10..]);
+        assert_eq!(map.index, None);
+        map.reorder(&["t", "m"]);
+        assert_eq!(map.keys().take(3).collect::<Vec<_>>(), vec!["t", "m", "k"]);
+        assert_eq!(map["m"], kind("m"));
+    }
+}
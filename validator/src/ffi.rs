@@ -0,0 +1,85 @@
+//! C functions of the validators, declared in `include/validator.h`, for the components of a
+//! project that aren't written in Rust. The functions are exported once the crate is linked in a
+//! `staticlib` or a `cdylib`, typically a small crate of the project re-exporting this module.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use crate::validation::email::validate_email;
+use crate::validation::ip::{validate_ip, validate_ip_v4, validate_ip_v6};
+use crate::validation::urls::validate_url;
+
+/// The result of a validation, its values are part of the ABI and never change
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidatorResult {
+    Valid = 0,
+    Invalid = 1,
+    /// The value is a null pointer
+    NullPointer = 2,
+    /// The value isn't UTF-8
+    InvalidUtf8 = 3,
+}
+
+unsafe fn validate(value: *const c_char, validator: fn(&str) -> bool) -> ValidatorResult {
+    if value.is_null() {
+        return ValidatorResult::NullPointer;
+    }
+    match CStr::from_ptr(value).to_str() {
+        Ok(value) if validator(value) => ValidatorResult::Valid,
+        Ok(_) => ValidatorResult::Invalid,
+        Err(_) => ValidatorResult::InvalidUtf8,
+    }
+}
+
+macro_rules! export {
+    ($($name:ident => $validator:ident,)*) => {$(
+        #[doc = concat!("Validates a UTF-8 string ending with a nul byte with `", stringify!($validator), "`")]
+        ///
+        /// # Safety
+        ///
+        /// The value must be null or point to a string ending with a nul byte
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(value: *const c_char) -> ValidatorResult {
+            validate(value, |value| $validator(value))
+        }
+    )*};
+}
+
+export! {
+    validator_validate_email => validate_email,
+    validator_validate_url => validate_url,
+    validator_validate_ip => validate_ip,
+    validator_validate_ip_v4 => validate_ip_v4,
+    validator_validate_ip_v6 => validate_ip_v6,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::ptr;
+
+    use super::*;
+
+    #[test]
+    fn test_ffi_validators() {
+        let email = CString::new("bob@example.com").unwrap();
+        let ip = CString::new("::1").unwrap();
+        unsafe {
+            assert_eq!(validator_validate_email(email.as_ptr()), ValidatorResult::Valid);
+            assert_eq!(validator_validate_url(email.as_ptr()), ValidatorResult::Invalid);
+            assert_eq!(validator_validate_ip(ip.as_ptr()), ValidatorResult::Valid);
+            assert_eq!(validator_validate_ip_v4(ip.as_ptr()), ValidatorResult::Invalid);
+            assert_eq!(validator_validate_ip_v6(ip.as_ptr()), ValidatorResult::Valid);
+        }
+    }
+
+    #[test]
+    fn test_ffi_invalid_input() {
+        let not_utf8 = CString::new(vec![0xff, 0xfe]).unwrap();
+        unsafe {
+            assert_eq!(validator_validate_email(ptr::null()), ValidatorResult::NullPointer);
+            assert_eq!(validator_validate_url(not_utf8.as_ptr()), ValidatorResult::InvalidUtf8);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! The constructors of the rules of the fields of a [`StructValidator`](crate::StructValidator),
+//! each starting a [`FieldValidator`] with a single rule that more can be chained to, like
+//! `email().required()`.
+
+use std::ops::RangeBounds;
+
+use regex::Regex;
+
+use crate::dynamic::{FieldValidator, StructValidator};
+use crate::validation::check::Numeric;
+
+pub fn required() -> FieldValidator {
+    FieldValidator::new().required()
+}
+
+pub fn email() -> FieldValidator {
+    FieldValidator::new().email()
+}
+
+pub fn url() -> FieldValidator {
+    FieldValidator::new().url()
+}
+
+/// See [`FieldValidator::length`]
+pub fn length<R: RangeBounds<u64>>(length: R) -> FieldValidator {
+    FieldValidator::new().length(length)
+}
+
+pub fn length_equal(equal: u64) -> FieldValidator {
+    FieldValidator::new().length_equal(equal)
+}
+
+/// See [`FieldValidator::range`]
+pub fn range<T, R>(range: R) -> FieldValidator
+where
+    T: Numeric,
+    R: RangeBounds<T>,
+{
+    FieldValidator::new().range(range)
+}
+
+pub fn percentage(exclusive: bool) -> FieldValidator {
+    FieldValidator::new().percentage(exclusive)
+}
+
+pub fn ratio(exclusive: bool) -> FieldValidator {
+    FieldValidator::new().ratio(exclusive)
+}
+
+pub fn bits_within(mask: u64) -> FieldValidator {
+    FieldValidator::new().bits_within(mask)
+}
+
+pub fn contains(needle: &str) -> FieldValidator {
+    FieldValidator::new().contains(needle)
+}
+
+pub fn does_not_contain(needle: &str) -> FieldValidator {
+    FieldValidator::new().does_not_contain(needle)
+}
+
+pub fn regex(regex: Regex) -> FieldValidator {
+    FieldValidator::new().regex(regex)
+}
+
+pub fn must_match(other: &str) -> FieldValidator {
+    FieldValidator::new().must_match(other)
+}
+
+#[cfg(feature = "card")]
+pub fn credit_card() -> FieldValidator {
+    FieldValidator::new().credit_card()
+}
+
+#[cfg(feature = "phone")]
+pub fn phone() -> FieldValidator {
+    FieldValidator::new().phone()
+}
+
+#[cfg(feature = "unic")]
+pub fn non_control_character() -> FieldValidator {
+    FieldValidator::new().non_control_character()
+}
+
+/// See [`FieldValidator::nested`]
+pub fn nested(validator: StructValidator) -> FieldValidator {
+    FieldValidator::new().nested(validator)
+}
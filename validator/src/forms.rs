@@ -0,0 +1,87 @@
+//! Validation of the fields of a form one at a time with the rules derived for the struct it is
+//! submitted as, for the frontends written in Rust and compiled to WebAssembly, like the ones of
+//! Leptos or Yew, so a field is checked as it is typed with the same rules as the server:
+//!
+//! ```ignore
+//! let on_input = move |event| {
+//!     let email = event_target_value(&event);
+//!     set_errors(forms::validate_field::<Signup, _>("email", &email).err().unwrap_or_default());
+//! };
+//! ```
+//!
+//! The fields are named as in the errors, `#[serde(rename)]` and `#[validate(rename)]` included.
+
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::dynamic::rule_passes;
+use crate::rules::{FieldRules, Rule};
+use crate::traits::ValidationRules;
+use crate::types::ValidationError;
+
+/// The rules of a field of `T`, `None` if it has no validators, to show them in the form like
+/// the maximum length of a text input
+pub fn field_rules<T: ValidationRules>(field: &str) -> Option<FieldRules> {
+    T::validation_rules().into_iter().find(|rules| rules.field == field && field != "__all__")
+}
+
+/// Validates the value of a field of `T` with its rules, and its elements with the `inner` ones
+/// if it is a list. The rules that need more than the value, like `custom`, `must_match`,
+/// `nested` or `regex` with a path, are left to the validation of the whole struct, as are the
+/// struct level validations. The `skip_if` and `validate_if` conditions aren't evaluated, the
+/// rules are checked as if they held.
+///
+/// A `null` value is an empty `Option`, it only fails `required`.
+pub fn validate_field<T, V>(field: &str, value: &V) -> Result<(), Vec<ValidationError>>
+where
+    T: ValidationRules,
+    V: Serialize + ?Sized,
+{
+    let rules = match field_rules::<T>(field) {
+        Some(rules) => rules,
+        None => return Ok(()),
+    };
+    let value = serde_json::to_value(value).unwrap_or(Value::Null);
+    let mut errors = vec![];
+    check(&rules.rules, &value, &mut errors);
+    if let Value::Array(elements) = &value {
+        for element in elements {
+            check(&rules.inner, element, &mut errors);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn check(rules: &[Rule], value: &Value, errors: &mut Vec<ValidationError>) {
+    for rule in rules {
+        let passes = match (rule.kind, value) {
+            ("required", value) => !value.is_null(),
+            (_, Value::Null) => true,
+            (_, value) => rule_passes(rule, value).unwrap_or(true),
+        };
+        if !passes {
+            errors.push(error(rule, value));
+        }
+    }
+}
+
+/// The error of a rule, with its arguments and the value as params like the derived ones
+fn error(rule: &Rule, value: &Value) -> ValidationError {
+    let mut error = ValidationError::new("");
+    error.code = rule.code.clone();
+    error.message = rule.message.clone();
+    for (name, param) in &rule.params {
+        error.add_param(name.clone(), param);
+    }
+    if !value.is_null() {
+        error.add_param(Cow::from("value"), value);
+    }
+    error
+}
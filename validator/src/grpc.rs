@@ -0,0 +1,107 @@
+use crate::types::ValidationErrors;
+
+/// The gRPC status code of the requests with invalid arguments
+pub const INVALID_ARGUMENT: i32 = 3;
+
+/// The type URL of the `google.rpc.BadRequest` detail of [`GrpcStatus`]
+pub const BAD_REQUEST_TYPE_URL: &str = "type.googleapis.com/google.rpc.BadRequest";
+
+/// A `google.rpc.Status` describing validation errors, with the `INVALID_ARGUMENT` code and a
+/// `google.rpc.BadRequest` detail with a field violation per error, as the clients of the
+/// Google APIs expect. [`GrpcStatus::to_bytes`] encodes it for the `grpc-status-details-bin`
+/// metadata, which is what the details of a `tonic::Status` are:
+///
+/// ```ignore
+/// fn validated<T: Validate>(request: Request<T>) -> Result<Request<T>, Status> {
+///     if let Err(errors) = request.get_ref().validate() {
+///         let status = GrpcStatus::new(&errors);
+///         let details = status.to_bytes().into();
+///         return Err(Status::with_details(Code::InvalidArgument, status.message, details));
+///     }
+///     Ok(request)
+/// }
+/// ```
+///
+/// The message can be changed before encoding it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrpcStatus {
+    pub code: i32,
+    pub message: String,
+    pub violations: Vec<FieldViolation>,
+}
+
+/// A `google.rpc.BadRequest.FieldViolation`, for one error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldViolation {
+    /// The path of the field, like `addresses[2].street`
+    pub field: String,
+    /// The message of the error, or the error displayed if it has none
+    pub description: String,
+    /// The code of the error
+    pub reason: String,
+}
+
+impl GrpcStatus {
+    /// Creates the status of the errors, in the order of `ValidationErrors::iter`
+    pub fn new(errors: &ValidationErrors) -> GrpcStatus {
+        let violations = errors
+            .iter()
+            .map(|(path, error)| FieldViolation {
+                field: path.to_string(),
+                description: match error.message {
+                    Some(ref message) => message.to_string(),
+                    None => error.to_string(),
+                },
+                reason: error.code.to_string(),
+            })
+            .collect();
+        GrpcStatus { code: INVALID_ARGUMENT, message: "Validation failed".to_string(), violations }
+    }
+
+    /// The status encoded in protobuf
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bad_request = vec![];
+        for violation in &self.violations {
+            let mut encoded = vec![];
+            encode_string(&mut encoded, 1, &violation.field);
+            encode_string(&mut encoded, 2, &violation.description);
+            encode_string(&mut encoded, 3, &violation.reason);
+            encode_bytes(&mut bad_request, 1, &encoded);
+        }
+        let mut any = vec![];
+        encode_string(&mut any, 1, BAD_REQUEST_TYPE_URL);
+        encode_bytes(&mut any, 2, &bad_request);
+
+        let mut status = vec![];
+        if self.code != 0 {
+            encode_varint(&mut status, 1 << 3);
+            // Negative int32 are encoded on 10 bytes, sign extended
+            encode_varint(&mut status, i64::from(self.code) as u64);
+        }
+        encode_string(&mut status, 2, &self.message);
+        encode_bytes(&mut status, 3, &any);
+        status
+    }
+}
+
+fn encode_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+/// A length delimited field, the empty ones are left out as they are the default
+fn encode_bytes(buffer: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    if bytes.is_empty() {
+        return;
+    }
+    encode_varint(buffer, field << 3 | 2);
+    encode_varint(buffer, bytes.len() as u64);
+    buffer.extend_from_slice(bytes);
+}
+
+fn encode_string(buffer: &mut Vec<u8>, field: u64, string: &str) {
+    encode_bytes(buffer, field, string.as_bytes());
+}
@@ -0,0 +1,491 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::param::ParamValue;
+use crate::types::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+/// The messages of the built-in validators, by language
+const BUILTIN_RESOURCES: &[(&str, &str)] =
+    &[("en", include_str!("../locales/en.txt")), ("fr", include_str!("../locales/fr.txt"))];
+
+/// A part of a message template
+#[derive(Debug, Clone, PartialEq)]
+enum PatternElement {
+    Text(String),
+    Variable(String),
+}
+
+type Pattern = Vec<PatternElement>;
+
+/// The templates of a message: its attributes in declaration order followed by its value
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Message {
+    variants: Vec<Pattern>,
+}
+
+/// A set of message templates for one locale, keyed by error code.
+///
+/// The templates are written in a small syntax of this crate that looks like Fluent
+/// (<https://projectfluent.org>) but isn't Fluent: a line `code = template` per message, indented
+/// lines continuing it or adding `.name = template` variants, `#` comments, and `{ $variable }` and
+/// `{ "literal" }` placeables. There are no selectors, terms nor functions, and a resource using
+/// them is rejected. The indented `.name` lines look like Fluent attributes but are variants of the
+/// message: the first one whose variables are all known for an error is used, and the value
+/// otherwise. For example `length` has variants for errors with both a `min` and a `max`, only one
+/// of them or an `equal`.
+///
+/// The codes can contain dots, like the ones namespaced with `code_prefix`.
+///
+/// The variables are the params of the error, `$field` and `$code`. Their values are formatted
+/// with the [`ParamFormatter`] of the bundle if it has one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageBundle {
+    locale: String,
+    messages: HashMap<String, Message>,
+    formatter: Option<ParamFormatter>,
+}
+
+type FormatParam = dyn Fn(&str, &ParamValue) -> Option<String> + Send + Sync;
+
+/// Formats the values of the params in the messages of a [`MessageBundle`] for the conventions of
+/// its locale, like `1.000.000` instead of `1000000` in German. It is called with the name and the
+/// [`ParamValue`] of each param, and the default formatting is used when it returns `None`.
+///
+/// Two formatters are equal if they are the same one.
+#[derive(Clone)]
+pub struct ParamFormatter(Arc<FormatParam>);
+
+impl ParamFormatter {
+    pub fn new<F>(format: F) -> ParamFormatter
+    where
+        F: Fn(&str, &ParamValue) -> Option<String> + Send + Sync + 'static,
+    {
+        ParamFormatter(Arc::new(format))
+    }
+
+    /// Formats the numbers with these separators between the groups of thousands and before the
+    /// decimals, leaving the other params to the default formatting
+    pub fn numbers(thousands_separator: &str, decimal_separator: &str) -> ParamFormatter {
+        let (thousands, decimal) = (thousands_separator.to_string(), decimal_separator.to_string());
+        ParamFormatter::new(move |_, value| {
+            let is_number = value.as_f64().is_some();
+            is_number.then(|| format_number(&format_value(value), &thousands, &decimal))
+        })
+    }
+
+    /// The number formatting of a locale like `de` or `de-AT`, by language. Returns `None` for the
+    /// languages it doesn't know.
+    pub fn for_locale(locale: &str) -> Option<ParamFormatter> {
+        let language = locale.split(['-', '_']).next().unwrap_or_default().to_lowercase();
+        let (thousands, decimal) = match language.as_str() {
+            "en" | "ja" | "ko" | "zh" => (",", "."),
+            "de" | "es" | "it" | "nl" | "pt" | "da" | "id" | "tr" => (".", ","),
+            // A narrow no-break space
+            "fr" => ("\u{202f}", ","),
+            "pl" | "ru" | "sv" | "fi" | "nb" | "cs" | "uk" => ("\u{a0}", ","),
+            _ => return None,
+        };
+        Some(ParamFormatter::numbers(thousands, decimal))
+    }
+
+    /// Formats a param, `None` to use the default formatting
+    pub fn format(&self, name: &str, value: &ParamValue) -> Option<String> {
+        (self.0)(name, value)
+    }
+}
+
+impl fmt::Debug for ParamFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ParamFormatter")
+    }
+}
+
+impl PartialEq for ParamFormatter {
+    fn eq(&self, other: &ParamFormatter) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The error returned when a resource isn't valid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageParseError {
+    /// The line of the error, starting at 1
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for MessageParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid message resource at line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for MessageParseError {}
+
+impl MessageBundle {
+    /// Creates an empty bundle for the given locale
+    pub fn new(locale: impl Into<String>) -> MessageBundle {
+        MessageBundle { locale: locale.into(), messages: HashMap::new(), formatter: None }
+    }
+
+    /// Creates a bundle with the messages of the built-in validators for the given locale, like
+    /// `fr` or `fr-CA` (which uses the `fr` messages). Returns `None` if there are no messages for
+    /// that language, the available ones are English (`en`) and French (`fr`).
+    pub fn builtin(locale: impl Into<String>) -> Option<MessageBundle> {
+        let locale = locale.into();
+        let language = locale.split(['-', '_']).next().unwrap_or_default().to_lowercase();
+        let (_, resource) = BUILTIN_RESOURCES.iter().find(|(lang, _)| *lang == language)?;
+
+        let mut bundle = MessageBundle::new(locale);
+        bundle.add_resource(resource).expect("The built-in messages are valid");
+        Some(bundle)
+    }
+
+    /// The locale of the bundle
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Adds the messages of a resource in the syntax described on [`MessageBundle`], replacing the
+    /// messages with the same code. Nothing is added if the resource is invalid.
+    pub fn add_resource(&mut self, source: &str) -> Result<(), MessageParseError> {
+        let messages = parse_resource(source)?;
+        self.messages.extend(messages);
+        Ok(())
+    }
+
+    /// Formats the params in the messages with that formatter, like
+    /// `ParamFormatter::for_locale(bundle.locale())`
+    pub fn set_formatter(&mut self, formatter: ParamFormatter) {
+        self.formatter = Some(formatter);
+    }
+
+    /// Whether the bundle has a message for that code
+    #[must_use]
+    pub fn has_message(&self, code: &str) -> bool {
+        self.messages.contains_key(code)
+    }
+
+    /// Renders the message of the error for the given field, if the bundle has a message for its
+    /// code with a variant whose variables are all known
+    pub fn format(&self, error: &ValidationError, field: &str) -> Option<String> {
+        let message = self.messages.get(error.code.as_ref())?;
+        let variable = |name: &str| match error.params.get(name) {
+            Some(value) => Some(
+                self.formatter
+                    .as_ref()
+                    .and_then(|formatter| formatter.format(name, value))
+                    .unwrap_or_else(|| format_value(value)),
+            ),
+            None if name == "field" => Some(field.to_string()),
+            None if name == "code" => Some(error.code.to_string()),
+            None => None,
+        };
+
+        message.variants.iter().find_map(|pattern| {
+            pattern.iter().try_fold(String::new(), |mut formatted, element| {
+                match element {
+                    PatternElement::Text(text) => formatted.push_str(text),
+                    PatternElement::Variable(name) => formatted.push_str(&variable(name)?),
+                }
+                Some(formatted)
+            })
+        })
+    }
+}
+
+impl ValidationErrors {
+    /// Replaces the messages of all the errors, including the ones of nested structs, by their
+    /// message in the bundle. The errors whose code has no message in the bundle are left as is.
+    pub fn localize(&mut self, bundle: &MessageBundle) {
+        for (field, kind) in self.errors_mut() {
+            match kind {
+                ValidationErrorsKind::Field(errors) => {
+                    for error in errors {
+                        if let Some(message) = bundle.format(error, field) {
+                            error.message = Some(message.into());
+                        }
+                    }
+                }
+                ValidationErrorsKind::Struct(errors) => errors.localize(bundle),
+                ValidationErrorsKind::List(entries) => {
+                    entries.values_mut().for_each(|errors| errors.localize(bundle))
+                }
+                ValidationErrorsKind::Map(entries) => {
+                    entries.values_mut().for_each(|errors| errors.localize(bundle))
+                }
+            }
+        }
+    }
+}
+
+/// Formats a param like `interpolate_message` does, except for the whole floats which are shown
+/// without decimals since the bounds of `range` and `length` are stored as floats
+fn format_value(value: &ParamValue) -> String {
+    match value {
+        ParamValue::Str(s) => s.to_string(),
+        ParamValue::Float(f) if f.fract() == 0.0 && f.abs() < 1e15 => format!("{}", *f as i64),
+        value => value.to_string(),
+    }
+}
+
+/// Adds the separators to a number formatted by `format_value`
+fn format_number(number: &str, thousands_separator: &str, decimal_separator: &str) -> String {
+    // Exponents are left as is
+    if number.contains(['e', 'E']) {
+        return number.to_string();
+    }
+    let (sign, number) = match number.strip_prefix('-') {
+        Some(number) => ("-", number),
+        None => ("", number),
+    };
+    let (integer, decimals) = match number.split_once('.') {
+        Some((integer, decimals)) => (integer, Some(decimals)),
+        None => (number, None),
+    };
+
+    let mut formatted = sign.to_string();
+    for (idx, digit) in integer.chars().enumerate() {
+        if idx > 0 && (integer.len() - idx) % 3 == 0 {
+            formatted.push_str(thousands_separator);
+        }
+        formatted.push(digit);
+    }
+    if let Some(decimals) = decimals {
+        formatted.push_str(decimal_separator);
+        formatted.push_str(decimals);
+    }
+    formatted
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// The identifiers separated by dots, like the codes prefixed with `code_prefix`
+fn is_code(code: &str) -> bool {
+    code.split('.').all(is_identifier)
+}
+
+/// A message whose lines are still being read, its patterns with the line they start at
+struct PendingMessage {
+    code: String,
+    line: usize,
+    value: (String, usize),
+    attributes: Vec<(String, usize)>,
+}
+
+impl PendingMessage {
+    fn parse(self) -> Result<(String, Message), MessageParseError> {
+        let mut variants = vec![];
+        for (text, line) in self.attributes.iter().chain(std::iter::once(&self.value)) {
+            let pattern = parse_pattern(text.trim_end(), *line)?;
+            if !pattern.is_empty() {
+                variants.push(pattern);
+            }
+        }
+        if variants.is_empty() {
+            return Err(MessageParseError {
+                line: self.line,
+                message: format!("The message `{}` has no value nor attributes", self.code),
+            });
+        }
+        Ok((self.code, Message { variants }))
+    }
+}
+
+fn parse_resource(source: &str) -> Result<HashMap<String, Message>, MessageParseError> {
+    let mut messages = HashMap::new();
+    let mut pending: Option<PendingMessage> = None;
+
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let error =
+            |message: &str| MessageParseError { line: line_number, message: message.into() };
+
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with(' ') {
+            let message =
+                pending.as_mut().ok_or_else(|| error("Indented line outside of a message"))?;
+            let trimmed = line.trim_start();
+            if let Some(attribute) = trimmed.strip_prefix('.') {
+                let (name, text) = attribute
+                    .split_once('=')
+                    .ok_or_else(|| error("Expected `=` after the attribute name"))?;
+                if !is_identifier(name.trim()) {
+                    return Err(error("Invalid attribute name"));
+                }
+                message.attributes.push((text.trim_start().to_string(), line_number));
+            } else {
+                let (text, _) = message.attributes.last_mut().unwrap_or(&mut message.value);
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str(trimmed);
+            }
+            continue;
+        }
+
+        if let Some(message) = pending.take() {
+            let (code, message) = message.parse()?;
+            messages.insert(code, message);
+        }
+        let (code, text) =
+            line.split_once('=').ok_or_else(|| error("Expected `=` after the code"))?;
+        let code = code.trim();
+        if !is_code(code) {
+            return Err(error("Invalid message code"));
+        }
+        pending = Some(PendingMessage {
+            code: code.to_string(),
+            line: line_number,
+            value: (text.trim_start().to_string(), line_number),
+            attributes: vec![],
+        });
+    }
+
+    if let Some(message) = pending.take() {
+        let (code, message) = message.parse()?;
+        messages.insert(code, message);
+    }
+    Ok(messages)
+}
+
+fn parse_pattern(text: &str, line: usize) -> Result<Pattern, MessageParseError> {
+    let error = |message: &str| MessageParseError { line, message: message.into() };
+    let mut elements = vec![];
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        if start > 0 {
+            elements.push(PatternElement::Text(rest[..start].to_string()));
+        }
+        let end = rest[start..].find('}').ok_or_else(|| error("Unclosed placeable"))? + start;
+        let placeable = rest[start + 1..end].trim();
+        if let Some(name) = placeable.strip_prefix('$') {
+            if !is_identifier(name) {
+                return Err(error("Invalid variable name"));
+            }
+            elements.push(PatternElement::Variable(name.to_string()));
+        } else if placeable.len() >= 2 && placeable.starts_with('"') && placeable.ends_with('"') {
+            elements.push(PatternElement::Text(placeable[1..placeable.len() - 1].to_string()));
+        } else {
+            return Err(error("Only variables and string literals are supported in placeables"));
+        }
+        rest = &rest[end + 1..];
+    }
+    if !rest.is_empty() {
+        elements.push(PatternElement::Text(rest.to_string()));
+    }
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_resource() {
+        let messages = parse_resource(
+            "# A comment\nemail = Invalid { $value }\nlength = Bad\n    .min = At least { $min }\n      and more\n",
+        )
+        .unwrap();
+        assert_eq!(
+            messages["email"].variants,
+            vec![vec![
+                PatternElement::Text("Invalid ".to_string()),
+                PatternElement::Variable("value".to_string()),
+            ]]
+        );
+        assert_eq!(
+            messages["length"].variants,
+            vec![
+                vec![
+                    PatternElement::Text("At least ".to_string()),
+                    PatternElement::Variable("min".to_string()),
+                    PatternElement::Text("\nand more".to_string()),
+                ],
+                vec![PatternElement::Text("Bad".to_string())],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_resource_dotted_codes() {
+        let messages =
+            parse_resource("user.length = Bad\n    .min = Short\nitem.v2.url = Bad").unwrap();
+        assert_eq!(messages["user.length"].variants.len(), 2);
+        assert!(messages.contains_key("item.v2.url"));
+    }
+
+    #[test]
+    fn test_parse_resource_errors() {
+        assert_eq!(parse_resource("email").unwrap_err().line, 1);
+        assert_eq!(parse_resource("email = a\n\n  .0 = b").unwrap_err().line, 3);
+        assert_eq!(parse_resource("email = { $ }").unwrap_err().line, 1);
+        assert_eq!(parse_resource("email = { -term }").unwrap_err().line, 1);
+        assert_eq!(parse_resource("email = {").unwrap_err().line, 1);
+        assert_eq!(parse_resource("email =\nurl = a").unwrap_err().line, 1);
+        assert_eq!(parse_resource("  .a = b").unwrap_err().line, 1);
+        assert_eq!(parse_resource("user..length = a").unwrap_err().line, 1);
+        assert_eq!(parse_resource("email = a\nuser. = b").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number("1000000", ".", ","), "1.000.000");
+        assert_eq!(format_number("-1234.5", ",", "."), "-1,234.5");
+        assert_eq!(format_number("999", ".", ","), "999");
+        assert_eq!(format_number("1e300", ".", ","), "1e300");
+    }
+
+    #[test]
+    fn test_builtin_resources_are_valid() {
+        for (language, _) in BUILTIN_RESOURCES {
+            assert!(MessageBundle::builtin(*language).is_some());
+        }
+        assert_eq!(MessageBundle::builtin("fr-CA").unwrap().locale(), "fr-CA");
+        assert!(MessageBundle::builtin("de").is_none());
+    }
+
+    #[test]
+    fn test_format_uses_the_first_complete_variant() {
+        let bundle = MessageBundle::builtin("en").unwrap();
+        let mut err = ValidationError::new("length");
+        assert_eq!(bundle.format(&err, "name").unwrap(), "Has an invalid length");
+        err.add_param(Cow::from("min"), &2.0);
+        assert_eq!(bundle.format(&err, "name").unwrap(), "Must have a length of at least 2");
+        err.add_param(Cow::from("max"), &2.5);
+        assert_eq!(bundle.format(&err, "name").unwrap(), "Must have a length between 2 and 2.5");
+    }
+
+    #[test]
+    fn test_formatter_gets_the_param_values() {
+        let mut bundle = MessageBundle::new("en");
+        bundle.add_resource("range = At most { $max }, not { $value }").unwrap();
+        bundle.set_formatter(ParamFormatter::new(|name, value| match (name, value) {
+            ("value", ParamValue::Int(i)) => Some(format!("{} units", i)),
+            _ => None,
+        }));
+        let mut err = ValidationError::new("range");
+        err.add_param(Cow::from("max"), &10.0);
+        err.add_param(Cow::from("value"), &12);
+        assert_eq!(bundle.format(&err, "count").unwrap(), "At most 10, not 12 units");
+    }
+
+    #[test]
+    fn test_format_knows_the_field_and_code() {
+        let mut bundle = MessageBundle::new("en");
+        bundle.add_resource("custom = { $field } is invalid ({ $code }, { \"literal\" })").unwrap();
+        let err = ValidationError::new("custom");
+        assert_eq!(bundle.format(&err, "name").unwrap(), "name is invalid (custom, literal)");
+        assert_eq!(bundle.format(&ValidationError::new("other"), "name"), None);
+    }
+}
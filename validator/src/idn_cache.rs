@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+static CAPACITY: AtomicUsize = AtomicUsize::new(0);
+
+static CACHE: Mutex<Option<Lru>> = Mutex::new(None);
+
+/// Caches whether the last `capacity` internationalized domains seen by `validate_email` are
+/// valid once converted to punycode, so bulk validations of addresses from a few domains only
+/// convert each of them once. The least recently used domains are evicted first.
+///
+/// The cache is disabled by default, a capacity of 0 disables it again. Changing the capacity
+/// empties the cache.
+pub fn set_idn_cache_capacity(capacity: usize) {
+    let mut cache = CACHE.lock().unwrap();
+    CAPACITY.store(capacity, Ordering::Relaxed);
+    *cache = None;
+}
+
+/// The capacity of the IDN cache set with `set_idn_cache_capacity`, 0 if it is disabled
+#[must_use]
+pub fn idn_cache_capacity() -> usize {
+    CAPACITY.load(Ordering::Relaxed)
+}
+
+/// Empties the IDN cache, keeping its capacity
+pub fn clear_idn_cache() {
+    *CACHE.lock().unwrap() = None;
+}
+
+/// The verdict of `check` on the domain, from the cache if it is enabled and has it. The lock
+/// isn't held while `check` runs.
+pub(crate) fn cached_verdict(domain: &str, check: impl FnOnce(&str) -> bool) -> bool {
+    let capacity = CAPACITY.load(Ordering::Relaxed);
+    if capacity == 0 {
+        return check(domain);
+    }
+    if let Some(verdict) = CACHE.lock().unwrap().as_mut().and_then(|lru| lru.get(domain)) {
+        return verdict;
+    }
+    let verdict = check(domain);
+    let mut cache = CACHE.lock().unwrap();
+    // The capacity may have changed meanwhile, the verdict is then only cached by the next call
+    if CAPACITY.load(Ordering::Relaxed) == capacity {
+        cache.get_or_insert_with(|| Lru::new(capacity)).insert(domain, verdict);
+    }
+    verdict
+}
+
+const NIL: usize = usize::MAX;
+
+struct Node {
+    domain: Box<str>,
+    verdict: bool,
+    prev: usize,
+    next: usize,
+}
+
+/// The verdicts of the domains, in a list from the most recently used to the least recently used
+/// one whose nodes are reused once the capacity is reached
+struct Lru {
+    capacity: usize,
+    positions: HashMap<Box<str>, usize>,
+    nodes: Vec<Node>,
+    head: usize,
+    tail: usize,
+}
+
+impl Lru {
+    fn new(capacity: usize) -> Lru {
+        Lru { capacity, positions: HashMap::new(), nodes: Vec::new(), head: NIL, tail: NIL }
+    }
+
+    fn get(&mut self, domain: &str) -> Option<bool> {
+        let i = *self.positions.get(domain)?;
+        self.unlink(i);
+        self.push_front(i);
+        Some(self.nodes[i].verdict)
+    }
+
+    fn insert(&mut self, domain: &str, verdict: bool) {
+        if let Some(&i) = self.positions.get(domain) {
+            self.nodes[i].verdict = verdict;
+            self.unlink(i);
+            self.push_front(i);
+            return;
+        }
+
+        let i = if self.nodes.len() < self.capacity {
+            self.nodes.push(Node { domain: domain.into(), verdict, prev: NIL, next: NIL });
+            self.nodes.len() - 1
+        } else {
+            let i = self.tail;
+            self.unlink(i);
+            self.positions.remove(&self.nodes[i].domain);
+            self.nodes[i].domain = domain.into();
+            self.nodes[i].verdict = verdict;
+            i
+        };
+        self.positions.insert(domain.into(), i);
+        self.push_front(i);
+    }
+
+    fn unlink(&mut self, i: usize) {
+        let (prev, next) = (self.nodes[i].prev, self.nodes[i].next);
+        match prev {
+            NIL => self.head = next,
+            prev => self.nodes[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            next => self.nodes[next].prev = prev,
+        }
+    }
+
+    fn push_front(&mut self, i: usize) {
+        self.nodes[i].prev = NIL;
+        self.nodes[i].next = self.head;
+        match self.head {
+            NIL => self.tail = i,
+            head => self.nodes[head].prev = i,
+        }
+        self.head = i;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evicts_the_least_recently_used_domain() {
+        let mut lru = Lru::new(2);
+        lru.insert("a.tld", true);
+        lru.insert("b.tld", false);
+        assert_eq!(lru.get("a.tld"), Some(true));
+        lru.insert("c.tld", true);
+        assert_eq!(lru.get("b.tld"), None);
+        assert_eq!(lru.get("a.tld"), Some(true));
+        assert_eq!(lru.get("c.tld"), Some(true));
+
+        lru.insert("a.tld", false);
+        lru.insert("d.tld", true);
+        assert_eq!(lru.get("c.tld"), None);
+        assert_eq!(lru.get("a.tld"), Some(false));
+        assert_eq!(lru.get("d.tld"), Some(true));
+        assert_eq!(lru.positions.len(), 2);
+    }
+
+    #[test]
+    fn test_checks_each_domain_once() {
+        set_idn_cache_capacity(8);
+        let mut checks = 0;
+        for _ in 0..3 {
+            let verdict = cached_verdict("例え.テスト", |_| {
+                checks += 1;
+                true
+            });
+            assert!(verdict);
+        }
+        assert_eq!(checks, 1);
+        assert_eq!(idn_cache_capacity(), 8);
+
+        clear_idn_cache();
+        assert!(!cached_verdict("例え.テスト", |_| false));
+        set_idn_cache_capacity(0);
+        assert!(cached_verdict("例え.テスト", |_| true));
+    }
+}
@@ -0,0 +1,279 @@
+use serde_json::{json, Map, Value};
+
+use crate::rules::Rule;
+use crate::traits::ValidationRules;
+
+/// The kind of JSON value a Rust type is serialized to, when it can be known from its name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum JsonType {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Array,
+    Object,
+}
+
+impl JsonType {
+    fn name(self) -> &'static str {
+        match self {
+            JsonType::String => "string",
+            JsonType::Integer => "integer",
+            JsonType::Number => "number",
+            JsonType::Boolean => "boolean",
+            JsonType::Array => "array",
+            JsonType::Object => "object",
+        }
+    }
+}
+
+/// Returns a [draft 2020-12](https://json-schema.org/draft/2020-12/schema) JSON Schema of the
+/// fields with validators of `T`, from its `ValidationRules`, so the same constraints can be
+/// enforced by a frontend or an API gateway. For example `length` is `minLength` and `maxLength`
+/// on a string, `range` is `minimum` and `maximum` and `email` is `format: email`.
+///
+/// The fields without validators are not in the schema, nor the validators that can't be
+/// expressed in it, like `custom`, `must_match` or the struct level ones. The nested structs are
+/// only described as objects.
+pub fn to_json_schema<T: ValidationRules>() -> Value {
+    let mut properties = Map::new();
+    let mut required = vec![];
+    for field in T::validation_rules() {
+        if field.field == "__all__" {
+            continue;
+        }
+        let (optional, field_type) = match unwrap_generic(field.field_type, "Option") {
+            Some(inner) => (true, inner),
+            None => (false, field.field_type),
+        };
+        if !field.conditional
+            && (!optional || field.rules.iter().any(|rule| rule.kind == "required"))
+        {
+            required.push(Value::from(field.field));
+        }
+
+        let mut schema = schema_of(field_type, &field.rules);
+        let (key_type, inner_type) = element_types(field_type);
+        if !field.keys.is_empty() {
+            schema.insert(
+                "propertyNames".to_string(),
+                Value::Object(schema_of(key_type, &field.keys)),
+            );
+        }
+        if !field.inner.is_empty() {
+            let inner = Value::Object(schema_of(inner_type, &field.inner));
+            match json_type(field_type) {
+                Some(JsonType::Object) => schema.insert("additionalProperties".to_string(), inner),
+                _ => schema.insert("items".to_string(), inner),
+            };
+        }
+        properties.insert(field.field.to_string(), Value::Object(schema));
+    }
+
+    let mut schema = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": properties,
+    });
+    if !required.is_empty() {
+        schema["required"] = Value::Array(required);
+    }
+    schema
+}
+
+/// Adds the constraints of the validators of `T` to the properties of a schema generated for it by
+/// another tool, like the OpenAPI schema of `utoipa` or `schemars` serialized to JSON, so they
+/// don't have to be maintained twice. The constraints replace the ones of the same keyword, the
+/// `type` of the properties and the properties that aren't in the schema are left as is.
+pub fn enrich_json_schema<T: ValidationRules>(schema: &mut Value) {
+    let constraints = to_json_schema::<T>();
+    let (constraints, properties) =
+        match (constraints.get("properties"), schema.get_mut("properties")) {
+            (Some(Value::Object(constraints)), Some(Value::Object(properties))) => {
+                (constraints, properties)
+            }
+            _ => return,
+        };
+    for (name, constraints) in constraints {
+        let property = match properties.get_mut(name) {
+            Some(Value::Object(property)) => property,
+            _ => continue,
+        };
+        if let Value::Object(constraints) = constraints {
+            for (keyword, value) in constraints {
+                if keyword != "type" {
+                    property.insert(keyword.clone(), value.clone());
+                }
+            }
+        }
+    }
+}
+
+/// The constraints of the rules on a value of that type
+fn schema_of(field_type: &str, rules: &[Rule]) -> Map<String, Value> {
+    let field_type = unwrap_generic(field_type, "Option").unwrap_or(field_type);
+    let json_type = json_type(field_type);
+    let mut schema = Map::new();
+    if let Some(json_type) = json_type {
+        schema.insert("type".to_string(), Value::from(json_type.name()));
+    }
+    let mut patterns = vec![];
+
+    for rule in rules {
+        let param = |name: &str| rule.params.get(name).cloned();
+        let mut set = |keyword: &str, value: Option<Value>| {
+            if let Some(value) = value {
+                schema.insert(keyword.to_string(), value);
+            }
+        };
+        match rule.kind {
+            "length" => {
+                let (min, max) = match json_type {
+                    Some(JsonType::Array) => ("minItems", "maxItems"),
+                    Some(JsonType::Object) => ("minProperties", "maxProperties"),
+                    _ => ("minLength", "maxLength"),
+                };
+                set(min, param("min").or_else(|| param("equal")).map(as_integer));
+                set(max, param("max").or_else(|| param("equal")).map(as_integer));
+            }
+            "range" => {
+                set("minimum", param("min"));
+                set("maximum", param("max"));
+                set("exclusiveMinimum", param("exclusive_min"));
+                set("exclusiveMaximum", param("exclusive_max"));
+                set("multipleOf", param("step"));
+            }
+            "percentage" | "ratio" => {
+                let max = if rule.kind == "percentage" { 100 } else { 1 };
+                if param("exclusive") == Some(Value::Bool(true)) {
+                    set("exclusiveMinimum", Some(Value::from(0)));
+                    set("exclusiveMaximum", Some(Value::from(max)));
+                } else {
+                    set("minimum", Some(Value::from(0)));
+                    set("maximum", Some(Value::from(max)));
+                }
+            }
+            "email" => set("format", Some(Value::from("email"))),
+            "url" => set("format", Some(Value::from("uri"))),
+            "regex" => {
+                if let Some(Value::String(pattern)) = param("pattern") {
+                    patterns.push(pattern);
+                }
+            }
+            "contains" if json_type == Some(JsonType::String) => {
+                if let Some(Value::String(needle)) = param("needle") {
+                    patterns.push(regex::escape(&needle));
+                }
+            }
+            "does_not_contain" if json_type == Some(JsonType::String) => {
+                if let Some(Value::String(needle)) = param("needle") {
+                    set("not", Some(json!({ "pattern": regex::escape(&needle) })));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    // A schema has a single `pattern`, the others are in an `allOf`
+    let mut patterns = patterns.into_iter();
+    if let Some(pattern) = patterns.next() {
+        schema.insert("pattern".to_string(), Value::from(pattern));
+    }
+    let others: Vec<_> = patterns.map(|pattern| json!({ "pattern": pattern })).collect();
+    if !others.is_empty() {
+        schema.insert("allOf".to_string(), Value::Array(others));
+    }
+    schema
+}
+
+/// The bounds of `length` are stored as floats
+pub(crate) fn as_integer(value: Value) -> Value {
+    match value.as_f64() {
+        Some(f) if f.fract() == 0.0 && f >= 0.0 => Value::from(f as u64),
+        _ => value,
+    }
+}
+
+pub(crate) fn json_type(field_type: &str) -> Option<JsonType> {
+    let name = field_type.split('<').next().unwrap_or_default();
+    let name = match name.strip_prefix('&') {
+        // References with a lifetime are written without spaces, like `&'astr`
+        Some(_) if name.ends_with("str") => "str",
+        Some(_) if name.contains('[') => return Some(JsonType::Array),
+        Some(reference) => match reference.strip_prefix('\'') {
+            Some(lifetime) => lifetime.trim_start_matches(|c: char| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'
+            }),
+            None => reference.strip_prefix("mut").unwrap_or(reference),
+        },
+        None => name,
+    };
+    let name = name.rsplit("::").next().unwrap_or_default();
+
+    match name {
+        _ if name.starts_with('[') => Some(JsonType::Array),
+        "str" | "String" | "Cow" | "char" => Some(JsonType::String),
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+        | "isize" => Some(JsonType::Integer),
+        "f32" | "f64" => Some(JsonType::Number),
+        "bool" => Some(JsonType::Boolean),
+        "Vec" | "VecDeque" | "HashSet" | "BTreeSet" | "IndexSet" => Some(JsonType::Array),
+        "HashMap" | "BTreeMap" | "IndexMap" => Some(JsonType::Object),
+        _ => None,
+    }
+}
+
+/// The types of the keys and of the elements, or values, of a collection type
+pub(crate) fn element_types(field_type: &str) -> (&str, &str) {
+    let arguments = match (field_type.find('<'), field_type.rfind('>')) {
+        (Some(start), Some(end)) if start < end => &field_type[start + 1..end],
+        _ => {
+            // Slices and arrays
+            let element = field_type.trim_start_matches('&').trim_start_matches('[');
+            return ("", element.split(';').next().unwrap_or_default().trim_end_matches(']'));
+        }
+    };
+    // Splits at the first comma that isn't in the arguments of a nested type
+    let mut depth = 0;
+    for (idx, c) in arguments.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => return (&arguments[..idx], &arguments[idx + 1..]),
+            _ => (),
+        }
+    }
+    ("", arguments)
+}
+
+/// The argument of a type like `Option<T>`
+pub(crate) fn unwrap_generic<'a>(field_type: &'a str, name: &str) -> Option<&'a str> {
+    let field_type = field_type.strip_prefix("std::option::").unwrap_or(field_type);
+    field_type.strip_prefix(name)?.strip_prefix('<')?.strip_suffix('>')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_type() {
+        assert_eq!(json_type("String"), Some(JsonType::String));
+        assert_eq!(json_type("&'astr"), Some(JsonType::String));
+        assert_eq!(json_type("Cow<'a,str>"), Some(JsonType::String));
+        assert_eq!(json_type("std::collections::HashMap<String,u8>"), Some(JsonType::Object));
+        assert_eq!(json_type("&[u8]"), Some(JsonType::Array));
+        assert_eq!(json_type("&'aVec<u8>"), Some(JsonType::Array));
+        assert_eq!(json_type("[u8;4]"), Some(JsonType::Array));
+        assert_eq!(json_type("&u32"), Some(JsonType::Integer));
+        assert_eq!(json_type("Address"), None);
+    }
+
+    #[test]
+    fn test_element_types() {
+        assert_eq!(element_types("Vec<String>"), ("", "String"));
+        assert_eq!(element_types("HashMap<String,Vec<u8>>"), ("String", "Vec<u8>"));
+        assert_eq!(element_types("[u8;4]"), ("", "u8"));
+        assert_eq!(element_types("&[i64]"), ("", "i64"));
+    }
+}
@@ -43,11 +43,22 @@
 //! | `url`                   |                                                       |
 //! | `length`                |                                                       |
 //! | `range`                 |                                                       |
+//! | `percentage`            |                                                       |
+//! | `ratio`                 |                                                       |
+//! | `bits_within`           |                                                       |
 //! | `must_match`            |                                                       |
+//! | `greater_than`          |                                                       |
+//! | `greater_than_or_equal` |                                                       |
+//! | `less_than`             |                                                       |
+//! | `less_than_or_equal`    |                                                       |
+//! | `before`                |                                                       |
+//! | `after`                 |                                                       |
 //! | `contains`              |                                                       |
 //! | `does_not_contain`      |                                                       |
 //! | `custom`                |                                                       |
+//! | `custom_async`          | (Requires the feature `async` to be enabled)          |
 //! | `regex`                 |                                                       |
+//! | `rule`                  | (Registered with `register_validator!`)               |
 //! | `credit_card`           | (Requires the feature `card` to be enabled)           |
 //! | `phone`                 | (Requires the feature `phone` to be enabled)          |
 //! | `non_control_character` | (Required the feature `unic` to be enabled)           |
@@ -64,30 +75,349 @@
 //! validator = { version = "0.12", features = ["derive"] }
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "std")]
+mod batch;
+#[cfg(feature = "std")]
+mod code;
+#[cfg(feature = "std")]
+pub mod combinators;
+#[cfg(feature = "std")]
+mod config;
+#[cfg(feature = "config-file")]
+mod config_file;
+#[cfg(feature = "std")]
+mod depth;
+#[cfg(feature = "std")]
+mod deserialization;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+#[cfg(feature = "std")]
 mod display_impl;
+#[cfg(feature = "std")]
+mod dynamic;
+#[cfg(feature = "std")]
+mod error_builder;
+#[cfg(feature = "std")]
+mod error_map;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "std")]
+pub mod field;
+#[cfg(feature = "forms")]
+pub mod forms;
+#[cfg(feature = "grpc")]
+mod grpc;
+#[cfg(feature = "i18n")]
+mod i18n;
+#[cfg(feature = "std")]
+mod idn_cache;
+#[cfg(feature = "std")]
+mod json_schema;
+#[cfg(feature = "std")]
+mod normalization;
+#[cfg(feature = "std")]
+mod observer;
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "std")]
+mod param;
+#[cfg(feature = "std")]
+mod path;
+#[cfg(feature = "std")]
+mod problem;
+#[cfg(feature = "std")]
+mod proto;
+#[cfg(feature = "std")]
+mod registry;
+#[cfg(feature = "std")]
+mod report;
+#[cfg(feature = "std")]
+mod rules;
+#[cfg(feature = "test-strategies")]
+mod samples;
+#[cfg(feature = "std")]
+mod serialization;
+#[cfg(feature = "tower")]
+mod tower;
+#[cfg(feature = "tracing")]
+mod tracing;
+#[cfg(feature = "std")]
 mod traits;
-mod types;
+#[cfg(feature = "std")]
+pub mod types;
+#[cfg(feature = "std")]
+mod typescript;
+#[cfg(feature = "std")]
+mod valid;
 mod validation;
 
+pub use validation::bits_within::validate_bits_within;
 #[cfg(feature = "card")]
 pub use validation::cards::validate_credit_card;
-pub use validation::contains::validate_contains;
+#[cfg(feature = "card")]
+pub use validation::check::check_credit_card;
+#[cfg(feature = "unic")]
+pub use validation::check::{check_nfc, check_nfkc, check_non_control_character};
+#[cfg(feature = "phone")]
+pub use validation::check::check_phone;
+#[cfg(feature = "std")]
+pub use validation::check::{
+    check_bits_within, check_contains, check_contains_word, check_does_not_contain,
+    check_does_not_contain_word, check_email, check_greater_than, check_greater_than_or_equal,
+    check_ip, check_ip_v4, check_ip_v6, check_length, check_less_than, check_less_than_or_equal,
+    check_luhn, check_must_match, check_percentage, check_range, check_ratio, check_required,
+    check_url, Numeric,
+};
+pub use validation::compare::{
+    validate_greater_than, validate_greater_than_or_equal, validate_less_than,
+    validate_less_than_or_equal,
+};
+pub use validation::contains::{validate_contains, Contains};
 pub use validation::does_not_contain::validate_does_not_contain;
-pub use validation::email::{validate_email, ValidateEmail};
+#[cfg(feature = "std")]
+pub use validation::email::{email_error_span, validate_email, ValidateEmail};
 pub use validation::ip::{validate_ip, validate_ip_v4, validate_ip_v6};
 pub use validation::length::{validate_length, ValidateLength};
+#[cfg(feature = "async")]
+pub use validation::length_reader::validate_length_async_reader;
+#[cfg(feature = "std")]
+pub use validation::length_reader::{validate_length_reader, LengthCounter, LengthUnit};
+pub use validation::literal;
+pub use validation::luhn::validate_luhn;
 pub use validation::must_match::validate_must_match;
 #[cfg(feature = "unic")]
-pub use validation::non_control_character::validate_non_control_character;
+pub use validation::non_control_character::{
+    control_character_span, validate_non_control_character,
+};
+#[cfg(feature = "unic")]
+pub use validation::normalization_form::{validate_nfc, validate_nfkc};
+pub use validation::percentage::validate_percentage;
 #[cfg(feature = "phone")]
 pub use validation::phone::validate_phone;
-pub use validation::range::{validate_range, ValidateRange};
-
+pub use validation::range::{
+    validate_range, validate_range_step, validate_range_step_integer, ValidateRange,
+};
+pub use validation::ratio::validate_ratio;
 pub use validation::required::{validate_required, ValidateRequired};
-pub use validation::urls::{validate_url, ValidateUrl};
+#[cfg(feature = "std")]
+pub use validation::urls::{url_error_span, validate_url, ValidateUrl};
+#[cfg(feature = "std")]
+pub use validation::words::{validate_contains_word, validate_does_not_contain_word, WordList};
+
+#[cfg(feature = "axum")]
+pub use crate::axum::{
+    ErrorsResponse, RejectionConfig, ValidatedForm, ValidatedJson, ValidatedPath, ValidatedQuery,
+    ValidationRejection,
+};
+#[cfg(feature = "tower")]
+pub use crate::tower::{ValidateJson, ValidateJsonLayer};
+#[cfg(feature = "std")]
+pub use batch::{BatchReport, BatchValidator, RowError, DESERIALIZE_CODE};
+#[cfg(feature = "std")]
+pub use code::ErrorCode;
+#[cfg(feature = "std")]
+pub use config::ValidationConfig;
+#[cfg(feature = "config-file")]
+pub use config_file::{load_config, ConfigFileError};
+#[cfg(feature = "std")]
+pub use depth::{max_nesting_depth, set_max_nesting_depth, DEFAULT_MAX_NESTING_DEPTH};
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::{Diagnostics, Label};
+#[cfg(feature = "std")]
+pub use display_impl::CompactDisplay;
+#[cfg(feature = "std")]
+pub use dynamic::{DynamicRulesError, DynamicValidator, FieldValidator, StructValidator};
+#[cfg(feature = "std")]
+pub use error_builder::ValidationErrorBuilder;
+#[cfg(feature = "std")]
+pub use error_map::ErrorMap;
+#[cfg(feature = "grpc")]
+pub use grpc::{FieldViolation, GrpcStatus, BAD_REQUEST_TYPE_URL, INVALID_ARGUMENT};
+#[cfg(feature = "i18n")]
+pub use i18n::{MessageBundle, MessageParseError, ParamFormatter};
+#[cfg(feature = "std")]
+pub use idn_cache::{clear_idn_cache, idn_cache_capacity, set_idn_cache_capacity};
+#[cfg(feature = "std")]
+pub use json_schema::{enrich_json_schema, to_json_schema};
+#[cfg(feature = "unic")]
+pub use normalization::{normalize_nfc, normalize_nfkc};
+#[cfg(feature = "std")]
+pub use normalization::{
+    normalize_capitalize, normalize_lowercase, normalize_trim, normalize_truncate,
+    normalize_uppercase, NormalizeString,
+};
+#[cfg(feature = "std")]
+pub use observer::{remove_validation_observer, set_validation_observer, ValidationObserver};
+#[cfg(feature = "parallel")]
+pub use parallel::validate_all_par;
+#[cfg(feature = "std")]
+pub use param::ParamValue;
+#[cfg(feature = "std")]
+pub use path::{FieldPath, PathSegment};
+#[cfg(feature = "std")]
+pub use problem::{ProblemDetails, ProblemError, PROBLEM_JSON_CONTENT_TYPE};
+#[cfg(feature = "std")]
+pub use proto::ProtoRules;
+#[cfg(feature = "std")]
+pub use registry::{is_validator_registered, register_validator, validate_registered};
+#[cfg(feature = "std")]
+pub use report::ValidationReport;
+#[cfg(feature = "std")]
+pub use rules::{FieldRules, Rule};
+#[cfg(feature = "test-strategies")]
+pub use samples::{InvalidSample, SampleError, Samples};
+#[cfg(feature = "std")]
+pub use serialization::{ErrorsShape, ShapedErrors};
+#[cfg(feature = "async")]
+pub use traits::ValidateAsync;
+#[cfg(feature = "std")]
+pub use traits::{
+    AsRegex, CustomResult, FromValidationErrors, HasLen, Normalize, SchemaResult, Validate,
+    ValidateArgs, ValidateWithContext, ValidationRules,
+};
+#[cfg(feature = "std")]
+pub use types::{
+    ErrorSource, Severity, ValidationError, ValidationErrors, ValidationErrorsKind,
+    ValidationResultExt,
+};
+#[cfg(feature = "std")]
+pub use typescript::{to_zod_schema, TypeScriptGenerator};
+#[cfg(feature = "std")]
+pub use valid::Valid;
+
+// Used by the code generated by the derive
+#[doc(hidden)]
+#[cfg(feature = "std")]
+pub mod __private {
+    pub use regex::Regex;
+
+    pub use crate::config::should_stop;
+    pub use crate::observer::observed;
+    #[cfg(feature = "parallel")]
+    pub use crate::parallel::validate_nested_par;
+    pub use crate::report::{is_lenient, is_reporting};
+    use crate::{Severity, Validate, ValidationError, ValidationErrors};
+
+    /// Validates a nested field with the mode of the struct it is in, one level deeper
+    pub fn validate_nested<const FAIL_FAST: bool, T: Validate + ?Sized>(
+        value: &T,
+    ) -> Result<(), ValidationErrors> {
+        let validate = || {
+            if FAIL_FAST {
+                value.validate_fail_fast()
+            } else {
+                value.validate()
+            }
+        };
+        #[cfg(feature = "tracing")]
+        let result = crate::depth::validate_deeper(|| {
+            if crate::tracing::is_tracing() {
+                crate::tracing::traced_nested::<T, _>(validate)
+            } else {
+                validate()
+            }
+        });
+        #[cfg(not(feature = "tracing"))]
+        let result = crate::depth::validate_deeper(validate);
+        crate::config::nested_done(&result);
+        result
+    }
 
-pub use traits::{Contains, HasLen, Validate, ValidateArgs};
-pub use types::{ValidationError, ValidationErrors, ValidationErrorsKind};
+    /// Adds the errors of a validator with a severity other than `Error`
+    pub fn add_with_severity(
+        errors: &mut ValidationErrors,
+        mut found: ValidationErrors,
+        severity: Severity,
+    ) {
+        found.set_severity(severity);
+        errors.extend(found);
+    }
 
+    /// The first error at that path with that code, for `#[derive(FromValidationErrors)]`
+    pub fn find_error<'a>(
+        errors: &'a ValidationErrors,
+        field: Option<&str>,
+        code: Option<&str>,
+    ) -> Option<&'a ValidationError> {
+        let has_code = |error: &&ValidationError| code.is_none_or(|code| error.code == code);
+        match field {
+            Some(field) => errors.field_errors_at(field)?.iter().find(has_code),
+            None => errors.iter().map(|(_, error)| error).find(has_code),
+        }
+    }
+
+    /// Whether a result has errors of the `Error` severity, the others don't stop the validation
+    pub fn is_blocking(result: &Result<(), ValidationErrors>) -> bool {
+        result.as_ref().is_err_and(ValidationErrors::is_blocking)
+    }
+
+    /// A nested value of a struct deriving `ValidateAsync`. Its `validate_nested_async` method
+    /// awaits the `ValidateAsync` implementation of the value when it has one, and returns `None`
+    /// otherwise: the impl for `AsyncNested` is picked over the one for `&AsyncNested` when the
+    /// type of the value implements `ValidateAsync`.
+    #[cfg(feature = "async")]
+    pub struct AsyncNested<'a, T: ?Sized>(pub &'a T);
+
+    #[cfg(feature = "async")]
+    pub type NestedFuture<'a> = std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<(), ValidationErrors>> + Send + 'a>,
+    >;
+
+    #[cfg(feature = "async")]
+    pub trait ViaValidateAsync<'a> {
+        fn validate_nested_async(&self) -> Option<NestedFuture<'a>>;
+    }
+
+    /// Only the errors of the async validators, the ones of `Validate` were already added to the
+    /// struct the value is nested in
+    #[cfg(feature = "async")]
+    impl<'a, T: crate::ValidateAsync + Sync + ?Sized> ViaValidateAsync<'a> for AsyncNested<'a, T> {
+        fn validate_nested_async(&self) -> Option<NestedFuture<'a>> {
+            Some(self.0.validate_async_only())
+        }
+    }
+
+    /// Adds the errors of `ValidateAsync::validate_async_only` to the result of
+    /// `Validate::validate`
+    #[cfg(feature = "async")]
+    pub fn merge_async(
+        result: Result<(), ValidationErrors>,
+        async_result: Result<(), ValidationErrors>,
+    ) -> Result<(), ValidationErrors> {
+        match (result, async_result) {
+            (result, Ok(())) => result,
+            (Ok(()), Err(async_errors)) => Err(async_errors),
+            (Err(mut errors), Err(async_errors)) => {
+                errors.extend_async(async_errors);
+                Err(errors)
+            }
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait ViaValidate<'a> {
+        fn validate_nested_async(&self) -> Option<NestedFuture<'a>>;
+    }
+
+    #[cfg(feature = "async")]
+    impl<'a, T: ?Sized> ViaValidate<'a> for &AsyncNested<'a, T> {
+        fn validate_nested_async(&self) -> Option<NestedFuture<'a>> {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "derive")]
+pub use validator_derive::FromValidationErrors;
+#[cfg(feature = "derive")]
+pub use validator_derive::Normalize;
 #[cfg(feature = "derive")]
 pub use validator_derive::Validate;
+#[cfg(feature = "async")]
+pub use validator_derive::ValidateAsync;
@@ -0,0 +1,147 @@
+#[cfg(feature = "unic")]
+use unicode_normalization::UnicodeNormalization;
+
+/// The values the normalizations of `#[normalize(..)]` can be applied to: strings, and the strings
+/// of an `Option` or a `Vec`
+pub trait NormalizeString {
+    fn apply(&mut self, normalization: fn(&mut String));
+}
+
+impl NormalizeString for String {
+    fn apply(&mut self, normalization: fn(&mut String)) {
+        normalization(self)
+    }
+}
+
+impl<T: NormalizeString> NormalizeString for Option<T> {
+    fn apply(&mut self, normalization: fn(&mut String)) {
+        if let Some(value) = self {
+            value.apply(normalization)
+        }
+    }
+}
+
+impl<T: NormalizeString> NormalizeString for Vec<T> {
+    fn apply(&mut self, normalization: fn(&mut String)) {
+        for value in self {
+            value.apply(normalization)
+        }
+    }
+}
+
+/// Removes the leading and trailing whitespace
+pub fn normalize_trim(value: &mut String) {
+    let trimmed = value.trim();
+    if trimmed.len() != value.len() {
+        *value = trimmed.to_string();
+    }
+}
+
+pub fn normalize_lowercase(value: &mut String) {
+    *value = value.to_lowercase();
+}
+
+pub fn normalize_uppercase(value: &mut String) {
+    *value = value.to_uppercase();
+}
+
+/// Uppercases the first character
+pub fn normalize_capitalize(value: &mut String) {
+    let mut chars = value.chars();
+    if let Some(first) = chars.next() {
+        if !first.is_uppercase() {
+            *value = first.to_uppercase().chain(chars).collect();
+        }
+    }
+}
+
+/// Keeps at most `max` characters
+pub fn normalize_truncate(value: &mut String, max: usize) {
+    if let Some((index, _)) = value.char_indices().nth(max) {
+        value.truncate(index);
+    }
+}
+
+/// Puts the string in the Unicode Normalization Form C, so the same text is always made of the
+/// same characters
+#[cfg(feature = "unic")]
+pub fn normalize_nfc(value: &mut String) {
+    *value = value.nfc().collect();
+}
+
+/// Puts the string in the Unicode Normalization Form KC, which also replaces the compatibility
+/// characters like ligatures and full-width letters by their usual equivalents
+#[cfg(feature = "unic")]
+pub fn normalize_nfkc(value: &mut String) {
+    *value = value.nfkc().collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_trim() {
+        let mut value = "  hello world \n".to_string();
+        normalize_trim(&mut value);
+        assert_eq!(value, "hello world");
+    }
+
+    #[test]
+    fn test_normalize_case() {
+        let mut value = "Hello Wörld".to_string();
+        normalize_lowercase(&mut value);
+        assert_eq!(value, "hello wörld");
+        normalize_uppercase(&mut value);
+        assert_eq!(value, "HELLO WÖRLD");
+    }
+
+    #[test]
+    fn test_normalize_capitalize() {
+        let mut value = "élan vital".to_string();
+        normalize_capitalize(&mut value);
+        assert_eq!(value, "Élan vital");
+
+        let mut value = String::new();
+        normalize_capitalize(&mut value);
+        assert_eq!(value, "");
+    }
+
+    #[test]
+    fn test_normalize_truncate() {
+        let mut value = "héllo".to_string();
+        normalize_truncate(&mut value, 2);
+        assert_eq!(value, "hé");
+
+        let mut value = "hi".to_string();
+        normalize_truncate(&mut value, 5);
+        assert_eq!(value, "hi");
+    }
+
+    #[cfg(feature = "unic")]
+    #[test]
+    fn test_normalize_nfc() {
+        let mut value = "e\u{301}te\u{301}".to_string();
+        normalize_nfc(&mut value);
+        assert_eq!(value, "\u{e9}t\u{e9}");
+    }
+
+    #[cfg(feature = "unic")]
+    #[test]
+    fn test_normalize_nfkc() {
+        let mut value = "\u{fb01}le \u{ff21}e\u{301}".to_string();
+        normalize_nfkc(&mut value);
+        assert_eq!(value, "file A\u{e9}");
+    }
+
+    #[test]
+    fn test_apply_to_options_and_vecs() {
+        let mut value = Some(vec![" a ".to_string(), "b ".to_string()]);
+        value.apply(normalize_trim);
+        assert_eq!(value, Some(vec!["a".to_string(), "b".to_string()]));
+
+        let mut value: Option<String> = None;
+        value.apply(normalize_trim);
+        assert_eq!(value, None);
+    }
+}
@@ -0,0 +1,107 @@
+use std::any::type_name;
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use crate::path::FieldPath;
+use crate::types::ValidationErrors;
+
+/// Is told the outcome of the validations, to count them as metrics for example. It is either
+/// registered for the whole program with [`set_validation_observer`], or given to a single call
+/// of `Validate::validate_observed`.
+///
+/// The nested structs are part of the validation of the struct they are in, they aren't reported
+/// on their own.
+pub trait ValidationObserver {
+    /// A value of that type is valid
+    fn on_success(&self, _type_name: &'static str) {}
+
+    /// A value of that type is invalid, called for each of its errors
+    fn on_failure(&self, _type_name: &'static str, _field: &FieldPath<'_>, _code: &str) {}
+}
+
+type GlobalObserver = Arc<dyn ValidationObserver + Send + Sync>;
+
+static OBSERVER: RwLock<Option<GlobalObserver>> = RwLock::new(None);
+
+/// Whether there is a global observer, so the validations don't take the lock when there isn't
+static HAS_OBSERVER: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static SUPPRESSED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Registers the observer told the outcome of every validation of a derived `Validate`, in place
+/// of the previous one if any
+pub fn set_validation_observer<O>(observer: O)
+where
+    O: ValidationObserver + Send + Sync + 'static,
+{
+    *OBSERVER.write().unwrap() = Some(Arc::new(observer));
+    HAS_OBSERVER.store(true, Ordering::Release);
+}
+
+/// Removes the observer registered with [`set_validation_observer`]
+pub fn remove_validation_observer() {
+    HAS_OBSERVER.store(false, Ordering::Release);
+    *OBSERVER.write().unwrap() = None;
+}
+
+fn notify(
+    observer: &dyn ValidationObserver,
+    type_name: &'static str,
+    result: &Result<(), ValidationErrors>,
+) {
+    match result {
+        Ok(()) => observer.on_success(type_name),
+        Err(errors) => {
+            for (path, error) in errors.iter() {
+                observer.on_failure(type_name, &path, &error.code);
+            }
+        }
+    }
+}
+
+/// Tells the global observer the outcome of the validation of a `T`, unless it is nested or
+/// observed by another observer. Called by the generated code.
+pub fn observed<T: ?Sized>(result: Result<(), ValidationErrors>) -> Result<(), ValidationErrors> {
+    if !HAS_OBSERVER.load(Ordering::Acquire)
+        || crate::depth::nesting_depth() > 0
+        || SUPPRESSED.with(|suppressed| suppressed.get())
+    {
+        return result;
+    }
+    // The lock isn't held while the observer runs, so it can validate or replace itself
+    let observer = OBSERVER.read().unwrap().clone();
+    if let Some(observer) = observer {
+        notify(&*observer, type_name::<T>(), &result);
+    }
+    result
+}
+
+/// Restores the previous state when the observed validation is done, even if it panics
+struct SuppressedGuard(bool);
+
+impl Drop for SuppressedGuard {
+    fn drop(&mut self) {
+        SUPPRESSED.with(|suppressed| suppressed.set(self.0));
+    }
+}
+
+/// Runs the validation of a `T` telling that observer its outcome instead of the global one
+pub(crate) fn observe_with<T: ?Sized, F>(
+    observer: &dyn ValidationObserver,
+    validate: F,
+) -> Result<(), ValidationErrors>
+where
+    F: FnOnce() -> Result<(), ValidationErrors>,
+{
+    let result = {
+        let _guard = SuppressedGuard(SUPPRESSED.with(|suppressed| suppressed.replace(true)));
+        validate()
+    };
+    if crate::depth::nesting_depth() == 0 {
+        notify(observer, type_name::<T>(), &result);
+    }
+    result
+}
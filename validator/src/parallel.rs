@@ -0,0 +1,147 @@
+use std::cell::Cell;
+use std::num::NonZeroUsize;
+use std::panic;
+use std::thread;
+
+use crate::traits::Validate;
+use crate::types::ValidationErrors;
+
+/// The minimum number of values validated by a thread, below it spawning costs more than it saves
+const MIN_CHUNK_LEN: usize = 64;
+
+thread_local! {
+    /// Whether the thread is one of the threads of a parallel validation
+    static IN_PARALLEL: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Maps the values on as many threads as there are cores, keeping their order.
+///
+/// There is no thread pool: each call spawns its threads, which end with it. A call from one of
+/// those threads, like a nested `#[validate(nested, parallel)]` list, maps on that thread instead
+/// of multiplying the threads by the number of cores at each level.
+fn map_par<T, R, F>(values: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    if IN_PARALLEL.with(Cell::get) {
+        return values.iter().map(f).collect();
+    }
+    let cores = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    map_on_threads(values, cores.min(values.len().div_ceil(MIN_CHUNK_LEN)), f)
+}
+
+/// Maps the values on that number of threads, each mapping a contiguous chunk of them
+fn map_on_threads<T, R, F>(values: &[T], threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    if threads <= 1 {
+        return values.iter().map(f).collect();
+    }
+
+    let chunk_len = values.len().div_ceil(threads);
+    let f = &f;
+    thread::scope(|scope| {
+        let handles: Vec<_> = values
+            .chunks(chunk_len)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    IN_PARALLEL.with(|in_parallel| in_parallel.set(true));
+                    chunk.iter().map(f).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    })
+}
+
+/// Validates the values on all the cores, for the imports of hundreds of thousands of records,
+/// returning the result of each value in their order. The threads are spawned for the call rather
+/// than taken from a pool, and the parallel validations they run are done on them sequentially:
+///
+/// ```ignore
+/// let results = validate_all_par(&records);
+/// let invalid = results.iter().filter(|result| result.is_err()).count();
+/// ```
+pub fn validate_all_par<T: Validate + Sync>(values: &[T]) -> Vec<Result<(), ValidationErrors>> {
+    map_par(values, T::validate)
+}
+
+/// Whether the validation has no state kept per thread that the other threads would miss: the
+/// limits of `validate_with_config`, or the mode of a report, of a lenient or a traced validation
+fn can_run_in_parallel() -> bool {
+    #[cfg(feature = "tracing")]
+    if crate::tracing::is_tracing() {
+        return false;
+    }
+    !crate::config::is_configured()
+        && !crate::report::is_reporting()
+        && !crate::report::is_lenient()
+}
+
+/// Validates the elements of a list with `#[validate(nested, parallel)]` on all the cores, or on
+/// the current thread when the validation can't be split between threads. Called by the
+/// generated code.
+pub fn validate_nested_par<const FAIL_FAST: bool, T: Validate + Sync>(
+    values: &[T],
+) -> Vec<Result<(), ValidationErrors>> {
+    if !can_run_in_parallel() {
+        return values
+            .iter()
+            .take_while(|_| !crate::config::should_stop())
+            .map(crate::__private::validate_nested::<FAIL_FAST, T>)
+            .collect();
+    }
+    let depth = crate::depth::nesting_depth();
+    map_par(values, |value| {
+        crate::depth::at_depth(depth, || crate::__private::validate_nested::<FAIL_FAST, T>(value))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_on_threads_keeps_the_order() {
+        let values: Vec<usize> = (0..1000).collect();
+        for threads in &[1, 3, 4, 7] {
+            let doubled = map_on_threads(&values, *threads, |value| value * 2);
+            assert_eq!(doubled, values.iter().map(|value| value * 2).collect::<Vec<_>>());
+            let mut ids = map_on_threads(&values, *threads, |_| thread::current().id());
+            ids.dedup();
+            assert_eq!(ids.len(), *threads);
+        }
+    }
+
+    #[test]
+    fn test_validations_on_threads_are_at_the_depth_of_the_caller() {
+        let depths = crate::depth::at_depth(3, || {
+            let depth = crate::depth::nesting_depth();
+            map_on_threads(&[(); 8], 4, |_| {
+                crate::depth::at_depth(depth, crate::depth::nesting_depth)
+            })
+        });
+        assert_eq!(depths, vec![3; 8]);
+        assert_eq!(crate::depth::nesting_depth(), 0);
+    }
+
+    #[test]
+    fn test_nested_maps_stay_on_the_threads_of_the_outer_one() {
+        let values: Vec<usize> = (0..4).collect();
+        let inner: Vec<usize> = (0..MIN_CHUNK_LEN * 4).collect();
+        let ids = map_on_threads(&values, 4, |_| {
+            let outer = thread::current().id();
+            map_par(&inner, |_| thread::current().id()).iter().all(|id| *id == outer)
+        });
+        assert_eq!(ids, vec![true; 4]);
+        assert!(!IN_PARALLEL.with(Cell::get));
+    }
+}
@@ -0,0 +1,626 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Index;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{self, Serialize, Serializer};
+use serde_derive::Serialize;
+use serde_json::value::Serializer as JsonSerializer;
+use serde_json::Value;
+
+/// The value of a param of a [`ValidationError`](crate::ValidationError), without going through
+/// `serde_json::Value` for the numbers, the booleans and the strings
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ParamValue {
+    Int(i64),
+    Float(f64),
+    Str(Cow<'static, str>),
+    Bool(bool),
+    List(Vec<ParamValue>),
+    /// `null`, an object, or an integer out of the range of an `i64`
+    Json(Value),
+}
+
+impl ParamValue {
+    /// Converts a JSON value, the integers too big for an `i64` become floats. Returns `None` for
+    /// `null`, objects and lists containing either.
+    pub fn from_json(value: &Value) -> Option<ParamValue> {
+        match value {
+            Value::Bool(b) => Some(ParamValue::Bool(*b)),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => Some(ParamValue::Int(i)),
+                None => n.as_f64().map(ParamValue::Float),
+            },
+            Value::String(s) => Some(ParamValue::Str(Cow::from(s.clone()))),
+            Value::Array(values) => values
+                .iter()
+                .map(ParamValue::from_json)
+                .collect::<Option<_>>()
+                .map(ParamValue::List),
+            Value::Null | Value::Object(_) => None,
+        }
+    }
+
+    /// Converts a value of any type, like [`ValidationError::add_param`] does
+    ///
+    /// [`ValidationError::add_param`]: crate::ValidationError::add_param
+    pub fn from_serialize<T: Serialize + ?Sized>(value: &T) -> ParamValue {
+        value.serialize(ParamSerializer).unwrap_or(ParamValue::Json(Value::Null))
+    }
+
+    /// The value as JSON, as it is serialized
+    pub fn to_json(&self) -> Value {
+        match self {
+            ParamValue::Int(i) => Value::from(*i),
+            ParamValue::Float(f) => Value::from(*f),
+            ParamValue::Str(s) => Value::from(s.as_ref()),
+            ParamValue::Bool(b) => Value::Bool(*b),
+            ParamValue::List(values) => values.iter().map(ParamValue::to_json).collect(),
+            ParamValue::Json(value) => value.clone(),
+        }
+    }
+
+    /// The value if it is an integer fitting in an `i64`
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            ParamValue::Int(i) => Some(*i),
+            ParamValue::Json(value) => value.as_i64(),
+            _ => None,
+        }
+    }
+
+    /// The value if it is a non-negative integer fitting in a `u64`
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            ParamValue::Int(i) => u64::try_from(*i).ok(),
+            ParamValue::Json(value) => value.as_u64(),
+            _ => None,
+        }
+    }
+
+    /// The value if it is a number, integers are converted
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            ParamValue::Int(i) => Some(*i as f64),
+            ParamValue::Float(f) => Some(*f),
+            ParamValue::Json(value) => value.as_f64(),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ParamValue::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ParamValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, ParamValue::Json(Value::Null))
+    }
+}
+
+/// Keeps the numbers that fit in the other variants out of `Json`, so that a deserialized error
+/// is equal to the one that was serialized
+impl From<Value> for ParamValue {
+    fn from(value: Value) -> ParamValue {
+        match value {
+            Value::Bool(b) => ParamValue::Bool(b),
+            Value::Number(n) => match (n.as_i64(), n.is_f64()) {
+                (Some(i), _) => ParamValue::Int(i),
+                (None, true) => ParamValue::Float(n.as_f64().unwrap_or_default()),
+                (None, false) => ParamValue::Json(Value::Number(n)),
+            },
+            Value::String(s) => ParamValue::Str(Cow::from(s)),
+            Value::Array(values) => {
+                ParamValue::List(values.into_iter().map(ParamValue::from).collect())
+            }
+            value => ParamValue::Json(value),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ParamValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ParamValue, D::Error> {
+        Value::deserialize(deserializer).map(ParamValue::from)
+    }
+}
+
+impl From<&'static str> for ParamValue {
+    fn from(s: &'static str) -> ParamValue {
+        ParamValue::Str(Cow::Borrowed(s))
+    }
+}
+
+impl From<String> for ParamValue {
+    fn from(s: String) -> ParamValue {
+        ParamValue::Str(Cow::Owned(s))
+    }
+}
+
+impl From<Cow<'static, str>> for ParamValue {
+    fn from(s: Cow<'static, str>) -> ParamValue {
+        ParamValue::Str(s)
+    }
+}
+
+impl From<bool> for ParamValue {
+    fn from(b: bool) -> ParamValue {
+        ParamValue::Bool(b)
+    }
+}
+
+impl From<f64> for ParamValue {
+    fn from(f: f64) -> ParamValue {
+        ParamValue::from_serialize(&f)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for ParamValue {
+                fn from(i: $ty) -> ParamValue {
+                    ParamValue::from_serialize(&i)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// The element of a list, `null` for the other values or out of the list, like `serde_json::Value`
+impl Index<usize> for ParamValue {
+    type Output = ParamValue;
+
+    fn index(&self, index: usize) -> &ParamValue {
+        static NULL: ParamValue = ParamValue::Json(Value::Null);
+        match self {
+            ParamValue::List(values) => values.get(index).unwrap_or(&NULL),
+            _ => &NULL,
+        }
+    }
+}
+
+/// Written as JSON, like `serde_json::Value`
+impl fmt::Display for ParamValue {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamValue::Int(i) => write!(fmt, "{}", i),
+            ParamValue::Bool(b) => write!(fmt, "{}", b),
+            value => write!(fmt, "{}", value.to_json()),
+        }
+    }
+}
+
+impl PartialEq<Value> for ParamValue {
+    fn eq(&self, other: &Value) -> bool {
+        self.to_json() == *other
+    }
+}
+
+impl PartialEq<ParamValue> for Value {
+    fn eq(&self, other: &ParamValue) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<str> for ParamValue {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == Some(other)
+    }
+}
+
+impl PartialEq<&str> for ParamValue {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == Some(*other)
+    }
+}
+
+impl PartialEq<String> for ParamValue {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == Some(other.as_str())
+    }
+}
+
+impl PartialEq<bool> for ParamValue {
+    fn eq(&self, other: &bool) -> bool {
+        self.as_bool() == Some(*other)
+    }
+}
+
+macro_rules! impl_partial_eq_number {
+    ($($ty:ty => $as:ident),*) => {
+        $(
+            impl PartialEq<$ty> for ParamValue {
+                fn eq(&self, other: &$ty) -> bool {
+                    self.$as() == Some(*other as _)
+                }
+            }
+        )*
+    };
+}
+
+impl_partial_eq_number!(
+    i8 => as_i64, i16 => as_i64, i32 => as_i64, i64 => as_i64, isize => as_i64,
+    u8 => as_u64, u16 => as_u64, u32 => as_u64, u64 => as_u64, usize => as_u64,
+    f32 => as_f64, f64 => as_f64
+);
+
+/// Serializes a value into a [`ParamValue`]. The maps, the structs and the variants holding data
+/// go through `serde_json::Value`.
+struct ParamSerializer;
+
+impl Serializer for ParamSerializer {
+    type Ok = ParamValue;
+    type Error = serde_json::Error;
+
+    type SerializeSeq = SerializeList;
+    type SerializeTuple = SerializeList;
+    type SerializeTupleStruct = SerializeList;
+    type SerializeTupleVariant = Json<<JsonSerializer as Serializer>::SerializeTupleVariant>;
+    type SerializeMap = Json<<JsonSerializer as Serializer>::SerializeMap>;
+    type SerializeStruct = Json<<JsonSerializer as Serializer>::SerializeStruct>;
+    type SerializeStructVariant = Json<<JsonSerializer as Serializer>::SerializeStructVariant>;
+
+    fn serialize_bool(self, v: bool) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Int(i64::from(v)))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Int(i64::from(v)))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Int(i64::from(v)))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Int(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<ParamValue, Self::Error> {
+        match i64::try_from(v) {
+            Ok(v) => Ok(ParamValue::Int(v)),
+            Err(_) => JsonSerializer.serialize_i128(v).map(ParamValue::Json),
+        }
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Int(i64::from(v)))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Int(i64::from(v)))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Int(i64::from(v)))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<ParamValue, Self::Error> {
+        match i64::try_from(v) {
+            Ok(v) => Ok(ParamValue::Int(v)),
+            Err(_) => Ok(ParamValue::Json(Value::from(v))),
+        }
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<ParamValue, Self::Error> {
+        match i64::try_from(v) {
+            Ok(v) => Ok(ParamValue::Int(v)),
+            Err(_) => JsonSerializer.serialize_u128(v).map(ParamValue::Json),
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<ParamValue, Self::Error> {
+        self.serialize_f64(f64::from(v))
+    }
+
+    /// The infinities and NaN are `null`, as in JSON
+    fn serialize_f64(self, v: f64) -> Result<ParamValue, Self::Error> {
+        match v.is_finite() {
+            true => Ok(ParamValue::Float(v)),
+            false => Ok(ParamValue::Json(Value::Null)),
+        }
+    }
+
+    fn serialize_char(self, v: char) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Str(Cow::from(v.to_string())))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Str(Cow::from(v.to_string())))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::List(v.iter().map(|b| ParamValue::Int(i64::from(*b))).collect()))
+    }
+
+    fn serialize_none(self) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Json(Value::Null))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<ParamValue, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Json(Value::Null))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Json(Value::Null))
+    }
+
+    /// The variant names are static, so the enums without data are params without allocating
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::Str(Cow::Borrowed(variant)))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<ParamValue, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<ParamValue, Self::Error> {
+        JsonSerializer
+            .serialize_newtype_variant(name, variant_index, variant, value)
+            .map(ParamValue::Json)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeList, Self::Error> {
+        Ok(SerializeList(Vec::with_capacity(len.unwrap_or_default())))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeList, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeList, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        JsonSerializer.serialize_tuple_variant(name, variant_index, variant, len).map(Json)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        JsonSerializer.serialize_map(len).map(Json)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        JsonSerializer.serialize_struct(name, len).map(Json)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        JsonSerializer.serialize_struct_variant(name, variant_index, variant, len).map(Json)
+    }
+}
+
+struct SerializeList(Vec<ParamValue>);
+
+impl ser::SerializeSeq for SerializeList {
+    type Ok = ParamValue;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.push(value.serialize(ParamSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<ParamValue, Self::Error> {
+        Ok(ParamValue::List(self.0))
+    }
+}
+
+impl ser::SerializeTuple for SerializeList {
+    type Ok = ParamValue;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<ParamValue, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeList {
+    type Ok = ParamValue;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<ParamValue, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+/// A serializer of `serde_json` whose value ends up in [`ParamValue::Json`]
+struct Json<S>(S);
+
+impl<S> ser::SerializeTupleVariant for Json<S>
+where
+    S: ser::SerializeTupleVariant<Ok = Value, Error = serde_json::Error>,
+{
+    type Ok = ParamValue;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_field(value)
+    }
+
+    fn end(self) -> Result<ParamValue, Self::Error> {
+        self.0.end().map(ParamValue::Json)
+    }
+}
+
+impl<S> ser::SerializeMap for Json<S>
+where
+    S: ser::SerializeMap<Ok = Value, Error = serde_json::Error>,
+{
+    type Ok = ParamValue;
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.0.serialize_key(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_value(value)
+    }
+
+    fn end(self) -> Result<ParamValue, Self::Error> {
+        self.0.end().map(ParamValue::Json)
+    }
+}
+
+impl<S> ser::SerializeStruct for Json<S>
+where
+    S: ser::SerializeStruct<Ok = Value, Error = serde_json::Error>,
+{
+    type Ok = ParamValue;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_field(key, value)
+    }
+
+    fn end(self) -> Result<ParamValue, Self::Error> {
+        self.0.end().map(ParamValue::Json)
+    }
+}
+
+impl<S> ser::SerializeStructVariant for Json<S>
+where
+    S: ser::SerializeStructVariant<Ok = Value, Error = serde_json::Error>,
+{
+    type Ok = ParamValue;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_field(key, value)
+    }
+
+    fn end(self) -> Result<ParamValue, Self::Error> {
+        self.0.end().map(ParamValue::Json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(serde_derive::Serialize)]
+    enum Mode {
+        Strict,
+        Limit(u8),
+    }
+
+    #[test]
+    fn test_params_serialize_like_json_values() {
+        let mut map = BTreeMap::new();
+        map.insert("a", 1);
+        let values = vec![
+            json!(3),
+            json!(-3),
+            json!(u64::MAX),
+            json!(1.5),
+            json!("text"),
+            json!(true),
+            json!([1, "a", null]),
+            json!({"a": 1}),
+            json!(null),
+        ];
+        let params = vec![
+            ParamValue::from_serialize(&3u8),
+            ParamValue::from_serialize(&-3i64),
+            ParamValue::from_serialize(&u64::MAX),
+            ParamValue::from_serialize(&1.5f32),
+            ParamValue::from_serialize("text"),
+            ParamValue::from_serialize(&true),
+            ParamValue::from_serialize(&(1, 'a', None::<i32>)),
+            ParamValue::from_serialize(&map),
+            ParamValue::from_serialize(&f64::NAN),
+        ];
+        for (param, value) in params.iter().zip(&values) {
+            assert_eq!(param, value);
+            assert_eq!(serde_json::to_value(param).unwrap(), *value);
+            assert_eq!(param.to_string(), value.to_string());
+            assert_eq!(ParamValue::from(value.clone()), *param);
+        }
+    }
+
+    #[test]
+    fn test_simple_params_are_not_json() {
+        assert_eq!(ParamValue::from_serialize(&u32::MAX), ParamValue::Int(i64::from(u32::MAX)));
+        assert_eq!(ParamValue::from_serialize(&2.0), ParamValue::Float(2.0));
+        assert_eq!(ParamValue::from_serialize(&Mode::Strict), ParamValue::Str(Cow::from("Strict")));
+        assert_eq!(ParamValue::from_serialize(&Mode::Limit(3)), json!({"Limit": 3}));
+        assert!(matches!(ParamValue::from("needle"), ParamValue::Str(Cow::Borrowed("needle"))));
+        assert_eq!(ParamValue::from_serialize(&u64::MAX).as_u64(), Some(u64::MAX));
+    }
+}
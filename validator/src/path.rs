@@ -0,0 +1,90 @@
+use std::fmt;
+
+/// A step in the path of a nested error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathSegment<'a> {
+    /// A field of a struct
+    Field(&'a str),
+    /// An entry of a vector
+    Index(usize),
+    /// An entry of a map
+    Key(&'a str),
+}
+
+/// The path of a field in nested errors, like `addresses[2].street`. It is displayed as such
+/// and can be converted to a JSON Pointer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct FieldPath<'a> {
+    segments: Vec<PathSegment<'a>>,
+}
+
+impl<'a> FieldPath<'a> {
+    pub fn new(segments: Vec<PathSegment<'a>>) -> FieldPath<'a> {
+        FieldPath { segments }
+    }
+
+    pub fn segments(&self) -> &[PathSegment<'a>] {
+        &self.segments
+    }
+
+    /// The name of the field the path ends with, `street` for `addresses[2].street`
+    pub fn field(&self) -> Option<&'a str> {
+        self.segments.iter().rev().find_map(|segment| match segment {
+            PathSegment::Field(field) => Some(*field),
+            _ => None,
+        })
+    }
+
+    /// The JSON Pointer (RFC 6901) of the path, like `/addresses/2/street`
+    pub fn to_pointer(&self) -> String {
+        let mut pointer = String::new();
+        for segment in &self.segments {
+            pointer.push('/');
+            match segment {
+                PathSegment::Field(token) | PathSegment::Key(token) => {
+                    pointer.push_str(&token.replace('~', "~0").replace('/', "~1"))
+                }
+                PathSegment::Index(index) => pointer.push_str(&index.to_string()),
+            }
+        }
+        pointer
+    }
+}
+
+impl fmt::Display for FieldPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                PathSegment::Field(field) if i == 0 => write!(f, "{}", field)?,
+                PathSegment::Field(field) => write!(f, ".{}", field)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+                PathSegment::Key(key) => write!(f, "[{}]", key)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Splits a dotted path like `profile.emails[0].address` in its segments, the brackets can
+/// contain dots for the keys of maps. The brackets are always parsed as keys since an index can't
+/// be told apart from a key.
+pub(crate) fn parse_path(path: &str) -> Option<Vec<PathSegment<'_>>> {
+    let mut segments = vec![];
+    let mut rest = path;
+    while !rest.is_empty() {
+        if let Some(bracketed) = rest.strip_prefix('[') {
+            let end = bracketed.find(']')?;
+            segments.push(PathSegment::Key(&bracketed[..end]));
+            rest = &bracketed[end + 1..];
+        } else {
+            let field = rest.strip_prefix('.').unwrap_or(rest);
+            let end = field.find(['.', '[']).unwrap_or(field.len());
+            if end == 0 {
+                return None;
+            }
+            segments.push(PathSegment::Field(&field[..end]));
+            rest = &field[end..];
+        }
+    }
+    Some(segments)
+}
@@ -0,0 +1,67 @@
+use serde_derive::Serialize;
+
+use crate::types::ValidationErrors;
+
+/// The media type of [`ProblemDetails`] bodies
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+/// An RFC 7807 problem details object describing validation errors, which serializes to an
+/// `application/problem+json` body. The errors are in the `errors` extension member.
+///
+/// The members other than `errors` have defaults suitable for most APIs and can be changed
+/// before serializing it.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub title: String,
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    pub errors: Vec<ProblemError>,
+}
+
+/// One error of [`ProblemDetails`], for the part of the payload its JSON Pointer points at
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ProblemError {
+    pub pointer: String,
+    pub code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl ProblemDetails {
+    /// Creates the problem details of the errors, an `about:blank` problem with the 422 status.
+    /// The errors are sorted by pointer, in the order they were added for each field.
+    pub fn new(errors: &ValidationErrors) -> ProblemDetails {
+        let errors = errors
+            .flatten_pointers()
+            .into_iter()
+            .flat_map(|(pointer, errors)| {
+                errors.iter().map(move |error| ProblemError {
+                    pointer: pointer.clone(),
+                    code: error.code.to_string(),
+                    detail: error.message.as_ref().map(|message| message.to_string()),
+                })
+            })
+            .collect();
+
+        ProblemDetails {
+            type_: "about:blank".to_string(),
+            title: "Validation failed".to_string(),
+            status: 422,
+            detail: None,
+            instance: None,
+            errors,
+        }
+    }
+}
+
+impl ValidationErrors {
+    /// Returns the RFC 7807 problem details of the errors, see [`ProblemDetails`]
+    pub fn to_problem_details(&self) -> ProblemDetails {
+        ProblemDetails::new(self)
+    }
+}
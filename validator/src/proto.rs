@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+
+/// The validators of protobuf messages, turned into the attributes `prost-build` adds to the
+/// structs it generates so they derive `Validate`, like `protoc-gen-validate` rules would. The
+/// messages and fields are keyed by their path in the `.proto` files, the validators are written
+/// as in a `#[validate(...)]` attribute:
+///
+/// ```ignore
+/// // build.rs
+/// let mut rules = ProtoRules::new();
+/// rules.field(".shop.Order", "email", "email").field(".shop.Order", "quantity", "range(min = 1)");
+///
+/// let mut config = prost_build::Config::new();
+/// for (path, attribute) in rules.type_attributes() {
+///     config.type_attribute(path, attribute);
+/// }
+/// for (path, attribute) in rules.field_attributes() {
+///     config.field_attribute(path, attribute);
+/// }
+/// config.compile_protos(&["src/shop.proto"], &["src/"])?;
+/// ```
+///
+/// The crate compiling the messages needs the `derive` feature of `validator`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProtoRules {
+    messages: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+}
+
+impl ProtoRules {
+    pub fn new() -> ProtoRules {
+        ProtoRules::default()
+    }
+
+    /// Reads the rules from a JSON file, an object of messages with an object of fields each
+    /// mapping to the list of their validators:
+    ///
+    /// ```json
+    /// {
+    ///     ".shop.Order": { "email": ["email"], "items": ["length(min = 1)", "nested"] },
+    ///     ".shop.Item": { "sku": ["length(equal = 8)"] }
+    /// }
+    /// ```
+    ///
+    /// A message without fields still derives `Validate`, for the messages nested in others.
+    pub fn from_json(source: &str) -> Result<ProtoRules, serde_json::Error> {
+        Ok(ProtoRules { messages: serde_json::from_str(source)? })
+    }
+
+    /// Makes the message derive `Validate`, as it does once one of its fields has a validator
+    pub fn message(&mut self, message: &str) -> &mut ProtoRules {
+        self.messages.entry(message.to_string()).or_default();
+        self
+    }
+
+    /// Adds a validator to a field of a message
+    pub fn field(&mut self, message: &str, field: &str, validator: &str) -> &mut ProtoRules {
+        self.messages
+            .entry(message.to_string())
+            .or_default()
+            .entry(field.to_string())
+            .or_default()
+            .push(validator.to_string());
+        self
+    }
+
+    /// The `derive` attribute of every message, for `prost_build::Config::type_attribute`
+    pub fn type_attributes(&self) -> Vec<(String, String)> {
+        self.messages
+            .keys()
+            .map(|message| (message.clone(), "#[derive(::validator::Validate)]".to_string()))
+            .collect()
+    }
+
+    /// The `validate` attribute of every field with validators, for
+    /// `prost_build::Config::field_attribute`
+    pub fn field_attributes(&self) -> Vec<(String, String)> {
+        let mut attributes = vec![];
+        for (message, fields) in &self.messages {
+            for (field, validators) in fields {
+                if validators.is_empty() {
+                    continue;
+                }
+                attributes.push((
+                    format!("{}.{}", message, field),
+                    format!("#[validate({})]", validators.join(", ")),
+                ));
+            }
+        }
+        attributes
+    }
+}
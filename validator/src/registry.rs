@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use crate::types::ValidationError;
+
+type RegisteredValidator = Arc<dyn Fn(&str) -> Result<(), ValidationError> + Send + Sync>;
+
+fn registry() -> &'static RwLock<HashMap<String, RegisteredValidator>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, RegisteredValidator>>> = OnceLock::new();
+    REGISTRY.get_or_init(RwLock::default)
+}
+
+/// Registers a function under a name so it can be used with `#[validate(rule = "name")]` on
+/// any struct. Registering a name again replaces the previous function.
+///
+/// The registry is global to the program and filled at runtime, typically at startup before
+/// anything is validated.
+pub fn register_validator<F>(name: impl Into<String>, function: F)
+where
+    F: Fn(&str) -> Result<(), ValidationError> + Send + Sync + 'static,
+{
+    registry().write().unwrap().insert(name.into(), Arc::new(function));
+}
+
+/// Whether a function is registered under that name
+#[must_use]
+pub fn is_validator_registered(name: &str) -> bool {
+    registry().read().unwrap().contains_key(name)
+}
+
+/// Validates the value with the function registered under that name
+///
+/// # Panics
+///
+/// If no function is registered under that name, as the validation can't be skipped silently
+pub fn validate_registered<T: AsRef<str> + ?Sized>(
+    name: &str,
+    value: &T,
+) -> Result<(), ValidationError> {
+    // The lock isn't held during the call so the function can register validators too
+    let function = match registry().read().unwrap().get(name) {
+        Some(function) => Arc::clone(function),
+        None => panic!("No validator is registered as `{}`, see `register_validator!`", name),
+    };
+    function(value.as_ref())
+}
+
+/// Registers a function under a name for `#[validate(rule = "name")]`, see [`register_validator`]
+///
+/// ```
+/// use validator::{register_validator, ValidationError};
+///
+/// fn validate_iban(value: &str) -> Result<(), ValidationError> {
+///     if value.starts_with("FR") {
+///         Ok(())
+///     } else {
+///         Err(ValidationError::new("iban"))
+///     }
+/// }
+///
+/// register_validator!("iban_custom", validate_iban);
+/// ```
+#[macro_export]
+macro_rules! register_validator {
+    ($name:expr, $function:expr $(,)?) => {
+        $crate::register_validator($name, $function)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn not_empty(value: &str) -> Result<(), ValidationError> {
+        if value.is_empty() {
+            return Err(ValidationError::new("empty"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_registered() {
+        register_validator("registry_not_empty", not_empty);
+        assert!(is_validator_registered("registry_not_empty"));
+        assert!(validate_registered("registry_not_empty", "a").is_ok());
+        assert_eq!(validate_registered("registry_not_empty", "").unwrap_err().code, "empty");
+    }
+
+    #[test]
+    fn test_register_replaces_the_function() {
+        register_validator("registry_replaced", not_empty);
+        register_validator("registry_replaced", |_: &str| Ok(()));
+        assert!(validate_registered("registry_replaced", "").is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "No validator is registered as `registry_missing`")]
+    fn test_validate_unregistered_panics() {
+        let _ = validate_registered("registry_missing", "a");
+    }
+}
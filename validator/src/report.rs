@@ -0,0 +1,116 @@
+use std::cell::Cell;
+
+use crate::types::{Severity, ValidationErrors};
+
+thread_local! {
+    static REPORTING: Cell<bool> = const { Cell::new(false) };
+    static LENIENT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether the validators with a `severity` other than `error` run: they only do in
+/// `Validate::validate_report`
+pub fn is_reporting() -> bool {
+    REPORTING.with(|reporting| reporting.get())
+}
+
+/// Restores the previous reporting state when the report is done, even if it panics
+struct ReportingGuard(bool);
+
+impl Drop for ReportingGuard {
+    fn drop(&mut self) {
+        REPORTING.with(|reporting| reporting.set(self.0));
+    }
+}
+
+/// Whether the validation collects all the errors even in fail fast mode: it does in
+/// `Validate::validate_lenient`
+pub fn is_lenient() -> bool {
+    LENIENT.with(|lenient| lenient.get())
+}
+
+/// Restores the previous lenient state when the validation is done, even if it panics
+struct LenientGuard(bool);
+
+impl Drop for LenientGuard {
+    fn drop(&mut self) {
+        LENIENT.with(|lenient| lenient.set(self.0));
+    }
+}
+
+/// Runs a validation collecting all its errors, the structs in fail fast mode included
+pub(crate) fn collect_lenient<F>(validate: F) -> ValidationErrors
+where
+    F: FnOnce() -> Result<(), ValidationErrors>,
+{
+    let _guard = LenientGuard(LENIENT.with(|lenient| lenient.replace(true)));
+    validate().err().unwrap_or_default()
+}
+
+/// The errors of all the severities found by `Validate::validate_report`. Unlike `validate`, the
+/// warnings and infos are not errors: the value is valid if there are only those.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ValidationReport {
+    issues: ValidationErrors,
+}
+
+impl ValidationReport {
+    /// Runs a validation with the validators of all the severities and collects their errors
+    pub fn collect<F>(validate: F) -> ValidationReport
+    where
+        F: FnOnce() -> Result<(), ValidationErrors>,
+    {
+        let _guard = ReportingGuard(REPORTING.with(|reporting| reporting.replace(true)));
+        let issues = validate().err().unwrap_or_default();
+        ValidationReport { issues }
+    }
+
+    /// Whether there are no errors of the `Error` severity
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        !self.issues.is_blocking()
+    }
+
+    /// Whether nothing was found, of any severity
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// All the errors found, of every severity
+    pub fn issues(&self) -> &ValidationErrors {
+        &self.issues
+    }
+
+    /// The errors of that severity only
+    pub fn with_severity(&self, severity: Severity) -> ValidationErrors {
+        let mut errors = self.issues.clone();
+        errors.retain(&mut |err| err.severity == severity);
+        errors
+    }
+
+    /// The errors of the `Error` severity
+    pub fn errors(&self) -> ValidationErrors {
+        self.with_severity(Severity::Error)
+    }
+
+    /// The errors of the `Warning` severity
+    pub fn warnings(&self) -> ValidationErrors {
+        self.with_severity(Severity::Warning)
+    }
+
+    /// The errors of the `Info` severity
+    pub fn infos(&self) -> ValidationErrors {
+        self.with_severity(Severity::Info)
+    }
+
+    /// Returns the result `validate` would have returned, with only the errors of the `Error`
+    /// severity
+    pub fn into_result(mut self) -> Result<(), ValidationErrors> {
+        self.issues.retain(&mut |err| err.severity.is_error());
+        if self.issues.is_empty() {
+            Ok(())
+        } else {
+            Err(self.issues)
+        }
+    }
+}
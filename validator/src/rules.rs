@@ -0,0 +1,44 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::ser::Serialize;
+use serde_derive::Serialize;
+use serde_json::{to_value, Value};
+
+/// A validator of a field as data, with the code and message of the errors it reports and its
+/// arguments as params: `min` and `max` for `length` for example
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct Rule {
+    /// The name of the validator, like `length` or `email`
+    pub kind: &'static str,
+    pub code: Cow<'static, str>,
+    pub message: Option<Cow<'static, str>>,
+    pub params: HashMap<Cow<'static, str>, Value>,
+}
+
+impl Rule {
+    pub fn new(kind: &'static str, code: &'static str) -> Rule {
+        Rule { kind, code: Cow::from(code), message: None, params: HashMap::new() }
+    }
+
+    pub fn add_param<T: Serialize>(&mut self, name: Cow<'static, str>, val: &T) {
+        self.params.insert(name, to_value(val).unwrap());
+    }
+}
+
+/// The rules of a field, keyed like its errors. The struct level validations are in `__all__`
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct FieldRules {
+    pub field: &'static str,
+    /// The type of the field as written without spaces, like `Option<String>`, empty for
+    /// `__all__`. It isn't serialized.
+    #[serde(skip_serializing)]
+    pub field_type: &'static str,
+    pub rules: Vec<Rule>,
+    /// The rules of the keys if the field is a map
+    pub keys: Vec<Rule>,
+    /// The rules of the elements if the field is a list, or of the values if it is a map
+    pub inner: Vec<Rule>,
+    /// Whether the rules only apply under a `skip_if` or `validate_if` condition
+    pub conditional: bool,
+}
@@ -0,0 +1,678 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::dynamic::rule_passes;
+use crate::json_schema::{element_types, json_type, unwrap_generic, JsonType};
+use crate::rules::{FieldRules, Rule};
+use crate::traits::ValidationRules;
+
+/// The validators values are generated for
+const SUPPORTED: &[&str] = &[
+    "required",
+    "length",
+    "range",
+    "percentage",
+    "ratio",
+    "email",
+    "url",
+    "contains",
+    "does_not_contain",
+    "non_control_character",
+];
+
+/// The number of values tried for rules that can't be satisfied directly, like
+/// `does_not_contain`, before giving up
+const TRIES: usize = 32;
+
+/// The most elements or characters added past the minimum length, so the values stay small
+const MAX_EXTRA_LENGTH: usize = 16;
+
+/// A value breaking one of the rules of a struct, generated by [`Samples::invalid`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidSample {
+    /// The field breaking the rule, or with an element or a key breaking it
+    pub field: &'static str,
+    /// The code of the error of the rule
+    pub code: Cow<'static, str>,
+    /// The whole value, the other fields are valid
+    pub value: Value,
+}
+
+/// Why [`Samples`] couldn't generate a value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SampleError {
+    /// The field has a validator values can't be generated for, like `regex`, `custom` or
+    /// `nested`, and isn't in the base value
+    Unsupported { field: &'static str, kind: &'static str },
+    /// The field has a type values can't be generated for, like a struct, and isn't in the base
+    /// value
+    UnknownType { field: &'static str, field_type: &'static str },
+    /// No value passing all the rules of the field was found, they likely contradict each other
+    Unsatisfiable { field: &'static str },
+    /// The value generated couldn't be deserialized into the struct, it is likely missing a field
+    /// without validators from the base value
+    Deserialize { message: String },
+}
+
+impl fmt::Display for SampleError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SampleError::Unsupported { field, kind } => {
+                write!(fmt, "Can't generate a value of `{}` for its `{}` rule", field, kind)
+            }
+            SampleError::UnknownType { field, field_type } => {
+                write!(fmt, "Can't generate a value of `{}` of type `{}`", field, field_type)
+            }
+            SampleError::Unsatisfiable { field } => {
+                write!(fmt, "No value of `{}` passes all its rules", field)
+            }
+            SampleError::Deserialize { message } => {
+                write!(fmt, "The value generated couldn't be deserialized: {}", message)
+            }
+        }
+    }
+}
+
+impl Error for SampleError {}
+
+/// Generates JSON values of a struct from its `ValidationRules`, either passing all its rules or
+/// each breaking one of them, for property tests and negative tests instead of fixtures written
+/// by hand, and valid instances of the struct for the data of tests and demos. The values are
+/// deserialized into the struct, and the same seed always generates the
+/// same values so a proptest strategy is a map of a seed:
+///
+/// ```ignore
+/// fn signup() -> impl Strategy<Value = Signup> {
+///     any::<u64>().prop_map(|seed| {
+///         let value = Samples::new(seed).base(json!({"team": "blue"})).valid::<Signup>().unwrap();
+///         serde_json::from_value(value).unwrap()
+///     })
+/// }
+/// ```
+///
+/// Values are generated for the strings, numbers, booleans, lists and maps with the `required`,
+/// `length`, `range`, `percentage`, `ratio`, `email`, `url`, `contains`, `does_not_contain` and
+/// `non_control_character` validators. The fields without validators, and the ones with other
+/// validators or types, are taken from the [`base`](Samples::base) value. The struct level
+/// validations aren't checked.
+#[derive(Debug, Clone, Default)]
+pub struct Samples {
+    state: u64,
+    base: Map<String, Value>,
+}
+
+impl Samples {
+    pub fn new(seed: u64) -> Samples {
+        Samples { state: seed, base: Map::new() }
+    }
+
+    /// The object the values start from, with the fields that aren't generated. A value that
+    /// isn't an object is ignored.
+    pub fn base(mut self, base: Value) -> Samples {
+        if let Value::Object(base) = base {
+            self.base = base;
+        }
+        self
+    }
+
+    /// A value passing the rules of the fields of `T`
+    pub fn valid<T: ValidationRules>(&mut self) -> Result<Value, SampleError> {
+        self.valid_object(&T::validation_rules()).map(Value::Object)
+    }
+
+    /// A valid `T`, to seed a database for tests or a demo
+    pub fn instance<T>(&mut self) -> Result<T, SampleError>
+    where
+        T: ValidationRules + DeserializeOwned,
+    {
+        serde_json::from_value(self.valid::<T>()?)
+            .map_err(|error| SampleError::Deserialize { message: error.to_string() })
+    }
+
+    /// That many valid `T`, like the rows of the tables of a demo
+    pub fn instances<T>(&mut self, count: usize) -> Result<Vec<T>, SampleError>
+    where
+        T: ValidationRules + DeserializeOwned,
+    {
+        (0..count).map(|_| self.instance()).collect()
+    }
+
+    /// Values each breaking a rule of `T`, or of the elements or keys of one of its fields, at
+    /// least one for each rule that can be broken. The rules of the fields with a `skip_if` or
+    /// `validate_if` condition are left out, they might not run.
+    pub fn invalid<T: ValidationRules>(&mut self) -> Result<Vec<InvalidSample>, SampleError> {
+        let rules = T::validation_rules();
+        let valid = self.valid_object(&rules)?;
+        let mut samples = vec![];
+        for field in &rules {
+            if field.field == "__all__" || field.conditional || check_supported(field).is_err() {
+                continue;
+            }
+            for (code, value) in self.violations(field) {
+                let mut object = valid.clone();
+                object.insert(field.field.to_string(), value);
+                samples.push(InvalidSample {
+                    field: field.field,
+                    code,
+                    value: Value::Object(object),
+                });
+            }
+        }
+        Ok(samples)
+    }
+
+    fn valid_object(&mut self, rules: &[FieldRules]) -> Result<Map<String, Value>, SampleError> {
+        let mut object = self.base.clone();
+        for field in rules.iter().filter(|field| field.field != "__all__") {
+            if let Err(error) = check_supported(field) {
+                if object.contains_key(field.field) {
+                    continue;
+                }
+                return Err(error);
+            }
+            let value = self
+                .generate(field.field_type, &field.rules, &field.keys, &field.inner)
+                .ok_or(SampleError::Unsatisfiable { field: field.field })?;
+            object.insert(field.field.to_string(), value);
+        }
+        Ok(object)
+    }
+
+    /// The values of the field breaking each of its rules, with the code of the rule
+    fn violations(&mut self, field: &FieldRules) -> Vec<(Cow<'static, str>, Value)> {
+        let mut violations = vec![];
+        for (idx, rule) in field.rules.iter().enumerate() {
+            if rule.kind == "required" && unwrap_generic(field.field_type, "Option").is_some() {
+                violations.push((rule.code.clone(), Value::Null));
+            }
+            for value in
+                self.breaking(field.field_type, &field.rules, &field.keys, &field.inner, idx)
+            {
+                violations.push((rule.code.clone(), value));
+            }
+        }
+
+        let (key_type, inner_type) = element_types(strip_option(field.field_type));
+        for (idx, rule) in field.inner.iter().enumerate() {
+            for element in self.breaking(inner_type, &field.inner, &[], &[], idx) {
+                if let Some(value) = self.with_element(field, None, Some(element)) {
+                    violations.push((rule.code.clone(), value));
+                }
+            }
+        }
+        let key_type = if key_type.is_empty() { "String" } else { key_type };
+        for (idx, rule) in field.keys.iter().enumerate() {
+            for key in self.breaking(key_type, &field.keys, &[], &[], idx) {
+                if let Some(value) = self.with_element(field, key_string(key), None) {
+                    violations.push((rule.code.clone(), value));
+                }
+            }
+        }
+        violations
+    }
+
+    /// Values passing the rules except the one at `idx`, or only breaking that one when the
+    /// others can't be passed at the same time
+    fn breaking(
+        &mut self,
+        field_type: &str,
+        rules: &[Rule],
+        keys: &[Rule],
+        inner: &[Rule],
+        idx: usize,
+    ) -> Vec<Value> {
+        let broken = &rules[idx];
+        let mut values = vec![];
+        for replacement in replacements(broken, field_type) {
+            let others = rules.iter().enumerate().filter(|(other, _)| *other != idx);
+            let rules: Vec<_> =
+                others.map(|(_, rule)| rule.clone()).chain(replacement.iter().cloned()).collect();
+            let value = self
+                .generate(field_type, &rules, keys, inner)
+                .filter(|value| rule_passes(broken, value) == Some(false))
+                .or_else(|| {
+                    self.generate(field_type, &replacement, keys, inner)
+                        .filter(|value| rule_passes(broken, value) == Some(false))
+                });
+            values.extend(value);
+        }
+        values
+    }
+
+    /// A valid collection of the field with one of its elements or keys replaced
+    fn with_element(
+        &mut self,
+        field: &FieldRules,
+        key: Option<String>,
+        element: Option<Value>,
+    ) -> Option<Value> {
+        let mut rules = field.rules.clone();
+        rules.push(rule("length", &[("min", Value::from(1))]));
+        let mut collection = self.generate(field.field_type, &rules, &field.keys, &field.inner)?;
+        match &mut collection {
+            Value::Array(elements) => elements[0] = element?,
+            Value::Object(entries) => {
+                let first = entries.keys().next()?.clone();
+                let value = entries.remove(&first)?;
+                entries.insert(key.unwrap_or(first), element.unwrap_or(value));
+            }
+            _ => return None,
+        }
+        Some(collection)
+    }
+
+    /// A value of that type passing the rules, its elements passing the `inner` rules and its keys
+    /// the `keys` ones
+    fn generate(
+        &mut self,
+        field_type: &str,
+        rules: &[Rule],
+        keys: &[Rule],
+        inner: &[Rule],
+    ) -> Option<Value> {
+        let field_type = strip_option(field_type);
+        for _ in 0..TRIES {
+            let value = match json_type(field_type)? {
+                JsonType::String => self.string(field_type, rules)?,
+                JsonType::Integer => self.integer(field_type, rules)?,
+                JsonType::Number => self.number(rules)?,
+                JsonType::Boolean => Value::Bool(self.next() & 1 == 0),
+                JsonType::Array => self.array(field_type, rules, inner)?,
+                JsonType::Object => self.map(field_type, rules, keys, inner)?,
+            };
+            if rules.iter().all(|rule| rule_passes(rule, &value) != Some(false)) {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn string(&mut self, field_type: &str, rules: &[Rule]) -> Option<Value> {
+        let has = |kind: &str| rules.iter().any(|rule| rule.kind == kind);
+        let (prefix, suffix) = if has("email") {
+            ("", "@example.com")
+        } else if has("url") {
+            ("https://example.com/", "")
+        } else {
+            ("", "")
+        };
+        let needles: String = rules
+            .iter()
+            .filter(|rule| rule.kind == "contains")
+            .filter_map(|rule| rule.params.get("needle")?.as_str())
+            .collect();
+        let fixed = prefix.len() + suffix.len() + needles.chars().count();
+
+        let (min, max) = match field_type.ends_with("char") {
+            true => (Some(1), Some(1)),
+            false => length_bounds(rules),
+        };
+        // The local part of an email can't be empty
+        let min = min.unwrap_or(1).max(fixed + usize::from(has("email")));
+        let length = self.length(min, max)?;
+
+        let mut middle = self.word(length - fixed);
+        let at = self.between(0, middle.len());
+        middle.insert_str(at, &needles);
+        Some(Value::from(format!("{}{}{}", prefix, middle, suffix)))
+    }
+
+    fn integer(&mut self, field_type: &str, rules: &[Rule]) -> Option<Value> {
+        let bounds = NumberBounds::of(rules);
+        let (type_min, type_max) = integer_limits(field_type);
+        let min = bounds.min.map(|(min, exclusive)| match exclusive {
+            true => min.floor() as i128 + 1,
+            false => min.ceil() as i128,
+        });
+        let max = bounds.max.map(|(max, exclusive)| match exclusive {
+            true => max.ceil() as i128 - 1,
+            false => max.floor() as i128,
+        });
+        let (min, max) = match (min, max) {
+            (Some(min), Some(max)) => (min, max),
+            (Some(min), None) => (min, min.saturating_add(100)),
+            (None, Some(max)) => (if max >= 0 { (max - 100).max(0) } else { max - 100 }, max),
+            (None, None) => (0, 100),
+        };
+        let (min, max) = (min.max(type_min), max.min(type_max));
+        if min > max {
+            return None;
+        }
+
+        let value = match bounds.step {
+            Some((origin, step)) => {
+                let first = ((min as f64 - origin) / step).ceil() as i128;
+                let last = ((max as f64 - origin) / step).floor() as i128;
+                let value = origin + self.between_i128(first, last)? as f64 * step;
+                if value.fract() != 0.0 {
+                    return None;
+                }
+                value as i128
+            }
+            None => self.between_i128(min, max)?,
+        };
+        match i64::try_from(value) {
+            Ok(value) => Some(Value::from(value)),
+            Err(_) => u64::try_from(value).ok().map(Value::from),
+        }
+    }
+
+    fn number(&mut self, rules: &[Rule]) -> Option<Value> {
+        let bounds = NumberBounds::of(rules);
+        let (min, max) = match (bounds.min.map(|b| b.0), bounds.max.map(|b| b.0)) {
+            (Some(min), Some(max)) => (min, max),
+            (Some(min), None) => (min, min + 100.0),
+            (None, Some(max)) => {
+                (if max >= 0.0 { (max - 100.0).max(0.0) } else { max - 100.0 }, max)
+            }
+            (None, None) => (0.0, 100.0),
+        };
+        let value = match bounds.step {
+            Some((origin, step)) => {
+                let first = ((min - origin) / step).ceil() as i128;
+                let last = ((max - origin) / step).floor() as i128;
+                origin + self.between_i128(first, last)? as f64 * step
+            }
+            // A single value when the bounds are equal, else one strictly between them so the
+            // exclusive bounds pass
+            None if min == max => min,
+            None if min > max => return None,
+            None => min + (max - min) * ((self.next() % 999 + 1) as f64 / 1000.0),
+        };
+        serde_json::Number::from_f64(value).map(Value::Number)
+    }
+
+    fn array(&mut self, field_type: &str, rules: &[Rule], inner: &[Rule]) -> Option<Value> {
+        let (_, element_type) = element_types(field_type);
+        let (min, max) = match array_size(field_type) {
+            Some(size) => (Some(size), Some(size)),
+            None => length_bounds(rules),
+        };
+        let min = min.unwrap_or(0);
+        let length = self.length(min, max)?;
+        // A set can have fewer elements than the length drawn when its elements have few values
+        let unique = field_type.contains("Set<");
+
+        let mut elements = Vec::with_capacity(length);
+        for _ in 0..length * TRIES {
+            if elements.len() == length {
+                break;
+            }
+            let element = self.generate(element_type, inner, &[], &[])?;
+            if !unique || !elements.contains(&element) {
+                elements.push(element);
+            }
+        }
+        (elements.len() >= min).then_some(Value::Array(elements))
+    }
+
+    fn map(
+        &mut self,
+        field_type: &str,
+        rules: &[Rule],
+        keys: &[Rule],
+        inner: &[Rule],
+    ) -> Option<Value> {
+        let (key_type, value_type) = element_types(field_type);
+        let key_type = if key_type.is_empty() { "String" } else { key_type };
+        let (min, max) = length_bounds(rules);
+        let min = min.unwrap_or(0);
+        let length = self.length(min, max)?;
+
+        let mut entries = Map::new();
+        for _ in 0..length * TRIES {
+            if entries.len() == length {
+                break;
+            }
+            let key = key_string(self.generate(key_type, keys, &[], &[])?)?;
+            let value = self.generate(value_type, inner, &[], &[])?;
+            entries.insert(key, value);
+        }
+        (entries.len() >= min).then_some(Value::Object(entries))
+    }
+
+    /// Lowercase letters alternating consonants and vowels, so the values read like words in a
+    /// demo
+    fn word(&mut self, length: usize) -> String {
+        const CONSONANTS: &[u8] = b"bcdfghjklmnprstvz";
+        const VOWELS: &[u8] = b"aeiou";
+        let vowel_first = self.next() & 1 == 0;
+        (0..length)
+            .map(|idx| {
+                let letters = if (idx % 2 == 0) == vowel_first { VOWELS } else { CONSONANTS };
+                char::from(letters[(self.next() % letters.len() as u64) as usize])
+            })
+            .collect()
+    }
+
+    /// A length between the bounds, at most `MAX_EXTRA_LENGTH` above the minimum
+    fn length(&mut self, min: usize, max: Option<usize>) -> Option<usize> {
+        let max = max.unwrap_or(usize::MAX).min(min.saturating_add(MAX_EXTRA_LENGTH));
+        (min <= max).then(|| self.between(min, max))
+    }
+
+    fn between(&mut self, min: usize, max: usize) -> usize {
+        min + (self.next() % (max - min + 1) as u64) as usize
+    }
+
+    fn between_i128(&mut self, min: i128, max: i128) -> Option<i128> {
+        if min > max {
+            return None;
+        }
+        let span = (max - min).saturating_add(1).min(u64::MAX as i128) as u64;
+        Some(min + (self.next() % span) as i128)
+    }
+
+    /// The next number of a splitmix64 generator
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// The bounds of the numbers passing `range`, `percentage` and `ratio`, whether they are
+/// exclusive and the grid of the step
+#[derive(Debug, Default)]
+struct NumberBounds {
+    min: Option<(f64, bool)>,
+    max: Option<(f64, bool)>,
+    step: Option<(f64, f64)>,
+}
+
+impl NumberBounds {
+    fn of(rules: &[Rule]) -> NumberBounds {
+        let mut bounds = NumberBounds::default();
+        for rule in rules {
+            let param = |name: &str| rule.params.get(name).and_then(Value::as_f64);
+            match rule.kind {
+                "range" => {
+                    bounds.add_min(param("min"), false);
+                    bounds.add_min(param("exclusive_min"), true);
+                    bounds.add_max(param("max"), false);
+                    bounds.add_max(param("exclusive_max"), true);
+                    if let Some(step) = param("step").filter(|step| *step > 0.0) {
+                        let origin = param("min").or_else(|| param("exclusive_min"));
+                        bounds.step = Some((origin.unwrap_or(0.0), step));
+                    }
+                }
+                "percentage" | "ratio" => {
+                    let exclusive = rule.params.get("exclusive") == Some(&Value::Bool(true));
+                    bounds.add_min(Some(0.0), exclusive);
+                    bounds.add_max(
+                        Some(if rule.kind == "percentage" { 100.0 } else { 1.0 }),
+                        exclusive,
+                    );
+                }
+                _ => (),
+            }
+        }
+        bounds
+    }
+
+    fn add_min(&mut self, min: Option<f64>, exclusive: bool) {
+        if let Some(min) = min {
+            match self.min {
+                Some((current, current_exclusive))
+                    if current > min || (current == min && current_exclusive) => {}
+                _ => self.min = Some((min, exclusive)),
+            }
+        }
+    }
+
+    fn add_max(&mut self, max: Option<f64>, exclusive: bool) {
+        if let Some(max) = max {
+            match self.max {
+                Some((current, current_exclusive))
+                    if current < max || (current == max && current_exclusive) => {}
+                _ => self.max = Some((max, exclusive)),
+            }
+        }
+    }
+}
+
+/// The rules to generate with in place of a rule so the values break it
+fn replacements(broken: &Rule, field_type: &str) -> Vec<Vec<Rule>> {
+    let param = |name: &str| broken.params.get(name).and_then(Value::as_f64);
+    let exactly = |value: f64| vec![rule("range", &[("min", value.into()), ("max", value.into())])];
+    let length = |length: f64| vec![rule("length", &[("equal", Value::from(length as u64))])];
+    let needle = |kind, needle: &str| vec![rule(kind, &[("needle", Value::from(needle))])];
+    let below =
+        if json_type(strip_option(field_type)) == Some(JsonType::Integer) { 1.0 } else { 0.5 };
+
+    match broken.kind {
+        "length" => {
+            let mut replacements = vec![];
+            if let Some(min) = param("min").filter(|min| *min > 0.0) {
+                replacements.push(length(min - 1.0));
+            }
+            if let Some(max) = param("max") {
+                replacements.push(length(max + 1.0));
+            }
+            if let Some(equal) = param("equal") {
+                replacements.push(length(equal + 1.0));
+            }
+            replacements
+        }
+        "range" => {
+            let mut replacements = vec![];
+            replacements.extend(param("min").map(|min| exactly(min - below)));
+            replacements.extend(param("exclusive_min").map(exactly));
+            replacements.extend(param("max").map(|max| exactly(max + below)));
+            replacements.extend(param("exclusive_max").map(exactly));
+            replacements
+        }
+        "percentage" | "ratio" => {
+            let max = if broken.kind == "percentage" { 100.0 } else { 1.0 };
+            match broken.params.get("exclusive") == Some(&Value::Bool(true)) {
+                true => vec![exactly(0.0), exactly(max)],
+                false => vec![exactly(-below), exactly(max + below)],
+            }
+        }
+        // Random letters are neither emails nor URLs
+        "email" | "url" => vec![vec![]],
+        "contains" => broken
+            .params
+            .get("needle")
+            .and_then(Value::as_str)
+            .map(|value| needle("does_not_contain", value))
+            .into_iter()
+            .collect(),
+        "does_not_contain" => broken
+            .params
+            .get("needle")
+            .and_then(Value::as_str)
+            .map(|value| needle("contains", value))
+            .into_iter()
+            .collect(),
+        "non_control_character" => vec![needle("contains", "\u{7}")],
+        _ => vec![],
+    }
+}
+
+/// Checks the values of the field can be generated
+fn check_supported(field: &FieldRules) -> Result<(), SampleError> {
+    let mut rules = field.rules.iter().chain(&field.keys).chain(&field.inner);
+    // The words of a `WordList` are only known at runtime
+    if let Some(rule) =
+        rules.find(|rule| !SUPPORTED.contains(&rule.kind) || rule.params.contains_key("words"))
+    {
+        return Err(SampleError::Unsupported { field: field.field, kind: rule.kind });
+    }
+    let field_type = strip_option(field.field_type);
+    let known = match json_type(field_type) {
+        Some(JsonType::Array) | Some(JsonType::Object) => {
+            json_type(strip_option(element_types(field_type).1)).is_some()
+        }
+        json_type => json_type.is_some(),
+    };
+    match known {
+        true => Ok(()),
+        false => Err(SampleError::UnknownType { field: field.field, field_type: field.field_type }),
+    }
+}
+
+fn rule(kind: &'static str, params: &[(&'static str, Value)]) -> Rule {
+    let mut rule = Rule::new(kind, kind);
+    for (name, value) in params {
+        rule.params.insert(Cow::from(*name), value.clone());
+    }
+    rule
+}
+
+/// The bounds of the lengths passing the `length` rules
+fn length_bounds(rules: &[Rule]) -> (Option<usize>, Option<usize>) {
+    let (mut min, mut max) = (None, None);
+    for rule in rules.iter().filter(|rule| rule.kind == "length") {
+        let param = |name: &str| rule.params.get(name).and_then(Value::as_f64).map(|v| v as usize);
+        let (rule_min, rule_max) = match param("equal") {
+            Some(equal) => (Some(equal), Some(equal)),
+            None => (param("min"), param("max")),
+        };
+        min = min.max(rule_min);
+        max = match (max, rule_max) {
+            (Some(max), Some(rule_max)) => Some(usize::min(max, rule_max)),
+            (max, rule_max) => max.or(rule_max),
+        };
+    }
+    (min, max)
+}
+
+/// The size of an array type like `[u8;4]`
+fn array_size(field_type: &str) -> Option<usize> {
+    let size = field_type.strip_suffix(']')?.rsplit(';').next()?;
+    size.parse().ok()
+}
+
+fn integer_limits(field_type: &str) -> (i128, i128) {
+    match field_type.rsplit("::").next().unwrap_or_default() {
+        "u8" => (0, u8::MAX.into()),
+        "u16" => (0, u16::MAX.into()),
+        "u32" => (0, u32::MAX.into()),
+        "u64" | "u128" | "usize" => (0, u64::MAX.into()),
+        "i8" => (i8::MIN.into(), i8::MAX.into()),
+        "i16" => (i16::MIN.into(), i16::MAX.into()),
+        "i32" => (i32::MIN.into(), i32::MAX.into()),
+        _ => (i64::MIN.into(), i64::MAX.into()),
+    }
+}
+
+/// The keys of JSON objects are strings, the ones of maps of numbers included
+fn key_string(key: Value) -> Option<String> {
+    match key {
+        Value::String(key) => Some(key),
+        Value::Number(key) => Some(key.to_string()),
+        _ => None,
+    }
+}
+
+fn strip_option(field_type: &str) -> &str {
+    unwrap_generic(field_type, "Option").unwrap_or(field_type)
+}
@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+use serde_derive::Serialize;
+
+use crate::param::ParamValue;
+use crate::types::ValidationErrors;
+
+/// The shapes `ValidationErrors` can be serialized to with [`ValidationErrors::with_shape`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorsShape {
+    /// The shape of the `Serialize` implementation of `ValidationErrors`: the nested structs,
+    /// vectors and maps are nested objects
+    Nested,
+    /// An object mapping the dotted path of each field to the messages of its errors, their code
+    /// for the errors without a message: `{"addresses[0].street": ["length"]}`
+    FlatMessages,
+    /// An array of `{field, code, message, params}` objects, `field` being the dotted path of the
+    /// field
+    List,
+}
+
+/// Serializes the errors in the chosen shape, see [`ValidationErrors::with_shape`]
+#[derive(Debug, Clone, Copy)]
+pub struct ShapedErrors<'a> {
+    errors: &'a ValidationErrors,
+    shape: ErrorsShape,
+}
+
+/// An entry of the `List` shape
+#[derive(Serialize)]
+struct ListedError<'a> {
+    field: &'a str,
+    code: &'a str,
+    message: Option<&'a str>,
+    params: &'a HashMap<Cow<'static, str>, ParamValue>,
+}
+
+impl ValidationErrors {
+    /// Returns a value serializing the errors in the given shape, for example
+    /// `serde_json::to_string(&errors.with_shape(ErrorsShape::FlatMessages))`
+    pub fn with_shape(&self, shape: ErrorsShape) -> ShapedErrors<'_> {
+        ShapedErrors { errors: self, shape }
+    }
+}
+
+impl Serialize for ShapedErrors<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.shape {
+            ErrorsShape::Nested => self.errors.serialize(serializer),
+            ErrorsShape::FlatMessages => {
+                let flat = self.errors.flatten();
+                let mut map = serializer.serialize_map(Some(flat.len()))?;
+                for (field, errors) in &flat {
+                    let messages = errors
+                        .iter()
+                        .map(|error| error.message.as_deref().unwrap_or(&error.code))
+                        .collect::<Vec<_>>();
+                    map.serialize_entry(field, &messages)?;
+                }
+                map.end()
+            }
+            ErrorsShape::List => {
+                let flat = self.errors.flatten();
+                let mut seq = serializer.serialize_seq(None)?;
+                for (field, errors) in &flat {
+                    for error in errors.iter() {
+                        seq.serialize_element(&ListedError {
+                            field,
+                            code: &error.code,
+                            message: error.message.as_deref(),
+                            params: &error.params,
+                        })?;
+                    }
+                }
+                seq.end()
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use http::header::CONTENT_TYPE;
+use http::{HeaderValue, Request, Response, StatusCode};
+use http_body::Body;
+use http_body_util::{BodyExt, Full};
+use serde::de::DeserializeOwned;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::serialization::ErrorsShape;
+use crate::traits::Validate;
+
+/// A layer validating the JSON bodies of the requests as a `T` before they reach the service.
+/// The requests with an invalid body get a 422 with the errors as JSON, the ones whose body isn't
+/// a `T` a 400 with the error of the deserialization.
+///
+/// The service gets the requests with their body collected in a `Full<Bytes>`, and the
+/// deserialized `T` in their extensions.
+///
+/// ```ignore
+/// let service = ServiceBuilder::new()
+///     .layer(ValidateJsonLayer::<Signup>::new())
+///     .service(signup_service);
+/// ```
+pub struct ValidateJsonLayer<T> {
+    status: StatusCode,
+    shape: ErrorsShape,
+    _payload: PhantomData<fn() -> T>,
+}
+
+impl<T> ValidateJsonLayer<T> {
+    /// A layer responding to invalid bodies with a 422 and the `Nested` shape
+    pub fn new() -> ValidateJsonLayer<T> {
+        ValidateJsonLayer {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            shape: ErrorsShape::Nested,
+            _payload: PhantomData,
+        }
+    }
+
+    /// Responds to invalid bodies with that status and the errors in that shape
+    pub fn with_response(mut self, status: StatusCode, shape: ErrorsShape) -> ValidateJsonLayer<T> {
+        self.status = status;
+        self.shape = shape;
+        self
+    }
+}
+
+impl<T> Default for ValidateJsonLayer<T> {
+    fn default() -> ValidateJsonLayer<T> {
+        ValidateJsonLayer::new()
+    }
+}
+
+impl<T> Clone for ValidateJsonLayer<T> {
+    fn clone(&self) -> ValidateJsonLayer<T> {
+        ValidateJsonLayer { status: self.status, shape: self.shape, _payload: PhantomData }
+    }
+}
+
+impl<S, T> Layer<S> for ValidateJsonLayer<T> {
+    type Service = ValidateJson<S, T>;
+
+    fn layer(&self, inner: S) -> ValidateJson<S, T> {
+        ValidateJson { inner, status: self.status, shape: self.shape, _payload: PhantomData }
+    }
+}
+
+/// The service of [`ValidateJsonLayer`]
+pub struct ValidateJson<S, T> {
+    inner: S,
+    status: StatusCode,
+    shape: ErrorsShape,
+    _payload: PhantomData<fn() -> T>,
+}
+
+impl<S: Clone, T> Clone for ValidateJson<S, T> {
+    fn clone(&self) -> ValidateJson<S, T> {
+        ValidateJson {
+            inner: self.inner.clone(),
+            status: self.status,
+            shape: self.shape,
+            _payload: PhantomData,
+        }
+    }
+}
+
+fn respond<B: From<String>>(
+    status: StatusCode,
+    content_type: &'static str,
+    body: String,
+) -> Response<B> {
+    let mut response = Response::new(B::from(body));
+    *response.status_mut() = status;
+    response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    response
+}
+
+impl<S, T, ReqBody, ResBody> Service<Request<ReqBody>> for ValidateJson<S, T>
+where
+    S: Service<Request<Full<Bytes>>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send,
+    T: DeserializeOwned + Validate + Clone + Send + Sync + 'static,
+    ReqBody: Body + Send + 'static,
+    ReqBody::Data: Send,
+    ResBody: From<String>,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response<ResBody>, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        // The clone is ready, the service that was polled is called
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let (status, shape) = (self.status, self.shape);
+
+        Box::pin(async move {
+            let (mut parts, body) = request.into_parts();
+            let bytes = match body.collect().await {
+                Ok(collected) => collected.to_bytes(),
+                Err(_) => {
+                    return Ok(respond(
+                        StatusCode::BAD_REQUEST,
+                        "text/plain; charset=utf-8",
+                        "Failed to read the request body".to_string(),
+                    ))
+                }
+            };
+
+            let payload = match serde_json::from_slice::<T>(&bytes) {
+                Ok(payload) => payload,
+                Err(err) => {
+                    return Ok(respond(
+                        StatusCode::BAD_REQUEST,
+                        "text/plain; charset=utf-8",
+                        format!("Failed to deserialize the JSON body: {}", err),
+                    ))
+                }
+            };
+            if let Err(errors) = payload.validate() {
+                let body = serde_json::to_string(&errors.with_shape(shape))
+                    .expect("The errors can be serialized");
+                return Ok(respond(status, "application/json", body));
+            }
+
+            parts.extensions.insert(payload);
+            inner.call(Request::from_parts(parts, Full::new(bytes))).await
+        })
+    }
+}
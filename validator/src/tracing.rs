@@ -0,0 +1,60 @@
+use std::any::type_name;
+use std::cell::Cell;
+
+use tracing::{debug, debug_span, field};
+
+use crate::types::ValidationErrors;
+
+thread_local! {
+    static TRACING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether the validation is run by `Validate::validate_traced`, the nested structs then have
+/// their own span
+pub fn is_tracing() -> bool {
+    TRACING.with(|tracing| tracing.get())
+}
+
+/// Restores the previous tracing state when the validation is done, even if it panics
+struct TracingGuard(bool);
+
+impl Drop for TracingGuard {
+    fn drop(&mut self) {
+        TRACING.with(|tracing| tracing.set(self.0));
+    }
+}
+
+/// Runs the validation of a `T` in a `validate` span with its type, recording whether it is
+/// valid, and emits an event in the span with the path and the code of each error
+pub(crate) fn traced<T: ?Sized, F>(validate: F) -> Result<(), ValidationErrors>
+where
+    F: FnOnce() -> Result<(), ValidationErrors>,
+{
+    let _guard = TracingGuard(TRACING.with(|tracing| tracing.replace(true)));
+    in_span::<T, F>(validate, true)
+}
+
+/// Runs the validation of a nested `T` in its own `validate` span, its errors being reported by
+/// the outermost span
+pub(crate) fn traced_nested<T: ?Sized, F>(validate: F) -> Result<(), ValidationErrors>
+where
+    F: FnOnce() -> Result<(), ValidationErrors>,
+{
+    in_span::<T, F>(validate, false)
+}
+
+fn in_span<T: ?Sized, F>(validate: F, events: bool) -> Result<(), ValidationErrors>
+where
+    F: FnOnce() -> Result<(), ValidationErrors>,
+{
+    let span = debug_span!("validate", r#type = type_name::<T>(), valid = field::Empty);
+    let _entered = span.enter();
+    let result = validate();
+    span.record("valid", result.is_ok());
+    if let (Err(errors), true) = (&result, events) {
+        for (path, error) in errors.iter() {
+            debug!(field = %path, code = %error.code, "validation failed");
+        }
+    }
+    result
+}
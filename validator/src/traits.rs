@@ -1,10 +1,21 @@
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, OnceLock};
 
 #[cfg(feature = "indexmap")]
 use indexmap::{IndexMap, IndexSet};
+use regex::Regex;
 
-use crate::types::ValidationErrors;
+use crate::config::ValidationConfig;
+use crate::observer::ValidationObserver;
+use crate::report::ValidationReport;
+use crate::rules::FieldRules;
+use crate::types::{ErrorSource, ValidationError, ValidationErrors, ValidationErrorsKind};
 
 /// Trait to implement if one wants to make the `length` validator
 /// work for more types
@@ -20,13 +31,13 @@ impl HasLen for String {
     }
 }
 
-impl<'a> HasLen for &'a String {
+impl HasLen for &String {
     fn length(&self) -> u64 {
         self.chars().count() as u64
     }
 }
 
-impl<'a> HasLen for &'a str {
+impl HasLen for &str {
     fn length(&self) -> u64 {
         self.chars().count() as u64
     }
@@ -44,7 +55,7 @@ impl<T> HasLen for Vec<T> {
     }
 }
 
-impl<'a, T> HasLen for &'a Vec<T> {
+impl<T> HasLen for &Vec<T> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -68,7 +79,7 @@ impl<T, const N: usize> HasLen for &[T; N] {
     }
 }
 
-impl<'a, K, V, S> HasLen for &'a HashMap<K, V, S> {
+impl<K, V, S> HasLen for &HashMap<K, V, S> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -80,7 +91,7 @@ impl<K, V, S> HasLen for HashMap<K, V, S> {
     }
 }
 
-impl<'a, T, S> HasLen for &'a HashSet<T, S> {
+impl<T, S> HasLen for &HashSet<T, S> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -92,7 +103,7 @@ impl<T, S> HasLen for HashSet<T, S> {
     }
 }
 
-impl<'a, K, V> HasLen for &'a BTreeMap<K, V> {
+impl<K, V> HasLen for &BTreeMap<K, V> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -104,7 +115,7 @@ impl<K, V> HasLen for BTreeMap<K, V> {
     }
 }
 
-impl<'a, T> HasLen for &'a BTreeSet<T> {
+impl<T> HasLen for &BTreeSet<T> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -117,7 +128,7 @@ impl<T> HasLen for BTreeSet<T> {
 }
 
 #[cfg(feature = "indexmap")]
-impl<'a, K, V> HasLen for &'a IndexMap<K, V> {
+impl<K, V> HasLen for &IndexMap<K, V> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -131,7 +142,7 @@ impl<K, V> HasLen for IndexMap<K, V> {
 }
 
 #[cfg(feature = "indexmap")]
-impl<'a, T> HasLen for &'a IndexSet<T> {
+impl<T> HasLen for &IndexSet<T> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -144,46 +155,90 @@ impl<T> HasLen for IndexSet<T> {
     }
 }
 
-/// Trait to implement if one wants to make the `contains` validator
-/// work for more types
-pub trait Contains {
-    #[must_use]
-    fn has_element(&self, needle: &str) -> bool;
+/// What the `regex` validator accepts as its `path`: a `Regex` (or anything dereferencing to it,
+/// like a `lazy_static`) or a `OnceLock<Regex>` that has been initialized before validating
+pub trait AsRegex {
+    fn as_regex(&self) -> &Regex;
+}
+
+impl AsRegex for Regex {
+    fn as_regex(&self) -> &Regex {
+        self
+    }
+}
+
+impl AsRegex for OnceLock<Regex> {
+    fn as_regex(&self) -> &Regex {
+        self.get().expect("the `OnceLock<Regex>` used by the `regex` validator is not initialized")
+    }
 }
 
-impl Contains for String {
-    fn has_element(&self, needle: &str) -> bool {
-        self.contains(needle)
+impl<T: AsRegex> AsRegex for &T {
+    fn as_regex(&self) -> &Regex {
+        T::as_regex(self)
     }
 }
 
-impl<'a> Contains for &'a String {
-    fn has_element(&self, needle: &str) -> bool {
-        self.contains(needle)
+/// The values a `custom` function or closure can return: the `Result` of a custom validation
+/// function, with a single `ValidationError` or several ones when a check finds multiple problems,
+/// or for closures a `bool` telling whether the value is valid, in which case the error only has
+/// the code of the validator
+pub trait CustomResult {
+    fn into_errors(self, code: &'static str) -> Result<(), Vec<ValidationError>>;
+}
+
+impl CustomResult for bool {
+    fn into_errors(self, code: &'static str) -> Result<(), Vec<ValidationError>> {
+        if self {
+            Ok(())
+        } else {
+            Err(vec![ValidationError::new(code)])
+        }
     }
 }
 
-impl<'a> Contains for &'a str {
-    fn has_element(&self, needle: &str) -> bool {
-        self.contains(needle)
+impl CustomResult for Result<(), ValidationError> {
+    fn into_errors(self, _code: &'static str) -> Result<(), Vec<ValidationError>> {
+        self.map_err(|err| vec![err])
     }
 }
 
-impl<'a> Contains for Cow<'a, str> {
-    fn has_element(&self, needle: &str) -> bool {
-        self.contains(needle)
+impl CustomResult for Result<(), Vec<ValidationError>> {
+    fn into_errors(self, _code: &'static str) -> Result<(), Vec<ValidationError>> {
+        self
     }
 }
 
-impl<S, H: ::std::hash::BuildHasher> Contains for HashMap<String, S, H> {
-    fn has_element(&self, needle: &str) -> bool {
-        self.contains_key(needle)
+/// Any other error is the source of an error with the code of the validator
+impl CustomResult for Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    fn into_errors(self, code: &'static str) -> Result<(), Vec<ValidationError>> {
+        self.map_err(|err| {
+            let mut error = ValidationError::new(code);
+            error.source = Some(ErrorSource::from(err));
+            vec![error]
+        })
+    }
+}
+
+/// The values a `schema` function can return: a single `ValidationError`, reported in `__all__`,
+/// or `ValidationErrors` targeting specific fields of the struct
+pub trait SchemaResult {
+    fn into_errors(self) -> Result<(), ValidationErrors>;
+}
+
+impl SchemaResult for Result<(), ValidationError> {
+    fn into_errors(self) -> Result<(), ValidationErrors> {
+        self.map_err(|err| {
+            let mut errors = ValidationErrors::new();
+            errors.add("__all__", err);
+            errors
+        })
     }
 }
 
-impl<'a, S, H: ::std::hash::BuildHasher> Contains for &'a HashMap<String, S, H> {
-    fn has_element(&self, needle: &str) -> bool {
-        self.contains_key(needle)
+impl SchemaResult for Result<(), ValidationErrors> {
+    fn into_errors(self) -> Result<(), ValidationErrors> {
+        self
     }
 }
 
@@ -192,12 +247,275 @@ impl<'a, S, H: ::std::hash::BuildHasher> Contains for &'a HashMap<String, S, H>
 /// forwarded to the `ValidateArgs<'v_a>` trait.
 pub trait Validate {
     fn validate(&self) -> Result<(), ValidationErrors>;
+
+    /// Stops at the first error instead of collecting all of them, for hot paths that only need
+    /// to know whether the value is valid. The derive implements it, other types fall back to
+    /// `validate`.
+    fn validate_fail_fast(&self) -> Result<(), ValidationErrors> {
+        self.validate()
+    }
+
+    /// Also runs the validators with a `warning` or `info` severity, including in the nested
+    /// structs, and returns everything they found. The value is valid if the report only has
+    /// warnings and infos.
+    fn validate_report(&self) -> ValidationReport {
+        ValidationReport::collect(|| self.validate())
+    }
+
+    /// Never fails but returns all the errors `validate` would reject the value with, empty if
+    /// it is valid, even for the structs in fail fast mode. Useful to log what would fail before
+    /// enforcing a validation.
+    fn validate_lenient(&self) -> ValidationErrors {
+        crate::report::collect_lenient(|| self.validate())
+    }
+
+    /// Validates with the limits of the config: the validation stops once `max_errors` errors are
+    /// found, or at the first invalid nested value with `stop_on_first_nested_failure`, and
+    /// returns the errors found so far
+    fn validate_with_config(&self, config: &ValidationConfig) -> Result<(), ValidationErrors> {
+        crate::config::with_config(config, || self.validate())
+    }
+
+    /// Validates telling that observer the outcome instead of the one registered with
+    /// `set_validation_observer`
+    fn validate_observed(&self, observer: &dyn ValidationObserver) -> Result<(), ValidationErrors> {
+        crate::observer::observe_with::<Self, _>(observer, || self.validate())
+    }
+
+    /// Validates in a `validate` span at the debug level, with the type of the value and whether
+    /// it is valid, and emits a debug event with the `field` and the `code` of each error. The
+    /// nested structs have their own span.
+    #[cfg(feature = "tracing")]
+    fn validate_traced(&self) -> Result<(), ValidationErrors> {
+        crate::tracing::traced::<Self, _>(|| self.validate())
+    }
 }
 
 impl<T: Validate> Validate for &T {
     fn validate(&self) -> Result<(), ValidationErrors> {
         T::validate(*self)
     }
+
+    fn validate_fail_fast(&self) -> Result<(), ValidationErrors> {
+        T::validate_fail_fast(*self)
+    }
+}
+
+impl<T: Validate> Validate for Box<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        T::validate(self)
+    }
+
+    fn validate_fail_fast(&self) -> Result<(), ValidationErrors> {
+        T::validate_fail_fast(self)
+    }
+}
+
+impl<T: Validate> Validate for Rc<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        T::validate(self)
+    }
+
+    fn validate_fail_fast(&self) -> Result<(), ValidationErrors> {
+        T::validate_fail_fast(self)
+    }
+}
+
+impl<T: Validate> Validate for Arc<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        T::validate(self)
+    }
+
+    fn validate_fail_fast(&self) -> Result<(), ValidationErrors> {
+        T::validate_fail_fast(self)
+    }
+}
+
+impl<'a, T: Validate + ToOwned> Validate for Cow<'a, T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        T::validate(self)
+    }
+
+    fn validate_fail_fast(&self) -> Result<(), ValidationErrors> {
+        T::validate_fail_fast(self)
+    }
+}
+
+/// `None` is always valid
+impl<T: Validate> Validate for Option<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            Some(value) => value.validate(),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_fail_fast(&self) -> Result<(), ValidationErrors> {
+        match self {
+            Some(value) => value.validate_fail_fast(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The errors of the elements are in a list under `__all__`, keyed by their index
+impl<T: Validate> Validate for Vec<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let errors = self
+            .iter()
+            .enumerate()
+            .filter_map(|(i, value)| value.validate().err().map(|err| (i, Box::new(err))))
+            .collect::<BTreeMap<_, _>>();
+
+        list_errors(errors)
+    }
+
+    fn validate_fail_fast(&self) -> Result<(), ValidationErrors> {
+        let errors = self
+            .iter()
+            .enumerate()
+            .find_map(|(i, value)| value.validate_fail_fast().err().map(|err| (i, Box::new(err))))
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+
+        list_errors(errors)
+    }
+}
+
+fn list_errors(errors: BTreeMap<usize, Box<ValidationErrors>>) -> Result<(), ValidationErrors> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        let mut all = ValidationErrors::new();
+        all.errors_mut().insert("__all__", ValidationErrorsKind::List(errors));
+        Err(all)
+    }
+}
+
+/// The errors of the values are in a map under `__all__`, keyed by the string representation of
+/// their key
+impl<K: ToString, T: Validate, S> Validate for HashMap<K, T, S> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let errors = self
+            .iter()
+            .filter_map(|(key, value)| {
+                value.validate().err().map(|err| (key.to_string(), Box::new(err)))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        map_errors(errors)
+    }
+
+    fn validate_fail_fast(&self) -> Result<(), ValidationErrors> {
+        let errors = self
+            .iter()
+            .find_map(|(key, value)| {
+                value.validate_fail_fast().err().map(|err| (key.to_string(), Box::new(err)))
+            })
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+
+        map_errors(errors)
+    }
+}
+
+fn map_errors(errors: BTreeMap<String, Box<ValidationErrors>>) -> Result<(), ValidationErrors> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        let mut all = ValidationErrors::new();
+        all.errors_mut().insert("__all__", ValidationErrorsKind::Map(errors));
+        Err(all)
+    }
+}
+
+/// This trait will be implemented by deriving `Validate`. It describes the validators of each
+/// field as data, to share the rules with a frontend or a documentation instead of writing them
+/// twice.
+pub trait ValidationRules {
+    fn validation_rules() -> Vec<FieldRules>;
+}
+
+/// This trait will be implemented by deriving `Normalize`. It cleans up the fields marked with
+/// `#[normalize(..)]`, trimming or lowercasing them for example, so the validation runs on the
+/// cleaned values.
+pub trait Normalize {
+    fn normalize(&mut self);
+
+    /// Returns the normalized value, `value.clone().normalized()` cleans up a copy
+    fn normalized(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        self.normalize();
+        self
+    }
+
+    /// Normalizes the value then validates it, nested fields included if they are marked with
+    /// `#[normalize(nested)]` and `#[validate(nested)]`
+    fn validify(&mut self) -> Result<(), ValidationErrors>
+    where
+        Self: Validate,
+    {
+        self.normalize();
+        self.validate()
+    }
+}
+
+impl<T: Normalize> Normalize for Option<T> {
+    fn normalize(&mut self) {
+        if let Some(value) = self {
+            value.normalize()
+        }
+    }
+}
+
+impl<T: Normalize> Normalize for Vec<T> {
+    fn normalize(&mut self) {
+        for value in self {
+            value.normalize()
+        }
+    }
+}
+
+/// This trait will be implemented by deriving `ValidateAsync`. It runs the synchronous validation
+/// of `Validate` first and then awaits the `custom_async` validators of the fields, and the ones of
+/// the nested fields whose type implements `ValidateAsync`, so checks like uniqueness in a
+/// database end up in the same `ValidationErrors` as the other validators.
+#[cfg(feature = "async")]
+pub trait ValidateAsync: Validate + Sync {
+    /// Awaits only the async validators, including the ones of the nested fields, without running
+    /// the ones of `Validate`
+    fn validate_async_only(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + Send + '_>>;
+
+    /// Runs `Validate::validate` once and adds the errors of
+    /// [`validate_async_only`](ValidateAsync::validate_async_only) to its errors
+    fn validate_async(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + Send + '_>> {
+        Box::pin(async move {
+            let result = self.validate();
+            let async_result = self.validate_async_only().await;
+            crate::__private::merge_async(result, async_result)
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: ValidateAsync + Sync> ValidateAsync for &T {
+    fn validate_async_only(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + Send + '_>> {
+        T::validate_async_only(*self)
+    }
+
+    fn validate_async(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + Send + '_>> {
+        T::validate_async(*self)
+    }
 }
 
 /// This trait will be implemented by deriving `Validate`. This implementation can take one
@@ -210,3 +528,28 @@ pub trait ValidateArgs<'v_a> {
 
     fn validate_args(&self, args: Self::Args) -> Result<(), ValidationErrors>;
 }
+
+/// This trait will be implemented by deriving `Validate` on a struct with a
+/// `#[validate(context = "Type")]` attribute. The context is shared by all the `custom` and
+/// `schema` validators marked with `use_context`, which get a reference to it as their last
+/// argument. It is forwarded to `ValidateArgs<'v_a>` with `&'v_a Self::Context` as `Args`.
+pub trait ValidateWithContext {
+    type Context: ?Sized;
+
+    fn validate_with_context(&self, context: &Self::Context) -> Result<(), ValidationErrors>;
+}
+
+impl<T: ValidateWithContext> ValidateWithContext for &T {
+    type Context = T::Context;
+
+    fn validate_with_context(&self, context: &Self::Context) -> Result<(), ValidationErrors> {
+        T::validate_with_context(*self, context)
+    }
+}
+
+/// Maps validation errors to an application error, implemented by deriving
+/// `FromValidationErrors` on an enum whose variants say which errors they are mapped from
+pub trait FromValidationErrors: Sized {
+    /// Returns the value the errors are mapped to, or the errors back if none of them is
+    fn from_validation_errors(errors: ValidationErrors) -> Result<Self, ValidationErrors>;
+}
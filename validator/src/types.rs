@@ -1,24 +1,196 @@
+//! The errors of the validations, and wrappers of values that are valid by construction so
+//! their invariants live in the type system
+
 use std::borrow::Cow;
-use std::collections::{hash_map::Entry::Vacant, BTreeMap, HashMap};
+use std::collections::btree_map::Entry as BTreeEntry;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
 
-use serde::ser::Serialize;
+use serde::ser::{Serialize, SerializeMap, Serializer};
 use serde_derive::{Deserialize, Serialize};
-use serde_json::{to_value, Value};
+
+use crate::error_map::ErrorMap;
+use crate::param::ParamValue;
+use crate::path::{parse_path, FieldPath, PathSegment};
+
+mod newtypes;
+
+pub use newtypes::{BoundedString, BoundedVec, NonEmptyString, Percentage, Port};
+
+/// How serious an error is: only the `Error` ones make a value invalid, the others are reported by
+/// `Validate::validate_report`
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    #[default]
+    Error,
+}
+
+impl Severity {
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        *self == Severity::Error
+    }
+}
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct ValidationError {
     pub code: Cow<'static, str>,
     pub message: Option<Cow<'static, str>>,
-    pub params: HashMap<Cow<'static, str>, Value>,
+    pub params: HashMap<Cow<'static, str>, ParamValue>,
+    /// Only serialized when it isn't `Error`
+    #[serde(default, skip_serializing_if = "Severity::is_error")]
+    pub severity: Severity,
+    /// The error that caused this one, returned by `Error::source`. It isn't serialized.
+    #[serde(skip)]
+    pub source: Option<ErrorSource>,
+}
+
+/// The underlying error of a [`ValidationError`], like the error of the parser a custom validator
+/// used. Two sources are equal if they display the same.
+#[derive(Clone)]
+pub struct ErrorSource(Arc<dyn std::error::Error + Send + Sync + 'static>);
+
+impl ErrorSource {
+    pub fn new<E: std::error::Error + Send + Sync + 'static>(error: E) -> ErrorSource {
+        ErrorSource(Arc::new(error))
+    }
+
+    pub fn get(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        &*self.0
+    }
+}
+
+impl From<Box<dyn std::error::Error + Send + Sync + 'static>> for ErrorSource {
+    fn from(error: Box<dyn std::error::Error + Send + Sync + 'static>) -> ErrorSource {
+        ErrorSource(Arc::from(error))
+    }
+}
+
+impl fmt::Debug for ErrorSource {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, fmt)
+    }
+}
+
+impl PartialEq for ErrorSource {
+    fn eq(&self, other: &ErrorSource) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0.to_string() == other.0.to_string()
+    }
 }
 
 impl ValidationError {
     pub fn new(code: &'static str) -> ValidationError {
-        ValidationError { code: Cow::from(code), message: None, params: HashMap::new() }
+        ValidationError {
+            code: Cow::from(code),
+            message: None,
+            params: HashMap::new(),
+            severity: Severity::Error,
+            source: None,
+        }
+    }
+
+    /// Sets the error that caused this one, so error reporters show it
+    #[must_use]
+    pub fn with_source<E>(mut self, source: E) -> ValidationError
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        self.source = Some(ErrorSource::new(source));
+        self
     }
 
     pub fn add_param<T: Serialize>(&mut self, name: Cow<'static, str>, val: &T) {
-        self.params.insert(name, to_value(val).unwrap());
+        self.params.insert(name, ParamValue::from_serialize(val));
+    }
+
+    /// Adds a param without serializing it, the `&'static str` ones being borrowed
+    pub fn add_param_value(&mut self, name: Cow<'static, str>, val: impl Into<ParamValue>) {
+        self.params.insert(name, val.into());
+    }
+
+    /// Returns the param of that name as a [`ParamValue`], if it has one that can be represented
+    /// as such
+    pub fn param(&self, name: &str) -> Option<ParamValue> {
+        match self.params.get(name)? {
+            ParamValue::Json(value) => ParamValue::from_json(value),
+            value => Some(value.clone()),
+        }
+    }
+
+    /// Returns the param of that name if it is an integer fitting in an `i64`. Whole floats count
+    /// as integers since the bounds of `range` are stored as floats.
+    pub fn param_i64(&self, name: &str) -> Option<i64> {
+        let value = self.params.get(name)?;
+        value.as_i64().or_else(|| {
+            value
+                .as_f64()
+                .filter(|f| f.fract() == 0.0 && *f >= i64::MIN as f64 && *f < i64::MAX as f64)
+                .map(|f| f as i64)
+        })
+    }
+
+    /// Returns the param of that name if it is a number, integers are converted
+    pub fn param_f64(&self, name: &str) -> Option<f64> {
+        self.params.get(name).and_then(ParamValue::as_f64)
+    }
+
+    /// Returns the param of that name if it is a string
+    pub fn param_str(&self, name: &str) -> Option<&str> {
+        self.params.get(name).and_then(ParamValue::as_str)
+    }
+
+    /// Returns the param of that name if it is a boolean
+    pub fn param_bool(&self, name: &str) -> Option<bool> {
+        self.params.get(name).and_then(ParamValue::as_bool)
+    }
+
+    /// Replaces the `{name}` placeholders of the message with the value of the param of the same
+    /// name, `{field}` with the given field name and `{code}` with the code of the error.
+    /// Placeholders that don't match any of those are left as is.
+    pub fn interpolate_message(&mut self, field: &str) {
+        let message = match self.message {
+            Some(ref message) if message.contains('{') => message,
+            _ => return,
+        };
+
+        let mut interpolated = String::with_capacity(message.len());
+        let mut rest = &message[..];
+        while let Some(start) = rest.find('{') {
+            interpolated.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let name = rest[1..]
+                .find('}')
+                .map(|end| &rest[1..=end])
+                .filter(|name| name.chars().all(|c| c.is_alphanumeric() || c == '_'));
+            let replacement = name.and_then(|name| match self.params.get(name) {
+                Some(ParamValue::Str(s)) => Some(s.to_string()),
+                Some(value) => Some(value.to_string()),
+                None if name == "field" => Some(field.to_string()),
+                None if name == "code" => Some(self.code.to_string()),
+                None => None,
+            });
+
+            match (name, replacement) {
+                (Some(name), Some(replacement)) => {
+                    interpolated.push_str(&replacement);
+                    rest = &rest[name.len() + 2..];
+                }
+                _ => {
+                    interpolated.push('{');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        interpolated.push_str(rest);
+
+        self.message = Some(Cow::from(interpolated));
     }
 }
 
@@ -26,8 +198,8 @@ impl std::error::Error for ValidationError {
     fn description(&self) -> &str {
         &self.code
     }
-    fn cause(&self) -> Option<&dyn std::error::Error> {
-        None
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.as_ref().map(|source| source.get() as &(dyn std::error::Error + 'static))
     }
 }
 
@@ -36,25 +208,242 @@ impl std::error::Error for ValidationError {
 pub enum ValidationErrorsKind {
     Struct(Box<ValidationErrors>),
     List(BTreeMap<usize, Box<ValidationErrors>>),
+    Map(BTreeMap<String, Box<ValidationErrors>>),
     Field(Vec<ValidationError>),
 }
 
-#[derive(Default, Debug, Serialize, Clone, PartialEq)]
-pub struct ValidationErrors(HashMap<&'static str, ValidationErrorsKind>);
+impl ValidationErrorsKind {
+    /// Whether there is an error of the `Error` severity among these errors
+    fn is_blocking(&self) -> bool {
+        match self {
+            ValidationErrorsKind::Field(errors) => errors.iter().any(|err| err.severity.is_error()),
+            ValidationErrorsKind::Struct(errors) => errors.is_blocking(),
+            ValidationErrorsKind::List(entries) => {
+                entries.values().any(|errors| errors.is_blocking())
+            }
+            ValidationErrorsKind::Map(entries) => {
+                entries.values().any(|errors| errors.is_blocking())
+            }
+        }
+    }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&ValidationError) -> bool) {
+        match self {
+            ValidationErrorsKind::Field(errors) => errors.retain(|err| f(err)),
+            ValidationErrorsKind::Struct(errors) => errors.retain(f),
+            ValidationErrorsKind::List(entries) => {
+                entries.values_mut().for_each(|errors| errors.retain(f));
+                entries.retain(|_, errors| !errors.is_empty());
+            }
+            ValidationErrorsKind::Map(entries) => {
+                entries.values_mut().for_each(|errors| errors.retain(f));
+                entries.retain(|_, errors| !errors.is_empty());
+            }
+        }
+    }
+
+    fn set_severity(&mut self, severity: Severity) {
+        match self {
+            ValidationErrorsKind::Field(errors) => {
+                errors.iter_mut().for_each(|err| err.severity = severity)
+            }
+            ValidationErrorsKind::Struct(errors) => errors.set_severity(severity),
+            ValidationErrorsKind::List(entries) => {
+                entries.values_mut().for_each(|errors| errors.set_severity(severity))
+            }
+            ValidationErrorsKind::Map(entries) => {
+                entries.values_mut().for_each(|errors| errors.set_severity(severity))
+            }
+        }
+    }
+
+    fn dedup(&mut self) {
+        match self {
+            ValidationErrorsKind::Field(errors) => {
+                let mut kept: Vec<ValidationError> = Vec::with_capacity(errors.len());
+                for error in errors.drain(..) {
+                    if !kept.iter().any(|k| k.code == error.code && k.params == error.params) {
+                        kept.push(error);
+                    }
+                }
+                *errors = kept;
+            }
+            ValidationErrorsKind::Struct(errors) => errors.dedup(),
+            ValidationErrorsKind::List(entries) => {
+                entries.values_mut().for_each(|errors| errors.dedup())
+            }
+            ValidationErrorsKind::Map(entries) => {
+                entries.values_mut().for_each(|errors| errors.dedup())
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            ValidationErrorsKind::Field(errors) => errors.is_empty(),
+            ValidationErrorsKind::Struct(errors) => errors.is_empty(),
+            ValidationErrorsKind::List(entries) => entries.is_empty(),
+            ValidationErrorsKind::Map(entries) => entries.is_empty(),
+        }
+    }
+
+    fn extend(&mut self, other: ValidationErrorsKind) {
+        fn extend_entries<K: Ord>(
+            entries: &mut BTreeMap<K, Box<ValidationErrors>>,
+            other: BTreeMap<K, Box<ValidationErrors>>,
+        ) {
+            for (key, errors) in other {
+                match entries.entry(key) {
+                    BTreeEntry::Vacant(entry) => {
+                        entry.insert(errors);
+                    }
+                    BTreeEntry::Occupied(mut entry) => entry.get_mut().extend(*errors),
+                }
+            }
+        }
+
+        match (self, other) {
+            (ValidationErrorsKind::Field(errors), ValidationErrorsKind::Field(other)) => {
+                errors.extend(other)
+            }
+            (ValidationErrorsKind::Struct(errors), ValidationErrorsKind::Struct(other)) => {
+                errors.extend(*other)
+            }
+            (ValidationErrorsKind::List(entries), ValidationErrorsKind::List(other)) => {
+                extend_entries(entries, other)
+            }
+            (ValidationErrorsKind::Map(entries), ValidationErrorsKind::Map(other)) => {
+                extend_entries(entries, other)
+            }
+            _ => panic!("Attempt to merge different kinds of ValidationErrorsKind instances"),
+        }
+    }
+
+    /// Adds the errors of the async validators, like `extend` except that the errors of a field
+    /// take the place of the errors of its nested fields, like in `Validate` where a field with
+    /// errors isn't validated as a nested struct
+    #[cfg(feature = "async")]
+    fn extend_async(&mut self, other: ValidationErrorsKind) {
+        fn extend_entries<K: Ord>(
+            entries: &mut BTreeMap<K, Box<ValidationErrors>>,
+            other: BTreeMap<K, Box<ValidationErrors>>,
+        ) {
+            for (key, errors) in other {
+                match entries.entry(key) {
+                    BTreeEntry::Vacant(entry) => {
+                        entry.insert(errors);
+                    }
+                    BTreeEntry::Occupied(mut entry) => entry.get_mut().extend_async(*errors),
+                }
+            }
+        }
+
+        match (self, other) {
+            (ValidationErrorsKind::Field(errors), ValidationErrorsKind::Field(other)) => {
+                errors.extend(other)
+            }
+            (ValidationErrorsKind::Field(_), _) => (),
+            (kind, other @ ValidationErrorsKind::Field(_)) => *kind = other,
+            (ValidationErrorsKind::Struct(errors), ValidationErrorsKind::Struct(other)) => {
+                errors.extend_async(*other)
+            }
+            (ValidationErrorsKind::List(entries), ValidationErrorsKind::List(other)) => {
+                extend_entries(entries, other)
+            }
+            (ValidationErrorsKind::Map(entries), ValidationErrorsKind::Map(other)) => {
+                extend_entries(entries, other)
+            }
+            _ => panic!("Attempt to merge different kinds of ValidationErrorsKind instances"),
+        }
+    }
+}
+
+/// Combinators to compose validation results, for manual `Validate` implementations or the
+/// validation of several sources
+pub trait ValidationResultExt: Sized {
+    /// Returns a result with the errors of both results
+    fn and_merge(self, other: Result<(), ValidationErrors>) -> Result<(), ValidationErrors>;
+
+    /// Validates with `f` and returns a result with the errors of both results, `f` is run even if
+    /// `self` has errors
+    fn and_validate<F>(self, f: F) -> Result<(), ValidationErrors>
+    where
+        F: FnOnce() -> Result<(), ValidationErrors>,
+    {
+        self.and_merge(f())
+    }
+
+    /// Returns a result with the errors of `child` nested under `field`, like
+    /// [`ValidationErrors::merge`]
+    fn and_nested(
+        self,
+        field: impl Into<Cow<'static, str>>,
+        child: Result<(), ValidationErrors>,
+    ) -> Result<(), ValidationErrors>;
+}
+
+impl ValidationResultExt for Result<(), ValidationErrors> {
+    fn and_merge(self, other: Result<(), ValidationErrors>) -> Result<(), ValidationErrors> {
+        ValidationErrors::merge_results([self, other])
+    }
+
+    fn and_nested(
+        self,
+        field: impl Into<Cow<'static, str>>,
+        child: Result<(), ValidationErrors>,
+    ) -> Result<(), ValidationErrors> {
+        match child {
+            Ok(()) => self,
+            Err(errors) => {
+                let mut nested = ValidationErrors::new();
+                nested.add_nested(field, errors);
+                self.and_merge(Err(nested))
+            }
+        }
+    }
+}
+
+/// The errors of a struct, keyed by field. The order in which the fields got errors is kept so
+/// they are serialized, displayed and iterated in that order, which the derive sets to the
+/// declaration order of the fields.
+#[derive(Default, Debug, Clone)]
+pub struct ValidationErrors(ErrorMap);
 
 impl ValidationErrors {
-    pub fn new() -> ValidationErrors {
-        ValidationErrors(HashMap::new())
+    pub const fn new() -> ValidationErrors {
+        ValidationErrors(ErrorMap::new())
     }
 
     /// Returns a boolean indicating whether a validation result includes validation errors for a
     /// given field. May be used as a condition for performing nested struct validations on a field
     /// in the absence of field-level validation errors.
+    ///
+    /// Only the errors of the `Error` severity are taken into account.
     #[must_use]
-    pub fn has_error(result: &Result<(), ValidationErrors>, field: &'static str) -> bool {
+    pub fn has_error(result: &Result<(), ValidationErrors>, field: &str) -> bool {
         match result {
             Ok(()) => false,
-            Err(ref errs) => errs.contains_key(field),
+            Err(ref errs) => errs.0.get(field).is_some_and(ValidationErrorsKind::is_blocking),
+        }
+    }
+
+    /// Combines several validation results, like the ones of the body, path and query of a
+    /// request, into one with all their errors. See [`ValidationErrors::extend`] for how the
+    /// errors of the same field are combined.
+    pub fn merge_results<I>(results: I) -> Result<(), ValidationErrors>
+    where
+        I: IntoIterator<Item = Result<(), ValidationErrors>>,
+    {
+        let mut errors = ValidationErrors::new();
+        for result in results {
+            if let Err(other) = result {
+                errors.extend(other);
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
         }
     }
 
@@ -62,42 +451,97 @@ impl ValidationErrors {
     /// validation result for one of its fields.
     pub fn merge(
         parent: Result<(), ValidationErrors>,
-        field: &'static str,
+        field: impl Into<Cow<'static, str>>,
         child: Result<(), ValidationErrors>,
     ) -> Result<(), ValidationErrors> {
         match child {
             Ok(()) => parent,
             Err(errors) => {
                 parent.and_then(|_| Err(ValidationErrors::new())).map_err(|mut parent_errors| {
-                    parent_errors.add_nested(field, ValidationErrorsKind::Struct(Box::new(errors)));
+                    parent_errors
+                        .insert_kind(field, ValidationErrorsKind::Struct(Box::new(errors)));
                     parent_errors
                 })
             }
         }
     }
 
+    /// The former name of [`ValidationErrors::merge_list`]
+    #[deprecated(note = "use merge_list")]
+    pub fn merge_all(
+        parent: Result<(), ValidationErrors>,
+        field: impl Into<Cow<'static, str>>,
+        children: Vec<Result<(), ValidationErrors>>,
+    ) -> Result<(), ValidationErrors> {
+        ValidationErrors::merge_list(parent, field, children)
+    }
+
     /// Returns the combined outcome of a struct's validation result along with the nested
     /// validation result for one of its fields where that field is a vector of validating structs.
-    pub fn merge_all(
+    ///
+    /// Any error of a child outside of `field`, like the errors of the `inner` validators, is kept
+    /// in the entry of that index.
+    pub fn merge_list(
         parent: Result<(), ValidationErrors>,
-        field: &'static str,
+        field: impl Into<Cow<'static, str>>,
         children: Vec<Result<(), ValidationErrors>>,
     ) -> Result<(), ValidationErrors> {
+        let field = field.into();
         let errors = children
             .into_iter()
             .enumerate()
-            .filter_map(|(i, res)| res.err().map(|mut err| (i, err.remove(field))))
-            .filter_map(|(i, entry)| match entry {
-                Some(ValidationErrorsKind::Struct(errors)) => Some((i, errors)),
-                _ => None,
+            .filter_map(|(i, res)| res.err().map(|err| (i, err)))
+            .map(|(i, mut err)| {
+                let mut entry = match err.remove(&field) {
+                    Some(ValidationErrorsKind::Struct(errors)) => *errors,
+                    _ => ValidationErrors::new(),
+                };
+                entry.extend(err);
+                (i, Box::new(entry))
+            })
+            .filter(|(_, entry)| !entry.is_empty())
+            .collect::<BTreeMap<_, _>>();
+
+        if errors.is_empty() {
+            parent
+        } else {
+            parent.and_then(|_| Err(ValidationErrors::new())).map_err(|mut parent_errors| {
+                parent_errors.insert_kind(field, ValidationErrorsKind::List(errors));
+                parent_errors
+            })
+        }
+    }
+
+    /// Returns the combined outcome of a struct's validation result along with the nested
+    /// validation result for one of its fields where that field is a map of validating structs.
+    ///
+    /// The children are keyed by the string representation of the map keys. Any error of a child
+    /// outside of `field`, like the errors of the key validators, is kept in the entry of that key.
+    pub fn merge_map(
+        parent: Result<(), ValidationErrors>,
+        field: impl Into<Cow<'static, str>>,
+        children: Vec<(String, Result<(), ValidationErrors>)>,
+    ) -> Result<(), ValidationErrors> {
+        let field = field.into();
+        let errors = children
+            .into_iter()
+            .filter_map(|(key, res)| res.err().map(|err| (key, err)))
+            .map(|(key, mut err)| {
+                let mut entry = match err.remove(&field) {
+                    Some(ValidationErrorsKind::Struct(errors)) => *errors,
+                    _ => ValidationErrors::new(),
+                };
+                entry.extend(err);
+                (key, Box::new(entry))
             })
+            .filter(|(_, entry)| !entry.is_empty())
             .collect::<BTreeMap<_, _>>();
 
         if errors.is_empty() {
             parent
         } else {
             parent.and_then(|_| Err(ValidationErrors::new())).map_err(|mut parent_errors| {
-                parent_errors.add_nested(field, ValidationErrorsKind::List(errors));
+                parent_errors.insert_kind(field, ValidationErrorsKind::Map(errors));
                 parent_errors
             })
         }
@@ -105,28 +549,28 @@ impl ValidationErrors {
 
     /// Returns a map of field-level validation errors found for the struct that was validated and
     /// any of it's nested structs that are tagged for validation.
-    pub fn errors(&self) -> &HashMap<&'static str, ValidationErrorsKind> {
+    pub fn errors(&self) -> &ErrorMap {
         &self.0
     }
 
     /// Returns a mutable map of field-level validation errors found for the struct that was validated and
     /// any of it's nested structs that are tagged for validation.
-    pub fn errors_mut(&mut self) -> &mut HashMap<&'static str, ValidationErrorsKind> {
+    pub fn errors_mut(&mut self) -> &mut ErrorMap {
         &mut self.0
     }
 
     /// Consume the struct, returning the validation errors found
-    pub fn into_errors(self) -> HashMap<&'static str, ValidationErrorsKind> {
+    pub fn into_errors(self) -> ErrorMap {
         self.0
     }
 
     /// Returns a map of only field-level validation errors found for the struct that was validated.
-    pub fn field_errors(&self) -> HashMap<&'static str, &Vec<ValidationError>> {
+    pub fn field_errors(&self) -> HashMap<&str, &Vec<ValidationError>> {
         self.0
             .iter()
             .filter_map(|(k, v)| {
                 if let ValidationErrorsKind::Field(errors) = v {
-                    Some((*k, errors))
+                    Some((k, errors))
                 } else {
                     None
                 }
@@ -134,13 +578,183 @@ impl ValidationErrors {
             .collect::<HashMap<_, _>>()
     }
 
-    pub fn add(&mut self, field: &'static str, error: ValidationError) {
-        if let ValidationErrorsKind::Field(ref mut vec) =
-            self.0.entry(field).or_insert_with(|| ValidationErrorsKind::Field(vec![]))
-        {
-            vec.push(error);
-        } else {
-            panic!("Attempt to add field validation to a non-Field ValidationErrorsKind instance");
+    /// Returns the errors of a nested vector of validating structs, keyed by the index of the
+    /// invalid entries, if the given field has any.
+    pub fn list_errors(&self, field: &str) -> Option<&BTreeMap<usize, Box<ValidationErrors>>> {
+        match self.0.get(field) {
+            Some(ValidationErrorsKind::List(errors)) => Some(errors),
+            _ => None,
+        }
+    }
+
+    /// Returns the errors of the entry at `index` of a nested vector of validating structs,
+    /// if that entry is invalid.
+    pub fn errors_at(&self, field: &str, index: usize) -> Option<&ValidationErrors> {
+        self.list_errors(field).and_then(|errors| errors.get(&index)).map(|errors| &**errors)
+    }
+
+    /// Returns the errors of every field, including the fields of the nested structs, keyed by
+    /// their dotted path like `addresses[2].street`. Map entries use the key in the brackets.
+    pub fn flatten(&self) -> BTreeMap<String, &[ValidationError]> {
+        let mut flat = BTreeMap::new();
+        self.visit_fields(&mut vec![], &mut |path, errors| {
+            flat.insert(FieldPath::new(path.to_vec()).to_string(), errors);
+        });
+        flat
+    }
+
+    /// Like [`ValidationErrors::flatten`] but keyed by JSON Pointers (RFC 6901) like
+    /// `/addresses/2/street`, to point at the invalid part of a JSON payload
+    pub fn flatten_pointers(&self) -> BTreeMap<String, &[ValidationError]> {
+        let mut flat = BTreeMap::new();
+        self.visit_fields(&mut vec![], &mut |path, errors| {
+            flat.insert(FieldPath::new(path.to_vec()).to_pointer(), errors);
+        });
+        flat
+    }
+
+    /// Returns every error with the path of its field, including the errors of nested structs.
+    /// The fields are in the order of [`ValidationErrors::iter_fields`] at each level and the
+    /// entries of vectors and maps are sorted by index and key.
+    pub fn iter(&self) -> impl Iterator<Item = (FieldPath<'_>, &ValidationError)> {
+        let mut leaves = vec![];
+        self.visit_fields(&mut vec![], &mut |path, errors| {
+            leaves.extend(errors.iter().map(|error| (FieldPath::new(path.to_vec()), error)));
+        });
+        leaves.into_iter()
+    }
+
+    /// Groups every error, nested ones included, by code: each code has the errors with that code
+    /// and the paths of their fields, in the order of [`ValidationErrors::iter`]
+    pub fn by_code(&self) -> BTreeMap<&str, Vec<(FieldPath<'_>, &ValidationError)>> {
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for (path, error) in self.iter() {
+            groups.entry(error.code.as_ref()).or_default().push((path, error));
+        }
+        groups
+    }
+
+    /// Returns the errors of the nested struct, vector entry or map entry at a path like
+    /// `profile.emails[0]`, if it is invalid
+    pub fn at(&self, path: &str) -> Option<&ValidationErrors> {
+        match self.lookup(path)? {
+            PathErrors::Errors(errors) => Some(errors),
+            PathErrors::Kind(_) => None,
+        }
+    }
+
+    /// Returns the errors of the field at a path like `profile.emails[0].address`, if it has any
+    pub fn field_errors_at(&self, path: &str) -> Option<&[ValidationError]> {
+        match self.lookup(path)? {
+            PathErrors::Kind(ValidationErrorsKind::Field(errors)) => Some(errors),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the field at that path has an error with the given code
+    #[must_use]
+    pub fn field_has_code(&self, path: &str, code: &str) -> bool {
+        self.field_errors_at(path).is_some_and(|errors| errors.iter().any(|err| err.code == code))
+    }
+
+    fn lookup(&self, path: &str) -> Option<PathErrors<'_>> {
+        let mut current = PathErrors::Errors(self);
+        for segment in parse_path(path)? {
+            current = match (current, segment) {
+                (PathErrors::Errors(errors), PathSegment::Field(field)) => {
+                    match errors.0.get(field)? {
+                        ValidationErrorsKind::Struct(errors) => PathErrors::Errors(errors),
+                        kind => PathErrors::Kind(kind),
+                    }
+                }
+                (PathErrors::Kind(ValidationErrorsKind::List(entries)), PathSegment::Key(key)) => {
+                    PathErrors::Errors(entries.get(&key.parse::<usize>().ok()?)?)
+                }
+                (PathErrors::Kind(ValidationErrorsKind::Map(entries)), PathSegment::Key(key)) => {
+                    PathErrors::Errors(entries.get(key)?)
+                }
+                _ => return None,
+            };
+        }
+        Some(current)
+    }
+
+    /// Calls `visit` with the path and the errors of every field, in order
+    fn visit_fields<'a>(
+        &'a self,
+        path: &mut Vec<PathSegment<'a>>,
+        visit: &mut dyn FnMut(&[PathSegment<'a>], &'a [ValidationError]),
+    ) {
+        for (field, kind) in self.iter_fields() {
+            path.push(PathSegment::Field(field));
+            match kind {
+                ValidationErrorsKind::Field(errors) => visit(path, errors),
+                ValidationErrorsKind::Struct(errors) => errors.visit_fields(path, visit),
+                ValidationErrorsKind::List(entries) => {
+                    for (index, errors) in entries {
+                        path.push(PathSegment::Index(*index));
+                        errors.visit_fields(path, visit);
+                        path.pop();
+                    }
+                }
+                ValidationErrorsKind::Map(entries) => {
+                    for (key, errors) in entries {
+                        path.push(PathSegment::Key(key));
+                        errors.visit_fields(path, visit);
+                        path.pop();
+                    }
+                }
+            }
+            path.pop();
+        }
+    }
+
+    /// Adds the errors of a nested struct under `field`, they are merged with the errors of that
+    /// struct already added if any. Nothing is added if `errors` is empty.
+    ///
+    /// # Panics
+    ///
+    /// If `field` already has errors that are not the errors of a nested struct
+    pub fn add_nested(&mut self, field: impl Into<Cow<'static, str>>, errors: ValidationErrors) {
+        if errors.is_empty() {
+            return;
+        }
+        let mut nested = ValidationErrors::new();
+        nested.insert_kind(field, ValidationErrorsKind::Struct(Box::new(errors)));
+        self.extend(nested);
+    }
+
+    /// Adds all the errors of `other`. The errors of a field present in both are appended, and the
+    /// errors of nested structs, vectors and maps are merged recursively.
+    ///
+    /// # Panics
+    ///
+    /// If a field has different kinds of errors in both, for example field errors in one and the
+    /// errors of a nested struct in the other
+    pub fn extend(&mut self, other: ValidationErrors) {
+        for (field, kind) in other.0 {
+            match self.0.get_mut(&field) {
+                Some(existing) => existing.extend(kind),
+                None => {
+                    self.0.insert(field, kind);
+                }
+            }
+        }
+    }
+
+    pub fn add(&mut self, field: impl Into<Cow<'static, str>>, error: ValidationError) {
+        if !crate::config::count_error() {
+            return;
+        }
+        let field = field.into();
+        match self.0.get_mut(&field) {
+            Some(ValidationErrorsKind::Field(errors)) => errors.push(error),
+            Some(_) => panic!(
+                "Attempt to add field validation to a non-Field ValidationErrorsKind instance"
+            ),
+            None => {
+                self.0.insert(field, ValidationErrorsKind::Field(vec![error]));
+            }
         }
     }
 
@@ -149,29 +763,122 @@ impl ValidationErrors {
         self.0.is_empty()
     }
 
-    fn add_nested(&mut self, field: &'static str, errors: ValidationErrorsKind) {
-        if let Vacant(entry) = self.0.entry(field) {
-            entry.insert(errors);
-        } else {
-            panic!("Attempt to replace non-empty ValidationErrors entry");
+    /// Whether there is an error of the `Error` severity, including in the nested structs. Only
+    /// those make a value invalid.
+    #[must_use]
+    pub fn is_blocking(&self) -> bool {
+        self.0.values().any(ValidationErrorsKind::is_blocking)
+    }
+
+    /// Keeps only the errors for which `f` returns `true`, including the errors of the nested
+    /// structs. The fields and nested entries left without errors are removed.
+    pub fn retain(&mut self, f: &mut dyn FnMut(&ValidationError) -> bool) {
+        for kind in self.0.values_mut() {
+            kind.retain(f);
         }
+        self.0.retain(|_, kind| !kind.is_empty());
     }
 
-    #[must_use]
-    fn contains_key(&self, field: &'static str) -> bool {
-        self.0.contains_key(field)
+    /// Adds the errors of the async validators of a value to the ones of its `Validate`
+    /// implementation, see `ValidationErrorsKind::extend_async`
+    #[cfg(feature = "async")]
+    pub(crate) fn extend_async(&mut self, other: ValidationErrors) {
+        for (field, kind) in other.0 {
+            match self.0.get_mut(&field) {
+                Some(existing) => existing.extend_async(kind),
+                None => {
+                    self.0.insert(field, kind);
+                }
+            }
+        }
+    }
+
+    /// Sets the severity of all the errors, including the errors of the nested structs
+    pub fn set_severity(&mut self, severity: Severity) {
+        for kind in self.0.values_mut() {
+            kind.set_severity(severity);
+        }
+    }
+
+    /// Removes the errors of a field with the same code and params as an error before them,
+    /// including in the nested structs. They appear when a field is validated through several
+    /// paths or the errors of several sources are merged.
+    pub fn dedup(&mut self) {
+        for kind in self.0.values_mut() {
+            kind.dedup();
+        }
     }
 
-    fn remove(&mut self, field: &'static str) -> Option<ValidationErrorsKind> {
+    /// Returns the fields with errors and their errors in order: the order set with
+    /// [`ValidationErrors::set_field_order`] or the order they got errors otherwise
+    pub fn iter_fields(&self) -> impl Iterator<Item = (&str, &ValidationErrorsKind)> {
+        self.0.iter()
+    }
+
+    /// Orders the fields with errors like in `fields`, the fields that aren't in it come after
+    /// them in the order they got errors
+    pub fn set_field_order(&mut self, fields: &[&str]) {
+        self.0.reorder(fields);
+    }
+
+    pub(crate) fn insert_kind(
+        &mut self,
+        field: impl Into<Cow<'static, str>>,
+        errors: ValidationErrorsKind,
+    ) {
+        if self.0.insert(field, errors).is_some() {
+            panic!("Attempt to replace non-empty ValidationErrors entry");
+        }
+    }
+
+    fn remove(&mut self, field: &str) -> Option<ValidationErrorsKind> {
         self.0.remove(field)
     }
 }
 
+/// What a path points at in the errors tree
+enum PathErrors<'a> {
+    Errors(&'a ValidationErrors),
+    Kind(&'a ValidationErrorsKind),
+}
+
+/// The order of the fields doesn't matter for equality
+impl PartialEq for ValidationErrors {
+    fn eq(&self, other: &ValidationErrors) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Serialize for ValidationErrors {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (field, kind) in self.iter_fields() {
+            map.serialize_entry(field, kind)?;
+        }
+        map.end()
+    }
+}
+
+/// The source of the errors is their first error in the order of the fields: the first error of
+/// a field, or the errors of a nested struct or of the first invalid element of a nested list or
+/// map, whose source is their first error and so on
 impl std::error::Error for ValidationErrors {
     fn description(&self) -> &str {
         "Validation failed"
     }
-    fn cause(&self) -> Option<&dyn std::error::Error> {
-        None
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        let (_, kind) = self.iter_fields().next()?;
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                errors.first().map(|error| error as &(dyn std::error::Error + 'static))
+            }
+            ValidationErrorsKind::Struct(errors) => Some(&**errors),
+            ValidationErrorsKind::List(errors) => {
+                errors.values().next().map(|errors| &**errors as &(dyn std::error::Error + 'static))
+            }
+            ValidationErrorsKind::Map(errors) => {
+                errors.values().next().map(|errors| &**errors as &(dyn std::error::Error + 'static))
+            }
+        }
     }
 }
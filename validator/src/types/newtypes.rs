@@ -0,0 +1,366 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use serde::de::{Deserialize, Deserializer, Error};
+use serde_derive::Serialize;
+
+use crate::traits::Validate;
+use crate::types::{ValidationError, ValidationErrors};
+use crate::validation::percentage::validate_percentage;
+
+/// The error of a value whose length isn't in the bounds, like the one of the `length` validator
+fn length_error(length: usize, min: usize, max: Option<usize>) -> ValidationError {
+    let mut error = ValidationError::new("length");
+    error.add_param_value(Cow::from("min"), min);
+    if let Some(max) = max {
+        error.add_param_value(Cow::from("max"), max);
+    }
+    error.add_param_value(Cow::from("length"), length);
+    error
+}
+
+/// Implements the traits shared by the wrappers of a value with an invariant: it can only be
+/// created through `TryFrom` or deserialization, which check the invariant, it derefs to the
+/// value but doesn't give a mutable access to it, and its `Validate` impl always passes
+macro_rules! impl_newtype {
+    ($name:ident $(<$(const $param:ident),*>)?, $inner:ty) => {
+        impl<$($(const $param: usize),*)?> $name<$($($param),*)?> {
+            pub fn into_inner(self) -> $inner {
+                self.0
+            }
+        }
+
+        impl<$($(const $param: usize),*)?> Deref for $name<$($($param),*)?> {
+            type Target = $inner;
+
+            fn deref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl<$($(const $param: usize),*)?> AsRef<$inner> for $name<$($($param),*)?> {
+            fn as_ref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl<$($(const $param: usize),*)?> From<$name<$($($param),*)?>> for $inner {
+            fn from(value: $name<$($($param),*)?>) -> $inner {
+                value.0
+            }
+        }
+
+        /// Deserializes the value then checks it, the error is displayed in the message of the
+        /// deserialization error
+        impl<'de, $($(const $param: usize),*)?> Deserialize<'de> for $name<$($($param),*)?> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <$inner>::deserialize(deserializer)?;
+                Self::try_from(value).map_err(D::Error::custom)
+            }
+        }
+
+        /// The invariant holds since the value was created
+        impl<$($(const $param: usize),*)?> Validate for $name<$($($param),*)?> {
+            fn validate(&self) -> Result<(), ValidationErrors> {
+                Ok(())
+            }
+        }
+    };
+}
+
+/// A string that isn't empty, the error of an empty one being a `length` error with a `min` of 1
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct NonEmptyString(String);
+
+impl_newtype!(NonEmptyString, String);
+
+impl TryFrom<String> for NonEmptyString {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<NonEmptyString, ValidationError> {
+        match value.is_empty() {
+            true => Err(length_error(0, 1, None)),
+            false => Ok(NonEmptyString(value)),
+        }
+    }
+}
+
+impl TryFrom<&str> for NonEmptyString {
+    type Error = ValidationError;
+
+    fn try_from(value: &str) -> Result<NonEmptyString, ValidationError> {
+        NonEmptyString::try_from(value.to_string())
+    }
+}
+
+impl FromStr for NonEmptyString {
+    type Err = ValidationError;
+
+    fn from_str(value: &str) -> Result<NonEmptyString, ValidationError> {
+        NonEmptyString::try_from(value)
+    }
+}
+
+impl fmt::Display for NonEmptyString {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(&self.0)
+    }
+}
+
+/// A string of `MIN` to `MAX` characters, counted like the `length` validator does
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct BoundedString<const MIN: usize, const MAX: usize>(String);
+
+impl_newtype!(BoundedString<const MIN, const MAX>, String);
+
+impl<const MIN: usize, const MAX: usize> TryFrom<String> for BoundedString<MIN, MAX> {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<BoundedString<MIN, MAX>, ValidationError> {
+        let length = value.chars().count();
+        match (MIN..=MAX).contains(&length) {
+            true => Ok(BoundedString(value)),
+            false => Err(length_error(length, MIN, Some(MAX))),
+        }
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> TryFrom<&str> for BoundedString<MIN, MAX> {
+    type Error = ValidationError;
+
+    fn try_from(value: &str) -> Result<BoundedString<MIN, MAX>, ValidationError> {
+        BoundedString::try_from(value.to_string())
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> FromStr for BoundedString<MIN, MAX> {
+    type Err = ValidationError;
+
+    fn from_str(value: &str) -> Result<BoundedString<MIN, MAX>, ValidationError> {
+        BoundedString::try_from(value)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> fmt::Display for BoundedString<MIN, MAX> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(&self.0)
+    }
+}
+
+/// A list of `MIN` to `MAX` elements. Unlike the other wrappers, its validation validates the
+/// elements when they implement `Validate`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct BoundedVec<T, const MIN: usize, const MAX: usize>(Vec<T>);
+
+impl<T, const MIN: usize, const MAX: usize> BoundedVec<T, MIN, MAX> {
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> Deref for BoundedVec<T, MIN, MAX> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> AsRef<[T]> for BoundedVec<T, MIN, MAX> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> From<BoundedVec<T, MIN, MAX>> for Vec<T> {
+    fn from(value: BoundedVec<T, MIN, MAX>) -> Vec<T> {
+        value.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> TryFrom<Vec<T>> for BoundedVec<T, MIN, MAX> {
+    type Error = ValidationError;
+
+    fn try_from(value: Vec<T>) -> Result<BoundedVec<T, MIN, MAX>, ValidationError> {
+        match (MIN..=MAX).contains(&value.len()) {
+            true => Ok(BoundedVec(value)),
+            false => Err(length_error(value.len(), MIN, Some(MAX))),
+        }
+    }
+}
+
+impl<'a, T, const MIN: usize, const MAX: usize> IntoIterator for &'a BoundedVec<T, MIN, MAX> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Deserializes the list then checks its length, the error is displayed in the message of the
+/// deserialization error
+impl<'de, T, const MIN: usize, const MAX: usize> Deserialize<'de> for BoundedVec<T, MIN, MAX>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Vec::<T>::deserialize(deserializer)?;
+        BoundedVec::try_from(value).map_err(D::Error::custom)
+    }
+}
+
+/// The errors of the elements are in a list under `__all__`, keyed by their index, like for a
+/// `Vec`
+impl<T: Validate, const MIN: usize, const MAX: usize> Validate for BoundedVec<T, MIN, MAX> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.0.validate()
+    }
+
+    fn validate_fail_fast(&self) -> Result<(), ValidationErrors> {
+        self.0.validate_fail_fast()
+    }
+}
+
+/// A TCP or UDP port other than 0, the error of 0 being a `range` error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct Port(u16);
+
+impl_newtype!(Port, u16);
+
+impl Port {
+    pub fn get(self) -> u16 {
+        self.0
+    }
+}
+
+impl TryFrom<u16> for Port {
+    type Error = ValidationError;
+
+    fn try_from(value: u16) -> Result<Port, ValidationError> {
+        if value == 0 {
+            let mut error = ValidationError::new("range");
+            error.add_param_value(Cow::from("min"), 1);
+            error.add_param_value(Cow::from("max"), u16::MAX);
+            error.add_param_value(Cow::from("value"), value);
+            return Err(error);
+        }
+        Ok(Port(value))
+    }
+}
+
+impl FromStr for Port {
+    type Err = ValidationError;
+
+    /// The error of a string that isn't a number is a `range` error as well, with the parse
+    /// error as its source
+    fn from_str(value: &str) -> Result<Port, ValidationError> {
+        match value.parse::<u16>() {
+            Ok(port) => Port::try_from(port),
+            Err(e) => {
+                let mut error = ValidationError::new("range").with_source(e);
+                error.add_param_value(Cow::from("min"), 1);
+                error.add_param_value(Cow::from("max"), u16::MAX);
+                Err(error)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Port {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}", self.0)
+    }
+}
+
+/// A number between 0 and 100 included, the error of another one, NaN included, being a
+/// `percentage` error
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
+pub struct Percentage(f64);
+
+impl_newtype!(Percentage, f64);
+
+impl Percentage {
+    pub fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl TryFrom<f64> for Percentage {
+    type Error = ValidationError;
+
+    fn try_from(value: f64) -> Result<Percentage, ValidationError> {
+        if value.is_nan() || !validate_percentage(value, false) {
+            let mut error = ValidationError::new("percentage");
+            error.add_param_value(Cow::from("value"), value);
+            return Err(error);
+        }
+        Ok(Percentage(value))
+    }
+}
+
+impl fmt::Display for Percentage {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}%", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::*;
+
+    #[test]
+    fn test_strings_check_their_length() {
+        assert_eq!(&*NonEmptyString::try_from("a").unwrap(), "a");
+        let error = NonEmptyString::try_from("").unwrap_err();
+        assert_eq!(error.code, "length");
+        assert_eq!(error.param_i64("min"), Some(1));
+
+        assert!(BoundedString::<2, 3>::try_from("日本").is_ok());
+        assert!("abc".parse::<BoundedString<2, 3>>().is_ok());
+        let error = BoundedString::<2, 3>::try_from("abcd").unwrap_err();
+        assert_eq!((error.param_i64("max"), error.param_i64("length")), (Some(3), Some(4)));
+    }
+
+    #[test]
+    fn test_vec_checks_its_length() {
+        let list = BoundedVec::<u8, 1, 2>::try_from(vec![1, 2]).unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.into_inner(), vec![1, 2]);
+        assert!(BoundedVec::<u8, 1, 2>::try_from(vec![]).is_err());
+        assert!(BoundedVec::<u8, 1, 2>::try_from(vec![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_numbers_check_their_range() {
+        assert_eq!(Port::try_from(8080).unwrap().get(), 8080);
+        assert_eq!(Port::try_from(0).unwrap_err().code, "range");
+        assert_eq!("443".parse::<Port>().unwrap().get(), 443);
+        assert!("http".parse::<Port>().is_err());
+
+        assert_eq!(Percentage::try_from(12.5).unwrap().to_string(), "12.5%");
+        assert_eq!(Percentage::try_from(100.1).unwrap_err().code, "percentage");
+        assert!(Percentage::try_from(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_deserialization_checks_the_value() {
+        let name: NonEmptyString = serde_json::from_str(r#""bob""#).unwrap();
+        assert_eq!(serde_json::to_string(&name).unwrap(), r#""bob""#);
+        assert!(serde_json::from_str::<NonEmptyString>(r#""""#).is_err());
+        assert!(serde_json::from_str::<BoundedVec<Port, 1, 3>>("[80, 443]").is_ok());
+        assert!(serde_json::from_str::<BoundedVec<Port, 1, 3>>("[80, 0]").is_err());
+        assert!(serde_json::from_str::<BoundedVec<Port, 1, 3>>("[]").is_err());
+        assert!(serde_json::from_str::<Percentage>("101").is_err());
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt::Write;
+use std::io;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::json_schema::to_json_schema;
+use crate::traits::ValidationRules;
+
+/// Generates a TypeScript module with a [zod](https://zod.dev) schema for each struct added, from
+/// their `ValidationRules`, so a web frontend checks the same rules as the server. It works from
+/// the JSON Schema of [`to_json_schema`], the same validators are left out.
+///
+/// The fields without validators aren't in the schemas, which let them through untouched.
+///
+/// ```ignore
+/// // build.rs
+/// TypeScriptGenerator::new()
+///     .add::<Signup>("Signup")
+///     .write_to("frontend/src/validation.ts")
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TypeScriptGenerator {
+    schemas: Vec<(String, Value)>,
+}
+
+impl TypeScriptGenerator {
+    pub fn new() -> TypeScriptGenerator {
+        TypeScriptGenerator::default()
+    }
+
+    /// Adds the schema of `T`, exported as `<name>Schema`
+    pub fn add<T: ValidationRules>(&mut self, name: &str) -> &mut TypeScriptGenerator {
+        self.schemas.push((name.to_string(), to_json_schema::<T>()));
+        self
+    }
+
+    /// The source of the module
+    pub fn generate(&self) -> String {
+        let mut source = String::from(
+            "// Generated from the validation rules of the server, do not edit\nimport { z } from \"zod\";\n",
+        );
+        for (name, schema) in &self.schemas {
+            let _ = write!(source, "\nexport const {}Schema = {};\n", name, zod(schema));
+        }
+        source
+    }
+
+    /// Writes the module to that file, unless it already has that content so a build script
+    /// doesn't trigger a rebuild of the frontend every time
+    pub fn write_to(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let source = self.generate();
+        if std::fs::read_to_string(path).is_ok_and(|existing| existing == source) {
+            return Ok(());
+        }
+        std::fs::write(path, source)
+    }
+}
+
+/// Returns the zod schema of `T` as a TypeScript expression, see [`TypeScriptGenerator`]
+pub fn to_zod_schema<T: ValidationRules>() -> String {
+    zod(&to_json_schema::<T>())
+}
+
+/// The zod expression of a JSON Schema made by `to_json_schema`
+fn zod(schema: &Value) -> String {
+    let schema = match schema {
+        Value::Object(schema) => schema,
+        _ => return "z.unknown()".to_string(),
+    };
+    let number = |keyword: &str| schema.get(keyword).map(Value::to_string);
+    let mut zod = match schema.get("type").and_then(Value::as_str) {
+        Some("string") => "z.string()".to_string(),
+        Some("integer") => "z.number().int()".to_string(),
+        Some("number") => "z.number()".to_string(),
+        Some("boolean") => "z.boolean()".to_string(),
+        Some("array") => {
+            format!("z.array({})", schema.get("items").map_or("z.unknown()".to_string(), zod))
+        }
+        Some("object") if schema.contains_key("properties") => zod_object(schema),
+        Some("object") => format!(
+            "z.record({}, {})",
+            schema.get("propertyNames").map_or("z.string()".to_string(), zod),
+            schema.get("additionalProperties").map_or("z.unknown()".to_string(), zod)
+        ),
+        _ => "z.unknown()".to_string(),
+    };
+
+    let methods = [
+        ("minLength", "min"),
+        ("maxLength", "max"),
+        ("minItems", "min"),
+        ("maxItems", "max"),
+        ("minimum", "gte"),
+        ("maximum", "lte"),
+        ("exclusiveMinimum", "gt"),
+        ("exclusiveMaximum", "lt"),
+        ("multipleOf", "multipleOf"),
+    ];
+    for (keyword, method) in methods {
+        if let Some(value) = number(keyword) {
+            let _ = write!(zod, ".{}({})", method, value);
+        }
+    }
+    match schema.get("format").and_then(Value::as_str) {
+        Some("email") => zod.push_str(".email()"),
+        Some("uri") => zod.push_str(".url()"),
+        _ => (),
+    }
+    let patterns = schema.get("pattern").into_iter().chain(
+        schema
+            .get("allOf")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|schema| schema.get("pattern")),
+    );
+    for pattern in patterns {
+        let _ = write!(zod, ".regex(new RegExp({}))", pattern);
+    }
+    if let Some(pattern) = schema.get("not").and_then(|not| not.get("pattern")) {
+        let _ = write!(zod, ".refine((value) => !new RegExp({}).test(value))", pattern);
+    }
+    zod
+}
+
+fn zod_object(schema: &Map<String, Value>) -> String {
+    let required = schema.get("required").and_then(Value::as_array);
+    let is_required = |name: &str| required.is_some_and(|r| r.iter().any(|n| n == name));
+    let mut fields = String::new();
+    if let Some(Value::Object(properties)) = schema.get("properties") {
+        for (name, property) in properties {
+            let optional = if is_required(name) { "" } else { ".optional()" };
+            let _ = write!(
+                fields,
+                "\n  {}: {}{},",
+                Value::from(name.as_str()),
+                zod(property),
+                optional
+            );
+        }
+    }
+    if fields.is_empty() {
+        return "z.object({}).passthrough()".to_string();
+    }
+    format!("z.object({{{}\n}}).passthrough()", fields)
+}
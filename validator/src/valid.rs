@@ -0,0 +1,71 @@
+use std::ops::Deref;
+
+use serde::de::{Deserialize, Deserializer, Error};
+use serde_derive::Serialize;
+
+use crate::traits::Validate;
+use crate::types::ValidationErrors;
+
+/// A value that passed its validation. It can only be created with [`Valid::new`] or by
+/// deserializing it, which validates the value and fails with the errors as a deserialization
+/// error, so an invalid value is never handed out.
+///
+/// It derefs to the value but doesn't give a mutable access to it, which could make it invalid.
+///
+/// ```ignore
+/// use serde::Deserialize;
+/// use validator::{Valid, Validate};
+///
+/// #[derive(Debug, Deserialize, Validate)]
+/// struct Config {
+///     #[validate(range(min = 1))]
+///     workers: u32,
+/// }
+///
+/// let config: Valid<Config> = serde_json::from_str(r#"{"workers": 4}"#).unwrap();
+/// assert_eq!(config.workers, 4);
+/// assert!(serde_json::from_str::<Valid<Config>>(r#"{"workers": 0}"#).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct Valid<T>(T);
+
+impl<T: Validate> Valid<T> {
+    /// Validates the value, returning its errors if it is invalid
+    pub fn new(value: T) -> Result<Valid<T>, ValidationErrors> {
+        value.validate()?;
+        Ok(Valid(value))
+    }
+}
+
+impl<T> Valid<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Valid<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Valid<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Deserializes the value then validates it, the errors are displayed in the message of the
+/// deserialization error
+impl<'de, T> Deserialize<'de> for Valid<T>
+where
+    T: Deserialize<'de> + Validate,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Valid<T>, D::Error> {
+        let value = T::deserialize(deserializer)?;
+        Valid::new(value).map_err(D::Error::custom)
+    }
+}
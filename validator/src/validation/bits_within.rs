@@ -0,0 +1,35 @@
+/// Validates that the given `value` doesn't set any bit outside of `mask`.
+///
+/// Signed values should be cast to `u64` first: negative numbers then set the high bits
+/// and are rejected unless the mask allows them.
+#[must_use]
+pub const fn validate_bits_within(value: u64, mask: u64) -> bool {
+    value & !mask == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_bits_within;
+
+    #[test]
+    fn test_validate_bits_within() {
+        let tests = vec![
+            (0b0000, 0b1111, true),
+            (0b0101, 0b1111, true),
+            (0b1111, 0b1111, true),
+            (0b1_0000, 0b1111, false),
+            (0b0100, 0b1010, false),
+            (0b1010, 0b1010, true),
+            (u64::MAX, 0b1111, false),
+        ];
+
+        for (value, mask, expected) in tests {
+            assert_eq!(validate_bits_within(value, mask), expected);
+        }
+    }
+
+    #[test]
+    fn test_validate_bits_within_negative_number() {
+        assert!(!validate_bits_within(-1i32 as u64, 0xFF));
+    }
+}
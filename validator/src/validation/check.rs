@@ -0,0 +1,406 @@
+//! The validators returning the error the derive would have added for the field instead of a
+//! `bool`, with the same code and params, so a hand written `Validate` implementation reports
+//! the same errors as a derived one:
+//!
+//! ```
+//! use validator::{check_email, check_length, Validate, ValidationErrors};
+//!
+//! struct SignupData {
+//!     mail: String,
+//!     name: String,
+//! }
+//!
+//! impl Validate for SignupData {
+//!     fn validate(&self) -> Result<(), ValidationErrors> {
+//!         let mut errors = ValidationErrors::new();
+//!         if let Err(err) = check_email(&self.mail) {
+//!             errors.add("mail", err);
+//!         }
+//!         if let Err(err) = check_length(&self.name, Some(1), None, None) {
+//!             errors.add("name", err);
+//!         }
+//!         match errors.is_empty() {
+//!             true => Ok(()),
+//!             false => Err(errors),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The `value` param isn't added by the derive for the `redact` fields, it can be removed from
+//! the error with `err.params.remove("value")`.
+
+use std::borrow::Cow;
+
+use serde::Serialize;
+
+use crate::types::ValidationError;
+use crate::validation::compare::{
+    validate_greater_than, validate_greater_than_or_equal, validate_less_than,
+    validate_less_than_or_equal,
+};
+use crate::validation::contains::Contains;
+use crate::validation::email::ValidateEmail;
+use crate::validation::ip::{validate_ip, validate_ip_v4, validate_ip_v6};
+use crate::validation::length::ValidateLength;
+use crate::validation::required::ValidateRequired;
+use crate::validation::urls::ValidateUrl;
+use crate::validation::words::WordList;
+use crate::{
+    validate_bits_within, validate_luhn, validate_percentage, validate_range, validate_ratio,
+};
+
+/// A primitive number, compared as a `f64` like the derive does for the `range`, `percentage`
+/// and `ratio` validators
+pub trait Numeric: Serialize + Copy {
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_numeric {
+    ($($ty:ty)*) => {
+        $(impl Numeric for $ty {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+impl_numeric!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+/// An error with that code and the value as its `value` param
+fn error_with_value<T: Serialize>(code: &'static str, value: &T) -> ValidationError {
+    let mut err = ValidationError::new(code);
+    err.add_param(Cow::from("value"), value);
+    err
+}
+
+fn check_with_value<T: Serialize>(
+    valid: bool,
+    code: &'static str,
+    value: &T,
+) -> Result<(), ValidationError> {
+    match valid {
+        true => Ok(()),
+        false => Err(error_with_value(code, value)),
+    }
+}
+
+/// See [`validate_email`](crate::validate_email), fails with an `email` error
+pub fn check_email<T: ValidateEmail + Serialize>(value: T) -> Result<(), ValidationError> {
+    check_with_value(value.validate_email(), "email", &value)
+}
+
+/// See [`validate_url`](crate::validate_url), fails with a `url` error
+pub fn check_url<T: ValidateUrl + Serialize>(value: T) -> Result<(), ValidationError> {
+    check_with_value(value.validate_url(), "url", &value)
+}
+
+/// See [`validate_length`](crate::validate_length), fails with a `length` error having the
+/// bounds that are given as params
+pub fn check_length<T: ValidateLength + Serialize>(
+    value: T,
+    min: Option<u64>,
+    max: Option<u64>,
+    equal: Option<u64>,
+) -> Result<(), ValidationError> {
+    if value.validate_length(min, max, equal) {
+        return Ok(());
+    }
+    let mut err = ValidationError::new("length");
+    for (name, bound) in [("min", min), ("max", max), ("equal", equal)] {
+        if let Some(bound) = bound {
+            err.add_param(Cow::from(name), &bound);
+        }
+    }
+    err.add_param(Cow::from("value"), &value);
+    Err(err)
+}
+
+/// See [`validate_range`](crate::validate_range), fails with a `range` error having the bounds
+/// that are given as params. The bounds are `f64`s as in the derive, which compares the value
+/// as a `f64`.
+pub fn check_range<T: Numeric>(
+    value: T,
+    min: Option<f64>,
+    max: Option<f64>,
+    exclusive_min: Option<f64>,
+    exclusive_max: Option<f64>,
+) -> Result<(), ValidationError> {
+    if validate_range(value.to_f64(), min, max, exclusive_min, exclusive_max) {
+        return Ok(());
+    }
+    let mut err = ValidationError::new("range");
+    let bounds = [
+        ("min", min),
+        ("max", max),
+        ("exclusive_min", exclusive_min),
+        ("exclusive_max", exclusive_max),
+    ];
+    for (name, bound) in bounds {
+        if let Some(bound) = bound {
+            err.add_param(Cow::from(name), &bound);
+        }
+    }
+    err.add_param(Cow::from("value"), &value);
+    Err(err)
+}
+
+fn check_share<T: Numeric>(
+    value: T,
+    exclusive: bool,
+    code: &'static str,
+    max: f64,
+    valid: bool,
+) -> Result<(), ValidationError> {
+    if valid {
+        return Ok(());
+    }
+    let mut err = ValidationError::new(code);
+    err.add_param(Cow::from("min"), &0.0);
+    err.add_param(Cow::from("max"), &max);
+    err.add_param(Cow::from("exclusive"), &exclusive);
+    err.add_param(Cow::from("value"), &value);
+    Err(err)
+}
+
+/// See [`validate_percentage`](crate::validate_percentage), fails with a `percentage` error
+pub fn check_percentage<T: Numeric>(value: T, exclusive: bool) -> Result<(), ValidationError> {
+    let valid = validate_percentage(value.to_f64(), exclusive);
+    check_share(value, exclusive, "percentage", 100.0, valid)
+}
+
+/// See [`validate_ratio`](crate::validate_ratio), fails with a `ratio` error
+pub fn check_ratio<T: Numeric>(value: T, exclusive: bool) -> Result<(), ValidationError> {
+    let valid = validate_ratio(value.to_f64(), exclusive);
+    check_share(value, exclusive, "ratio", 1.0, valid)
+}
+
+/// See [`validate_bits_within`](crate::validate_bits_within), fails with a `bits_within` error
+pub fn check_bits_within<T: Into<u64> + Serialize + Copy>(
+    value: T,
+    mask: u64,
+) -> Result<(), ValidationError> {
+    if validate_bits_within(value.into(), mask) {
+        return Ok(());
+    }
+    let mut err = ValidationError::new("bits_within");
+    err.add_param(Cow::from("mask"), &mask);
+    err.add_param(Cow::from("value"), &value);
+    Err(err)
+}
+
+/// See [`validate_required`](crate::validate_required), fails with a `required` error
+pub fn check_required<T: ValidateRequired + Serialize>(value: &T) -> Result<(), ValidationError> {
+    check_with_value(value.validate_required(), "required", value)
+}
+
+/// See [`validate_contains`](crate::validate_contains), fails with a `contains` error
+pub fn check_contains<T: Contains + Serialize>(
+    value: T,
+    needle: &str,
+) -> Result<(), ValidationError> {
+    if value.has_element(needle) {
+        return Ok(());
+    }
+    let mut err = error_with_value("contains", &value);
+    err.add_param_value(Cow::from("needle"), needle.to_string());
+    Err(err)
+}
+
+/// See [`validate_does_not_contain`](crate::validate_does_not_contain), fails with a
+/// `does_not_contain` error
+pub fn check_does_not_contain<T: Contains + Serialize>(
+    value: T,
+    needle: &str,
+) -> Result<(), ValidationError> {
+    if !value.has_element(needle) {
+        return Ok(());
+    }
+    let mut err = error_with_value("does_not_contain", &value);
+    err.add_param_value(Cow::from("needle"), needle.to_string());
+    Err(err)
+}
+
+/// See [`validate_contains_word`](crate::validate_contains_word), fails with a `contains` error
+pub fn check_contains_word<T: AsRef<str> + Serialize>(
+    value: T,
+    words: &WordList,
+) -> Result<(), ValidationError> {
+    check_with_value(words.is_match(value.as_ref()), "contains", &value)
+}
+
+/// See [`validate_does_not_contain_word`](crate::validate_does_not_contain_word), fails with a
+/// `does_not_contain` error having the word found as its `word` param
+pub fn check_does_not_contain_word<T: AsRef<str> + Serialize>(
+    value: T,
+    words: &WordList,
+) -> Result<(), ValidationError> {
+    match words.find(value.as_ref()) {
+        Some(word) => {
+            let mut err = error_with_value("does_not_contain", &value);
+            err.add_param(Cow::from("word"), &word);
+            Err(err)
+        }
+        None => Ok(()),
+    }
+}
+
+/// See [`validate_must_match`](crate::validate_must_match), fails with a `must_match` error
+/// having the other value as its `other` param
+pub fn check_must_match<T: Eq + Serialize>(value: T, other: T) -> Result<(), ValidationError> {
+    check_comparison(value == other, "must_match", value, other)
+}
+
+fn check_comparison<T: Serialize>(
+    valid: bool,
+    code: &'static str,
+    value: T,
+    other: T,
+) -> Result<(), ValidationError> {
+    if valid {
+        return Ok(());
+    }
+    let mut err = error_with_value(code, &value);
+    err.add_param(Cow::from("other"), &other);
+    Err(err)
+}
+
+/// See [`validate_greater_than`](crate::validate_greater_than), fails with a `greater_than` error
+/// having the other value as its `other` param
+pub fn check_greater_than<T: PartialOrd + Serialize>(
+    value: T,
+    other: T,
+) -> Result<(), ValidationError> {
+    check_comparison(validate_greater_than(&value, &other), "greater_than", value, other)
+}
+
+/// See [`validate_greater_than_or_equal`](crate::validate_greater_than_or_equal), fails with a
+/// `greater_than_or_equal` error having the other value as its `other` param
+pub fn check_greater_than_or_equal<T: PartialOrd + Serialize>(
+    value: T,
+    other: T,
+) -> Result<(), ValidationError> {
+    let valid = validate_greater_than_or_equal(&value, &other);
+    check_comparison(valid, "greater_than_or_equal", value, other)
+}
+
+/// See [`validate_less_than`](crate::validate_less_than), fails with a `less_than` error having
+/// the other value as its `other` param
+pub fn check_less_than<T: PartialOrd + Serialize>(
+    value: T,
+    other: T,
+) -> Result<(), ValidationError> {
+    check_comparison(validate_less_than(&value, &other), "less_than", value, other)
+}
+
+/// See [`validate_less_than_or_equal`](crate::validate_less_than_or_equal), fails with a
+/// `less_than_or_equal` error having the other value as its `other` param
+pub fn check_less_than_or_equal<T: PartialOrd + Serialize>(
+    value: T,
+    other: T,
+) -> Result<(), ValidationError> {
+    let valid = validate_less_than_or_equal(&value, &other);
+    check_comparison(valid, "less_than_or_equal", value, other)
+}
+
+/// See [`validate_ip`](crate::validate_ip), fails with an `ip` error. The derive has no such
+/// validator, nor one for the IP versions and the Luhn checksum, their codes are the names of
+/// the functions.
+pub fn check_ip<'a, T: Into<Cow<'a, str>>>(value: T) -> Result<(), ValidationError> {
+    let value = value.into();
+    check_with_value(validate_ip(value.as_ref()), "ip", &value)
+}
+
+/// See [`validate_ip_v4`](crate::validate_ip_v4), fails with an `ip_v4` error
+pub fn check_ip_v4<'a, T: Into<Cow<'a, str>>>(value: T) -> Result<(), ValidationError> {
+    let value = value.into();
+    check_with_value(validate_ip_v4(value.as_ref()), "ip_v4", &value)
+}
+
+/// See [`validate_ip_v6`](crate::validate_ip_v6), fails with an `ip_v6` error
+pub fn check_ip_v6<'a, T: Into<Cow<'a, str>>>(value: T) -> Result<(), ValidationError> {
+    let value = value.into();
+    check_with_value(validate_ip_v6(value.as_ref()), "ip_v6", &value)
+}
+
+/// See [`validate_luhn`](crate::validate_luhn), fails with a `luhn` error
+pub fn check_luhn<'a, T: Into<Cow<'a, str>>>(value: T) -> Result<(), ValidationError> {
+    let value = value.into();
+    check_with_value(validate_luhn(value.as_ref()), "luhn", &value)
+}
+
+/// See [`validate_credit_card`](crate::validate_credit_card), fails with a `credit_card` error
+#[cfg(feature = "card")]
+pub fn check_credit_card<'a, T: Into<Cow<'a, str>>>(value: T) -> Result<(), ValidationError> {
+    let value = value.into();
+    check_with_value(crate::validate_credit_card(value.as_ref()), "credit_card", &value)
+}
+
+/// See [`validate_phone`](crate::validate_phone), fails with a `phone` error
+#[cfg(feature = "phone")]
+pub fn check_phone<'a, T: Into<Cow<'a, str>>>(value: T) -> Result<(), ValidationError> {
+    let value = value.into();
+    check_with_value(crate::validate_phone(value.as_ref()), "phone", &value)
+}
+
+/// See [`validate_non_control_character`](crate::validate_non_control_character), fails with a
+/// `non_control_character` error
+#[cfg(feature = "unic")]
+pub fn check_non_control_character<'a, T: Into<Cow<'a, str>>>(
+    value: T,
+) -> Result<(), ValidationError> {
+    let value = value.into();
+    let valid = crate::validate_non_control_character(value.as_ref());
+    check_with_value(valid, "non_control_character", &value)
+}
+
+/// See [`validate_nfc`](crate::validate_nfc), fails with an `nfc` error
+#[cfg(feature = "unic")]
+pub fn check_nfc<'a, T: Into<Cow<'a, str>>>(value: T) -> Result<(), ValidationError> {
+    let value = value.into();
+    check_with_value(crate::validate_nfc(value.as_ref()), "nfc", &value)
+}
+
+/// See [`validate_nfkc`](crate::validate_nfkc), fails with an `nfkc` error
+#[cfg(feature = "unic")]
+pub fn check_nfkc<'a, T: Into<Cow<'a, str>>>(value: T) -> Result<(), ValidationError> {
+    let value = value.into();
+    check_with_value(crate::validate_nfkc(value.as_ref()), "nfkc", &value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParamValue;
+
+    #[test]
+    fn test_check_length_has_the_given_bounds() {
+        assert!(check_length("hello", Some(1), Some(10), None).is_ok());
+
+        let err = check_length("hello", None, Some(2), None).unwrap_err();
+        assert_eq!(err.code, "length");
+        assert_eq!(err.param("max"), Some(ParamValue::Int(2)));
+        assert_eq!(err.param("min"), None);
+        assert_eq!(err.param("value"), Some(ParamValue::from("hello")));
+    }
+
+    #[test]
+    fn test_check_does_not_contain_word_has_the_word() {
+        static BANNED: WordList = WordList::ignoring_ascii_case(&["spam"]);
+        assert!(check_does_not_contain_word("ham", &BANNED).is_ok());
+
+        let err = check_does_not_contain_word("SPAM!", &BANNED).unwrap_err();
+        assert_eq!(err.code, "does_not_contain");
+        assert_eq!(err.param("word"), Some(ParamValue::from("spam")));
+    }
+
+    #[test]
+    fn test_check_comparisons_have_the_other_value() {
+        assert!(check_less_than(1, 2).is_ok());
+        let err = check_greater_than_or_equal(1, 2).unwrap_err();
+        assert_eq!(err.code, "greater_than_or_equal");
+        assert_eq!(err.param("value"), Some(ParamValue::Int(1)));
+        assert_eq!(err.param("other"), Some(ParamValue::Int(2)));
+    }
+}
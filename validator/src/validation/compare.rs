@@ -0,0 +1,67 @@
+/// Validates that the first value is strictly greater than the second one.
+/// Also used by the `after` validator
+#[must_use]
+pub fn validate_greater_than<T: PartialOrd>(a: T, b: T) -> bool {
+    a > b
+}
+
+/// Validates that the first value is greater than or equal to the second one.
+#[must_use]
+pub fn validate_greater_than_or_equal<T: PartialOrd>(a: T, b: T) -> bool {
+    a >= b
+}
+
+/// Validates that the first value is strictly less than the second one.
+/// Also used by the `before` validator
+#[must_use]
+pub fn validate_less_than<T: PartialOrd>(a: T, b: T) -> bool {
+    a < b
+}
+
+/// Validates that the first value is less than or equal to the second one.
+#[must_use]
+pub fn validate_less_than_or_equal<T: PartialOrd>(a: T, b: T) -> bool {
+    a <= b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        validate_greater_than, validate_greater_than_or_equal, validate_less_than,
+        validate_less_than_or_equal,
+    };
+
+    #[test]
+    fn test_validate_greater_than() {
+        assert!(validate_greater_than(3, 2));
+        assert!(!validate_greater_than(2, 2));
+        assert!(!validate_greater_than(1, 2));
+    }
+
+    #[test]
+    fn test_validate_greater_than_or_equal() {
+        assert!(validate_greater_than_or_equal(3, 2));
+        assert!(validate_greater_than_or_equal(2, 2));
+        assert!(!validate_greater_than_or_equal(1, 2));
+    }
+
+    #[test]
+    fn test_validate_less_than() {
+        assert!(validate_less_than(1.5, 2.0));
+        assert!(!validate_less_than(2.0, 2.0));
+        assert!(!validate_less_than(3.0, 2.0));
+    }
+
+    #[test]
+    fn test_validate_less_than_or_equal() {
+        assert!(validate_less_than_or_equal(1, 2));
+        assert!(validate_less_than_or_equal(2, 2));
+        assert!(!validate_less_than_or_equal(3, 2));
+    }
+
+    #[test]
+    fn test_validate_compare_strings() {
+        assert!(validate_less_than("2022-01-01", "2022-06-27"));
+        assert!(validate_greater_than(&"b".to_string(), &"a".to_string()));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::traits::Contains;
+use alloc::borrow::Cow;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 /// Validates whether the value contains the needle
 /// The value needs to implement the Contains trait, which is implement on String, str and Hashmap<String>
@@ -8,9 +11,55 @@ pub fn validate_contains<T: Contains>(val: T, needle: &str) -> bool {
     val.has_element(needle)
 }
 
+/// Trait to implement if one wants to make the `contains` validator
+/// work for more types
+pub trait Contains {
+    #[must_use]
+    fn has_element(&self, needle: &str) -> bool;
+}
+
+impl Contains for String {
+    fn has_element(&self, needle: &str) -> bool {
+        self.contains(needle)
+    }
+}
+
+impl Contains for &String {
+    fn has_element(&self, needle: &str) -> bool {
+        self.contains(needle)
+    }
+}
+
+impl Contains for &str {
+    fn has_element(&self, needle: &str) -> bool {
+        self.contains(needle)
+    }
+}
+
+impl<'a> Contains for Cow<'a, str> {
+    fn has_element(&self, needle: &str) -> bool {
+        self.contains(needle)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S, H: ::std::hash::BuildHasher> Contains for HashMap<String, S, H> {
+    fn has_element(&self, needle: &str) -> bool {
+        self.contains_key(needle)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S, H: ::std::hash::BuildHasher> Contains for &HashMap<String, S, H> {
+    fn has_element(&self, needle: &str) -> bool {
+        self.contains_key(needle)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
+    #[cfg(feature = "std")]
     use std::collections::HashMap;
 
     use super::*;
@@ -26,6 +75,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_validate_contains_hashmap_key() {
         let mut map = HashMap::new();
         map.insert("hey".to_string(), 1);
@@ -33,6 +83,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_validate_contains_hashmap_key_can_fail() {
         let mut map = HashMap::new();
         map.insert("hey".to_string(), 1);
@@ -1,20 +1,20 @@
 use idna::domain_to_ascii;
-use lazy_static::lazy_static;
-use regex::Regex;
 use std::borrow::Cow;
+use std::ops::Range;
 
+use crate::idn_cache::cached_verdict;
+use crate::validation::literal::{is_user_char, validate_domain_name, validate_user_part};
 use crate::{validation::ip::validate_ip, HasLen};
 
-lazy_static! {
-    // Regex from the specs
-    // https://html.spec.whatwg.org/multipage/forms.html#valid-e-mail-address
-    // It will mark esoteric email addresses like quoted string as invalid
-    static ref EMAIL_USER_RE: Regex = Regex::new(r"^(?i)[a-z0-9.!#$%&'*+/=?^_`{|}~-]+\z").unwrap();
-    static ref EMAIL_DOMAIN_RE: Regex = Regex::new(
-        r"(?i)^[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?(?:\.[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?)*$"
-    ).unwrap();
-    // literal form, ipv4 or ipv6 address (SMTP 4.1.3)
-    static ref EMAIL_LITERAL_RE: Regex = Regex::new(r"(?i)\[([A-f0-9:\.]+)\]\z").unwrap();
+// literal form, ipv4 or ipv6 address (SMTP 4.1.3) between brackets ending the domain, the address
+// being what follows the first `[` of the run of letters, digits, `:`, `.` and brackets (or one of
+// `\^_``) before the closing `]`
+fn domain_literal(domain_part: &str) -> Option<&str> {
+    let is_literal_char = |c: &u8| c.is_ascii_alphanumeric() || b":.[\\]^_`".contains(c);
+    let inner = domain_part.strip_suffix(']')?;
+    let run = inner.bytes().rposition(|c| !is_literal_char(&c)).map_or(0, |idx| idx + 1);
+    let open = inner[run..].find('[')?;
+    Some(&inner[run + open + 1..])
 }
 
 /// Validates whether the given string is an email based on the [HTML5 spec](https://html.spec.whatwg.org/multipage/forms.html#valid-e-mail-address).
@@ -25,19 +25,41 @@ pub fn validate_email<T: ValidateEmail>(val: T) -> bool {
     val.validate_email()
 }
 
+/// Returns the byte range of the part of an invalid email that breaks the rule, `None` if it is
+/// valid: the first character of the user part that isn't allowed, the user part if it is empty
+/// or too long, the domain part otherwise. It is the whole value if there is no `@`.
+#[must_use]
+pub fn email_error_span<T: ValidateEmail>(val: T) -> Option<Range<usize>> {
+    if val.validate_email() {
+        return None;
+    }
+    let val = val.to_email_string();
+    let at = match val.rfind('@') {
+        Some(at) => at,
+        None => return Some(0..val.len()),
+    };
+    let user_part = &val[..at];
+    if user_part.is_empty() || user_part.length() > 64 || !validate_user_part(user_part) {
+        let invalid =
+            user_part.char_indices().find(|(_, c)| !c.is_ascii() || !is_user_char(*c as u8));
+        return Some(match invalid {
+            Some((idx, c)) => idx..idx + c.len_utf8(),
+            None => 0..at,
+        });
+    }
+    Some(at + 1..val.len())
+}
+
 /// Checks if the domain is a valid domain and if not, check whether it's an IP
 #[must_use]
 fn validate_domain_part(domain_part: &str) -> bool {
-    if EMAIL_DOMAIN_RE.is_match(domain_part) {
+    if validate_domain_name(domain_part) {
         return true;
     }
 
     // maybe we have an ip as a domain?
-    match EMAIL_LITERAL_RE.captures(domain_part) {
-        Some(caps) => match caps.get(1) {
-            Some(c) => validate_ip(c.as_str()),
-            None => false,
-        },
+    match domain_literal(domain_part) {
+        Some(ip) => validate_ip(ip),
         None => false,
     }
 }
@@ -46,15 +68,12 @@ pub trait ValidateEmail {
     fn validate_email(&self) -> bool {
         let val = self.to_email_string();
 
-        if val.is_empty() || !val.contains('@') {
-            return false;
-        }
-
-        let parts: Vec<&str> = val.rsplitn(2, '@').collect();
-        let user_part = parts[1];
-        let domain_part = parts[0];
+        let (user_part, domain_part) = match val.rsplit_once('@') {
+            Some(parts) => parts,
+            None => return false,
+        };
 
-        // validate the length of each part of the email, BEFORE doing the regex
+        // validate the length of each part of the email, BEFORE checking the characters
         // according to RFC5321 the max length of the local part is 64 characters
         // and the max length of the domain part is 255 characters
         // https://datatracker.ietf.org/doc/html/rfc5321#section-4.5.3.1.1
@@ -62,16 +81,16 @@ pub trait ValidateEmail {
             return false;
         }
 
-        if !EMAIL_USER_RE.is_match(user_part) {
+        if !validate_user_part(user_part) {
             return false;
         }
 
         if !validate_domain_part(domain_part) {
             // Still the possibility of an [IDN](https://en.wikipedia.org/wiki/Internationalized_domain_name)
-            return match domain_to_ascii(domain_part) {
+            return cached_verdict(domain_part, |domain_part| match domain_to_ascii(domain_part) {
                 Ok(d) => validate_domain_part(&d),
                 Err(_) => false,
-            };
+            });
         }
 
         true
@@ -100,7 +119,7 @@ impl ValidateEmail for &String {
 
 impl ValidateEmail for Cow<'_, str> {
     fn to_email_string(&self) -> Cow<'_, str> {
-        self.clone()
+        Cow::from(self.as_ref())
     }
 }
 
@@ -108,7 +127,11 @@ impl ValidateEmail for Cow<'_, str> {
 mod tests {
     use std::borrow::Cow;
 
-    use super::validate_email;
+    use regex::Regex;
+
+    use super::{
+        domain_literal, email_error_span, validate_domain_name, validate_email, validate_user_part,
+    };
 
     #[test]
     fn test_validate_email() {
@@ -192,9 +215,68 @@ mod tests {
     fn test_validate_email_rfc5321() {
         // 65 character local part
         let test = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa@mail.com";
-        assert_eq!(validate_email(test), false);
+        assert!(!validate_email(test));
         // 256 character domain part
         let test = "a@aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa.com";
-        assert_eq!(validate_email(test), false);
+        assert!(!validate_email(test));
+    }
+
+    #[test]
+    fn test_email_parts_match_the_spec_regexes() {
+        let user_re = Regex::new(r"^(?i)[a-z0-9.!#$%&'*+/=?^_`{|}~-]+\z").unwrap();
+        let domain_re = Regex::new(
+            r"(?i)^[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?(?:\.[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?)*$",
+        )
+        .unwrap();
+        let literal_re = Regex::new(r"(?i)\[([A-f0-9:\.]+)\]\z").unwrap();
+        let tests = vec![
+            "",
+            "a",
+            "A.b-C",
+            "-a",
+            "a-",
+            "a..b",
+            ".a",
+            "a_b",
+            "a b",
+            "é",
+            "a\n",
+            "[127.0.0.1]",
+            "x[127.0.0.1]",
+            "x [127.0.0.1]",
+            "[[127.0.0.1]",
+            "[127.0.0.1]]",
+            "[]",
+            "[::1]",
+            "[2001:dB8::1]",
+            "[1.2.3.4]\n",
+        ];
+
+        for input in tests {
+            assert_eq!(validate_user_part(input), user_re.is_match(input), "{:?}", input);
+            assert_eq!(validate_domain_name(input), domain_re.is_match(input), "{:?}", input);
+            assert_eq!(
+                domain_literal(input).filter(|ip| !ip.is_empty()),
+                literal_re.captures(input).map(|caps| caps.get(1).unwrap().as_str()),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_email_error_span() {
+        let tests = vec![
+            ("email@here.com", None),
+            ("abc", Some(0..3)),
+            ("a b@here.com", Some(1..2)),
+            ("@here.com", Some(0..0)),
+            ("email@here..com", Some(6..15)),
+            ("abc@", Some(4..4)),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(email_error_span(input), expected, "{}", input);
+        }
     }
 }
@@ -1,6 +1,6 @@
-use std::borrow::Cow;
-use std::net::IpAddr;
-use std::str::FromStr;
+use alloc::borrow::Cow;
+use core::net::IpAddr;
+use core::str::FromStr;
 
 /// Validates whether the given string is an IP V4
 #[must_use]
@@ -8,7 +8,7 @@ pub fn validate_ip_v4<'a, T>(val: T) -> bool
 where
     T: Into<Cow<'a, str>>,
 {
-    IpAddr::from_str(val.into().as_ref()).map_or(false, |i| i.is_ipv4())
+    IpAddr::from_str(val.into().as_ref()).is_ok_and(|i| i.is_ipv4())
 }
 
 /// Validates whether the given string is an IP V6
@@ -17,7 +17,7 @@ pub fn validate_ip_v6<'a, T>(val: T) -> bool
 where
     T: Into<Cow<'a, str>>,
 {
-    IpAddr::from_str(val.into().as_ref()).map_or(false, |i| i.is_ipv6())
+    IpAddr::from_str(val.into().as_ref()).is_ok_and(|i| i.is_ipv6())
 }
 
 /// Validates whether the given string is an IP
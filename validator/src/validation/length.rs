@@ -1,4 +1,6 @@
-use std::{borrow::Cow, collections::{HashMap, HashSet, BTreeMap, BTreeSet}};
+use alloc::{borrow::Cow, collections::{BTreeMap, BTreeSet}, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 #[cfg(feature = "indexmap")]
 use indexmap::{IndexMap, IndexSet};
@@ -49,13 +51,13 @@ impl ValidateLength for String {
 	}
 }
 
-impl<'a> ValidateLength for &'a String {
+impl ValidateLength for &String {
     fn length(&self) -> u64 {
 		self.chars().count() as u64
 	}
 }
 
-impl<'a> ValidateLength for &'a str {
+impl ValidateLength for &str {
     fn length(&self) -> u64 {
 		self.chars().count() as u64
 	}
@@ -73,7 +75,7 @@ impl<T> ValidateLength for Vec<T> {
 	}
 }
 
-impl<'a, T> ValidateLength for &'a Vec<T> {
+impl<T> ValidateLength for &Vec<T> {
     fn length(&self) -> u64 {
 		self.len() as u64
 	}
@@ -97,31 +99,34 @@ impl<T, const N: usize> ValidateLength for &[T; N] {
 	}
 }
 
-impl<'a, K, V, S> ValidateLength for &'a HashMap<K, V, S> {
+#[cfg(feature = "std")]
+impl<K, V, S> ValidateLength for &HashMap<K, V, S> {
     fn length(&self) -> u64 {
 		self.len() as u64
 	}
 }
 
+#[cfg(feature = "std")]
 impl<K, V, S> ValidateLength for HashMap<K, V, S> {
     fn length(&self) -> u64 {
 		self.len() as u64
 	}
 }
 
-impl<'a, T, S> ValidateLength for &'a HashSet<T, S> {
+#[cfg(feature = "std")]
+impl<T, S> ValidateLength for &HashSet<T, S> {
     fn length(&self) -> u64 {
 		self.len() as u64
 	}
 }
 
-impl<'a, K, V> ValidateLength for &'a BTreeMap<K, V> {
+impl<K, V> ValidateLength for &BTreeMap<K, V> {
     fn length(&self) -> u64 {
 		self.len() as u64
 	}
 }
 
-impl<'a, T> ValidateLength for &'a BTreeSet<T> {
+impl<T> ValidateLength for &BTreeSet<T> {
     fn length(&self) -> u64 {
 		self.len() as u64
 	}
@@ -134,14 +139,14 @@ impl<T> ValidateLength for BTreeSet<T> {
 }
 
 #[cfg(feature = "indexmap")]
-impl<'a, K, V> ValidateLength for &'a IndexMap<K, V> {
+impl<K, V> ValidateLength for &IndexMap<K, V> {
     fn length(&self) -> u64 {
 		self.len() as u64
 	}
 }
 
 #[cfg(feature = "indexmap")]
-impl<'a, T> ValidateLength for &'a IndexSet<T> {
+impl<T> ValidateLength for &IndexSet<T> {
     fn length(&self) -> u64 {
 		self.len() as u64
 	}
@@ -0,0 +1,178 @@
+use std::io::{self, ErrorKind, Read};
+
+/// What the length of a stream counts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthUnit {
+    Bytes,
+    /// The characters of UTF-8 text, which isn't checked to be valid
+    Chars,
+}
+
+/// Validates the length of a value given in chunks, so a large payload is rejected as soon as it
+/// is too long without being buffered. Like [`validate_length`](crate::validate_length), `equal`
+/// overrides `min` and `max`.
+#[derive(Debug, Clone)]
+pub struct LengthCounter {
+    unit: LengthUnit,
+    min: Option<u64>,
+    max: Option<u64>,
+    equal: Option<u64>,
+    length: u64,
+}
+
+impl LengthCounter {
+    pub fn new(
+        unit: LengthUnit,
+        min: Option<u64>,
+        max: Option<u64>,
+        equal: Option<u64>,
+    ) -> LengthCounter {
+        LengthCounter { unit, min, max, equal, length: 0 }
+    }
+
+    /// Counts the next chunk, returns `false` once the value is too long. A character may be
+    /// split between two chunks.
+    pub fn update(&mut self, chunk: &[u8]) -> bool {
+        self.length += match self.unit {
+            LengthUnit::Bytes => chunk.len() as u64,
+            // Every character has a single byte that isn't a continuation byte
+            LengthUnit::Chars => chunk.iter().filter(|b| (**b as i8) >= -0x40).count() as u64,
+        };
+        !self.is_too_long()
+    }
+
+    /// The length counted so far
+    #[must_use]
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Whether the value is already too long, whatever comes next
+    #[must_use]
+    pub fn is_too_long(&self) -> bool {
+        self.equal.or(self.max).is_some_and(|max| self.length > max)
+    }
+
+    /// Whether the length of the whole value is valid, once all its chunks are counted
+    #[must_use]
+    pub fn finish(&self) -> bool {
+        match self.equal {
+            Some(equal) => self.length == equal,
+            None => !self.is_too_long() && self.min.is_none_or(|min| self.length >= min),
+        }
+    }
+}
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Validates the length of what a reader gives, reading it in chunks until it ends or is too
+/// long. The reader isn't read further once the value is known to be too long.
+pub fn validate_length_reader<R: Read>(
+    mut reader: R,
+    unit: LengthUnit,
+    min: Option<u64>,
+    max: Option<u64>,
+    equal: Option<u64>,
+) -> io::Result<bool> {
+    let mut counter = LengthCounter::new(unit, min, max, equal);
+    let mut buf = [0; CHUNK_SIZE];
+    loop {
+        let read = match reader.read(&mut buf) {
+            Ok(0) => return Ok(counter.finish()),
+            Ok(read) => read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if !counter.update(&buf[..read]) {
+            return Ok(false);
+        }
+    }
+}
+
+/// Validates the length of what an async reader gives, like [`validate_length_reader`]
+#[cfg(feature = "async")]
+pub async fn validate_length_async_reader<R: futures_io::AsyncRead + Unpin>(
+    mut reader: R,
+    unit: LengthUnit,
+    min: Option<u64>,
+    max: Option<u64>,
+    equal: Option<u64>,
+) -> io::Result<bool> {
+    use std::pin::Pin;
+
+    let mut counter = LengthCounter::new(unit, min, max, equal);
+    let mut buf = vec![0; CHUNK_SIZE];
+    loop {
+        let read = std::future::poll_fn(|cx| Pin::new(&mut reader).poll_read(cx, &mut buf)).await;
+        let read = match read {
+            Ok(0) => return Ok(counter.finish()),
+            Ok(read) => read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if !counter.update(&buf[..read]) {
+            return Ok(false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read};
+
+    use super::{validate_length_reader, LengthCounter, LengthUnit};
+
+    #[test]
+    fn test_counts_chars_split_between_chunks() {
+        let text = "日本語 text".as_bytes();
+        let mut counter = LengthCounter::new(LengthUnit::Chars, None, None, Some(8));
+        for chunk in text.chunks(2) {
+            assert!(counter.update(chunk));
+        }
+        assert_eq!(counter.length(), 8);
+        assert!(counter.finish());
+
+        let mut counter = LengthCounter::new(LengthUnit::Bytes, Some(1), Some(4), None);
+        assert!(counter.update(&text[..4]));
+        assert!(!counter.update(&text[4..5]));
+        assert!(counter.is_too_long());
+        assert!(!counter.finish());
+    }
+
+    #[test]
+    fn test_validate_length_reader() {
+        let tests = vec![
+            (LengthUnit::Bytes, Some(1), Some(10), None, "hello", true),
+            (LengthUnit::Bytes, Some(10), None, None, "hello", false),
+            (LengthUnit::Bytes, None, Some(5), None, "日本", false),
+            (LengthUnit::Chars, None, Some(5), None, "日本", true),
+            (LengthUnit::Chars, Some(1), Some(2), Some(5), "hello", true),
+            (LengthUnit::Chars, Some(1), None, None, "", false),
+        ];
+
+        for (unit, min, max, equal, input, expected) in tests {
+            let valid = validate_length_reader(input.as_bytes(), unit, min, max, equal).unwrap();
+            assert_eq!(valid, expected, "{:?} {:?}", unit, input);
+        }
+    }
+
+    /// Gives zeros forever, counting what it gave
+    struct Endless(usize);
+
+    impl Read for Endless {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            buf.fill(0);
+            self.0 += buf.len();
+            Ok(buf.len())
+        }
+    }
+
+    #[test]
+    fn test_stops_reading_once_too_long() {
+        let mut reader = Endless(0);
+        assert!(
+            !validate_length_reader(&mut reader, LengthUnit::Bytes, None, Some(10), None).unwrap()
+        );
+        assert_eq!(reader.0, super::CHUNK_SIZE);
+    }
+}
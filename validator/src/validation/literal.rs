@@ -0,0 +1,365 @@
+//! Validators usable in `const` contexts, so the values hard-coded in a program can be checked at
+//! compile time with the [`email!`](crate::email), [`luhn!`](crate::luhn), [`hex!`](crate::hex)
+//! and [`ip_v4!`](crate::ip_v4) macros:
+//!
+//! ```
+//! const SUPPORT: &str = validator::email!("support@example.com");
+//! const PORT: u16 = 8443;
+//! const _: () = assert!(validator::literal::range(PORT as i128, Some(1024), Some(65535)));
+//! ```
+//!
+//! An invalid literal fails the compilation:
+//!
+//! ```compile_fail
+//! const SUPPORT: &str = validator::email!("support@example..com");
+//! ```
+
+// The rules of the specs of emails, checked character by character
+// https://html.spec.whatwg.org/multipage/forms.html#valid-e-mail-address
+// It will mark esoteric email addresses like quoted string as invalid
+/// Whether the byte is allowed in the user part of an email
+pub(crate) const fn is_user_char(c: u8) -> bool {
+    if c.is_ascii_alphanumeric() {
+        return true;
+    }
+    let allowed = b".!#$%&'*+/=?^_`{|}~-";
+    let mut i = 0;
+    while i < allowed.len() {
+        if allowed[i] == c {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+#[cfg(feature = "std")]
+pub(crate) const fn validate_user_part(user_part: &str) -> bool {
+    user_part_bytes(user_part.as_bytes())
+}
+
+const fn user_part_bytes(bytes: &[u8]) -> bool {
+    if bytes.is_empty() {
+        return false;
+    }
+    let mut i = 0;
+    while i < bytes.len() {
+        if !is_user_char(bytes[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Dot separated labels of 1 to 63 letters, digits and hyphens, not starting or ending with a hyphen
+#[cfg(feature = "std")]
+pub(crate) const fn validate_domain_name(domain_part: &str) -> bool {
+    domain_name_bytes(domain_part.as_bytes())
+}
+
+const fn domain_name_bytes(bytes: &[u8]) -> bool {
+    // The start of the current label
+    let mut start = 0;
+    let mut i = 0;
+    while i <= bytes.len() {
+        if i == bytes.len() || bytes[i] == b'.' {
+            let len = i - start;
+            if len == 0
+                || len > 63
+                || !bytes[start].is_ascii_alphanumeric()
+                || !bytes[i - 1].is_ascii_alphanumeric()
+            {
+                return false;
+            }
+            start = i + 1;
+        } else if !bytes[i].is_ascii_alphanumeric() && bytes[i] != b'-' {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Whether the value is an email with an ASCII domain name or an IPv4 address literal, like
+/// [`validate_email`](crate::validate_email) which also accepts internationalized domains and
+/// IPv6 literals
+#[must_use]
+pub const fn email(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let mut at = bytes.len();
+    while at > 0 && bytes[at - 1] != b'@' {
+        at -= 1;
+    }
+    if at == 0 {
+        return false;
+    }
+    let (user_part, domain_part) = bytes.split_at(at - 1);
+    let (_, domain_part) = domain_part.split_at(1);
+    if user_part.len() > 64 || domain_part.len() > 255 || !user_part_bytes(user_part) {
+        return false;
+    }
+    if domain_name_bytes(domain_part) {
+        return true;
+    }
+
+    // Unlike for the runtime validator, nothing can come before the address literal
+    match domain_part {
+        [b'[', ip @ .., b']'] => ip_v4_bytes(ip),
+        _ => false,
+    }
+}
+
+/// Whether the value is an IPv4 address in its dotted decimal form, like
+/// [`validate_ip_v4`](crate::validate_ip_v4)
+#[must_use]
+pub const fn ip_v4(value: &str) -> bool {
+    ip_v4_bytes(value.as_bytes())
+}
+
+const fn ip_v4_bytes(bytes: &[u8]) -> bool {
+    let mut octets = 0;
+    let mut octet: u32 = 0;
+    let mut digits = 0;
+    let mut i = 0;
+    while i <= bytes.len() {
+        if i == bytes.len() || bytes[i] == b'.' {
+            // Leading zeros are rejected as they could be read as octal
+            if digits == 0 || octet > 255 || (digits > 1 && bytes[i - digits] == b'0') {
+                return false;
+            }
+            octets += 1;
+            octet = 0;
+            digits = 0;
+        } else if bytes[i].is_ascii_digit() && digits < 3 {
+            octet = octet * 10 + (bytes[i] - b'0') as u32;
+            digits += 1;
+        } else {
+            return false;
+        }
+        i += 1;
+    }
+    octets == 4
+}
+
+/// Whether the digits of the value pass the Luhn checksum, like
+/// [`validate_luhn`](crate::validate_luhn)
+#[must_use]
+pub const fn luhn(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let mut sum = 0;
+    let mut digits = 0;
+    let mut i = bytes.len();
+    while i > 0 {
+        i -= 1;
+        let c = bytes[i];
+        if c == b' ' || c == b'-' {
+            continue;
+        }
+        if !c.is_ascii_digit() {
+            return false;
+        }
+        let digit = (c - b'0') as u32;
+        sum += match digits % 2 {
+            0 => digit,
+            _ if digit > 4 => digit * 2 - 9,
+            _ => digit * 2,
+        };
+        digits += 1;
+    }
+    digits > 1 && sum % 10 == 0
+}
+
+/// Whether the value is a non empty string of hexadecimal digits, in either case
+#[must_use]
+pub const fn hex(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_hexdigit() {
+            return false;
+        }
+        i += 1;
+    }
+    !bytes.is_empty()
+}
+
+/// Whether every character of the value is one of the ASCII characters of `allowed`
+#[must_use]
+pub const fn charset(value: &str, allowed: &str) -> bool {
+    let (bytes, allowed) = (value.as_bytes(), allowed.as_bytes());
+    let mut i = 0;
+    'chars: while i < bytes.len() {
+        let mut j = 0;
+        while j < allowed.len() {
+            if bytes[i] == allowed[j] && bytes[i].is_ascii() {
+                i += 1;
+                continue 'chars;
+            }
+            j += 1;
+        }
+        return false;
+    }
+    true
+}
+
+/// Whether the value is between `min` and `max` included, like
+/// [`validate_range`](crate::validate_range) for integers
+#[must_use]
+pub const fn range(value: i128, min: Option<i128>, max: Option<i128>) -> bool {
+    if let Some(min) = min {
+        if value < min {
+            return false;
+        }
+    }
+    if let Some(max) = max {
+        if value > max {
+            return false;
+        }
+    }
+    true
+}
+
+// Checks a literal with a const validator, failing the compilation if it is invalid
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_literal {
+    ($check:path, $what:literal, $value:literal) => {{
+        const _: () = if !$check($value) {
+            ::core::panic!("{}", ::core::concat!("`", $value, "` is not ", $what));
+        };
+        $value
+    }};
+}
+
+/// Returns the string literal, failing the compilation if it isn't an email according to
+/// [`literal::email`](crate::literal::email)
+#[macro_export]
+macro_rules! email {
+    ($value:literal) => {
+        $crate::__check_literal!($crate::literal::email, "a valid email", $value)
+    };
+}
+
+/// Returns the string literal, failing the compilation if its digits don't pass the Luhn checksum
+#[macro_export]
+macro_rules! luhn {
+    ($value:literal) => {
+        $crate::__check_literal!($crate::literal::luhn, "a valid Luhn number", $value)
+    };
+}
+
+/// Returns the string literal, failing the compilation if it isn't made of hexadecimal digits
+#[macro_export]
+macro_rules! hex {
+    ($value:literal) => {
+        $crate::__check_literal!($crate::literal::hex, "a hexadecimal string", $value)
+    };
+}
+
+/// Returns the string literal, failing the compilation if it isn't an IPv4 address
+#[macro_export]
+macro_rules! ip_v4 {
+    ($value:literal) => {
+        $crate::__check_literal!($crate::literal::ip_v4, "a valid IPv4 address", $value)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{charset, email, hex, ip_v4, luhn, range};
+    use crate::{validate_ip_v4, validate_luhn};
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_email_matches_the_runtime_validator() {
+        let tests = vec![
+            "email@here.com",
+            "weirder-email@here.and.there.com",
+            r#"!def!xyz%abc@example.com"#,
+            "email@[127.0.0.1]",
+            "email@[127.0.0.256]",
+            "email@[01.0.0.1]",
+            "email@127.0.0.1",
+            "a@atm.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "a@atm.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "",
+            "abc",
+            "abc@",
+            "@abc",
+            "abc@bar",
+            "a @x.cz",
+            "abc@.com",
+            "something@@somewhere.com",
+            "example@invalid-.com",
+            "example@-invalid.com",
+            "trailingdot@shouldfail.com.",
+            "a@b.com\n",
+            "John.Doe@exam_ple.com",
+        ];
+
+        for input in tests {
+            assert_eq!(email(input), crate::validate_email(input), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_email_rejects_idn_and_ipv6_domains() {
+        assert!(!email("test@domain.with.idn.tld.उदाहरण.परीक्षा"));
+        assert!(!email("email@[2001:dB8::1]"));
+    }
+
+    #[test]
+    fn test_ip_v4_matches_the_runtime_validator() {
+        let tests = vec![
+            "1.1.1.1",
+            "255.0.0.0",
+            "0.0.0.0",
+            "256.1.1.1",
+            "25.1.1.",
+            ".25.1.1",
+            "25,1,1,1",
+            "1.1.1",
+            "1.1.1.1.1",
+            "01.1.1.1",
+            "1000.1.1.1",
+            "fe80::223:6cff:fe8a:2e8a",
+            "",
+        ];
+
+        for input in tests {
+            assert_eq!(ip_v4(input), validate_ip_v4(input), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_luhn_matches_the_runtime_validator() {
+        let tests = vec!["4539571147647251", "4539-5711 4764 7251", "79927398710", "4539a71", "0"];
+
+        for input in tests {
+            assert_eq!(luhn(input), validate_luhn(input), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_hex_charset_and_range() {
+        assert!(hex("DEADbeef01"));
+        assert!(!hex(""));
+        assert!(!hex("0x1f"));
+        assert!(charset("a-b_c", "abc-_"));
+        assert!(!charset("a b", "ab"));
+        assert!(!charset("é", "é"));
+        assert!(range(5, Some(1), Some(5)));
+        assert!(!range(0, Some(1), None));
+        assert!(!range(6, None, Some(5)));
+    }
+
+    #[test]
+    fn test_macros_return_the_literal() {
+        const SUPPORT: &str = crate::email!("support@example.com");
+        assert_eq!(SUPPORT, "support@example.com");
+        assert_eq!(crate::luhn!("79927398713"), "79927398713");
+        assert_eq!(crate::hex!("c0ffee"), "c0ffee");
+        assert_eq!(crate::ip_v4!("10.0.0.1"), "10.0.0.1");
+    }
+}
@@ -0,0 +1,46 @@
+use alloc::borrow::Cow;
+
+/// Validates that the digits of the given string pass the Luhn checksum, like card numbers,
+/// IMEIs or some national identifiers. Spaces and dashes are ignored, any other character that
+/// isn't a digit fails the validation, as do values with fewer than 2 digits.
+#[must_use]
+pub fn validate_luhn<'a, T>(value: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    crate::validation::literal::luhn(&value.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::validate_luhn;
+
+    #[test]
+    fn test_validate_luhn() {
+        let tests = vec![
+            ("4539571147647251", true),
+            ("4539-5711 4764 7251", true),
+            ("490154203237518", true),
+            ("79927398713", true),
+            ("79927398710", false),
+            ("4539571147647252", false),
+            ("4539a71147647251", false),
+            ("0", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_luhn(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_luhn_cow() {
+        let test: Cow<'static, str> = "79927398713".into();
+        assert!(validate_luhn(test));
+        let test: Cow<'static, str> = String::from("79927398710").into();
+        assert!(!validate_luhn(test));
+    }
+}
@@ -1,15 +1,31 @@
+pub mod bits_within;
 #[cfg(feature = "card")]
 pub mod cards;
+#[cfg(feature = "std")]
+pub mod check;
+pub mod compare;
 pub mod contains;
 pub mod does_not_contain;
+#[cfg(feature = "std")]
 pub mod email;
 pub mod ip;
 pub mod length;
+#[cfg(feature = "std")]
+pub mod length_reader;
+pub mod literal;
+pub mod luhn;
 pub mod must_match;
 #[cfg(feature = "unic")]
 pub mod non_control_character;
+#[cfg(feature = "unic")]
+pub mod normalization_form;
+pub mod percentage;
 #[cfg(feature = "phone")]
 pub mod phone;
 pub mod range;
+pub mod ratio;
 pub mod required;
+#[cfg(feature = "std")]
 pub mod urls;
+#[cfg(feature = "std")]
+pub mod words;
@@ -1,19 +1,76 @@
 use std::borrow::Cow;
-use unic_ucd_common::control;
+use std::convert::TryInto;
+use std::ops::Range;
+
+const ONES: u64 = 0x0101_0101_0101_0101;
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// Whether one of the bytes of the word is below `n`, which is at most 128
+fn has_byte_below(word: u64, n: u8) -> bool {
+    word.wrapping_sub(ONES * u64::from(n)) & !word & HIGH_BITS != 0
+}
+
+/// Whether one of the bytes of the word is a C0 control character or DEL
+fn has_c0_control(word: u64) -> bool {
+    has_byte_below(word, 0x20) || has_byte_below(word ^ (ONES * 0x7F), 1)
+}
+
+fn is_c0_control(byte: u8) -> bool {
+    byte < 0x20 || byte == 0x7F
+}
+
+/// The index of the first C0 control character, the bytes being checked 8 at a time
+fn first_c0_control(bytes: &[u8]) -> Option<usize> {
+    let mut words = bytes.chunks_exact(8);
+    let mut offset = 0;
+    for word in &mut words {
+        if has_c0_control(u64::from_le_bytes(word.try_into().unwrap())) {
+            break;
+        }
+        offset += 8;
+    }
+    bytes[offset..].iter().position(|byte| is_c0_control(*byte)).map(|idx| offset + idx)
+}
+
+/// The byte index of the first control character (general category `Cc`): the C0 controls and
+/// DEL, which are ASCII, and the C1 controls from U+0080 to U+009F, encoded as 0xC2 followed by
+/// 0x80 to 0x9F and found with `memchr`
+fn first_control(value: &str) -> Option<usize> {
+    let bytes = value.as_bytes();
+    let c0 = first_c0_control(bytes);
+    let before_c0 = &bytes[..c0.unwrap_or(bytes.len())];
+    let c1 = memchr::memchr_iter(0xC2, before_c0)
+        .find(|&idx| bytes.get(idx + 1).is_some_and(|next| (0x80..=0x9F).contains(next)));
+    c1.or(c0)
+}
 
 #[must_use]
 pub fn validate_non_control_character<'a, T>(alphabetic: T) -> bool
 where
     T: Into<Cow<'a, str>> + Clone,
 {
-    alphabetic.into().chars().all(|code| !control::is_control(code))
+    first_control(&alphabetic.into()).is_none()
+}
+
+/// Returns the byte range of the first control character, `None` if there is none
+#[must_use]
+pub fn control_character_span<'a, T>(alphabetic: T) -> Option<Range<usize>>
+where
+    T: Into<Cow<'a, str>> + Clone,
+{
+    let value = alphabetic.into();
+    let idx = first_control(&value)?;
+    let len = value[idx..].chars().next().unwrap().len_utf8();
+    Some(idx..idx + len)
 }
 
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
 
-    use super::validate_non_control_character;
+    use unic_ucd_common::control;
+
+    use super::{control_character_span, first_control, validate_non_control_character};
 
     #[test]
     fn test_non_control_character() {
@@ -44,4 +101,30 @@ mod tests {
         let test: Cow<'static, str> = String::from("\u{009F}").into();
         assert!(!validate_non_control_character(test));
     }
+
+    #[test]
+    fn test_first_control_matches_the_unicode_category() {
+        let mut buffer = [0; 4];
+        for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            let encoded = c.encode_utf8(&mut buffer);
+            assert_eq!(first_control(encoded).is_some(), control::is_control(c), "{:?}", c);
+        }
+
+        // The controls are found anywhere in the words scanned at once
+        let text = "société générale 2H₂ + O₂";
+        for idx in text.char_indices().map(|(idx, _)| idx).chain(Some(text.len())) {
+            for control in &["\u{0}", "\u{1f}", "\u{7f}", "\u{80}", "\u{9f}"] {
+                let value = format!("{}{}{}", &text[..idx], control, &text[idx..]);
+                assert_eq!(first_control(&value), Some(idx), "{:?}", value);
+            }
+        }
+        assert_eq!(first_control(text), None);
+    }
+
+    #[test]
+    fn test_control_character_span() {
+        assert_eq!(control_character_span("Himmel"), None);
+        assert_eq!(control_character_span("आ\u{000c}"), Some(3..4));
+        assert_eq!(control_character_span("आ\u{009F}\u{000c}"), Some(3..5));
+    }
 }
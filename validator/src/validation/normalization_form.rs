@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+/// Validates that the string is in the Unicode Normalization Form C, as produced by
+/// [`normalize_nfc`](crate::normalize_nfc), so that identifiers looking the same are stored and
+/// compared as the same characters
+#[must_use]
+pub fn validate_nfc<'a, T>(value: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    unicode_normalization::is_nfc(&value.into())
+}
+
+/// Validates that the string is in the Unicode Normalization Form KC, as produced by
+/// [`normalize_nfkc`](crate::normalize_nfkc), which also replaces the compatibility characters
+/// like ligatures and full-width letters by their usual equivalents
+#[must_use]
+pub fn validate_nfkc<'a, T>(value: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    unicode_normalization::is_nfkc(&value.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{validate_nfc, validate_nfkc};
+
+    #[test]
+    fn test_validate_nfc() {
+        let tests = vec![
+            ("", true),
+            ("ascii", true),
+            ("\u{e9}t\u{e9}", true),
+            ("e\u{301}t\u{e9}", false),
+            ("\u{fb01}le", true),
+            ("\u{212b}", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_nfc(input), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_nfkc() {
+        let tests = vec![
+            ("ascii", true),
+            ("\u{e9}t\u{e9}", true),
+            ("e\u{301}t\u{e9}", false),
+            ("\u{fb01}le", false),
+            ("\u{ff21}", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_nfkc(input), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_nfc_cow() {
+        assert!(validate_nfc(Cow::from("\u{e9}")));
+        assert!(!validate_nfkc(Cow::from(String::from("\u{fb01}"))));
+    }
+}
@@ -0,0 +1,33 @@
+/// Validates that the given `value` is a percentage, i.e. between 0 and 100.
+/// If `exclusive` is set, the limits themselves are not accepted.
+#[must_use]
+pub const fn validate_percentage(value: f64, exclusive: bool) -> bool {
+    if exclusive {
+        value > 0.0 && value < 100.0
+    } else {
+        !(value < 0.0 || value > 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_percentage;
+
+    #[test]
+    fn test_validate_percentage_inclusive() {
+        let tests = vec![(0.0, true), (50.0, true), (100.0, true), (-0.1, false), (100.1, false)];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_percentage(input, false), expected);
+        }
+    }
+
+    #[test]
+    fn test_validate_percentage_exclusive() {
+        let tests = vec![(0.0, false), (0.1, true), (99.9, true), (100.0, false)];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_percentage(input, true), expected);
+        }
+    }
+}
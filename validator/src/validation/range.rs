@@ -13,6 +13,38 @@ pub fn validate_range<T: ValidateRange<T>>(
     value.validate_range(min, max, exclusive_min, exclusive_max)
 }
 
+/// Validates that the given `value` falls on a grid of `step` starting at `origin`, ie
+/// that `value - origin` is a multiple of `step`.
+/// A small tolerance, relative to `step` and to the magnitude of the values, is used so that
+/// float steps such as `0.1` behave as expected.
+/// A `step` that is not a positive finite number never validates.
+#[must_use]
+pub fn validate_range_step(value: f64, origin: f64, step: f64) -> bool {
+    if !step.is_finite() || step <= 0.0 || !value.is_finite() || !origin.is_finite() {
+        return false;
+    }
+
+    // The distance to the closest multiple, `round` isn't available without `std`
+    let remainder = ((value - origin) % step).abs();
+    let distance = remainder.min(step - remainder);
+    // A few ULPs of the largest operand cover the rounding of the subtraction
+    let magnitude = value.abs().max(origin.abs());
+    distance <= step * 1e-9 + magnitude * 4.0 * f64::EPSILON
+}
+
+/// Same as [`validate_range_step`] for integer values, which aren't all representable as `f64`.
+/// The check is exact when `origin` and `step` are integers and falls back to
+/// [`validate_range_step`] otherwise.
+#[must_use]
+pub fn validate_range_step_integer(value: i128, origin: f64, step: f64) -> bool {
+    let is_integer = |x: f64| x % 1.0 == 0.0 && x >= i128::MIN as f64 && x < i128::MAX as f64;
+    if step > 0.0 && is_integer(step) && is_integer(origin) {
+        return value.checked_sub(origin as i128).is_some_and(|diff| diff % step as i128 == 0);
+    }
+
+    validate_range_step(value as f64, origin, step)
+}
+
 pub trait ValidateRange<T> {
     fn validate_range(
         &self,
@@ -75,7 +107,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::validate_range;
+    use super::{validate_range, validate_range_step, validate_range_step_integer};
 
     #[test]
     fn test_validate_range_generic_ok() {
@@ -129,4 +161,54 @@ mod tests {
         assert!(!validate_range(10, None, None, Some(10), None));
         assert!(validate_range(9, None, None, Some(8), None));
     }
+
+    #[test]
+    fn test_validate_range_step() {
+        assert!(validate_range_step(0.0, 0.0, 5.0));
+        assert!(validate_range_step(25.0, 0.0, 5.0));
+        assert!(validate_range_step(-10.0, 0.0, 5.0));
+        assert!(!validate_range_step(24.0, 0.0, 5.0));
+        assert!(validate_range_step(7.0, 2.0, 5.0));
+        assert!(!validate_range_step(5.0, 2.0, 5.0));
+    }
+
+    #[test]
+    fn test_validate_range_step_float() {
+        assert!(validate_range_step(0.3, 0.0, 0.1));
+        assert!(validate_range_step(19.99, 0.99, 0.5));
+        assert!(!validate_range_step(0.35, 0.0, 0.1));
+    }
+
+    #[test]
+    fn test_validate_range_step_invalid_step() {
+        assert!(!validate_range_step(10.0, 0.0, 0.0));
+        assert!(!validate_range_step(10.0, 0.0, -5.0));
+        assert!(!validate_range_step(10.0, 0.0, f64::NAN));
+        assert!(!validate_range_step(f64::INFINITY, 0.0, 5.0));
+    }
+
+    #[test]
+    fn test_validate_range_step_large_values() {
+        assert!(validate_range_step(5_000_000_000.0, 0.0, 5.0));
+        assert!(!validate_range_step(5_000_000_003.0, 0.0, 5.0));
+        assert!(validate_range_step(1e12, 0.0, 1.0));
+        assert!(!validate_range_step(1e12 + 0.5, 0.0, 1.0));
+        assert!(validate_range_step(1e12 + 0.3, 0.0, 0.1));
+        assert!(!validate_range_step(-1e12 - 0.5, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_validate_range_step_integer() {
+        assert!(validate_range_step_integer(25, 0.0, 5.0));
+        assert!(validate_range_step_integer(-10, 0.0, 5.0));
+        assert!(!validate_range_step_integer(24, 0.0, 5.0));
+        assert!(validate_range_step_integer(7, 2.0, 5.0));
+        assert!(!validate_range_step_integer(10, 0.0, 0.0));
+        assert!(validate_range_step_integer(3, 0.0, 0.5));
+        assert!(!validate_range_step_integer(3, 0.0, 2.5));
+        // Not representable as `f64`
+        assert!(validate_range_step_integer(i64::MAX as i128 - 7, 0.0, 2.0));
+        assert!(!validate_range_step_integer(i64::MAX as i128 - 8, 0.0, 2.0));
+        assert!(!validate_range_step_integer(u64::MAX as i128 - 1, 0.0, 4.0));
+    }
 }
@@ -0,0 +1,33 @@
+/// Validates that the given `value` is a ratio, i.e. between 0.0 and 1.0.
+/// If `exclusive` is set, the limits themselves are not accepted.
+#[must_use]
+pub const fn validate_ratio(value: f64, exclusive: bool) -> bool {
+    if exclusive {
+        value > 0.0 && value < 1.0
+    } else {
+        !(value < 0.0 || value > 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_ratio;
+
+    #[test]
+    fn test_validate_ratio_inclusive() {
+        let tests = vec![(0.0, true), (0.5, true), (1.0, true), (-0.01, false), (1.01, false)];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_ratio(input, false), expected);
+        }
+    }
+
+    #[test]
+    fn test_validate_ratio_exclusive() {
+        let tests = vec![(0.0, false), (0.01, true), (0.99, true), (1.0, false)];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_ratio(input, true), expected);
+        }
+    }
+}
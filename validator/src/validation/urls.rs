@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::ops::Range;
 use url::Url;
 
 /// Validates whether the string given is a url
@@ -7,6 +8,20 @@ pub fn validate_url<T: ValidateUrl>(val: T) -> bool {
     val.validate_url()
 }
 
+/// Returns the byte range of the part of an invalid url that breaks the rule, `None` if it is
+/// valid: its first whitespace or control character, the whole value if it has none
+#[must_use]
+pub fn url_error_span<T: ValidateUrl>(val: T) -> Option<Range<usize>> {
+    if val.validate_url() {
+        return None;
+    }
+    let val = val.to_url_string();
+    match val.char_indices().find(|(_, c)| c.is_whitespace() || c.is_control()) {
+        Some((idx, c)) => Some(idx..idx + c.len_utf8()),
+        None => Some(0..val.len()),
+    }
+}
+
 pub trait ValidateUrl {
     fn validate_url(&self) -> bool {
         Url::parse(&self.to_url_string()).is_ok()
@@ -35,7 +50,7 @@ impl ValidateUrl for &String {
 
 impl ValidateUrl for Cow<'_, str> {
     fn to_url_string(&self) -> Cow<'_, str> {
-        self.clone()
+        Cow::from(self.as_ref())
     }
 }
 
@@ -43,7 +58,7 @@ impl ValidateUrl for Cow<'_, str> {
 mod tests {
     use std::borrow::Cow;
 
-    use super::validate_url;
+    use super::{url_error_span, validate_url};
 
     #[test]
     fn test_validate_url() {
@@ -70,4 +85,11 @@ mod tests {
         let test: Cow<'static, str> = String::from("http").into();
         assert!(!validate_url(test));
     }
+
+    #[test]
+    fn test_url_error_span() {
+        assert_eq!(url_error_span("https://google.com"), None);
+        assert_eq!(url_error_span("http"), Some(0..4));
+        assert_eq!(url_error_span("https://goo gle.com"), Some(11..12));
+    }
 }
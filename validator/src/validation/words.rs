@@ -0,0 +1,134 @@
+use std::sync::OnceLock;
+
+use aho_corasick::AhoCorasick;
+
+/// A list of words searched at once in the values of the `contains(words = ..)` and
+/// `does_not_contain(words = ..)` validators, like a denylist of hundreds of banned words.
+///
+/// The words are compiled into an Aho-Corasick automaton the first time the list is used, which
+/// is then reused for every value, so the list is typically a static:
+///
+/// ```
+/// use validator::WordList;
+///
+/// static BANNED: WordList = WordList::ignoring_ascii_case(&["spam", "scam"]);
+///
+/// assert_eq!(BANNED.find("Not a SCAM"), Some("scam"));
+/// ```
+#[derive(Debug)]
+pub struct WordList {
+    words: &'static [&'static str],
+    ascii_case_insensitive: bool,
+    automaton: OnceLock<AhoCorasick>,
+    /// The words of a list built at runtime, `words` being empty
+    owned: Vec<String>,
+}
+
+impl WordList {
+    /// A list of words matched exactly
+    pub const fn new(words: &'static [&'static str]) -> WordList {
+        WordList {
+            words,
+            ascii_case_insensitive: false,
+            automaton: OnceLock::new(),
+            owned: Vec::new(),
+        }
+    }
+
+    /// A list of words matched regardless of the case of their ASCII letters
+    pub const fn ignoring_ascii_case(words: &'static [&'static str]) -> WordList {
+        WordList {
+            words,
+            ascii_case_insensitive: true,
+            automaton: OnceLock::new(),
+            owned: Vec::new(),
+        }
+    }
+
+    /// A list of words known at runtime, like the ones of a configuration file, compiled right away
+    pub fn from_words<I, S>(words: I, ascii_case_insensitive: bool) -> WordList
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let list = WordList {
+            words: &[],
+            ascii_case_insensitive,
+            automaton: OnceLock::new(),
+            owned: words.into_iter().map(Into::into).collect(),
+        };
+        list.automaton();
+        list
+    }
+
+    fn automaton(&self) -> &AhoCorasick {
+        self.automaton.get_or_init(|| {
+            let mut builder = AhoCorasick::builder();
+            builder.ascii_case_insensitive(self.ascii_case_insensitive);
+            match self.owned.is_empty() {
+                true => builder.build(self.words),
+                false => builder.build(&self.owned),
+            }
+            .expect("the automaton of a word list is too large")
+        })
+    }
+
+    /// Whether the value contains one of the words
+    #[must_use]
+    pub fn is_match(&self, value: &str) -> bool {
+        self.automaton().is_match(value)
+    }
+
+    /// The first word of the list found in the value, as it is in the list
+    #[must_use]
+    pub fn find(&self, value: &str) -> Option<&str> {
+        let pattern = self.automaton().find(value)?.pattern().as_usize();
+        match self.owned.is_empty() {
+            true => self.words.get(pattern).copied(),
+            false => self.owned.get(pattern).map(String::as_str),
+        }
+    }
+}
+
+/// Validates whether the value contains at least one of the words of the list
+#[must_use]
+pub fn validate_contains_word<T: AsRef<str>>(value: T, words: &WordList) -> bool {
+    words.is_match(value.as_ref())
+}
+
+/// Validates whether the value contains none of the words of the list
+#[must_use]
+pub fn validate_does_not_contain_word<T: AsRef<str>>(value: T, words: &WordList) -> bool {
+    !words.is_match(value.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+
+    static BANNED: WordList = WordList::new(&["spam", "scam", "free money"]);
+    static KEYWORDS: WordList = WordList::ignoring_ascii_case(&["rust", "serde"]);
+
+    #[test]
+    fn test_validate_word_lists() {
+        assert!(validate_does_not_contain_word("a message", &BANNED));
+        assert!(!validate_does_not_contain_word("get free money now", &BANNED));
+        assert!(validate_does_not_contain_word("SPAM", &BANNED));
+        assert!(validate_contains_word(String::from("Written in RUST"), &KEYWORDS));
+        assert!(validate_contains_word(Cow::from("serde_json"), &KEYWORDS));
+        assert!(!validate_contains_word("Python", &KEYWORDS));
+    }
+
+    #[test]
+    fn test_find_the_first_word() {
+        assert_eq!(BANNED.find("this scam is spam"), Some("scam"));
+        assert_eq!(KEYWORDS.find("Serde"), Some("serde"));
+        assert_eq!(BANNED.find(""), None);
+
+        let list = WordList::from_words(vec!["foo", "bar"], true);
+        assert_eq!(list.find("a BAR"), Some("bar"));
+        assert!(!list.is_match("baz"));
+    }
+}
@@ -1,4 +1,4 @@
-#[cfg(derive)]
+#[cfg(feature = "derive")]
 mod tests {
     use validator::Validate;
 
@@ -12,7 +12,7 @@ mod tests {
     fn test_message() {
         let bad_foo = Foo { foo: "hi!".into() };
         let err = format!("{}", bad_foo.validate().unwrap_err());
-        assert_eq!(err, "foo: Please provide a valid foo!");
+        assert_eq!(err, "foo: Please provide a valid foo! (length)");
     }
 
     #[derive(Validate)]
@@ -32,11 +32,11 @@ mod tests {
         let bad_foo = Foo { foo: "hi!".into() };
         let bad_bar = Bar { bar: bad_foo };
         let err = format!("{}", bad_bar.validate().unwrap_err());
-        assert_eq!(err, "bar.foo: Please provide a valid foo!");
+        assert_eq!(err, "bar.foo: Please provide a valid foo! (length)");
 
         let bad_deep_bar = DeepBar { deep_bar: bad_bar };
         let err = format!("{}", bad_deep_bar.validate().unwrap_err());
-        assert_eq!(err, "deep_bar.bar.foo: Please provide a valid foo!");
+        assert_eq!(err, "deep_bar.bar.foo: Please provide a valid foo! (length)");
     }
 
     #[derive(Validate)]
@@ -50,6 +50,44 @@ mod tests {
         let bad_foo = Foo { foo: "hi!".into() };
         let bad_baz = Baz { baz: vec![bad_foo] };
         let err = format!("{}", bad_baz.validate().unwrap_err());
-        assert_eq!(err, "baz[0].foo: Please provide a valid foo!");
+        assert_eq!(err, "baz[0].foo: Please provide a valid foo! (length)");
+    }
+
+    #[derive(Validate)]
+    struct Signup {
+        #[validate(email(message = "Not an email"), length(min = 5))]
+        mail: String,
+        #[validate(range(min = 18))]
+        age: u8,
+        #[validate]
+        baz: Baz,
+    }
+
+    fn invalid_signup() -> Signup {
+        Signup { mail: "bob".into(), age: 17, baz: Baz { baz: vec![Foo { foo: "hi!".into() }] } }
+    }
+
+    #[test]
+    fn test_one_line_per_error_in_declaration_order() {
+        let err = format!("{}", invalid_signup().validate().unwrap_err());
+        assert_eq!(
+            err,
+            "mail: Not an email (email)\n\
+             mail: length (min: 5, value: \"bob\")\n\
+             age: range (min: 18.0, value: 17)\n\
+             baz.baz[0].foo: Please provide a valid foo! (length)"
+        );
+    }
+
+    #[test]
+    fn test_compact() {
+        let err = invalid_signup().validate().unwrap_err();
+        assert_eq!(
+            err.display_compact().to_string(),
+            "mail: Not an email (email); \
+             mail: length (min: 5, value: \"bob\"); \
+             age: range (min: 18.0, value: 17); \
+             baz.baz[0].foo: Please provide a valid foo! (length)"
+        );
     }
 }
@@ -1,15 +1,24 @@
+use std::collections::HashMap;
+
+use std::sync::OnceLock;
+
 use proc_macro2::Span;
 use regex::Regex;
 
-use lazy_static::lazy_static;
 use proc_macro_error::abort;
 use syn::spanned::Spanned;
 
-lazy_static! {
-    pub static ref COW_TYPE: Regex = Regex::new(r"Cow<'[a-z]+,str>").unwrap();
-    pub static ref LEN_TYPE: Regex =
+pub fn cow_type() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"Cow<'[a-z]+,str>").unwrap())
+}
+
+fn len_type() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
         Regex::new(r"(Option<)?((Vec|HashMap|HashSet|BTreeMap|BTreeSet|IndexMap|IndexSet)<|\[)")
-            .unwrap();
+            .unwrap()
+    })
 }
 
 static CUSTOM_ARG_LIFETIME: &str = "v_a";
@@ -70,18 +79,76 @@ pub fn assert_string_type(name: &str, type_name: &str, field_type: &syn::Type) {
     }
 }
 
+/// The type of the field another field is checked against, or `None` if it is a path into a nested
+/// struct: the compiler checks the types of those
+fn find_other_field_type<'a>(
+    validator: &str,
+    field_name: &str,
+    other: &str,
+    field_types: &'a HashMap<String, String>,
+    field_attr: &syn::Attribute,
+) -> Option<&'a String> {
+    let mut path = other.split('.');
+    let head = path.next().unwrap_or_default();
+    if path.clone().any(|m| syn::parse_str::<syn::Member>(m).is_err()) {
+        abort!(
+            field_attr.span(),
+            "Invalid argument for `{}` validator of field `{}`: `{}` is not a path to a field",
+            validator,
+            field_name,
+            other
+        );
+    }
+
+    match field_types.get(head) {
+        Some(field_type) if head == other => Some(field_type),
+        Some(_) => None,
+        None => abort!(field_attr.span(), "Invalid argument for `{}` validator of field `{}`: the other field doesn't exist in struct", validator, field_name),
+    }
+}
+
 pub fn assert_type_matches(
     field_name: String,
     field_type: &str,
-    field_type2: Option<&String>,
+    other: &str,
+    field_types: &HashMap<String, String>,
     field_attr: &syn::Attribute,
 ) {
-    if let Some(t2) = field_type2 {
+    if let Some(t2) =
+        find_other_field_type("must_match", &field_name, other, field_types, field_attr)
+    {
         if field_type != t2 {
             abort!(field_attr.span(), "Invalid argument for `must_match` validator of field `{}`: types of field can't match", field_name);
         }
-    } else {
-        abort!(field_attr.span(), "Invalid argument for `must_match` validator of field `{}`: the other field doesn't exist in struct", field_name);
+    }
+}
+
+/// Checks that a field can be compared to the other one, their types have to be the same once the
+/// options are removed. Returns whether the other field is an option
+pub fn assert_comparable_types(
+    validator: &str,
+    field_name: String,
+    field_type: &str,
+    other: &str,
+    field_types: &HashMap<String, String>,
+    field_attr: &syn::Attribute,
+) -> bool {
+    fn strip_option(type_name: &str) -> Option<&str> {
+        type_name.strip_prefix("Option<").and_then(|t| t.strip_suffix('>'))
+    }
+
+    match find_other_field_type(validator, &field_name, other, field_types, field_attr) {
+        Some(t2) => {
+            let mut value_type = field_type;
+            while let Some(inner) = strip_option(value_type) {
+                value_type = inner;
+            }
+            if value_type != strip_option(t2).unwrap_or(t2) {
+                abort!(field_attr.span(), "Invalid argument for `{}` validator of field `{}`: types of field can't be compared", validator, field_name);
+            }
+            t2.starts_with("Option<")
+        }
+        None => false,
     }
 }
 
@@ -99,9 +166,9 @@ pub fn assert_has_len(field_name: String, type_name: &str, field_type: &syn::Typ
 
     if !type_name.contains("String")
         && !type_name.contains("str")
-        && !LEN_TYPE.is_match(type_name)
+        && !len_type().is_match(type_name)
         // a bit ugly
-        && !COW_TYPE.is_match(type_name)
+        && !cow_type().is_match(type_name)
     {
         abort!(field_type.span(),
                 "Validator `length` can only be used on types `String`, `&str`, Cow<'_,str>, `Vec`, slice, or map/set types (BTree/Hash/Index) but found `{}` for field `{}`",
@@ -121,6 +188,18 @@ pub fn assert_has_range(field_name: String, type_name: &str, field_type: &syn::T
     }
 }
 
+pub fn assert_has_bits(field_name: String, type_name: &str, field_type: &syn::Type) {
+    if !NUMBER_TYPES.contains(&type_name) || type_name.contains("f32") || type_name.contains("f64")
+    {
+        abort!(
+            field_type.span(),
+            "Validator `bits_within` can only be used on integer types but found `{}` for field `{}`",
+            type_name,
+            field_name
+        );
+    }
+}
+
 pub fn assert_custom_arg_type(field_span: &Span, field_type: &syn::Type) {
     match field_type {
         syn::Type::Reference(reference) => {
@@ -176,3 +255,47 @@ pub fn assert_custom_arg_type(field_span: &Span, field_type: &syn::Type) {
         }
     }
 }
+
+pub fn assert_custom_function(span: &Span, function: &str) {
+    match syn::parse_str::<syn::Expr>(function) {
+        Ok(syn::Expr::Path(_)) | Ok(syn::Expr::Closure(_)) => (),
+        _ => abort!(
+            span,
+            "Invalid `custom` validator: `{}` is neither the path of a function nor a closure",
+            function
+        ),
+    }
+}
+
+pub fn assert_regex_path(span: &Span, path: &str) {
+    match syn::parse_str::<syn::Expr>(path) {
+        Ok(syn::Expr::Path(_)) | Ok(syn::Expr::Call(_)) => (),
+        _ => abort!(
+            span,
+            "Invalid `regex` validator: `{}` is neither the path of a regex nor a function call",
+            path
+        ),
+    }
+}
+
+pub fn assert_word_list_path(span: &Span, path: &str) {
+    match syn::parse_str::<syn::Expr>(path) {
+        Ok(syn::Expr::Path(_)) | Ok(syn::Expr::Call(_)) => (),
+        _ => abort!(
+            span,
+            "Invalid `words` argument: `{}` is neither the path of a word list nor a function call",
+            path
+        ),
+    }
+}
+
+pub fn assert_message_fn(span: &Span, function: &str) {
+    match syn::parse_str::<syn::Expr>(function) {
+        Ok(syn::Expr::Path(_)) | Ok(syn::Expr::Closure(_)) => (),
+        _ => abort!(
+            span,
+            "Invalid `message_fn`: `{}` is neither the path of a function nor a closure",
+            function
+        ),
+    }
+}
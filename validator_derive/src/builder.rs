@@ -0,0 +1,176 @@
+use proc_macro_error::abort;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+
+use crate::validation::FieldInformation;
+use crate::{collect_fields, field_member, member_name};
+
+/// Generates the `<Struct>Builder` of `#[validate(builder)]`: it has a setter for each field and
+/// its `build` method validates the struct before returning it, so an invalid value can't be
+/// constructed through it.
+///
+/// Missing fields are reported as `required` errors, except for the `Option` fields which are
+/// `None` by default.
+pub fn impl_builder(
+    ast: &syn::DeriveInput,
+    fields_validations: &[FieldInformation],
+    generics: &syn::Generics,
+    arg_type: Option<&proc_macro2::TokenStream>,
+    code_prefix: &Option<String>,
+) -> proc_macro2::TokenStream {
+    let ident = &ast.ident;
+    let vis = &ast.vis;
+    let builder_ident = format_ident!("{}Builder", ident);
+    let builder_doc = format!("Builds a valid [`{}`], see `#[validate(builder)]`", ident);
+    let required_code = format!("{}required", code_prefix.as_deref().unwrap_or(""));
+
+    let mut idents = vec![];
+    let mut types = vec![];
+    let mut values = vec![];
+    let mut missing_checks = vec![];
+    for (index, field) in collect_fields(ast).iter().enumerate() {
+        let field_ident = match field.ident {
+            Some(ref field_ident) => field_ident,
+            None => abort!(
+                field.span(),
+                "`#[validate(builder)]` can only be used on structs with named fields"
+            ),
+        };
+        let field_type = &field.ty;
+        let is_option = matches!(field_type, syn::Type::Path(syn::TypePath { ref path, .. })
+            if path.segments.last().is_some_and(|segment| segment.ident == "Option"));
+
+        if is_option {
+            values.push(
+                quote!(#field_ident: self.#field_ident.unwrap_or(::std::option::Option::None)),
+            );
+        } else {
+            // The errors of a missing field are keyed like the errors of its validators
+            let member = field_member(field, index);
+            let name = fields_validations
+                .iter()
+                .find(|f| f.member == member)
+                .map_or_else(|| member_name(&member), |f| f.name.clone());
+            missing_checks.push(quote!(
+                if self.#field_ident.is_none() {
+                    missing.add(#name, ::validator::ValidationError::new(#required_code));
+                }
+            ));
+            values.push(quote!(#field_ident: self.#field_ident.unwrap()));
+        }
+        idents.push(field_ident.clone());
+        types.push(field_type.clone());
+    }
+
+    let struct_generics = &ast.generics;
+    let struct_where_clause = &ast.generics.where_clause;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (build_args, validate_call) = match arg_type {
+        Some(arg_type) => (
+            quote!(<'v_a>(self, args: #arg_type)),
+            quote!(::validator::ValidateArgs::validate_args(&value, args)),
+        ),
+        None => (quote!((self)), quote!(::validator::Validate::validate(&value))),
+    };
+    let setters = idents.iter().zip(types.iter()).map(|(field_ident, field_type)| {
+        quote!(
+            #vis fn #field_ident(mut self, value: impl ::std::convert::Into<#field_type>) -> Self {
+                self.#field_ident = ::std::option::Option::Some(value.into());
+                self
+            }
+        )
+    });
+
+    quote!(
+        #[doc = #builder_doc]
+        #vis struct #builder_ident #struct_generics #struct_where_clause {
+            #(#idents: ::std::option::Option<#types>,)*
+        }
+
+        impl #impl_generics ::std::default::Default for #builder_ident #ty_generics #where_clause {
+            fn default() -> Self {
+                #builder_ident { #(#idents: ::std::option::Option::None,)* }
+            }
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Starts building a value that is validated when it is built
+            #vis fn builder() -> #builder_ident #ty_generics {
+                ::std::default::Default::default()
+            }
+        }
+
+        impl #impl_generics #builder_ident #ty_generics #where_clause {
+            #(#setters)*
+
+            /// Returns the value if all the fields without a default are set and it is valid
+            #[allow(clippy::all)]
+            #[allow(unused_mut)]
+            #vis fn build #build_args -> ::std::result::Result<#ident #ty_generics, ::validator::ValidationErrors> {
+                let mut missing = ::validator::ValidationErrors::new();
+                #(#missing_checks)*
+                if !missing.is_empty() {
+                    return ::std::result::Result::Err(missing);
+                }
+
+                let value = #ident { #(#values,)* };
+                #validate_call?;
+                ::std::result::Result::Ok(value)
+            }
+        }
+    )
+}
+
+/// Generates the `new_validated` constructor of `#[validate(constructor)]`, taking all the fields
+/// in order (and the arguments or the context of the struct last) and returning the value only if
+/// it is valid
+pub fn impl_constructor(
+    ast: &syn::DeriveInput,
+    generics: &syn::Generics,
+    arg_type: Option<&proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    let ident = &ast.ident;
+    let vis = &ast.vis;
+    let fields = collect_fields(ast);
+
+    let params = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match field.ident {
+            Some(ref field_ident) => field_ident.clone(),
+            None => format_ident!("field_{}", index),
+        })
+        .collect::<Vec<_>>();
+    let types = fields.iter().map(|field| &field.ty);
+    let value = match fields.first().and_then(|field| field.ident.as_ref()) {
+        Some(_) => quote!(#ident { #(#params),* }),
+        None if fields.is_empty() => quote!(#ident {}),
+        None => quote!(#ident(#(#params),*)),
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (lifetime, args_param, validate_call) = match arg_type {
+        Some(arg_type) => (
+            quote!(<'v_a>),
+            quote!(args: #arg_type),
+            quote!(::validator::ValidateArgs::validate_args(&value, args)),
+        ),
+        None => (quote!(), quote!(), quote!(::validator::Validate::validate(&value))),
+    };
+
+    quote!(
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Creates the value from its fields, only returning it if it is valid
+            #[allow(clippy::all)]
+            #vis fn new_validated #lifetime(
+                #(#params: #types,)*
+                #args_param
+            ) -> ::std::result::Result<Self, ::validator::ValidationErrors> {
+                let value = #value;
+                #validate_call?;
+                ::std::result::Result::Ok(value)
+            }
+        }
+    )
+}
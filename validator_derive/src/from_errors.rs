@@ -0,0 +1,159 @@
+use proc_macro_error::abort;
+use quote::quote;
+use syn::spanned::Spanned;
+
+use crate::lit::lit_to_string;
+
+/// What a variant of the enum is mapped from
+enum Source {
+    /// The first error with that path and code, any of them being optional
+    Error { field: Option<String>, code: Option<String> },
+    /// All the errors that no other variant is mapped from
+    Fallback,
+}
+
+/// Implements `FromValidationErrors` for an enum whose variants have a
+/// `#[validation_error(field = "..", code = "..")]` or `#[validation_error(fallback)]`
+/// attribute. The variants are tried in order, and `From<ValidationErrors>` is implemented as
+/// well when there is a fallback.
+pub fn impl_from_validation_errors(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let variants = match ast.data {
+        syn::Data::Enum(syn::DataEnum { ref variants, .. }) => variants,
+        _ => abort!(ast.span(), "#[derive(FromValidationErrors)] can only be used with enums"),
+    };
+
+    let ident = &ast.ident;
+    let mut mappings = vec![];
+    let mut fallback = None;
+    for variant in variants {
+        let variant_ident = &variant.ident;
+        let attr = match variant.attrs.iter().find(|attr| attr.path.is_ident("validation_error")) {
+            Some(attr) => attr,
+            // The variants without the attribute are not mapped from validation errors
+            None => continue,
+        };
+        let has_field = match variant.fields {
+            syn::Fields::Unit => false,
+            syn::Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => true,
+            _ => abort!(
+                variant.span(),
+                "The variants of #[derive(FromValidationErrors)] can only be unit variants or have a single unnamed field"
+            ),
+        };
+
+        match parse_source(attr) {
+            Source::Error { field, code } => {
+                let field = option_str(&field);
+                let code = option_str(&code);
+                let value = if has_field {
+                    quote!(#ident::#variant_ident(::std::clone::Clone::clone(error)))
+                } else {
+                    quote!(#ident::#variant_ident)
+                };
+                mappings.push(quote!(
+                    if let ::std::option::Option::Some(error) =
+                        ::validator::__private::find_error(&errors, #field, #code)
+                    {
+                        return ::std::result::Result::Ok(#value);
+                    }
+                ));
+            }
+            Source::Fallback => {
+                if fallback.is_some() {
+                    abort!(attr.span(), "Only one variant can be the `fallback`");
+                }
+                fallback = Some(if has_field {
+                    quote!(#ident::#variant_ident(errors))
+                } else {
+                    quote!(#ident::#variant_ident)
+                });
+            }
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let (unmapped, from_impl) = match fallback {
+        Some(fallback) => (
+            quote!(::std::result::Result::Ok(#fallback)),
+            quote!(
+                impl #impl_generics ::std::convert::From<::validator::ValidationErrors> for #ident #ty_generics #where_clause {
+                    fn from(errors: ::validator::ValidationErrors) -> Self {
+                        match ::validator::FromValidationErrors::from_validation_errors(errors) {
+                            ::std::result::Result::Ok(value) => value,
+                            ::std::result::Result::Err(_) => ::std::unreachable!("the fallback variant maps all the errors"),
+                        }
+                    }
+                }
+            ),
+        ),
+        None => (quote!(::std::result::Result::Err(errors)), quote!()),
+    };
+
+    quote!(
+        #[allow(clippy::all)]
+        impl #impl_generics ::validator::FromValidationErrors for #ident #ty_generics #where_clause {
+            fn from_validation_errors(
+                errors: ::validator::ValidationErrors,
+            ) -> ::std::result::Result<Self, ::validator::ValidationErrors> {
+                #(#mappings)*
+                #unmapped
+            }
+        }
+
+        #from_impl
+    )
+}
+
+fn option_str(value: &Option<String>) -> proc_macro2::TokenStream {
+    match value {
+        Some(value) => quote!(::std::option::Option::Some(#value)),
+        None => quote!(::std::option::Option::None),
+    }
+}
+
+fn parse_source(attr: &syn::Attribute) -> Source {
+    let nested = match attr.parse_meta() {
+        Ok(syn::Meta::List(syn::MetaList { nested, .. })) if !nested.is_empty() => nested,
+        _ => abort!(
+            attr.span(),
+            "Invalid attribute #[validation_error]: it needs a `field`, a `code` or `fallback`, like #[validation_error(field = \"email\")]"
+        ),
+    };
+
+    let mut field = None;
+    let mut code = None;
+    let mut fallback = false;
+    for item in &nested {
+        match item {
+            syn::NestedMeta::Meta(syn::Meta::Path(ref path)) if path.is_ident("fallback") => {
+                fallback = true;
+            }
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                ref path,
+                ref lit,
+                ..
+            })) if path.is_ident("field") || path.is_ident("code") => {
+                let value = lit_to_string(lit).unwrap_or_else(|| {
+                    abort!(
+                        lit.span(),
+                        "invalid argument for #[validation_error]: only strings are allowed"
+                    )
+                });
+                if path.is_ident("field") {
+                    field = Some(value);
+                } else {
+                    code = Some(value);
+                }
+            }
+            _ => abort!(item.span(), "Unexpected item in #[validation_error]"),
+        }
+    }
+
+    if fallback {
+        if field.is_some() || code.is_some() {
+            abort!(attr.span(), "The `fallback` variant can't have a `field` or a `code`");
+        }
+        return Source::Fallback;
+    }
+    Source::Error { field, code }
+}
@@ -0,0 +1,247 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{parse_quote, Token};
+
+/// An item of a `#[garde(...)]` attribute, whose arguments can be expressions unlike the ones of
+/// `#[validate(...)]`
+enum Item {
+    /// `email`, the `chars` of `length(chars, min = 1)` or the regex of `pattern(RE)`
+    Path(syn::Path),
+    /// `min = 1`
+    NameValue(syn::Path, Box<syn::Expr>),
+    /// `length(min = 1)`
+    List(syn::Path, Vec<Item>),
+    /// The `"x"` of `contains("x")`
+    Lit(syn::Lit),
+}
+
+impl Parse for Item {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Lit) {
+            return Ok(Item::Lit(input.parse()?));
+        }
+        let path = input.call(syn::Path::parse_mod_style)?;
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            return Ok(Item::NameValue(path, Box::new(input.parse()?)));
+        }
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let items = Punctuated::<Item, Token![,]>::parse_terminated(&content)?;
+            return Ok(Item::List(path, items.into_iter().collect()));
+        }
+        Ok(Item::Path(path))
+    }
+}
+
+impl Item {
+    fn span(&self) -> proc_macro2::Span {
+        match self {
+            Item::Path(path) | Item::NameValue(path, _) | Item::List(path, _) => path.span(),
+            Item::Lit(lit) => lit.span(),
+        }
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Item::Path(path) | Item::NameValue(path, _) | Item::List(path, _) => {
+                path.to_token_stream().to_string().replace(' ', "")
+            }
+            Item::Lit(lit) => lit.to_token_stream().to_string(),
+        }
+    }
+}
+
+/// Replaces the `#[garde(...)]` attributes of the struct and of its fields by `#[validate(...)]`
+/// ones with the same rules, so the structs written for garde can derive `Validate` as they are
+/// while a codebase is migrated
+pub fn translate_attributes(ast: &mut syn::DeriveInput) {
+    ast.attrs = ast.attrs.drain(..).filter_map(translate_struct_attribute).collect();
+    if let syn::Data::Struct(syn::DataStruct { ref mut fields, .. }) = ast.data {
+        for (idx, field) in fields.iter_mut().enumerate() {
+            let name = match field.ident {
+                Some(ref ident) => ident.to_string(),
+                None => idx.to_string(),
+            };
+            field.attrs = field
+                .attrs
+                .drain(..)
+                .filter_map(|attr| translate_field_attribute(attr, &name))
+                .collect();
+        }
+    }
+}
+
+fn parse_items(attr: &syn::Attribute) -> Vec<Item> {
+    match attr.parse_args_with(Punctuated::<Item, Token![,]>::parse_terminated) {
+        Ok(items) => items.into_iter().collect(),
+        Err(e) => abort!(attr.span(), "Unable to parse this #[garde] attribute: {}", e),
+    }
+}
+
+fn validate_attribute(rules: Vec<TokenStream>) -> Option<syn::Attribute> {
+    if rules.is_empty() {
+        return None;
+    }
+    Some(parse_quote!(#[validate(#(#rules),*)]))
+}
+
+fn translate_struct_attribute(attr: syn::Attribute) -> Option<syn::Attribute> {
+    if !attr.path.is_ident("garde") {
+        return Some(attr);
+    }
+    let mut rules = vec![];
+    for item in parse_items(&attr) {
+        match item.name().as_str() {
+            // All the fields without validators are allowed
+            "allow_unvalidated" => (),
+            "transparent" => rules.push(quote!(transparent)),
+            name => abort!(
+                item.span(),
+                "The garde option `{}` has no equivalent, it needs to be translated by hand",
+                name
+            ),
+        }
+    }
+    validate_attribute(rules)
+}
+
+fn translate_field_attribute(attr: syn::Attribute, field: &str) -> Option<syn::Attribute> {
+    if !attr.path.is_ident("garde") {
+        return Some(attr);
+    }
+    let rules = parse_items(&attr).iter().filter_map(|item| translate_rule(item, field)).collect();
+    validate_attribute(rules)
+}
+
+/// The validator of a garde rule, `None` for `skip`
+fn translate_rule(item: &Item, field: &str) -> Option<TokenStream> {
+    let error = |msg: String| -> ! {
+        abort!(item.span(), "Invalid attribute #[garde] on field `{}`: {}", field, msg);
+    };
+    let no_equivalent = |hint: &str| -> ! {
+        error(format!("`{}` has no equivalent in validator, {}", item.name(), hint));
+    };
+    let string_argument = |args: &[Item]| match args {
+        [Item::Lit(syn::Lit::Str(s))] => s.value(),
+        _ => error(format!("`{}` needs a single string argument", item.name())),
+    };
+    let regex = |pattern: String, code: &str| quote!(regex(pattern = #pattern, code = #code));
+
+    let rule = match item {
+        Item::Path(path) => match item.name().as_str() {
+            "skip" => return None,
+            "required" | "email" | "url" | "credit_card" => quote!(#path),
+            "phone_number" => quote!(phone),
+            "dive" => quote!(nested),
+            "ascii" => regex("^[[:ascii:]]*$".to_string(), "ascii"),
+            "alphanumeric" => regex(r"^[\p{Alphabetic}\p{N}]*$".to_string(), "alphanumeric"),
+            "ip" | "ipv4" | "ipv6" => {
+                no_equivalent("use a `custom` validator calling `validate_ip`")
+            }
+            name => error(format!("unknown rule `{}`", name)),
+        },
+        Item::List(_, args) => match item.name().as_str() {
+            "length" => {
+                let is_bound = |name: &syn::Path| {
+                    name.is_ident("min") || name.is_ident("max") || name.is_ident("equal")
+                };
+                let args = args.iter().filter_map(|arg| match arg {
+                    // Only the lengths in characters can be checked
+                    Item::Path(mode) if mode.is_ident("chars") || mode.is_ident("simple") => None,
+                    Item::NameValue(name, value) if is_bound(name) => {
+                        let value = bound(value, field);
+                        Some(quote!(#name = #value))
+                    }
+                    arg => error(format!(
+                        "`{}` of `length` has no equivalent in validator, which counts characters",
+                        arg.name()
+                    )),
+                });
+                quote!(length(#(#args),*))
+            }
+            "range" => {
+                let args = args.iter().map(|arg| match arg {
+                    Item::NameValue(name, value)
+                        if name.is_ident("min") || name.is_ident("max") =>
+                    {
+                        let value = bound(value, field);
+                        quote!(#name = #value)
+                    }
+                    arg => error(format!("unknown argument `{}` of `range`", arg.name())),
+                });
+                quote!(range(#(#args),*))
+            }
+            "contains" => {
+                let needle = string_argument(args);
+                quote!(contains = #needle)
+            }
+            "prefix" => regex(format!("^{}", escape(&string_argument(args))), "prefix"),
+            "suffix" => regex(format!("{}$", escape(&string_argument(args))), "suffix"),
+            "pattern" => match args.as_slice() {
+                [Item::Lit(syn::Lit::Str(pattern))] => quote!(regex(pattern = #pattern)),
+                [Item::Path(path)] => {
+                    let path = path.to_token_stream().to_string();
+                    quote!(regex(path = #path))
+                }
+                _ => error("`pattern` needs a string or the path of a static regex".to_string()),
+            },
+            "matches" => match args.as_slice() {
+                [Item::Path(other)] if other.get_ident().is_some() => {
+                    let other = other.to_token_stream().to_string();
+                    quote!(must_match = #other)
+                }
+                _ => error("`matches` needs the name of a field".to_string()),
+            },
+            "inner" => {
+                let rules: Vec<_> =
+                    args.iter().filter_map(|arg| translate_rule(arg, field)).collect();
+                if rules.is_empty() {
+                    return None;
+                }
+                quote!(inner(#(#rules),*))
+            }
+            "custom" => no_equivalent(
+                "its functions take a context, use a `custom` validator returning a \
+                 `ValidationError`",
+            ),
+            name => error(format!("unknown rule `{}`", name)),
+        },
+        _ => error(format!("unknown rule `{}`", item.name())),
+    };
+    Some(rule)
+}
+
+/// A bound of `length` or `range` as an argument of `#[validate]`: the literals as they are and
+/// the paths of constants as strings
+fn bound(value: &syn::Expr, field: &str) -> TokenStream {
+    match value {
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => quote!(#lit),
+        syn::Expr::Path(syn::ExprPath { path, .. }) => {
+            let path = path.to_token_stream().to_string();
+            quote!(#path)
+        }
+        _ => abort!(
+            value.span(),
+            "Invalid attribute #[garde] on field `{}`: only literals and the paths of constants are allowed as bounds, a negative bound needs a constant",
+            field
+        ),
+    }
+}
+
+/// Escapes the characters of a regex
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\.+*?()|[]{}^$#&-~".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
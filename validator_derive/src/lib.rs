@@ -9,39 +9,140 @@ use quote::ToTokens;
 use quote::{quote, quote_spanned};
 use syn::{parse_quote, spanned::Spanned, GenericParam, Lifetime, LifetimeDef, Type};
 
-use asserts::{assert_has_len, assert_has_range, assert_string_type, assert_type_matches};
+use asserts::{
+    assert_comparable_types, assert_has_bits, assert_has_len, assert_has_range, assert_string_type,
+    assert_type_matches,
+};
 use lit::*;
-use quoting::{quote_schema_validations, quote_validator, FieldQuoter};
+#[cfg(feature = "async")]
+use quoting::{quote_custom_async_validation, quote_nested_async_validation};
+use quoting::{
+    quote_collection_validation, quote_schema_validations, quote_validator, FieldQuoter,
+};
+use rename::RenameRule;
 use validation::*;
-use validator_types::{CustomArgument, Validator};
+use validator_types::{Comparison, CustomArgument, Validator, ValueOrPath};
 
-use crate::asserts::assert_custom_arg_type;
+use crate::asserts::{
+    assert_custom_arg_type, assert_custom_function, assert_regex_path, assert_word_list_path,
+};
 
 mod asserts;
+mod builder;
+mod from_errors;
+#[cfg(feature = "garde")]
+mod garde;
 mod lit;
+mod normalize;
 mod quoting;
+mod rename;
+mod rules;
 mod validation;
 
-#[proc_macro_derive(Validate, attributes(validate))]
+#[cfg_attr(not(feature = "garde"), proc_macro_derive(Validate, attributes(validate)))]
+#[cfg_attr(feature = "garde", proc_macro_derive(Validate, attributes(validate, garde)))]
 #[proc_macro_error]
 pub fn derive_validation(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = syn::parse(input).unwrap();
+    #[allow(unused_mut)]
+    let mut ast = syn::parse(input).unwrap();
+    #[cfg(feature = "garde")]
+    garde::translate_attributes(&mut ast);
     impl_validate(&ast).into()
 }
 
+#[proc_macro_derive(Normalize, attributes(normalize))]
+#[proc_macro_error]
+pub fn derive_normalize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).unwrap();
+    normalize::impl_normalize(&ast).into()
+}
+
+#[proc_macro_derive(FromValidationErrors, attributes(validation_error))]
+#[proc_macro_error]
+pub fn derive_from_validation_errors(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).unwrap();
+    from_errors::impl_from_validation_errors(&ast).into()
+}
+
+#[cfg(feature = "async")]
+#[proc_macro_derive(ValidateAsync, attributes(validate))]
+#[proc_macro_error]
+pub fn derive_validation_async(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).unwrap();
+    impl_validate_async(&ast).into()
+}
+
 fn impl_validate(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    if is_transparent(&ast.attrs) {
+        return impl_validate_transparent(ast);
+    }
+
     // Collecting the validators
     let mut fields_validations = collect_field_validations(ast);
     let mut struct_validations = find_struct_validations(&ast.attrs);
     let (arg_type, has_arg) =
         construct_validator_argument_type(&mut fields_validations, &mut struct_validations);
-    let (validations, nested_validations) = quote_field_validations(fields_validations);
+    let context = find_struct_context(&ast.attrs);
+    let (arg_type, has_arg) =
+        context_argument_type(ast, context.as_ref(), &fields_validations, &struct_validations)
+            .map_or((arg_type, has_arg), |context_type| (context_type, true));
+    let nested_members = find_nested_members(&fields_validations);
+    let code_prefix = find_struct_code_prefix(&ast.attrs);
+    let field_rules =
+        rules::quote_validation_rules(&fields_validations, &struct_validations, &code_prefix);
+    let generics = add_nested_bounds(ast, &nested_members);
+    let builder = if is_builder(&ast.attrs) {
+        let builder_arg_type = if has_arg { Some(&arg_type) } else { None };
+        builder::impl_builder(ast, &fields_validations, &generics, builder_arg_type, &code_prefix)
+    } else {
+        quote!()
+    };
+    let constructor = if is_constructor(&ast.attrs) {
+        let constructor_arg_type = if has_arg { Some(&arg_type) } else { None };
+        builder::impl_constructor(ast, &generics, constructor_arg_type)
+    } else {
+        quote!()
+    };
+    // The errors are ordered like the fields once the nested ones are merged
+    let field_names = fields_validations.iter().map(|f| f.name.clone()).collect::<Vec<_>>();
+    let (validations, nested_validations) =
+        quote_field_validations(fields_validations, &code_prefix);
+
+    let schema_validations = quote_schema_validations(&struct_validations, &code_prefix);
+    // The struct level validations with `skip_on_field_errors` only look at the errors of the fields
+    let has_field_errors = if struct_validations.is_empty() {
+        quote!()
+    } else {
+        quote!(let has_field_errors = errors.is_blocking();)
+    };
 
-    let schema_validations = quote_schema_validations(&struct_validations);
+    // In fail fast mode, the validation returns as soon as an error is found unless it is
+    // lenient, and in any mode when a limit of `validate_with_config` is reached
+    let fail_fast = find_struct_fail_fast(&ast.attrs);
+    let return_on_error =
+        quote!(if FAIL_FAST && !::validator::__private::is_lenient() && errors.is_blocking()
+            || ::validator::__private::should_stop() && !errors.is_empty()
+        {
+            return ::std::result::Result::Err(errors);
+        });
+    let validations = validations.iter().map(|v| quote!(#v #return_on_error));
+    let schema_validations = schema_validations.iter().map(|v| quote!(#v #return_on_error));
+    let nested_validations = nested_validations.iter().map(|v| {
+        quote!(
+            #v
+            if FAIL_FAST
+                && !::validator::__private::is_lenient()
+                && ::validator::__private::is_blocking(&result)
+                || ::validator::__private::should_stop()
+            {
+                return result;
+            }
+        )
+    });
 
     // Struct specific definitions
     let ident = &ast.ident;
-    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     // The Validate trait implementation
     let validate_trait_impl = if !has_arg {
@@ -51,40 +152,92 @@ fn impl_validate(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
                     use ::validator::ValidateArgs;
                     self.validate_args(())
                 }
+
+                fn validate_fail_fast(&self) -> ::std::result::Result<(), ::validator::ValidationErrors> {
+                    ::validator::__private::observed::<Self>(self.__validator_validate::<true>(()))
+                }
+            }
+        )
+    } else {
+        quote!()
+    };
+
+    // The ValidateWithContext trait implementation, forwarding the context as the argument
+    let validate_with_context_trait_impl = if let Some(context) = context {
+        quote!(
+            impl #impl_generics ::validator::ValidateWithContext for #ident #ty_generics #where_clause {
+                type Context = #context;
+
+                fn validate_with_context(
+                    &self,
+                    context: &Self::Context,
+                ) -> ::std::result::Result<(), ::validator::ValidationErrors> {
+                    ::validator::ValidateArgs::validate_args(self, context)
+                }
             }
         )
     } else {
         quote!()
     };
 
+    // The ValidationRules trait implementation, describing the validators as data
+    let validation_rules_trait_impl = quote!(
+        impl #impl_generics ::validator::ValidationRules for #ident #ty_generics #where_clause {
+            fn validation_rules() -> ::std::vec::Vec<::validator::FieldRules> {
+                ::std::vec![#(#field_rules),*]
+            }
+        }
+    );
+
     // Adding the validator lifetime 'v_a
-    let mut expanded_generic = ast.generics.clone();
+    let mut expanded_generic = generics.clone();
     expanded_generic
         .params
         .insert(0, GenericParam::Lifetime(LifetimeDef::new(Lifetime::new("'v_a", ast.span()))));
 
-    let (impl_generics, _, _) = expanded_generic.split_for_impl();
+    let (expanded_impl_generics, _, _) = expanded_generic.split_for_impl();
 
     // Implementing ValidateArgs
     let impl_ast = quote!(
         #validate_trait_impl
 
+        #validate_with_context_trait_impl
+
+        #validation_rules_trait_impl
+
+        #builder
+
+        #constructor
+
         // We need this here to prevent formatting lints that can be caused by `quote_spanned!`
         // See: rust-lang/rust-clippy#6249 for more reference
         #[allow(clippy::all)]
         // Triggers when single_use_lifetimes rustc lint is configured in user project and there are no
         // usages of 'v_a lifetime in the generated impl definition
         #[allow(single_use_lifetimes)]
-        impl #impl_generics ::validator::ValidateArgs<'v_a> for #ident #ty_generics #where_clause {
+        impl #expanded_impl_generics ::validator::ValidateArgs<'v_a> for #ident #ty_generics #where_clause {
             type Args = #arg_type;
 
-            #[allow(unused_mut)]
-            #[allow(unused_variable)]
             fn validate_args(&self, args: Self::Args) -> ::std::result::Result<(), ::validator::ValidationErrors> {
+                ::validator::__private::observed::<Self>(self.__validator_validate::<#fail_fast>(args))
+            }
+        }
+
+        #[allow(clippy::all)]
+        #[allow(single_use_lifetimes)]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #[doc(hidden)]
+            #[allow(unused_mut)]
+            #[allow(unused_variables)]
+            fn __validator_validate<'v_a, const FAIL_FAST: bool>(
+                &self,
+                args: #arg_type,
+            ) -> ::std::result::Result<(), ::validator::ValidationErrors> {
                 let mut errors = ::validator::ValidationErrors::new();
 
                 #(#validations)*
 
+                #has_field_errors
                 #(#schema_validations)*
 
                 let mut result = if errors.is_empty() {
@@ -94,7 +247,10 @@ fn impl_validate(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
                 };
 
                 #(#nested_validations)*
-                result
+                result.map_err(|mut errors| {
+                    errors.set_field_order(&[#(#field_names),*]);
+                    errors
+                })
             }
         }
     );
@@ -104,37 +260,269 @@ fn impl_validate(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     impl_ast
 }
 
+/// A transparent newtype uses the validation of the type it wraps and returns its errors as is
+fn impl_validate_transparent(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let fields = collect_fields(ast);
+    if fields.len() != 1 {
+        abort!(
+            ast.span(),
+            "`#[validate(transparent)]` can only be used on structs with a single field"
+        );
+    }
+    if !find_struct_validations(&ast.attrs).is_empty() {
+        abort!(ast.span(), "`#[validate(transparent)]` can't be combined with schema validations");
+    }
+    if find_struct_context(&ast.attrs).is_some() {
+        abort!(ast.span(), "`#[validate(transparent)]` can't be combined with a context");
+    }
+    if is_builder(&ast.attrs) {
+        abort!(ast.span(), "`#[validate(transparent)]` can't be combined with a builder");
+    }
+    if let Some(attr) = fields[0].attrs.iter().find(|attr| attr.path == parse_quote!(validate)) {
+        abort!(
+            attr.span(),
+            "The field of a `#[validate(transparent)]` struct can't have validators, the validation of its type is used"
+        );
+    }
+
+    let member = field_member(&fields[0], 0);
+    let ident = &ast.ident;
+    let generics = add_nested_bounds(ast, std::slice::from_ref(&member));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut expanded_generic = generics.clone();
+    expanded_generic
+        .params
+        .insert(0, GenericParam::Lifetime(LifetimeDef::new(Lifetime::new("'v_a", ast.span()))));
+    let (args_impl_generics, _, _) = expanded_generic.split_for_impl();
+    let constructor = if is_constructor(&ast.attrs) {
+        builder::impl_constructor(ast, &generics, None)
+    } else {
+        quote!()
+    };
+
+    quote!(
+        #constructor
+
+        impl #impl_generics ::validator::Validate for #ident #ty_generics #where_clause {
+            fn validate(&self) -> ::std::result::Result<(), ::validator::ValidationErrors> {
+                ::validator::Validate::validate(&self.#member)
+            }
+
+            fn validate_fail_fast(&self) -> ::std::result::Result<(), ::validator::ValidationErrors> {
+                ::validator::Validate::validate_fail_fast(&self.#member)
+            }
+        }
+
+        #[allow(clippy::all)]
+        #[allow(single_use_lifetimes)]
+        impl #args_impl_generics ::validator::ValidateArgs<'v_a> for #ident #ty_generics #where_clause {
+            type Args = ();
+
+            fn validate_args(&self, _args: Self::Args) -> ::std::result::Result<(), ::validator::ValidationErrors> {
+                ::validator::Validate::validate(&self.#member)
+            }
+        }
+    )
+}
+
+/// The `ValidateAsync` implementation only awaits the `custom_async` validators in
+/// `validate_async_only`, all the other ones are run by the `Validate` implementation that
+/// `validate_async` calls first
+#[cfg(feature = "async")]
+fn impl_validate_async(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let fields_validations = collect_field_validations(ast);
+    let nested_members = find_nested_members(&fields_validations);
+    let code_prefix = find_struct_code_prefix(&ast.attrs);
+    let field_names = fields_validations.iter().map(|f| f.name.clone()).collect::<Vec<_>>();
+    let async_validations = quote_field_async_validations(fields_validations, &code_prefix);
+
+    let ident = &ast.ident;
+    let generics = add_nested_bounds(ast, &nested_members);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote!(
+        #[allow(clippy::all)]
+        impl #impl_generics ::validator::ValidateAsync for #ident #ty_generics #where_clause {
+            fn validate_async_only(
+                &self,
+            ) -> ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<
+                Output = ::std::result::Result<(), ::validator::ValidationErrors>
+            > + ::std::marker::Send + '_>> {
+                ::std::boxed::Box::pin(async move {
+                    #[allow(unused_mut)]
+                    let mut errors = ::validator::ValidationErrors::new();
+
+                    #(#async_validations)*
+
+                    if errors.is_empty() {
+                        ::std::result::Result::Ok(())
+                    } else {
+                        ::std::result::Result::Err(errors)
+                    }
+                })
+            }
+
+            fn validate_async(
+                &self,
+            ) -> ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<
+                Output = ::std::result::Result<(), ::validator::ValidationErrors>
+            > + ::std::marker::Send + '_>> {
+                ::std::boxed::Box::pin(async move {
+                    let result = ::validator::Validate::validate(self);
+                    let async_result = ::validator::ValidateAsync::validate_async_only(self).await;
+                    ::validator::__private::merge_async(result, async_result).map_err(|mut errors| {
+                        errors.set_field_order(&[#(#field_names),*]);
+                        errors
+                    })
+                })
+            }
+        }
+    )
+}
+
 fn collect_fields(ast: &syn::DeriveInput) -> Vec<syn::Field> {
     match ast.data {
         syn::Data::Struct(syn::DataStruct { ref fields, .. }) => {
-            if fields.iter().any(|field| field.ident.is_none()) {
-                abort!(
-                    fields.span(),
-                    "struct has unnamed fields";
-                    help = "#[derive(Validate)] can only be used on structs with named fields";
-                );
-            }
             fields.iter().cloned().collect::<Vec<_>>()
         }
         _ => abort!(ast.span(), "#[derive(Validate)] can only be used with structs"),
     }
 }
 
+/// How a field is accessed: by its name or by its position for tuple structs
+fn field_member(field: &syn::Field, index: usize) -> syn::Member {
+    match field.ident {
+        Some(ref ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(syn::Index { index: index as u32, span: field.span() }),
+    }
+}
+
+/// The name of a field, which is its position for tuple structs
+fn member_name(member: &syn::Member) -> String {
+    match member {
+        syn::Member::Named(ident) => ident.to_string(),
+        syn::Member::Unnamed(index) => index.index.to_string(),
+    }
+}
+
+/// The fields that are validated as nested structs
+fn find_nested_members(fields_validations: &[FieldInformation]) -> Vec<syn::Member> {
+    fields_validations
+        .iter()
+        .filter(|x| x.validations.iter().any(|v| matches!(v.validator, Validator::Nested)))
+        .map(|x| x.member.clone())
+        .collect()
+}
+
+/// Adds a `Validate` bound on the type validated by the given nested fields when it depends on
+/// the type parameters of the struct, so generic structs don't need to write it themselves
+fn add_nested_bounds(ast: &syn::DeriveInput, nested_members: &[syn::Member]) -> syn::Generics {
+    let mut generics = ast.generics.clone();
+    let type_params = generics.type_params().map(|p| p.ident.clone()).collect::<Vec<_>>();
+    if type_params.is_empty() {
+        return generics;
+    }
+
+    for (index, field) in collect_fields(ast).iter().enumerate() {
+        if !nested_members.contains(&field_member(field, index)) {
+            continue;
+        }
+
+        let nested_type = nested_value_type(&field.ty);
+        if mentions_any(nested_type.to_token_stream(), &type_params) {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#nested_type: ::validator::Validate));
+        }
+    }
+
+    generics
+}
+
+/// The type that implements `Validate` for a nested field: the field type itself or the
+/// elements of the collection for lists and maps, behind any `Option` and reference
+fn nested_value_type(ty: &syn::Type) -> &syn::Type {
+    if let Some(element) = find_element_type(ty) {
+        return element;
+    }
+
+    match ty {
+        syn::Type::Reference(syn::TypeReference { ref elem, .. })
+        | syn::Type::Group(syn::TypeGroup { ref elem, .. })
+        | syn::Type::Paren(syn::TypeParen { ref elem, .. }) => nested_value_type(elem),
+        syn::Type::Path(syn::TypePath { ref path, .. }) => match path.segments.last() {
+            Some(segment) if segment.ident == "Option" => {
+                generic_type_arg(segment, 0).map_or(ty, nested_value_type)
+            }
+            _ => ty,
+        },
+        _ => ty,
+    }
+}
+
+/// Finds the type of the elements of a list or of the values of a map, looking through `Option`
+/// and references
+fn find_element_type(ty: &syn::Type) -> Option<&syn::Type> {
+    match ty {
+        syn::Type::Reference(syn::TypeReference { ref elem, .. })
+        | syn::Type::Group(syn::TypeGroup { ref elem, .. })
+        | syn::Type::Paren(syn::TypeParen { ref elem, .. }) => find_element_type(elem),
+        syn::Type::Array(syn::TypeArray { ref elem, .. })
+        | syn::Type::Slice(syn::TypeSlice { ref elem, .. }) => Some(elem),
+        syn::Type::Path(syn::TypePath { ref path, .. }) => {
+            let segment = path.segments.last()?;
+            match segment.ident.to_string().as_ref() {
+                "Option" => generic_type_arg(segment, 0).and_then(find_element_type),
+                "Vec" | "HashSet" | "BTreeSet" | "IndexSet" => generic_type_arg(segment, 0),
+                "HashMap" | "FxHashMap" | "FnvHashMap" | "BTreeMap" | "IndexMap" => {
+                    generic_type_arg(segment, 1)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The type given as generic argument at `position`, like `T` at 0 in `Vec<T>`
+fn generic_type_arg(segment: &syn::PathSegment, position: usize) -> Option<&syn::Type> {
+    match segment.arguments {
+        syn::PathArguments::AngleBracketed(ref args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .nth(position),
+        _ => None,
+    }
+}
+
+/// Whether any of the identifiers appears in the tokens
+fn mentions_any(tokens: proc_macro2::TokenStream, idents: &[syn::Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ref ident) => idents.contains(ident),
+        proc_macro2::TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        _ => false,
+    })
+}
+
 fn collect_field_validations(ast: &syn::DeriveInput) -> Vec<FieldInformation> {
     let mut fields = collect_fields(ast);
 
     let field_types = find_fields_type(&fields);
-    fields.drain(..).fold(vec![], |mut acc, field| {
-        let key = field.ident.clone().unwrap().to_string();
-        let (name, validations) = find_validators_for_field(&field, &field_types);
-        acc.push(FieldInformation::new(
-            field,
-            field_types.get(&key).unwrap().clone(),
-            name,
-            validations,
-        ));
-        acc
-    })
+    let rename_all = find_rename_all(&ast.attrs);
+    fields.drain(..).enumerate().filter(|(_, field)| !is_skipped(field)).fold(
+        vec![],
+        |mut acc, (index, field)| {
+            let member = field_member(&field, index);
+            acc.push(find_validators_for_field(&field, member, &field_types, rename_all));
+            acc
+        },
+    )
 }
 
 fn construct_validator_argument_type(
@@ -189,30 +577,195 @@ fn construct_validator_argument_type(
     }
 }
 
+/// When the struct has a context, its validators get a reference to it as the argument instead.
+/// Returns the argument type in that case and checks that `use_context` is only used with one
+fn context_argument_type(
+    ast: &syn::DeriveInput,
+    context: Option<&syn::Type>,
+    fields_validations: &[FieldInformation],
+    struct_validations: &[SchemaValidation],
+) -> Option<proc_macro2::TokenStream> {
+    let has_custom_arg = fields_validations
+        .iter()
+        .flat_map(|x| x.validations.iter())
+        .any(|x| x.validator.get_custom_argument().is_some())
+        || struct_validations.iter().any(|x| x.args.is_some());
+    let uses_context = fields_validations
+        .iter()
+        .flat_map(|x| x.validations.iter())
+        .any(|x| matches!(x.validator, Validator::Custom { use_context: true, .. }))
+        || struct_validations.iter().any(|x| x.use_context);
+
+    match context {
+        Some(context) => {
+            if has_custom_arg {
+                abort!(
+                    ast.span(),
+                    "`arg` can't be used on a struct with a `#[validate(context = ...)]`, use `use_context` instead"
+                );
+            }
+            Some(quote!(&'v_a #context))
+        }
+        None => {
+            if uses_context {
+                abort!(
+                    ast.span(),
+                    "`use_context` requires the struct to have a `#[validate(context = \"Type\")]` attribute"
+                );
+            }
+            None
+        }
+    }
+}
+
 fn quote_field_validations(
     mut fields: Vec<FieldInformation>,
+    code_prefix: &Option<String>,
 ) -> (Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>) {
     let mut validations = vec![];
     let mut nested_validations = vec![];
 
     fields.drain(..).for_each(|x| {
-        let field_ident = x.field.ident.clone().unwrap();
-        let field_quoter = FieldQuoter::new(field_ident, x.name, x.field_type);
+        let mut key_validations = vec![];
+        if let Some(keys) = x.keys {
+            let key_quoter = FieldQuoter::new_map_key(keys.field_type)
+                .with_code_prefix(code_prefix.clone())
+                .with_redact(x.redact)
+                .with_spans(x.spans);
+            for validation in &keys.validations {
+                quote_validator(&key_quoter, validation, &mut key_validations, &mut vec![]);
+            }
+        }
 
+        let mut inner_validations = vec![];
+        if let Some(inner) = x.inner {
+            let element_quoter = FieldQuoter::new_element(&x.member, inner.field_type)
+                .with_code_prefix(code_prefix.clone())
+                .with_redact(x.redact)
+                .with_spans(x.spans);
+            for validation in &inner.validations {
+                quote_validator(&element_quoter, validation, &mut inner_validations, &mut vec![]);
+            }
+        }
+
+        let field_quoter = FieldQuoter::new(x.member, x.name, x.field_type)
+            .with_key_validations(key_validations)
+            .with_inner_validations(inner_validations)
+            .with_code_prefix(code_prefix.clone())
+            .with_redact(x.redact)
+            .with_spans(x.spans)
+            .with_parallel(x.parallel);
+
+        let mut field_validations = vec![];
+        let mut field_nested_validations = vec![];
+        if field_quoter.has_element_validations()
+            && !x.validations.iter().any(|v| matches!(v.validator, Validator::Nested))
+        {
+            field_nested_validations.push(quote_collection_validation(&field_quoter));
+        }
         for validation in &x.validations {
-            quote_validator(&field_quoter, validation, &mut validations, &mut nested_validations);
+            let severity = match validation.severity.as_deref() {
+                Some("warning") => quote!(::validator::Severity::Warning),
+                Some("info") => quote!(::validator::Severity::Info),
+                _ => {
+                    quote_validator(
+                        &field_quoter,
+                        validation,
+                        &mut field_validations,
+                        &mut field_nested_validations,
+                    );
+                    continue;
+                }
+            };
+
+            // The validators with another severity only run in reports, their errors are added
+            // with that severity
+            let mut reported = vec![];
+            quote_validator(&field_quoter, validation, &mut reported, &mut vec![]);
+            field_validations.push(quote!(
+                if ::validator::__private::is_reporting() {
+                    let mut __reported = ::std::mem::take(&mut errors);
+                    #(#reported)*
+                    ::validator::__private::add_with_severity(&mut __reported, errors, #severity);
+                    errors = __reported;
+                }
+            ));
+        }
+
+        match x.condition {
+            Some(condition) => {
+                if !field_validations.is_empty() {
+                    validations.push(quote!(if #condition { #(#field_validations)* }));
+                }
+                if !field_nested_validations.is_empty() {
+                    nested_validations
+                        .push(quote!(if #condition { #(#field_nested_validations)* }));
+                }
+            }
+            None => {
+                validations.append(&mut field_validations);
+                nested_validations.append(&mut field_nested_validations);
+            }
         }
     });
 
     (validations, nested_validations)
 }
 
+#[cfg(feature = "async")]
+fn quote_field_async_validations(
+    mut fields: Vec<FieldInformation>,
+    code_prefix: &Option<String>,
+) -> Vec<proc_macro2::TokenStream> {
+    let mut validations = vec![];
+
+    fields.drain(..).for_each(|x| {
+        let field_quoter = FieldQuoter::new(x.member, x.name, x.field_type)
+            .with_code_prefix(code_prefix.clone())
+            .with_redact(x.redact);
+
+        let field_validations = x
+            .validations
+            .iter()
+            .filter_map(|v| match v.validator {
+                Validator::CustomAsync { .. } => {
+                    Some(quote_custom_async_validation(&field_quoter, v))
+                }
+                Validator::Nested | Validator::RequiredNested => {
+                    Some(quote_nested_async_validation(&field_quoter))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if field_validations.is_empty() {
+            return;
+        }
+
+        match x.condition {
+            Some(condition) => validations.push(quote!(if #condition { #(#field_validations)* })),
+            None => validations.extend(field_validations),
+        }
+    });
+
+    validations
+}
+
 /// Find if a struct has some schema validation and returns the info if so
 fn find_struct_validation(attr: &syn::Attribute) -> SchemaValidation {
     let error = |span: Span, msg: &str| -> ! {
         abort!(span, "Invalid schema level validation: {}", msg);
     };
 
+    if let Ok(syn::Meta::List(syn::MetaList { ref nested, .. })) = attr.parse_meta() {
+        let is_expr = nested.iter().any(|item| {
+            matches!(item, syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { ref path, .. })) if path.is_ident("expr"))
+        });
+        if is_expr {
+            return find_struct_expr_validation(nested);
+        }
+    }
+
     if_chain! {
         if let Ok(syn::Meta::List(syn::MetaList { ref nested, .. })) = attr.parse_meta();
         if let syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { ref path, ref nested, .. })) = nested[0];
@@ -225,9 +778,11 @@ fn find_struct_validation(attr: &syn::Attribute) -> SchemaValidation {
 
             let mut function = String::new();
             let mut skip_on_field_errors = true;
+            let mut order = None;
             let mut code = None;
             let mut message = None;
             let mut args = None;
+            let mut use_context = false;
 
             for arg in nested {
                 if_chain! {
@@ -250,6 +805,13 @@ fn find_struct_validation(attr: &syn::Attribute) -> SchemaValidation {
                                     : only a bool is allowed"),
                                 };
                             },
+                            "order" => {
+                                order = match lit_to_int(lit) {
+                                    Some(s) => Some(s),
+                                    None => error(lit.span(), "invalid argument type for `order` \
+                                    : only an integer is allowed"),
+                                };
+                            },
                             "code" => {
                                 code = match lit_to_string(lit) {
                                     Some(s) => Some(s),
@@ -285,19 +847,32 @@ fn find_struct_validation(attr: &syn::Attribute) -> SchemaValidation {
                             _ => error(lit.span(), "Unknown argument")
                         }
                     } else {
+                        if let syn::NestedMeta::Meta(syn::Meta::Path(ref path)) = *arg {
+                            if path.is_ident("use_context") {
+                                use_context = true;
+                                continue;
+                            }
+                        }
                         error(arg.span(), "Unexpected args")
                     }
                 }
             }
 
+            if use_context && args.is_some() {
+                error(path.span(), "`arg` and `use_context` can't be combined");
+            }
+
             if function.is_empty() {
                 error(path.span(), "`function` is required");
             }
 
             SchemaValidation {
                 function,
+                expr: None,
                 args,
+                use_context,
                 skip_on_field_errors,
+                order,
                 code,
                 message,
             }
@@ -307,13 +882,249 @@ fn find_struct_validation(attr: &syn::Attribute) -> SchemaValidation {
     }
 }
 
+/// A struct level `#[validate(expr = "...", code = "...", message = "...")]` checking a boolean
+/// expression over `self`
+fn find_struct_expr_validation(
+    nested: &syn::punctuated::Punctuated<syn::NestedMeta, syn::token::Comma>,
+) -> SchemaValidation {
+    let error = |span: Span, msg: &str| -> ! {
+        abort!(span, "Invalid schema level validation: {}", msg);
+    };
+
+    let mut expr = None;
+    let mut skip_on_field_errors = true;
+    let mut order = None;
+    let mut code = None;
+    let mut message = None;
+
+    for arg in nested {
+        if let syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+            ref path,
+            ref lit,
+            ..
+        })) = *arg
+        {
+            let ident = path.get_ident().unwrap();
+            match ident.to_string().as_ref() {
+                "expr" => {
+                    expr = match lit_to_string(lit) {
+                        Some(s) if syn::parse_str::<syn::Expr>(&s).is_ok() => Some(s),
+                        Some(_) => error(lit.span(), "`expr` needs to be a valid expression"),
+                        None => error(
+                            lit.span(),
+                            "invalid argument type for `expr` : only a string is allowed",
+                        ),
+                    };
+                }
+                "skip_on_field_errors" => {
+                    skip_on_field_errors = match lit_to_bool(lit) {
+                        Some(s) => s,
+                        None => error(
+                            lit.span(),
+                            "invalid argument type for `skip_on_field_errors` : only a bool is allowed",
+                        ),
+                    };
+                }
+                "order" => {
+                    order = match lit_to_int(lit) {
+                        Some(s) => Some(s),
+                        None => error(
+                            lit.span(),
+                            "invalid argument type for `order` : only an integer is allowed",
+                        ),
+                    };
+                }
+                "code" => {
+                    code = match lit_to_string(lit) {
+                        Some(s) => Some(s),
+                        None => error(
+                            lit.span(),
+                            "invalid argument type for `code` : only a string is allowed",
+                        ),
+                    };
+                }
+                "message" => {
+                    message = match lit_to_string(lit) {
+                        Some(s) => Some(s),
+                        None => error(
+                            lit.span(),
+                            "invalid argument type for `message` : only a string is allowed",
+                        ),
+                    };
+                }
+                _ => error(lit.span(), "Unknown argument"),
+            }
+        } else {
+            error(arg.span(), "Unexpected args")
+        }
+    }
+
+    SchemaValidation {
+        function: String::new(),
+        expr,
+        args: None,
+        use_context: false,
+        skip_on_field_errors,
+        order,
+        code,
+        message,
+    }
+}
+
+/// Whether the attribute is a flag of the struct, like `#[validate(transparent)]`
+fn is_struct_flag_attribute(attr: &syn::Attribute, flag: &str) -> bool {
+    if_chain! {
+        if attr.path == parse_quote!(validate);
+        if let Ok(syn::Meta::List(syn::MetaList { ref nested, .. })) = attr.parse_meta();
+        if nested.len() == 1;
+        if let syn::NestedMeta::Meta(syn::Meta::Path(ref path)) = nested[0];
+        then {
+            path.is_ident(flag)
+        } else {
+            false
+        }
+    }
+}
+
+/// Whether the attribute is `#[validate(transparent)]`
+fn is_transparent_attribute(attr: &syn::Attribute) -> bool {
+    is_struct_flag_attribute(attr, "transparent")
+}
+
+/// Whether the attribute contains `skip`, like `#[validate(skip)]`
+fn is_skip_attribute(attr: &syn::Attribute) -> bool {
+    if_chain! {
+        if attr.path == parse_quote!(validate);
+        if let Ok(syn::Meta::List(syn::MetaList { ref nested, .. })) = attr.parse_meta();
+        then {
+            nested.iter().any(|item| {
+                matches!(item, syn::NestedMeta::Meta(syn::Meta::Path(ref path)) if path.is_ident("skip"))
+            })
+        } else {
+            false
+        }
+    }
+}
+
+/// Finds whether the field is excluded from the validation, in which case it can't have any
+/// other validation attribute
+fn is_skipped(field: &syn::Field) -> bool {
+    let validate_attrs =
+        field.attrs.iter().filter(|attr| attr.path == parse_quote!(validate)).collect::<Vec<_>>();
+    if !validate_attrs.iter().any(|attr| is_skip_attribute(attr)) {
+        return false;
+    }
+
+    let only_skip = validate_attrs.iter().all(|attr| {
+        is_skip_attribute(attr)
+            && matches!(attr.parse_meta(), Ok(syn::Meta::List(ref list)) if list.nested.len() == 1)
+    });
+    if !only_skip {
+        abort!(field.span(), "`#[validate(skip)]` can't be combined with other validators");
+    }
+    true
+}
+
+/// Finds whether the struct is a newtype validated like the type it wraps
+fn is_transparent(struct_attrs: &[syn::Attribute]) -> bool {
+    struct_attrs.iter().any(is_transparent_attribute)
+}
+
+/// Finds whether the struct gets a builder validating it with `#[validate(builder)]`
+fn is_builder(struct_attrs: &[syn::Attribute]) -> bool {
+    struct_attrs.iter().any(|attr| is_struct_flag_attribute(attr, "builder"))
+}
+
+/// Finds whether the struct gets a `new_validated` constructor with `#[validate(constructor)]`
+fn is_constructor(struct_attrs: &[syn::Attribute]) -> bool {
+    struct_attrs.iter().any(|attr| is_struct_flag_attribute(attr, "constructor"))
+}
+
+/// Returns the value of a struct option like `#[validate(context = "Type")]` if the attribute is
+/// that option
+fn struct_option_value(attr: &syn::Attribute, option: &str) -> Option<syn::Lit> {
+    if_chain! {
+        if attr.path == parse_quote!(validate);
+        if let Ok(syn::Meta::List(syn::MetaList { ref nested, .. })) = attr.parse_meta();
+        if nested.len() == 1;
+        if let syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. })) = nested[0];
+        if path.is_ident(option);
+        then {
+            Some(lit.clone())
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether the attribute is one of the options of the struct rather than a schema validation
+fn is_struct_option_attribute(attr: &syn::Attribute) -> bool {
+    is_transparent_attribute(attr)
+        || is_struct_flag_attribute(attr, "builder")
+        || is_struct_flag_attribute(attr, "constructor")
+        || ["context", "code_prefix", "mode"]
+            .iter()
+            .any(|option| struct_option_value(attr, option).is_some())
+}
+
+/// Returns the type given to `#[validate(context = "Type")]` if the attribute is one
+fn context_attribute_type(attr: &syn::Attribute) -> Option<syn::Type> {
+    let lit = struct_option_value(attr, "context")?;
+    match lit_to_string(&lit).map(|s| syn::parse_str::<syn::Type>(&s)) {
+        Some(Ok(context_type)) => Some(context_type),
+        _ => abort!(
+            lit.span(),
+            "invalid argument for `context`: expected a string containing a single type"
+        ),
+    }
+}
+
+/// Finds the type of the context the struct is validated with, if it has one
+fn find_struct_context(struct_attrs: &[syn::Attribute]) -> Option<syn::Type> {
+    let mut contexts = struct_attrs.iter().filter_map(context_attribute_type);
+    let context = contexts.next();
+    if contexts.next().is_some() {
+        abort!(Span::call_site(), "A struct can only have a single `#[validate(context = ...)]`");
+    }
+    context
+}
+
+/// Finds the prefix added to the codes of all the errors of the struct, if it has one
+fn find_struct_code_prefix(struct_attrs: &[syn::Attribute]) -> Option<String> {
+    struct_attrs.iter().find_map(|attr| struct_option_value(attr, "code_prefix")).map(|lit| {
+        match lit_to_string(&lit) {
+            Some(prefix) => prefix,
+            None => abort!(lit.span(), "invalid argument for `code_prefix`: expected a string"),
+        }
+    })
+}
+
+/// Finds whether the struct is validated in fail fast mode with `#[validate(mode = "fail_fast")]`
+fn find_struct_fail_fast(struct_attrs: &[syn::Attribute]) -> bool {
+    struct_attrs.iter().find_map(|attr| struct_option_value(attr, "mode")).is_some_and(|lit| {
+        match lit_to_string(&lit).as_deref() {
+            Some("fail_fast") => true,
+            Some("collect_all") => false,
+            _ => abort!(
+                lit.span(),
+                "invalid argument for `mode`: expected \"fail_fast\" or \"collect_all\""
+            ),
+        }
+    })
+}
+
 /// Finds all struct schema validations
+/// The struct level validations run by increasing `order`, then the ones without an order in the
+/// order they are declared
 fn find_struct_validations(struct_attrs: &[syn::Attribute]) -> Vec<SchemaValidation> {
-    struct_attrs
+    let mut validations: Vec<_> = struct_attrs
         .iter()
         .filter(|attribute| attribute.path == parse_quote!(validate))
+        .filter(|attribute| !is_struct_option_attribute(attribute))
         .map(find_struct_validation)
-        .collect()
+        .collect();
+    validations.sort_by_key(|v| v.order.unwrap_or(u64::MAX));
+    validations
 }
 
 /// Find the types (as string) for each field of the struct
@@ -321,8 +1132,8 @@ fn find_struct_validations(struct_attrs: &[syn::Attribute]) -> Vec<SchemaValidat
 fn find_fields_type(fields: &[syn::Field]) -> HashMap<String, String> {
     let mut types = HashMap::new();
 
-    for field in fields {
-        let field_ident = field.ident.clone().unwrap().to_string();
+    for (index, field) in fields.iter().enumerate() {
+        let field_ident = member_name(&field_member(field, index));
         let field_type = match field.ty {
             syn::Type::Path(syn::TypePath { ref path, .. }) => {
                 let mut tokens = proc_macro2::TokenStream::new();
@@ -357,28 +1168,162 @@ fn find_fields_type(fields: &[syn::Field]) -> HashMap<String, String> {
     types
 }
 
-/// Find everything we need to know about a field: its real name if it's changed from the serialization
-/// and the list of validators to run on it
+/// Finds the type of the keys of a map, looking through `Option` and references
+fn find_map_key_type(ty: &syn::Type) -> Option<syn::Type> {
+    match ty {
+        syn::Type::Reference(syn::TypeReference { ref elem, .. }) => find_map_key_type(elem),
+        syn::Type::Group(syn::TypeGroup { ref elem, .. }) => find_map_key_type(elem),
+        syn::Type::Path(syn::TypePath { ref path, .. }) => {
+            let segment = path.segments.last()?;
+            let args = match segment.arguments {
+                syn::PathArguments::AngleBracketed(ref args) => args,
+                _ => return None,
+            };
+            let mut types = args.args.iter().filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            });
+            let first = types.next()?;
+            if segment.ident == "Option" {
+                return find_map_key_type(first);
+            }
+            // A map has at least a key and a value type
+            types.next()?;
+            Some(first.clone())
+        }
+        _ => None,
+    }
+}
+
+/// Find the validators to run on the keys of a map by treating them as a field of the key type
+fn find_validators_for_map_keys(
+    field: &syn::Field,
+    member: &syn::Member,
+    meta_items: &[syn::NestedMeta],
+) -> FieldInformation {
+    let key_type = match find_map_key_type(&field.ty) {
+        Some(ty) => ty,
+        None => abort!(
+            field.ty.span(),
+            "Invalid attribute #[validate] on field `{}`: `keys` can only be used on maps",
+            member_name(member)
+        ),
+    };
+
+    let key_field = syn::Field {
+        attrs: vec![parse_quote!(#[validate(#(#meta_items),*)])],
+        vis: syn::Visibility::Inherited,
+        ident: field.ident.clone(),
+        colon_token: None,
+        ty: key_type,
+    };
+    let key_types = find_fields_type(std::slice::from_ref(&key_field))
+        .into_values()
+        .map(|ty| (member_name(member), ty))
+        .collect();
+    let keys = find_validators_for_field(&key_field, member.clone(), &key_types, None);
+
+    if keys.validations.iter().any(|v| {
+        matches!(
+            v.validator,
+            Validator::Nested
+                | Validator::MustMatch(_)
+                | Validator::Compare { .. }
+                | Validator::Required
+        )
+    }) || keys.condition.is_some()
+        || keys.keys.is_some()
+    {
+        abort!(
+            field.span(),
+            "Invalid attribute #[validate] on field `{}`: `keys` only supports validators working on a single value",
+            member_name(member)
+        );
+    }
+
+    keys
+}
+
+/// Find the validators to run on the elements of a list or on the values of a map by treating them
+/// as a field of the element type
+fn find_validators_for_inner(
+    field: &syn::Field,
+    member: &syn::Member,
+    meta_items: &[syn::NestedMeta],
+) -> FieldInformation {
+    let element_type = match find_element_type(&field.ty) {
+        Some(ty) => ty.clone(),
+        None => abort!(
+            field.ty.span(),
+            "Invalid attribute #[validate] on field `{}`: `inner` can only be used on lists and maps",
+            member_name(member)
+        ),
+    };
+
+    let element_field = syn::Field {
+        attrs: vec![parse_quote!(#[validate(#(#meta_items),*)])],
+        vis: syn::Visibility::Inherited,
+        ident: field.ident.clone(),
+        colon_token: None,
+        ty: element_type,
+    };
+    let element_types = find_fields_type(std::slice::from_ref(&element_field))
+        .into_values()
+        .map(|ty| (member_name(member), ty))
+        .collect();
+    let inner = find_validators_for_field(&element_field, member.clone(), &element_types, None);
+
+    if inner.validations.iter().any(|v| {
+        matches!(
+            v.validator,
+            Validator::Nested
+                | Validator::MustMatch(_)
+                | Validator::Compare { .. }
+                | Validator::Required
+        )
+    }) || inner.condition.is_some()
+        || inner.keys.is_some()
+        || inner.inner.is_some()
+        || inner.field_type.starts_with("Option<")
+    {
+        abort!(
+            field.span(),
+            "Invalid attribute #[validate] on field `{}`: `inner` only supports validators working on a single value",
+            member_name(member)
+        );
+    }
+
+    inner
+}
+
+/// Find everything we need to know about a field: its real name if it's changed from the serialization,
+/// the list of validators to run on it and on its keys if it is a map, and the condition under which they run
 fn find_validators_for_field(
     field: &syn::Field,
+    member: syn::Member,
     field_types: &HashMap<String, String>,
-) -> (String, Vec<FieldValidation>) {
-    let rust_ident = field.ident.clone().unwrap().to_string();
-    let mut field_ident = field.ident.clone().unwrap().to_string();
+    rename_all: Option<RenameRule>,
+) -> FieldInformation {
+    let rust_ident = member_name(&member);
+    let mut field_ident = rust_ident.clone();
+    let mut serde_rename = None;
+    let mut validate_rename = None;
 
     let error = |span: Span, msg: &str| -> ! {
-        abort!(
-            span,
-            "Invalid attribute #[validate] on field `{}`: {}",
-            field.ident.clone().unwrap().to_string(),
-            msg
-        );
+        abort!(span, "Invalid attribute #[validate] on field `{}`: {}", rust_ident, msg);
     };
 
     let field_type = field_types.get(&field_ident).unwrap();
 
     let mut validators = vec![];
+    let mut conditions = vec![];
+    let mut keys = None;
+    let mut inner = None;
     let mut has_validate = false;
+    let mut redact = false;
+    let mut spans = false;
+    #[cfg_attr(not(feature = "parallel"), allow(unused_mut))]
+    let mut parallel = false;
 
     for attr in &field.attrs {
         if attr.path != parse_quote!(validate) && attr.path != parse_quote!(serde) {
@@ -388,14 +1333,18 @@ fn find_validators_for_field(
         if attr.path == parse_quote!(validate) {
             has_validate = true;
         }
+        // The severity applies to the validators of the attribute it is in
+        let first_validator = validators.len();
+        let mut severity = None;
 
         match attr.parse_meta() {
             Ok(syn::Meta::List(syn::MetaList { ref nested, .. })) => {
                 let meta_items = nested.iter().collect::<Vec<_>>();
                 // original name before serde rename
                 if attr.path == parse_quote!(serde) {
-                    if let Some(s) = find_original_field_name(&meta_items) {
-                        field_ident = s;
+                    if let Some(s) = find_serde_name(&meta_items, "rename") {
+                        field_ident = s.clone();
+                        serde_rename = Some(s);
                     }
                     continue;
                 }
@@ -438,10 +1387,29 @@ fn find_validators_for_field(
                                     "required" => {
                                         validators.push(FieldValidation::new(Validator::Required));
                                     }
+                                    "nested" => {
+                                        validators.push(FieldValidation::new(Validator::Nested));
+                                    }
+                                    "percentage" => {
+                                        assert_has_range(rust_ident.clone(), field_type, &field.ty);
+                                        validators.push(FieldValidation::new(
+                                            Validator::Percentage { exclusive: false },
+                                        ));
+                                    }
+                                    "ratio" => {
+                                        assert_has_range(rust_ident.clone(), field_type, &field.ty);
+                                        validators.push(FieldValidation::new(Validator::Ratio {
+                                            exclusive: false,
+                                        }));
+                                    }
                                     "required_nested" => {
                                         validators.push(FieldValidation::new(Validator::Required));
                                         validators.push(FieldValidation::new(Validator::Nested));
                                     }
+                                    "redact" => redact = true,
+                                    "spans" => spans = true,
+                                    #[cfg(feature = "parallel")]
+                                    "parallel" => parallel = true,
                                     _ => {
                                         let mut ident = proc_macro2::TokenStream::new();
                                         name.to_tokens(&mut ident);
@@ -455,42 +1423,104 @@ fn find_validators_for_field(
                             }) => {
                                 let ident = path.get_ident().unwrap();
                                 match ident.to_string().as_ref() {
+                                    "rename" => match lit_to_string(lit) {
+                                        Some(s) => validate_rename = Some(s),
+                                        None => error(lit.span(), "invalid argument for `rename`: only strings are allowed"),
+                                    },
+                                    "severity" => match lit_to_string(lit).as_deref() {
+                                        Some("error") => severity = Some((lit.span(), None)),
+                                        Some(s @ ("warning" | "info")) => severity = Some((lit.span(), Some(s.to_string()))),
+                                        _ => error(lit.span(), "invalid argument for `severity`: only \"error\", \"warning\" and \"info\" are allowed"),
+                                    },
+                                    "skip_if" | "validate_if" => {
+                                        conditions.push(extract_field_condition(
+                                            &rust_ident,
+                                            &ident.to_string(),
+                                            lit,
+                                        ));
+                                    }
                                     "custom" => {
                                         match lit_to_string(lit) {
-                                            Some(s) => validators.push(FieldValidation::new(Validator::Custom {
+                                            Some(s) => {
+                                                assert_custom_function(&lit.span(), &s);
+                                                validators.push(FieldValidation::new(Validator::Custom {
                                                 function: s,
                                                 argument: Box::new(None),
-                                            })),
+                                                use_context: false,
+                                            }))
+                                            }
                                             None => error(lit.span(), "invalid argument for `custom` validator: only strings are allowed"),
                                         };
                                     }
+                                    #[cfg(feature = "async")]
+                                    "custom_async" => {
+                                        match lit_to_string(lit) {
+                                            Some(s) => validators.push(FieldValidation::new(Validator::CustomAsync { function: s })),
+                                            None => error(lit.span(), "invalid argument for `custom_async` validator: only strings are allowed"),
+                                        };
+                                    }
                                     "contains" => {
                                         match lit_to_string(lit) {
-                                            Some(s) => validators.push(FieldValidation::new(Validator::Contains(s))),
+                                            Some(s) => validators.push(FieldValidation::new(Validator::Contains(ValueOrPath::Value(s)))),
                                             None => error(lit.span(), "invalid argument for `contains` validator: only strings are allowed"),
                                         };
                                     }
+                                    "rule" => {
+                                        match lit_to_string(lit) {
+                                            Some(s) => validators.push(FieldValidation::new(Validator::Rule(s))),
+                                            None => error(lit.span(), "invalid argument for `rule` validator: only strings are allowed"),
+                                        };
+                                    }
                                     "does_not_contain" => {
                                         match lit_to_string(lit) {
-                                            Some(s) => validators.push(FieldValidation::new(Validator::DoesNotContain(s))),
+                                            Some(s) => validators.push(FieldValidation::new(Validator::DoesNotContain(ValueOrPath::Value(s)))),
                                             None => error(lit.span(), "invalid argument for `does_not_contain` validator: only strings are allowed"),
                                         };
                                     }
                                     "regex" => {
                                         match lit_to_string(lit) {
-                                            Some(s) => validators.push(FieldValidation::new(Validator::Regex(s))),
+                                            Some(s) => {
+                                                assert_regex_path(&lit.span(), &s);
+                                                validators.push(FieldValidation::new(Validator::Regex(ValueOrPath::Path(s))))
+                                            }
                                             None => error(lit.span(), "invalid argument for `regex` validator: only strings are allowed"),
                                         };
                                     }
+                                    "bits_within" => {
+                                        assert_has_bits(rust_ident.clone(), field_type, &field.ty);
+                                        match lit_to_u64_or_path(lit) {
+                                            Some(mask) => validators.push(FieldValidation::new(Validator::BitsWithin(mask))),
+                                            None => error(lit.span(), "invalid argument for `bits_within` validator: only number literals or value paths are allowed"),
+                                        };
+                                    }
                                     "must_match" => {
                                         match lit_to_string(lit) {
                                             Some(s) => {
-                                                assert_type_matches(rust_ident.clone(), field_type, field_types.get(&s), attr);
+                                                assert_type_matches(rust_ident.clone(), field_type, &s, field_types, attr);
                                                 validators.push(FieldValidation::new(Validator::MustMatch(s)));
                                             }
                                             None => error(lit.span(), "invalid argument for `must_match` validator: only strings are allowed"),
                                         };
                                     }
+                                    "greater_than"
+                                    | "greater_than_or_equal"
+                                    | "less_than"
+                                    | "less_than_or_equal"
+                                    | "before"
+                                    | "after" => {
+                                        let name = ident.to_string();
+                                        match lit_to_string(lit) {
+                                            Some(other) => {
+                                                let other_is_option = assert_comparable_types(&name, rust_ident.clone(), field_type, &other, field_types, attr);
+                                                validators.push(FieldValidation::new(Validator::Compare {
+                                                    comparison: Comparison::from_name(&name).unwrap(),
+                                                    other,
+                                                    other_is_option,
+                                                }));
+                                            }
+                                            None => error(lit.span(), &format!("invalid argument for `{}` validator: only strings are allowed", name)),
+                                        };
+                                    }
                                     v => abort!(
                                         path.span(),
                                         "unexpected name value validator: {:?}",
@@ -503,6 +1533,20 @@ fn find_validators_for_field(
                                 let meta_items = nested.iter().cloned().collect::<Vec<_>>();
                                 let ident = path.get_ident().unwrap();
                                 match ident.to_string().as_ref() {
+                                    "keys" => {
+                                        keys = Some(Box::new(find_validators_for_map_keys(
+                                            field,
+                                            &member,
+                                            &meta_items,
+                                        )));
+                                    }
+                                    "inner" => {
+                                        inner = Some(Box::new(find_validators_for_inner(
+                                            field,
+                                            &member,
+                                            &meta_items,
+                                        )));
+                                    }
                                     "length" => {
                                         assert_has_len(rust_ident.clone(), field_type, &field.ty);
                                         validators.push(extract_length_validation(
@@ -519,6 +1563,22 @@ fn find_validators_for_field(
                                             &meta_items,
                                         ));
                                     }
+                                    "percentage" | "ratio" => {
+                                        assert_has_range(rust_ident.clone(), field_type, &field.ty);
+                                        validators.push(extract_percentage_validation(
+                                            ident.to_string(),
+                                            rust_ident.clone(),
+                                            &meta_items,
+                                        ));
+                                    }
+                                    "bits_within" => {
+                                        assert_has_bits(rust_ident.clone(), field_type, &field.ty);
+                                        validators.push(extract_bits_within_validation(
+                                            rust_ident.clone(),
+                                            attr,
+                                            &meta_items,
+                                        ));
+                                    }
                                     "custom" => {
                                         validators.push(extract_custom_validation(
                                             rust_ident.clone(),
@@ -538,22 +1598,59 @@ fn find_validators_for_field(
                                             &meta_items,
                                         ));
                                     }
+                                    "required_nested" => {
+                                        validators.push(extract_argless_validation(
+                                            "required".to_string(),
+                                            rust_ident.clone(),
+                                            &meta_items,
+                                        ));
+                                        validators.push(FieldValidation::new(Validator::Nested));
+                                    }
+                                    #[cfg(feature = "async")]
+                                    "custom_async" => {
+                                        validators.push(extract_one_arg_validation(
+                                            "function",
+                                            ident.to_string(),
+                                            rust_ident.clone(),
+                                            &meta_items,
+                                        ));
+                                    }
                                     "contains" | "does_not_contain" => {
+                                        // A single needle or a `WordList` searched at once
+                                        let words = meta_items.iter().find_map(|item| match item {
+                                            syn::NestedMeta::Meta(syn::Meta::NameValue(nv))
+                                                if nv.path.is_ident("words") =>
+                                            {
+                                                Some(nv)
+                                            }
+                                            _ => None,
+                                        });
+                                        if let Some(words) = words {
+                                            if let Some(path) = lit_to_string(&words.lit) {
+                                                assert_word_list_path(&words.lit.span(), &path);
+                                            }
+                                        }
                                         validators.push(extract_one_arg_validation(
-                                            "pattern",
+                                            if words.is_some() { "words" } else { "pattern" },
                                             ident.to_string(),
                                             rust_ident.clone(),
                                             &meta_items,
                                         ));
                                     }
-                                    "regex" => {
+                                    "rule" => {
                                         validators.push(extract_one_arg_validation(
-                                            "path",
+                                            "name",
                                             ident.to_string(),
                                             rust_ident.clone(),
                                             &meta_items,
                                         ));
                                     }
+                                    "regex" => {
+                                        validators.push(extract_regex_validation(
+                                            rust_ident.clone(),
+                                            &meta_items,
+                                        ));
+                                    }
                                     "must_match" => {
                                         let validation = extract_one_arg_validation(
                                             "other",
@@ -565,7 +1662,37 @@ fn find_validators_for_field(
                                             assert_type_matches(
                                                 rust_ident.clone(),
                                                 field_type,
-                                                field_types.get(t2),
+                                                t2,
+                                                field_types,
+                                                attr,
+                                            );
+                                        }
+                                        validators.push(validation);
+                                    }
+                                    "greater_than"
+                                    | "greater_than_or_equal"
+                                    | "less_than"
+                                    | "less_than_or_equal"
+                                    | "before"
+                                    | "after" => {
+                                        let mut validation = extract_one_arg_validation(
+                                            "other",
+                                            ident.to_string(),
+                                            rust_ident.clone(),
+                                            &meta_items,
+                                        );
+                                        if let Validator::Compare {
+                                            ref other,
+                                            ref mut other_is_option,
+                                            ..
+                                        } = validation.validator
+                                        {
+                                            *other_is_option = assert_comparable_types(
+                                                &ident.to_string(),
+                                                rust_ident.clone(),
+                                                field_type,
+                                                other,
+                                                field_types,
                                                 attr,
                                             );
                                         }
@@ -578,6 +1705,21 @@ fn find_validators_for_field(
                         _ => unreachable!("Found a non Meta while looking for validators"),
                     };
                 }
+
+                if let Some((span, severity)) = severity {
+                    let attr_validators = &mut validators[first_validator..];
+                    if attr_validators.is_empty() {
+                        error(span, "`severity` needs a validator in the same attribute");
+                    }
+                    for validation in attr_validators {
+                        match validation.validator {
+                            Validator::Nested => error(span, "`severity` can't be applied to nested validations, they keep the severities of the nested struct"),
+                            #[cfg(feature = "async")]
+                            Validator::CustomAsync { .. } => error(span, "`severity` can't be applied to `custom_async`"),
+                            _ => validation.severity = severity.clone(),
+                        }
+                    }
+                }
             }
             Ok(syn::Meta::Path(_)) => validators.push(FieldValidation::new(Validator::Nested)),
             Ok(syn::Meta::NameValue(_)) => abort!(attr.span(), "Unexpected name=value argument"),
@@ -599,12 +1741,46 @@ fn find_validators_for_field(
             }
         }
 
-        if has_validate && validators.is_empty() {
+        if has_validate
+            && validators.is_empty()
+            && conditions.is_empty()
+            && keys.is_none()
+            && inner.is_none()
+            && validate_rename.is_none()
+        {
             error(attr.span(), "it needs at least one validator");
         }
     }
 
-    (field_ident, validators)
+    if !conditions.is_empty() && validators.is_empty() && keys.is_none() && inner.is_none() {
+        error(field.span(), "`skip_if` and `validate_if` need at least one validator");
+    }
+
+    if parallel {
+        if !validators.iter().any(|v| matches!(v.validator, Validator::Nested)) {
+            error(field.span(), "`parallel` applies to the nested validation, it needs `nested`");
+        }
+        let list_type = field_type.strip_prefix("Option<").unwrap_or(field_type);
+        if !list_type.starts_with("Vec<") {
+            error(field.span(), "`parallel` can only be used on `Vec` fields");
+        }
+        if inner.is_some() {
+            error(field.span(), "`parallel` can't be combined with `inner`");
+        }
+    }
+
+    let condition = if conditions.is_empty() { None } else { Some(quote!(#(#conditions)&&*)) };
+
+    // `#[validate(rename)]` wins over serde as the errors might not be for the deserialized input
+    let name = validate_rename
+        .or(serde_rename)
+        .or_else(|| rename_all.map(|rule| rule.apply_to_field(&rust_ident)))
+        .unwrap_or(rust_ident);
+
+    FieldInformation::new(member, field_type.clone(), name, validators, condition, keys, inner)
+        .with_redact(redact)
+        .with_spans(spans)
+        .with_parallel(parallel)
 }
 
 /// Serde can be used to rename fields on deserialization but most of the times
@@ -612,30 +1788,39 @@ fn find_validators_for_field(
 ///
 /// For example a JS frontend might send camelCase fields and Rust converts them to snake_case
 /// but we want to send the errors back with the original name
-fn find_original_field_name(meta_items: &[&syn::NestedMeta]) -> Option<String> {
-    let mut original_name = None;
-
+///
+/// Finds the value of `key = "..."` or of the `deserialize` side of `key(deserialize = "...")`
+/// in the items of a `#[serde]` attribute
+fn find_serde_name(meta_items: &[&syn::NestedMeta], key: &str) -> Option<String> {
     for meta_item in meta_items {
         match **meta_item {
-            syn::NestedMeta::Meta(ref item) => match *item {
-                syn::Meta::Path(_) => continue,
-                syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
-                    let ident = path.get_ident().unwrap();
-                    if ident == "rename" {
-                        original_name = Some(lit_to_string(lit).unwrap());
-                    }
-                }
-                syn::Meta::List(syn::MetaList { ref nested, .. }) => {
-                    return find_original_field_name(&nested.iter().collect::<Vec<_>>());
-                }
-            },
-            _ => unreachable!(),
-        };
-
-        if original_name.is_some() {
-            return original_name;
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                ref path,
+                ref lit,
+                ..
+            })) if path.is_ident(key) => return lit_to_string(lit),
+            syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList {
+                ref path, ref nested, ..
+            })) if path.is_ident(key) => {
+                return find_serde_name(&nested.iter().collect::<Vec<_>>(), "deserialize");
+            }
+            _ => continue,
         }
     }
 
-    original_name
+    None
+}
+
+/// Finds the serde `rename_all` rule of the struct, if any
+fn find_rename_all(struct_attrs: &[syn::Attribute]) -> Option<RenameRule> {
+    struct_attrs
+        .iter()
+        .filter(|attr| attr.path == parse_quote!(serde))
+        .find_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(syn::MetaList { ref nested, .. })) => {
+                find_serde_name(&nested.iter().collect::<Vec<_>>(), "rename_all")
+            }
+            _ => None,
+        })
+        .and_then(|rule| RenameRule::parse(&rule))
 }
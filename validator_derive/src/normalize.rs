@@ -0,0 +1,115 @@
+use proc_macro_error::abort;
+use quote::quote;
+use syn::spanned::Spanned;
+
+use crate::field_member;
+use crate::lit::{lit_to_int, lit_to_string};
+
+/// Implements `Normalize` by applying the normalizations of the `#[normalize(..)]` attributes to
+/// their fields, in the order they are written
+pub fn impl_normalize(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let fields = match ast.data {
+        syn::Data::Struct(syn::DataStruct { ref fields, .. }) => fields,
+        _ => abort!(ast.span(), "#[derive(Normalize)] can only be used with structs"),
+    };
+
+    let mut normalizations = vec![];
+    for (index, field) in fields.iter().enumerate() {
+        let member = field_member(field, index);
+        for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("normalize")) {
+            normalizations.extend(quote_normalizations(&member, attr));
+        }
+    }
+
+    let ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::validator::Normalize for #ident #ty_generics #where_clause {
+            fn normalize(&mut self) {
+                #(#normalizations)*
+            }
+        }
+    )
+}
+
+/// The normalizations of a `#[normalize(..)]` attribute: the built-in ones taking a string,
+/// `truncate(max = ..)`, `nested` and `custom` functions taking the whole field
+fn quote_normalizations(
+    member: &syn::Member,
+    attr: &syn::Attribute,
+) -> Vec<proc_macro2::TokenStream> {
+    let nested = match attr.parse_meta() {
+        Ok(syn::Meta::List(syn::MetaList { nested, .. })) if !nested.is_empty() => nested,
+        _ => abort!(
+            attr.span(),
+            "Invalid attribute #[normalize]: it needs a list of normalizations, like #[normalize(trim)]"
+        ),
+    };
+
+    nested
+        .iter()
+        .map(|item| match item {
+            syn::NestedMeta::Meta(syn::Meta::Path(ref path)) if path.get_ident().is_some() => {
+                let ident = path.get_ident().unwrap();
+                match ident.to_string().as_ref() {
+                    "trim" | "lowercase" | "uppercase" | "capitalize" => {
+                        quote_string_normalization(member, ident)
+                    }
+                    #[cfg(feature = "unic")]
+                    "nfc" | "nfkc" => quote_string_normalization(member, ident),
+                    "nested" => quote!(::validator::Normalize::normalize(&mut self.#member);),
+                    v => abort!(path.span(), "Unexpected normalization: {}", v),
+                }
+            }
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                ref path,
+                ref lit,
+                ..
+            })) if path.is_ident("custom") => quote_custom_normalization(member, lit),
+            syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { ref path, ref nested, .. }))
+                if path.is_ident("truncate") =>
+            {
+                let max = match nested.iter().next() {
+                    Some(syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        ref path,
+                        ref lit,
+                        ..
+                    }))) if nested.len() == 1 && path.is_ident("max") => lit_to_int(lit)
+                        .unwrap_or_else(|| {
+                            abort!(lit.span(), "invalid argument for `truncate`: `max` has to be an integer")
+                        }),
+                    _ => abort!(path.span(), "`truncate` needs a `max` argument, like truncate(max = 20)"),
+                } as usize;
+                quote!(
+                    ::validator::NormalizeString::apply(&mut self.#member, |value: &mut ::std::string::String| {
+                        ::validator::normalize_truncate(value, #max)
+                    });
+                )
+            }
+            _ => abort!(item.span(), "Unexpected item in #[normalize]"),
+        })
+        .collect()
+}
+
+fn quote_string_normalization(
+    member: &syn::Member,
+    normalization: &syn::Ident,
+) -> proc_macro2::TokenStream {
+    let function = syn::Ident::new(&format!("normalize_{}", normalization), normalization.span());
+    quote!(::validator::NormalizeString::apply(&mut self.#member, ::validator::#function);)
+}
+
+/// A `custom` normalization is a path to a function or a closure taking a mutable reference to
+/// the field
+fn quote_custom_normalization(member: &syn::Member, lit: &syn::Lit) -> proc_macro2::TokenStream {
+    let function = match lit_to_string(lit).map(|s| syn::parse_str::<syn::Expr>(&s)) {
+        Some(Ok(syn::Expr::Path(path))) => quote!(#path),
+        Some(Ok(syn::Expr::Closure(closure))) => quote!((#closure)),
+        _ => abort!(
+            lit.span(),
+            "invalid argument for `custom` normalization: it has to be a string with a path to a function or a closure"
+        ),
+    };
+    quote!(#function(&mut self.#member);)
+}
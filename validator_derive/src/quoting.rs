@@ -1,26 +1,172 @@
-use if_chain::if_chain;
 use proc_macro2::{self, Span};
 use quote::quote;
 
-use validator_types::{Validator, ValueOrPath};
+use validator_types::{Comparison, Validator, ValueOrPath};
 
-use crate::asserts::{COW_TYPE, NUMBER_TYPES};
+use crate::asserts::{cow_type, NUMBER_TYPES};
 use crate::lit::{option_to_tokens, value_or_path_to_tokens};
-use crate::validation::{FieldValidation, SchemaValidation};
+use crate::validation::{quote_self_predicate, FieldValidation, SchemaValidation};
 
 /// Pass around all the information needed for creating a validation
 #[derive(Debug)]
 pub struct FieldQuoter {
+    /// How the field is accessed on `self`: its name or its position in a tuple struct
+    member: syn::Member,
+    /// The ident the field is bound to when unwrapping options or iterating collections
     ident: syn::Ident,
     /// The field name
     name: String,
     /// The field type
     _type: String,
+    /// Whether the ident is a local reference (the key of a map) instead of a field of `self`
+    local: bool,
+    /// The validations to run on each key if the field is a map
+    key_validations: Option<proc_macro2::TokenStream>,
+    /// The validations to run on each element if the field is a list, or each value of a map
+    inner_validations: Option<proc_macro2::TokenStream>,
+    /// The `code_prefix` of the struct, added to the codes of the errors
+    code_prefix: Option<String>,
+    /// Whether the values are kept out of the params of the errors with `#[validate(redact)]`
+    redact: bool,
+    /// Whether the string validators add the range of the invalid part of the value to the
+    /// params of the errors with `#[validate(spans)]`
+    spans: bool,
+    /// Whether the elements of a nested list are validated on all the cores with
+    /// `#[validate(nested, parallel)]`
+    parallel: bool,
+}
+
+/// The ident a field is bound to, tuple struct fields don't have a name
+fn binding_ident(member: &syn::Member) -> syn::Ident {
+    match member {
+        syn::Member::Named(ref ident) => ident.clone(),
+        syn::Member::Unnamed(ref index) => {
+            syn::Ident::new(&format!("__field_{}", index.index), Span::call_site())
+        }
+    }
 }
 
 impl FieldQuoter {
-    pub fn new(ident: syn::Ident, name: String, _type: String) -> FieldQuoter {
-        FieldQuoter { ident, name, _type }
+    pub fn new(member: syn::Member, name: String, _type: String) -> FieldQuoter {
+        let ident = binding_ident(&member);
+        FieldQuoter {
+            member,
+            ident,
+            name,
+            _type,
+            local: false,
+            key_validations: None,
+            inner_validations: None,
+            code_prefix: None,
+            redact: false,
+            spans: false,
+            parallel: false,
+        }
+    }
+
+    /// A quoter for the keys of a map field, their errors are stored in `__key__`
+    pub fn new_map_key(_type: String) -> FieldQuoter {
+        let ident = syn::Ident::new("__key", Span::call_site());
+        FieldQuoter {
+            member: syn::Member::Named(ident.clone()),
+            ident,
+            name: "__key__".to_string(),
+            _type,
+            local: true,
+            key_validations: None,
+            inner_validations: None,
+            code_prefix: None,
+            redact: false,
+            spans: false,
+            parallel: false,
+        }
+    }
+
+    /// A quoter for the elements of a list or the values of a map field, bound to the same ident
+    /// as the field while iterating. Their errors are stored in `__value__`
+    pub fn new_element(member: &syn::Member, _type: String) -> FieldQuoter {
+        let ident = binding_ident(member);
+        FieldQuoter {
+            member: syn::Member::Named(ident.clone()),
+            ident,
+            name: "__value__".to_string(),
+            _type,
+            local: true,
+            key_validations: None,
+            inner_validations: None,
+            code_prefix: None,
+            redact: false,
+            spans: false,
+            parallel: false,
+        }
+    }
+
+    pub fn with_key_validations(mut self, key_validations: Vec<proc_macro2::TokenStream>) -> Self {
+        if !key_validations.is_empty() {
+            self.key_validations = Some(quote!(#(#key_validations)*));
+        }
+        self
+    }
+
+    pub fn with_code_prefix(mut self, code_prefix: Option<String>) -> Self {
+        self.code_prefix = code_prefix;
+        self
+    }
+
+    pub fn with_redact(mut self, redact: bool) -> Self {
+        self.redact = redact;
+        self
+    }
+
+    pub fn with_spans(mut self, spans: bool) -> Self {
+        self.spans = spans;
+        self
+    }
+
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    pub fn with_inner_validations(
+        mut self,
+        inner_validations: Vec<proc_macro2::TokenStream>,
+    ) -> Self {
+        if !inner_validations.is_empty() {
+            self.inner_validations = Some(quote!(#(#inner_validations)*));
+        }
+        self
+    }
+
+    /// Whether the keys or the elements of the field have validations of their own
+    pub fn has_element_validations(&self) -> bool {
+        self.key_validations.is_some() || self.inner_validations.is_some()
+    }
+
+    /// Adds a value to the params of the error, unless the field is redacted
+    pub fn quote_value_param(
+        &self,
+        name: &str,
+        value: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        if self.redact {
+            return quote!();
+        }
+        quote!(err.add_param(::std::borrow::Cow::from(#name), #value);)
+    }
+
+    /// Adds the `offset` and `length` of the byte range the expression returns, if any, to the
+    /// params of the error when the field has `#[validate(spans)]`
+    pub fn quote_span_params(&self, span: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if !self.spans {
+            return quote!();
+        }
+        quote!(
+            if let ::std::option::Option::Some(span) = #span {
+                err.add_param(::std::borrow::Cow::from("offset"), &span.start);
+                err.add_param(::std::borrow::Cow::from("length"), &span.len());
+            }
+        )
     }
 
     /// Don't put a & in front a pointer since we are going to pass
@@ -30,26 +176,38 @@ impl FieldQuoter {
     pub fn quote_validator_param(&self) -> proc_macro2::TokenStream {
         let ident = &self.ident;
 
+        if self.local {
+            return if NUMBER_TYPES.contains(&self._type.as_ref()) {
+                quote!(*#ident)
+            } else if cow_type().is_match(self._type.as_ref()) {
+                quote!(#ident.as_ref())
+            } else {
+                quote!(#ident)
+            };
+        }
+
+        let member = &self.member;
         if self._type.starts_with("Option<") {
             quote!(#ident)
-        } else if COW_TYPE.is_match(self._type.as_ref()) {
-            quote!(self.#ident.as_ref())
+        } else if cow_type().is_match(self._type.as_ref()) {
+            quote!(self.#member.as_ref())
         } else if self._type.starts_with('&') || NUMBER_TYPES.contains(&self._type.as_ref()) {
-            quote!(self.#ident)
+            quote!(self.#member)
         } else {
-            quote!(&self.#ident)
+            quote!(&self.#member)
         }
     }
 
     pub fn quote_validator_field(&self) -> proc_macro2::TokenStream {
         let ident = &self.ident;
+        let member = &self.member;
 
         if self._type.starts_with("Option<") || is_list(&self._type) || is_map(&self._type) {
             quote!(#ident)
-        } else if COW_TYPE.is_match(self._type.as_ref()) {
-            quote!(self.#ident.as_ref())
+        } else if cow_type().is_match(self._type.as_ref()) {
+            quote!(self.#member.as_ref())
         } else {
-            quote!(self.#ident)
+            quote!(self.#member)
         }
     }
 
@@ -68,17 +226,17 @@ impl FieldQuoter {
     /// Wrap the quoted output of a validation with a if let Some if
     /// the field type is an option
     pub fn wrap_if_option(&self, tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let field_ident = &self.ident;
+        let member = &self.member;
         let optional_pattern_matched = self.get_optional_validator_param();
         if self._type.starts_with("Option<Option<") {
             return quote!(
-                if let Some(Some(#optional_pattern_matched)) = self.#field_ident {
+                if let Some(Some(#optional_pattern_matched)) = self.#member {
                     #tokens
                 }
             );
         } else if self._type.starts_with("Option<") {
             return quote!(
-                if let Some(#optional_pattern_matched) = self.#field_ident {
+                if let Some(#optional_pattern_matched) = self.#member {
                     #tokens
                 }
             );
@@ -91,30 +249,82 @@ impl FieldQuoter {
     /// the field type is a vector
     pub fn wrap_if_collection(&self, tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
         let field_ident = &self.ident;
+        let member = &self.member;
         let field_name = &self.name;
 
         // When we're using an option, we'll have the field unwrapped, so we should not access it
         // through `self`.
-        let prefix = (!self._type.starts_with("Option<")).then(|| quote! { self. });
+        let collection = if self._type.starts_with("Option<") {
+            quote! { #field_ident }
+        } else {
+            quote! { self.#member }
+        };
+
+        // The errors of the keys and elements validations are kept in the entry of each element
+        let element_validations = if self.has_element_validations() {
+            let key_validations = &self.key_validations;
+            let inner_validations = &self.inner_validations;
+            Some(quote! {
+                let mut errors = ::validator::ValidationErrors::new();
+                #key_validations
+                #inner_validations
+                if !errors.is_empty() {
+                    result = ::std::result::Result::Err(errors);
+                }
+            })
+        } else {
+            None
+        };
+
+        // When iterating over a map, the iterator yields Item=(K, V): the values are validated
+        // like a list but the errors are keyed by the keys, which can also have validations.
+        if is_map(&self._type) {
+            let value = if tokens.is_empty() && self.inner_validations.is_none() {
+                quote!(_)
+            } else {
+                quote!(#field_ident)
+            };
+
+            return quote! {
+                if !::validator::ValidationErrors::has_error(&result, #field_name) {
+                    // Only the keys of the invalid entries are turned into strings
+                    let results: Vec<_> = #collection.iter().take_while(|_| !::validator::__private::should_stop()).filter_map(|(__key, #value)| {
+                        let mut result = ::std::result::Result::Ok(());
+                        #element_validations
+                        #tokens
+                        result.err().map(|err| (__key.to_string(), ::std::result::Result::Err(err)))
+                    }).collect();
+                    result = ::validator::ValidationErrors::merge_map(result, #field_name, results);
+                }
+            };
+        }
 
-        // When iterating over a list, the iterator has Item=T, while a map yields Item=(K, V), and
-        // we're only interested in V.
+        // When iterating over a list, the iterator has Item=T
         let args = if is_list(&self._type) {
             quote! { #field_ident }
-        } else if is_map(&self._type) {
-            quote! { (_, #field_ident) }
         } else {
             return tokens;
         };
 
+        // The elements of a nested list are validated with `validate_nested` on other threads
+        if self.parallel {
+            return quote! {
+                if !::validator::ValidationErrors::has_error(&result, #field_name) {
+                    let results = ::validator::__private::validate_nested_par::<FAIL_FAST, _>(&#collection);
+                    result = ::validator::ValidationErrors::merge_list(result, #field_name, results);
+                }
+            };
+        }
+
         quote! {
             if !::validator::ValidationErrors::has_error(&result, #field_name) {
-                let results: Vec<_> = #prefix #field_ident.iter().map(|#args| {
+                let results: Vec<_> = #collection.iter().take_while(|_| !::validator::__private::should_stop()).map(|#args| {
                     let mut result = ::std::result::Result::Ok(());
+                    #element_validations
                     #tokens
                     result
                 }).collect();
-                result = ::validator::ValidationErrors::merge_all(result, #field_name, results);
+                result = ::validator::ValidationErrors::merge_list(result, #field_name, results);
             }
         }
     }
@@ -148,6 +358,54 @@ fn is_list(_type: &str) -> bool {
     }
 }
 
+/// Whether the type is an integer that converts to `i128` without loss
+fn is_integer(_type: &str) -> bool {
+    if let Some(stripped) = _type.strip_prefix("&") {
+        is_integer(stripped)
+    } else if let Some(stripped) = _type.strip_prefix("Option<") {
+        is_integer(stripped.strip_suffix('>').unwrap_or(stripped))
+    } else {
+        ["usize", "u8", "u16", "u32", "u64", "isize", "i8", "i16", "i32", "i64", "i128"]
+            .contains(&_type)
+    }
+}
+
+/// The last changes to an error before adding it: its code gets the `code_prefix` of the struct
+/// and the `{...}` placeholders of its message are filled, or its `message_fn` called, now that
+/// all the params are added
+fn quote_finish_error<F: quote::ToTokens>(
+    field_name: F,
+    code_prefix: &Option<String>,
+    message: &Option<String>,
+    message_fn: &Option<String>,
+) -> proc_macro2::TokenStream {
+    let prefix_code = match code_prefix {
+        Some(prefix) => quote!(
+            err.code = ::std::borrow::Cow::from(::std::format!("{}{}", #prefix, err.code));
+        ),
+        None => quote!(),
+    };
+    let interpolate_message = match message {
+        Some(m) if m.contains('{') => quote!(err.interpolate_message(#field_name);),
+        _ => quote!(),
+    };
+    let call_message_fn = match message_fn {
+        Some(f) => {
+            let f: syn::Expr = syn::parse_str(f).unwrap();
+            quote!(
+                err.message = ::std::option::Option::Some(::std::borrow::Cow::from((#f)(&err)));
+            )
+        }
+        None => quote!(),
+    };
+
+    quote!(
+        #prefix_code
+        #interpolate_message
+        #call_message_fn
+    )
+}
+
 /// Quote an actual end-user error creation automatically
 fn quote_error(validation: &FieldValidation) -> proc_macro2::TokenStream {
     let code = &validation.code;
@@ -168,6 +426,12 @@ pub fn quote_length_validation(
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
     let validator_param = field_quoter.quote_validator_param();
 
     if let Validator::Length { min, max, equal } = &validation.validator {
@@ -201,6 +465,7 @@ pub fn quote_length_validation(
         );
 
         let quoted_error = quote_error(validation);
+        let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
         let quoted = quote!(
             if !::validator::validate_length(
                 #validator_param,
@@ -212,7 +477,8 @@ pub fn quote_length_validation(
                 #min_err_param_quoted
                 #max_err_param_quoted
                 #equal_err_param_quoted
-                err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+                #value_param
+                #finish_error
                 errors.add(#field_name, err);
             }
         );
@@ -228,15 +494,22 @@ pub fn quote_range_validation(
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
     let quoted_ident = field_quoter.quote_validator_param();
 
-    if let Validator::Range { ref min, ref max, ref exclusive_min, ref exclusive_max } =
+    if let Validator::Range { ref min, ref max, ref exclusive_min, ref exclusive_max, ref step } =
         validation.validator
     {
         let min_err_param_quoted = err_param_quoted(min, "min");
         let max_err_param_quoted = err_param_quoted(max, "max");
         let exclusive_min_err_param_quoted = err_param_quoted(exclusive_min, "exclusive_min");
         let exclusive_max_err_param_quoted = err_param_quoted(exclusive_max, "exclusive_max");
+        let step_err_param_quoted = err_param_quoted(step, "step");
 
         // Can't interpolate None
         let min_tokens = generate_tokens(min);
@@ -244,7 +517,28 @@ pub fn quote_range_validation(
         let exclusive_min_tokens = generate_tokens(exclusive_min);
         let exclusive_max_tokens = generate_tokens(exclusive_max);
 
+        // The grid of the step starts at the lower limit if there is one
+        let step_check = if let Some(step) = step {
+            let step = value_or_path_to_tokens(step);
+            let origin = match min.as_ref().or(exclusive_min.as_ref()) {
+                Some(origin) => {
+                    let origin = value_or_path_to_tokens(origin);
+                    quote!(#origin as f64)
+                }
+                None => quote!(0.0),
+            };
+            // Integers above 2^53 aren't representable as `f64`, check them exactly
+            if is_integer(&field_quoter._type) {
+                quote!(|| !::validator::validate_range_step_integer(#quoted_ident as i128, #origin, #step as f64))
+            } else {
+                quote!(|| !::validator::validate_range_step(#quoted_ident as f64, #origin, #step as f64))
+            }
+        } else {
+            quote!()
+        };
+
         let quoted_error = quote_error(validation);
+        let value_param = field_quoter.quote_value_param("value", quote!(&#quoted_ident));
         let quoted = quote!(
             if !::validator::validate_range(
                 #quoted_ident as f64,
@@ -252,13 +546,86 @@ pub fn quote_range_validation(
                 #max_tokens,
                 #exclusive_min_tokens,
                 #exclusive_max_tokens,
-            ) {
+            ) #step_check {
                 #quoted_error
                 #min_err_param_quoted
                 #max_err_param_quoted
                 #exclusive_min_err_param_quoted
                 #exclusive_max_err_param_quoted
-                err.add_param(::std::borrow::Cow::from("value"), &#quoted_ident);
+                #step_err_param_quoted
+                #value_param
+                #finish_error
+                errors.add(#field_name, err);
+            }
+        );
+
+        return field_quoter.wrap_if_option(quoted);
+    }
+
+    unreachable!()
+}
+
+pub fn quote_percentage_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
+    let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
+    let quoted_ident = field_quoter.quote_validator_param();
+
+    let (function, exclusive, max) = match validation.validator {
+        Validator::Percentage { exclusive } => {
+            (quote!(::validator::validate_percentage), exclusive, 100.0)
+        }
+        Validator::Ratio { exclusive } => (quote!(::validator::validate_ratio), exclusive, 1.0),
+        _ => unreachable!(),
+    };
+
+    let quoted_error = quote_error(validation);
+    let value_param = field_quoter.quote_value_param("value", quote!(&#quoted_ident));
+    let quoted = quote!(
+        if !#function(#quoted_ident as f64, #exclusive) {
+            #quoted_error
+            err.add_param(::std::borrow::Cow::from("min"), &0.0);
+            err.add_param(::std::borrow::Cow::from("max"), &#max);
+            err.add_param(::std::borrow::Cow::from("exclusive"), &#exclusive);
+            #value_param
+            #finish_error
+            errors.add(#field_name, err);
+        }
+    );
+
+    field_quoter.wrap_if_option(quoted)
+}
+
+pub fn quote_bits_within_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
+    let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
+    let quoted_ident = field_quoter.quote_validator_param();
+
+    if let Validator::BitsWithin(ref mask) = validation.validator {
+        let mask = value_or_path_to_tokens(mask);
+        let quoted_error = quote_error(validation);
+        let value_param = field_quoter.quote_value_param("value", quote!(&#quoted_ident));
+        let quoted = quote!(
+            if !::validator::validate_bits_within(#quoted_ident as u64, #mask as u64) {
+                #quoted_error
+                err.add_param(::std::borrow::Cow::from("mask"), &#mask);
+                #value_param
+                #finish_error
                 errors.add(#field_name, err);
             }
         );
@@ -295,13 +662,21 @@ pub fn quote_credit_card_validation(
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
     let validator_param = field_quoter.quote_validator_param();
 
     let quoted_error = quote_error(validation);
+    let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
     let quoted = quote!(
         if !::validator::validate_credit_card(#validator_param) {
             #quoted_error
-            err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+            #value_param
+            #finish_error
             errors.add(#field_name, err);
         }
     );
@@ -315,13 +690,21 @@ pub fn quote_phone_validation(
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
     let validator_param = field_quoter.quote_validator_param();
 
     let quoted_error = quote_error(validation);
+    let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
     let quoted = quote!(
         if !::validator::validate_phone(#validator_param) {
             #quoted_error
-            err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+            #value_param
+            #finish_error
             errors.add(#field_name, err);
         }
     );
@@ -335,13 +718,24 @@ pub fn quote_non_control_character_validation(
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
     let validator_param = field_quoter.quote_validator_param();
 
+    let span_param = field_quoter
+        .quote_span_params(quote!(::validator::control_character_span(#validator_param)));
     let quoted_error = quote_error(validation);
+    let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
     let quoted = quote!(
         if !::validator::validate_non_control_character(#validator_param) {
             #quoted_error
-            err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+            #value_param
+            #span_param
+            #finish_error
             errors.add(#field_name, err);
         }
     );
@@ -354,13 +748,24 @@ pub fn quote_url_validation(
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
     let validator_param = field_quoter.quote_validator_param();
 
+    let span_param =
+        field_quoter.quote_span_params(quote!(::validator::url_error_span(#validator_param)));
     let quoted_error = quote_error(validation);
+    let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
     let quoted = quote!(
         if !::validator::validate_url(#validator_param) {
             #quoted_error
-            err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+            #value_param
+            #span_param
+            #finish_error
             errors.add(#field_name, err);
         }
     );
@@ -373,13 +778,24 @@ pub fn quote_email_validation(
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
     let validator_param = field_quoter.quote_validator_param();
 
+    let span_param =
+        field_quoter.quote_span_params(quote!(::validator::email_error_span(#validator_param)));
     let quoted_error = quote_error(validation);
+    let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
     let quoted = quote!(
         if !::validator::validate_email(#validator_param) {
             #quoted_error
-            err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+            #value_param
+            #span_param
+            #finish_error
             errors.add(#field_name, err);
         }
     );
@@ -391,17 +807,26 @@ pub fn quote_must_match_validation(
     field_quoter: &FieldQuoter,
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
-    let ident = &field_quoter.ident;
+    let member = &field_quoter.member;
     let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
 
     if let Validator::MustMatch(ref other) = validation.validator {
-        let other_ident = syn::Ident::new(other, Span::call_site());
+        let other = quote_field_path(other);
         let quoted_error = quote_error(validation);
+        let value_param = field_quoter.quote_value_param("value", quote!(&self.#member));
+        let other_param = field_quoter.quote_value_param("other", quote!(&#other));
         let quoted = quote!(
-            if !::validator::validate_must_match(&self.#ident, &self.#other_ident) {
+            if !::validator::validate_must_match(&self.#member, &#other) {
                 #quoted_error
-                err.add_param(::std::borrow::Cow::from("value"), &self.#ident);
-                err.add_param(::std::borrow::Cow::from("other"), &self.#other_ident);
+                #value_param
+                #other_param
+                #finish_error
                 errors.add(#field_name, err);
             }
         );
@@ -412,38 +837,211 @@ pub fn quote_must_match_validation(
     unreachable!();
 }
 
+/// Access the field at the given path from `self`: a field or a path into a nested struct like
+/// `period.start`
+fn quote_field_path(path: &str) -> proc_macro2::TokenStream {
+    let members = path.split('.').map(|m| syn::parse_str::<syn::Member>(m).unwrap());
+    quote!(self #(.#members)*)
+}
+
+pub fn quote_compare_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
+    let member = &field_quoter.member;
+    let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
+
+    if let Validator::Compare { comparison, ref other, other_is_option } = validation.validator {
+        let function = match comparison {
+            Comparison::GreaterThan | Comparison::After => quote!(validate_greater_than),
+            Comparison::GreaterThanOrEqual => quote!(validate_greater_than_or_equal),
+            Comparison::LessThan | Comparison::Before => quote!(validate_less_than),
+            Comparison::LessThanOrEqual => quote!(validate_less_than_or_equal),
+        };
+        // Both sides are compared as references, the comparison is skipped if one of them is `None`
+        let value = if field_quoter._type.starts_with("Option<Option<") {
+            quote!(self.#member.as_ref().and_then(::std::option::Option::as_ref))
+        } else if field_quoter._type.starts_with("Option<") {
+            quote!(self.#member.as_ref())
+        } else {
+            quote!(::std::option::Option::Some(&self.#member))
+        };
+        let other = quote_field_path(other);
+        let other = if other_is_option {
+            quote!(#other.as_ref())
+        } else {
+            quote!(::std::option::Option::Some(&#other))
+        };
+
+        let quoted_error = quote_error(validation);
+        let value_param = field_quoter.quote_value_param("value", quote!(__value));
+        let other_param = field_quoter.quote_value_param("other", quote!(__other));
+        let quoted = quote!(
+            if let (::std::option::Option::Some(__value), ::std::option::Option::Some(__other)) = (#value, #other) {
+                if !::validator::#function(__value, __other) {
+                    #quoted_error
+                    #value_param
+                    #other_param
+                    #finish_error
+                    errors.add(#field_name, err);
+                }
+            }
+        );
+
+        return quoted;
+    }
+
+    unreachable!();
+}
+
 pub fn quote_custom_validation(
     field_quoter: &FieldQuoter,
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
     let validator_param = field_quoter.quote_validator_param();
 
-    if let Validator::Custom { function, argument, .. } = &validation.validator {
-        let fn_ident: syn::Path = syn::parse_str(function).unwrap();
+    if let Validator::Custom { function, argument, use_context } = &validation.validator {
+        let access = match (**argument).as_ref().and_then(|a| a.arg_access.as_ref()) {
+            Some(access) => quote!(, #access),
+            None if *use_context => quote!(, args),
+            None => quote!(),
+        };
 
-        let access = if_chain! {
-            if let Some(argument) = &**argument;
-            if let Some(access) = &argument.arg_access;
-            then {
-                quote!(, #access)
-            } else {
-                quote!()
-            }
+        let add_message_quoted = if let Some(ref m) = validation.message {
+            quote!(err.message = Some(::std::borrow::Cow::from(#m));)
+        } else {
+            quote!()
         };
 
+        // The function sets the code of its errors unless one is given in the attribute
+        let code = &validation.code;
+        let set_code_quoted = if code != validation.validator.code() {
+            quote!(err.code = ::std::borrow::Cow::from(#code);)
+        } else {
+            quote!()
+        };
+
+        // Functions can return several errors and inline closures a `bool` as well
+        let call = match syn::parse_str::<syn::Expr>(function) {
+            Ok(syn::Expr::Path(fn_ident)) => quote!(#fn_ident(#validator_param #access)),
+            Ok(syn::Expr::Closure(closure)) => quote!((#closure)(#validator_param #access)),
+            _ => unreachable!("the function of `custom` is checked when parsing"),
+        };
+
+        let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
+        let quoted = quote!(
+            match ::validator::CustomResult::into_errors(#call, #code) {
+                ::std::result::Result::Ok(()) => (),
+                ::std::result::Result::Err(errs) => {
+                    for mut err in errs {
+                        #set_code_quoted
+                        #add_message_quoted
+                        #value_param
+                        #finish_error
+                        errors.add(#field_name, err);
+                    }
+                },
+            };
+        );
+
+        return field_quoter.wrap_if_option(quoted);
+    }
+
+    unreachable!();
+}
+
+pub fn quote_rule_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
+    let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
+    let validator_param = field_quoter.quote_validator_param();
+
+    if let Validator::Rule(ref name) = validation.validator {
         let add_message_quoted = if let Some(ref m) = validation.message {
             quote!(err.message = Some(::std::borrow::Cow::from(#m));)
         } else {
             quote!()
         };
 
+        // Like `custom`, the registered function sets the code unless one is given
+        let code = &validation.code;
+        let set_code_quoted = if code != validation.validator.code() {
+            quote!(err.code = ::std::borrow::Cow::from(#code);)
+        } else {
+            quote!()
+        };
+
+        let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
         let quoted = quote!(
-            match #fn_ident(#validator_param #access) {
+            match ::validator::validate_registered(#name, #validator_param) {
                 ::std::result::Result::Ok(()) => (),
                 ::std::result::Result::Err(mut err) => {
+                    #set_code_quoted
                     #add_message_quoted
-                    err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+                    #value_param
+                    #finish_error
+                    errors.add(#field_name, err);
+                },
+            };
+        );
+
+        return field_quoter.wrap_if_option(quoted);
+    }
+
+    unreachable!();
+}
+
+#[cfg(feature = "async")]
+pub fn quote_custom_async_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
+    let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
+    let validator_param = field_quoter.quote_validator_param();
+
+    if let Validator::CustomAsync { function } = &validation.validator {
+        let fn_ident: syn::Path = syn::parse_str(function).unwrap();
+
+        let add_message_quoted = if let Some(ref m) = validation.message {
+            quote!(err.message = Some(::std::borrow::Cow::from(#m));)
+        } else {
+            quote!()
+        };
+
+        let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
+        let quoted = quote!(
+            match #fn_ident(#validator_param).await {
+                ::std::result::Result::Ok(()) => (),
+                ::std::result::Result::Err(mut err) => {
+                    #add_message_quoted
+                    #value_param
+                    #finish_error
                     errors.add(#field_name, err);
                 },
             };
@@ -460,15 +1058,39 @@ pub fn quote_contains_validation(
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
     let validator_param = field_quoter.quote_validator_param();
 
-    if let Validator::Contains(ref needle) = validation.validator {
+    if let Validator::Contains(ValueOrPath::Path(ref words)) = validation.validator {
+        let words: syn::Expr = syn::parse_str(words).unwrap();
+        let quoted_error = quote_error(validation);
+        let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
+        let quoted = quote!(
+            if !::validator::validate_contains_word(#validator_param, &(#words)) {
+                #quoted_error
+                #value_param
+                #finish_error
+                errors.add(#field_name, err);
+            }
+        );
+
+        return field_quoter.wrap_if_option(quoted);
+    }
+
+    if let Validator::Contains(ValueOrPath::Value(ref needle)) = validation.validator {
         let quoted_error = quote_error(validation);
+        let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
         let quoted = quote!(
             if !::validator::validate_contains(#validator_param, &#needle) {
                 #quoted_error
-                err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
-                err.add_param(::std::borrow::Cow::from("needle"), &#needle);
+                #value_param
+                err.add_param_value(::std::borrow::Cow::from("needle"), #needle);
+                #finish_error
                 errors.add(#field_name, err);
             }
         );
@@ -484,20 +1106,51 @@ pub fn quote_regex_validation(
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
     let validator_param = field_quoter.quote_validator_param();
 
     if let Validator::Regex(ref re) = validation.validator {
-        let re_ident: syn::Path = syn::parse_str(re).unwrap();
+        let (regex_static, re_ident) = match re {
+            ValueOrPath::Value(pattern) => (
+                quote!(
+                    static REGEX: ::std::sync::OnceLock<::validator::__private::Regex> =
+                        ::std::sync::OnceLock::new();
+                ),
+                quote!(REGEX.get_or_init(|| ::validator::__private::Regex::new(#pattern).unwrap())),
+            ),
+            ValueOrPath::Path(path) => {
+                let re_expr: syn::Expr = syn::parse_str(path).unwrap();
+                (quote!(), quote!((#re_expr)))
+            }
+        };
         let quoted_error = quote_error(validation);
+        let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
+        // The whole value doesn't match
+        let span_param = field_quoter.quote_span_params(quote!(::std::option::Option::Some(
+            0..<str>::len(#validator_param)
+        )));
         let quoted = quote!(
-            if !#re_ident.is_match(#validator_param) {
+            if !#re_ident.as_regex().is_match(#validator_param) {
                 #quoted_error
-                err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+                #value_param
+                #span_param
+                #finish_error
                 errors.add(#field_name, err);
             }
         );
 
-        return field_quoter.wrap_if_option(quoted);
+        let quoted = field_quoter.wrap_if_option(quoted);
+        return quote!({
+            #[allow(unused_imports)]
+            use ::validator::AsRegex as _;
+            #regex_static
+            #quoted
+        });
     }
 
     unreachable!();
@@ -506,10 +1159,87 @@ pub fn quote_regex_validation(
 pub fn quote_nested_validation(field_quoter: &FieldQuoter) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
     let validator_field = field_quoter.quote_validator_field();
-    let quoted = quote!(result = ::validator::ValidationErrors::merge(result, #field_name, #validator_field.validate()););
+    // `FAIL_FAST` is the const parameter of the generated validation function
+    let quoted = quote!(
+        result = ::validator::ValidationErrors::merge(
+            result,
+            #field_name,
+            ::validator::__private::validate_nested::<FAIL_FAST, _>(&#validator_field),
+        );
+    );
     field_quoter.wrap_if_option(field_quoter.wrap_if_collection(quoted))
 }
 
+/// Awaits the `ValidateAsync` implementation of the nested values when their type has one, adding
+/// the errors of their async validators to the ones `Validate` found for them
+#[cfg(feature = "async")]
+pub fn quote_nested_async_validation(field_quoter: &FieldQuoter) -> proc_macro2::TokenStream {
+    let field_name = &field_quoter.name;
+    let field_ident = &field_quoter.ident;
+    let member = &field_quoter.member;
+    let optional = field_quoter._type.starts_with("Option<");
+
+    let nested = |value: proc_macro2::TokenStream| {
+        quote!({
+            use ::validator::__private::{ViaValidate as _, ViaValidateAsync as _};
+            match (&::validator::__private::AsyncNested(#value)).validate_nested_async() {
+                ::std::option::Option::Some(future) => ::validator::ValidationErrors::merge(
+                    ::std::result::Result::Ok(()),
+                    #field_name,
+                    future.await,
+                ),
+                ::std::option::Option::None => ::std::result::Result::Ok(()),
+            }
+        })
+    };
+    let collection = if optional { quote!(#field_ident) } else { quote!(self.#member) };
+
+    // The elements are awaited one after the other, the errors are keyed like in `Validate`
+    let result = if is_map(&field_quoter._type) {
+        let element = nested(quote!(__value));
+        quote!({
+            let mut results = ::std::vec::Vec::new();
+            for (__key, __value) in #collection.iter() {
+                let result = #element;
+                if result.is_err() {
+                    results.push((__key.to_string(), result));
+                }
+            }
+            ::validator::ValidationErrors::merge_map(::std::result::Result::Ok(()), #field_name, results)
+        })
+    } else if is_list(&field_quoter._type) {
+        let element = nested(quote!(__value));
+        quote!({
+            let mut results = ::std::vec::Vec::new();
+            for __value in #collection.iter() {
+                results.push(#element);
+            }
+            ::validator::ValidationErrors::merge_list(::std::result::Result::Ok(()), #field_name, results)
+        })
+    } else if optional {
+        nested(quote!(#field_ident))
+    } else {
+        nested(quote!(&self.#member))
+    };
+
+    // Like in `Validate`, the nested values aren't validated when the field has errors of its own
+    field_quoter.wrap_if_option(quote!(
+        if !::std::matches!(
+            errors.errors().get(#field_name),
+            ::std::option::Option::Some(::validator::ValidationErrorsKind::Field(_))
+        ) {
+            if let ::std::result::Result::Err(nested) = #result {
+                errors.extend(nested);
+            }
+        }
+    ))
+}
+
+/// Validates the keys and elements of a collection that doesn't have nested validation
+pub fn quote_collection_validation(field_quoter: &FieldQuoter) -> proc_macro2::TokenStream {
+    field_quoter.wrap_if_option(field_quoter.wrap_if_collection(quote!()))
+}
+
 pub fn quote_validator(
     field_quoter: &FieldQuoter,
     validation: &FieldValidation,
@@ -523,14 +1253,27 @@ pub fn quote_validator(
         Validator::Range { .. } => {
             validations.push(quote_range_validation(field_quoter, validation))
         }
+        Validator::Percentage { .. } | Validator::Ratio { .. } => {
+            validations.push(quote_percentage_validation(field_quoter, validation))
+        }
+        Validator::BitsWithin(_) => {
+            validations.push(quote_bits_within_validation(field_quoter, validation))
+        }
         Validator::Email => validations.push(quote_email_validation(field_quoter, validation)),
         Validator::Url => validations.push(quote_url_validation(field_quoter, validation)),
         Validator::MustMatch(_) => {
             validations.push(quote_must_match_validation(field_quoter, validation))
         }
+        Validator::Compare { .. } => {
+            validations.push(quote_compare_validation(field_quoter, validation))
+        }
         Validator::Custom { .. } => {
             validations.push(quote_custom_validation(field_quoter, validation))
         }
+        Validator::Rule(_) => validations.push(quote_rule_validation(field_quoter, validation)),
+        // Only awaited by the `ValidateAsync` implementation
+        #[cfg(feature = "async")]
+        Validator::CustomAsync { .. } => (),
         Validator::Contains(_) => {
             validations.push(quote_contains_validation(field_quoter, validation))
         }
@@ -555,12 +1298,40 @@ pub fn quote_validator(
     }
 }
 
-pub fn quote_schema_validation(v: &SchemaValidation) -> proc_macro2::TokenStream {
+pub fn quote_schema_validation(
+    v: &SchemaValidation,
+    code_prefix: &Option<String>,
+) -> proc_macro2::TokenStream {
+    let quoted = if let Some(ref expr) = v.expr {
+        quote_schema_expr_validation(v, expr, code_prefix)
+    } else {
+        quote_schema_function_validation(v, code_prefix)
+    };
+
+    if !v.skip_on_field_errors {
+        return quoted;
+    }
+
+    quote!(
+        if !has_field_errors {
+            #quoted
+        }
+    )
+}
+
+/// The function returns either a `ValidationError` for `__all__` or `ValidationErrors` for
+/// some fields of the struct, the `code` and `message` of the validation apply to all of them
+fn quote_schema_function_validation(
+    v: &SchemaValidation,
+    code_prefix: &Option<String>,
+) -> proc_macro2::TokenStream {
     let fn_ident: syn::Path = syn::parse_str(&v.function).unwrap();
 
     let arg_quoted = if let Some(ref args) = v.args {
         let arg_type = &args.arg_access;
         quote!(self, #arg_type)
+    } else if v.use_context {
+        quote!(self, args)
     } else {
         quote!(self)
     };
@@ -571,29 +1342,78 @@ pub fn quote_schema_validation(v: &SchemaValidation) -> proc_macro2::TokenStream
         quote!()
     };
 
-    let mut_err_token = if v.message.is_some() { quote!(mut) } else { quote!() };
+    let set_code_quoted = if let Some(ref c) = v.code {
+        quote!(err.code = ::std::borrow::Cow::from(#c);)
+    } else {
+        quote!()
+    };
 
-    let quoted = quote!(
-        match #fn_ident(#arg_quoted) {
-            ::std::result::Result::Ok(()) => (),
-            ::std::result::Result::Err(#mut_err_token err) => {
-                #add_message_quoted
-                errors.add("__all__", err);
-            },
-        };
-    );
+    let finish_error = quote_finish_error(quote!(&field), code_prefix, &v.message, &None);
 
-    if !v.skip_on_field_errors {
-        return quoted;
-    }
+    let mut_err_token = if v.message.is_some() || v.code.is_some() || code_prefix.is_some() {
+        quote!(mut)
+    } else {
+        quote!()
+    };
+
+    quote!(
+        if let ::std::result::Result::Err(schema_errors) =
+            ::validator::SchemaResult::into_errors(#fn_ident(#arg_quoted))
+        {
+            for (field, kind) in schema_errors.into_errors() {
+                match kind {
+                    ::validator::ValidationErrorsKind::Field(field_errors) => {
+                        for #mut_err_token err in field_errors {
+                            #set_code_quoted
+                            #add_message_quoted
+                            #finish_error
+                            errors.add(field.clone(), err);
+                        }
+                    }
+                    kind => {
+                        if !errors.errors().contains_key(&field) {
+                            errors.errors_mut().insert(field, kind);
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+/// An `expr` validation adds an error with the given code, `expr` by default, when the expression
+/// is false
+fn quote_schema_expr_validation(
+    v: &SchemaValidation,
+    expr: &str,
+    code_prefix: &Option<String>,
+) -> proc_macro2::TokenStream {
+    let predicate = quote_self_predicate(&syn::parse_str(expr).unwrap());
+    let code = v.code.as_deref().unwrap_or("expr");
+
+    let add_message_quoted = if let Some(ref m) = v.message {
+        quote!(err.message = Some(::std::borrow::Cow::from(#m));)
+    } else {
+        quote!()
+    };
+
+    let finish_error = quote_finish_error("__all__", code_prefix, &v.message, &None);
 
     quote!(
-        #quoted
+        if !(#predicate) {
+            let mut err = ::validator::ValidationError::new(#code);
+            #add_message_quoted
+            #finish_error
+            errors.add("__all__", err);
+        }
     )
 }
 
-pub fn quote_schema_validations(validation: &[SchemaValidation]) -> Vec<proc_macro2::TokenStream> {
-    validation.iter().map(quote_schema_validation).collect()
+pub fn quote_schema_validations(
+    validation: &[SchemaValidation],
+    code_prefix: &Option<String>,
+) -> Vec<proc_macro2::TokenStream> {
+    validation.iter().map(|v| quote_schema_validation(v, code_prefix)).collect()
 }
 
 pub fn quote_required_validation(
@@ -601,19 +1421,38 @@ pub fn quote_required_validation(
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
-    let ident = &field_quoter.ident;
-    let validator_param = quote!(&self.#ident);
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
+    let member = &field_quoter.member;
+    // For an `Option<Option<T>>`, the outer option tells whether the field is present and the
+    // inner one whether it is null: a field that is absent is valid, only a null is an error
+    let double_option = field_quoter._type.starts_with("Option<Option<");
+    let validator_param = if double_option { quote!(__inner) } else { quote!(&self.#member) };
 
     let quoted_error = quote_error(validation);
+    let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
     let quoted = quote!(
         if !::validator::validate_required(#validator_param) {
             #quoted_error
-            err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+            #value_param
+            #finish_error
             errors.add(#field_name, err);
         }
     );
 
-    quoted
+    if double_option {
+        quote!(
+            if let ::std::option::Option::Some(ref __inner) = self.#member {
+                #quoted
+            }
+        )
+    } else {
+        quoted
+    }
 }
 
 pub fn quote_does_not_contain_validation(
@@ -621,15 +1460,48 @@ pub fn quote_does_not_contain_validation(
     validation: &FieldValidation,
 ) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
+    let finish_error = quote_finish_error(
+        field_name,
+        &field_quoter.code_prefix,
+        &validation.message,
+        &validation.message_fn,
+    );
     let validator_param = field_quoter.quote_validator_param();
 
-    if let Validator::DoesNotContain(ref needle) = validation.validator {
+    // The word found is a param of the error, unless the value is redacted as it is part of it
+    if let Validator::DoesNotContain(ValueOrPath::Path(ref words)) = validation.validator {
+        let words: syn::Expr = syn::parse_str(words).unwrap();
+        let quoted_error = quote_error(validation);
+        let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
+        let word_param = if field_quoter.redact {
+            quote!()
+        } else {
+            quote!(err.add_param(::std::borrow::Cow::from("word"), &__word);)
+        };
+        let quoted = quote!(
+            if let ::std::option::Option::Some(__word) =
+                (#words).find(::std::convert::AsRef::<str>::as_ref(&#validator_param))
+            {
+                #quoted_error
+                #value_param
+                #word_param
+                #finish_error
+                errors.add(#field_name, err);
+            }
+        );
+
+        return field_quoter.wrap_if_option(quoted);
+    }
+
+    if let Validator::DoesNotContain(ValueOrPath::Value(ref needle)) = validation.validator {
         let quoted_error = quote_error(validation);
+        let value_param = field_quoter.quote_value_param("value", quote!(&#validator_param));
         let quoted = quote!(
             if !::validator::validate_does_not_contain(#validator_param, &#needle) {
                 #quoted_error
-                err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
-                err.add_param(::std::borrow::Cow::from("needle"), &#needle);
+                #value_param
+                err.add_param_value(::std::borrow::Cow::from("needle"), #needle);
+                #finish_error
                 errors.add(#field_name, err);
             }
         );
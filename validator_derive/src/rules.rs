@@ -0,0 +1,174 @@
+use quote::quote;
+use validator_types::{Validator, ValueOrPath};
+
+use crate::lit::value_or_path_to_tokens;
+use crate::validation::{FieldInformation, FieldValidation, SchemaValidation};
+
+/// The `FieldRules` of each field with validators, and of the struct level validations in `__all__`
+pub fn quote_validation_rules(
+    fields: &[FieldInformation],
+    struct_validations: &[SchemaValidation],
+    code_prefix: &Option<String>,
+) -> Vec<proc_macro2::TokenStream> {
+    let quote_rules = |validations: Option<&[FieldValidation]>| {
+        let rules = validations.unwrap_or_default().iter().map(|v| quote_rule(v, code_prefix));
+        quote!(::std::vec![#(#rules),*])
+    };
+
+    let mut field_rules: Vec<_> = fields
+        .iter()
+        .filter(|f| !f.validations.is_empty() || f.keys.is_some() || f.inner.is_some())
+        .map(|f| {
+            let name = &f.name;
+            let field_type = &f.field_type;
+            let rules = quote_rules(Some(&f.validations));
+            let keys = quote_rules(f.keys.as_ref().map(|k| k.validations.as_slice()));
+            let inner = quote_rules(f.inner.as_ref().map(|i| i.validations.as_slice()));
+            let conditional = f.condition.is_some();
+            quote!(
+                ::validator::FieldRules {
+                    field: #name,
+                    field_type: #field_type,
+                    rules: #rules,
+                    keys: #keys,
+                    inner: #inner,
+                    conditional: #conditional,
+                }
+            )
+        })
+        .collect();
+
+    if !struct_validations.is_empty() {
+        let rules = struct_validations.iter().map(|v| quote_schema_rule(v, code_prefix));
+        field_rules.push(quote!(::validator::FieldRules {
+            field: "__all__",
+            field_type: "",
+            rules: ::std::vec![#(#rules),*],
+            keys: ::std::vec![],
+            inner: ::std::vec![],
+            conditional: false,
+        }));
+    }
+
+    field_rules
+}
+
+fn prefixed_code(code: &str, code_prefix: &Option<String>) -> String {
+    match code_prefix {
+        Some(prefix) => format!("{}{}", prefix, code),
+        None => code.to_string(),
+    }
+}
+
+fn quote_rule(
+    validation: &FieldValidation,
+    code_prefix: &Option<String>,
+) -> proc_macro2::TokenStream {
+    let kind = validation.validator.code();
+    let code = prefixed_code(&validation.code, code_prefix);
+    let message = quote_message(&validation.message);
+    let params = rule_params(&validation.validator)
+        .into_iter()
+        .map(|(name, value)| quote!(rule.add_param(::std::borrow::Cow::from(#name), &#value);));
+
+    quote!({
+        #[allow(unused_mut)]
+        let mut rule = ::validator::Rule::new(#kind, #code);
+        #message
+        #(#params)*
+        rule
+    })
+}
+
+fn quote_schema_rule(
+    validation: &SchemaValidation,
+    code_prefix: &Option<String>,
+) -> proc_macro2::TokenStream {
+    let (kind, param, value) = match validation.expr {
+        Some(ref expr) => ("expr", "expr", expr),
+        None => ("schema", "function", &validation.function),
+    };
+    let code = prefixed_code(validation.code.as_deref().unwrap_or(kind), code_prefix);
+    let message = quote_message(&validation.message);
+
+    quote!({
+        let mut rule = ::validator::Rule::new(#kind, #code);
+        #message
+        rule.add_param(::std::borrow::Cow::from(#param), &#value);
+        rule
+    })
+}
+
+fn quote_message(message: &Option<String>) -> proc_macro2::TokenStream {
+    match message {
+        Some(m) => {
+            quote!(rule.message = ::std::option::Option::Some(::std::borrow::Cow::from(#m));)
+        }
+        None => quote!(),
+    }
+}
+
+/// The arguments of a validator, named like the params of its errors
+fn rule_params(validator: &Validator) -> Vec<(&'static str, proc_macro2::TokenStream)> {
+    let mut params = vec![];
+    let mut push_value_or_path = |name, value: Option<proc_macro2::TokenStream>| {
+        if let Some(value) = value {
+            params.push((name, value));
+        }
+    };
+
+    match validator {
+        Validator::Length { min, max, equal } => {
+            push_value_or_path("min", min.as_ref().map(value_or_path_to_tokens));
+            push_value_or_path("max", max.as_ref().map(value_or_path_to_tokens));
+            push_value_or_path("equal", equal.as_ref().map(value_or_path_to_tokens));
+        }
+        Validator::Range { min, max, exclusive_min, exclusive_max, step } => {
+            push_value_or_path("min", min.as_ref().map(value_or_path_to_tokens));
+            push_value_or_path("max", max.as_ref().map(value_or_path_to_tokens));
+            push_value_or_path(
+                "exclusive_min",
+                exclusive_min.as_ref().map(value_or_path_to_tokens),
+            );
+            push_value_or_path(
+                "exclusive_max",
+                exclusive_max.as_ref().map(value_or_path_to_tokens),
+            );
+            push_value_or_path("step", step.as_ref().map(value_or_path_to_tokens));
+        }
+        Validator::Percentage { exclusive } | Validator::Ratio { exclusive } => {
+            push_value_or_path("exclusive", Some(quote!(#exclusive)));
+        }
+        Validator::BitsWithin(mask) => {
+            push_value_or_path("mask", Some(value_or_path_to_tokens(mask)))
+        }
+        Validator::MustMatch(other) | Validator::Compare { other, .. } => {
+            push_value_or_path("other", Some(quote!(#other)));
+        }
+        Validator::Contains(ValueOrPath::Value(needle))
+        | Validator::DoesNotContain(ValueOrPath::Value(needle)) => {
+            push_value_or_path("needle", Some(quote!(#needle)));
+        }
+        Validator::Contains(ValueOrPath::Path(words))
+        | Validator::DoesNotContain(ValueOrPath::Path(words)) => {
+            push_value_or_path("words", Some(quote!(#words)))
+        }
+        Validator::Regex(ValueOrPath::Value(pattern)) => {
+            push_value_or_path("pattern", Some(quote!(#pattern)))
+        }
+        Validator::Regex(ValueOrPath::Path(path)) => {
+            push_value_or_path("regex", Some(quote!(#path)))
+        }
+        Validator::Rule(name) => push_value_or_path("name", Some(quote!(#name))),
+        Validator::Custom { function, .. } => {
+            push_value_or_path("function", Some(quote!(#function)))
+        }
+        #[cfg(feature = "async")]
+        Validator::CustomAsync { function } => {
+            push_value_or_path("function", Some(quote!(#function)))
+        }
+        _ => (),
+    }
+
+    params
+}
@@ -1,16 +1,27 @@
 use proc_macro2::Span;
 use proc_macro_error::abort;
+use quote::quote;
 use syn::spanned::Spanned;
 
-use validator_types::{CustomArgument, Validator};
+use validator_types::{Comparison, CustomArgument, Validator, ValueOrPath};
 
-use crate::{asserts::assert_custom_arg_type, lit::*};
+use crate::{
+    asserts::{
+        assert_custom_arg_type, assert_custom_function, assert_message_fn, assert_regex_path,
+    },
+    lit::*,
+};
 
 #[derive(Debug)]
 pub struct SchemaValidation {
     pub function: String,
+    /// The boolean expression of an `expr` validation, used instead of the function
+    pub expr: Option<String>,
     pub args: Option<CustomArgument>,
+    pub use_context: bool,
     pub skip_on_field_errors: bool,
+    /// The position of the validation among the struct level ones, if set
+    pub order: Option<u64>,
     pub code: Option<String>,
     pub message: Option<String>,
 }
@@ -18,20 +29,62 @@ pub struct SchemaValidation {
 /// This struct holds the combined validation information for one filed
 #[derive(Debug)]
 pub struct FieldInformation {
-    pub field: syn::Field,
+    pub member: syn::Member,
     pub field_type: String,
     pub name: String,
     pub validations: Vec<FieldValidation>,
+    /// The condition that has to hold for the validations to run, if any
+    pub condition: Option<proc_macro2::TokenStream>,
+    /// The validations of the keys if the field is a map
+    pub keys: Option<Box<FieldInformation>>,
+    /// The validations of the elements if the field is a list, or of the values if it is a map
+    pub inner: Option<Box<FieldInformation>>,
+    /// `#[validate(redact)]`: the value of the field is never added to the params of its errors
+    pub redact: bool,
+    /// `#[validate(spans)]`: the string validators add the byte range of the part of the value
+    /// that breaks their rule to the params of their errors
+    pub spans: bool,
+    /// `#[validate(nested, parallel)]`: the elements of the list are validated on all the cores
+    pub parallel: bool,
 }
 
 impl FieldInformation {
     pub fn new(
-        field: syn::Field,
+        member: syn::Member,
         field_type: String,
         name: String,
         validations: Vec<FieldValidation>,
+        condition: Option<proc_macro2::TokenStream>,
+        keys: Option<Box<FieldInformation>>,
+        inner: Option<Box<FieldInformation>>,
     ) -> Self {
-        FieldInformation { field, field_type, name, validations }
+        FieldInformation {
+            member,
+            field_type,
+            name,
+            validations,
+            condition,
+            keys,
+            inner,
+            redact: false,
+            spans: false,
+            parallel: false,
+        }
+    }
+
+    pub fn with_redact(mut self, redact: bool) -> Self {
+        self.redact = redact;
+        self
+    }
+
+    pub fn with_spans(mut self, spans: bool) -> Self {
+        self.spans = spans;
+        self
+    }
+
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
     }
 }
 
@@ -40,12 +93,69 @@ impl FieldInformation {
 pub struct FieldValidation {
     pub code: String,
     pub message: Option<String>,
+    /// A function computing the message from the error, instead of a fixed `message`
+    pub message_fn: Option<String>,
     pub validator: Validator,
+    /// `warning` or `info` for the validators that don't make the value invalid, `None` for errors
+    pub severity: Option<String>,
 }
 
 impl FieldValidation {
     pub fn new(validator: Validator) -> FieldValidation {
-        FieldValidation { code: validator.code().to_string(), validator, message: None }
+        FieldValidation {
+            code: validator.code().to_string(),
+            validator,
+            message: None,
+            message_fn: None,
+            severity: None,
+        }
+    }
+}
+
+/// Extract the condition of a `skip_if` or `validate_if` attribute as a boolean expression that
+/// is true when the validations of the field should run.
+///
+/// The string can be a path to a function taking `&Self` (`Self::is_draft`), a closure taking
+/// `&Self` (`|s: &Self| s.is_draft`) or any boolean expression (`self.is_draft`).
+pub fn extract_field_condition(
+    field: &str,
+    name: &str,
+    lit: &syn::Lit,
+) -> proc_macro2::TokenStream {
+    let expr = match lit_to_string(lit) {
+        Some(s) => match syn::parse_str::<syn::Expr>(&s) {
+            Ok(expr) => expr,
+            Err(_) => abort!(
+                lit.span(),
+                "Invalid attribute #[validate] on field `{}`: `{}` needs to be a valid expression",
+                field,
+                name
+            ),
+        },
+        None => abort!(
+            lit.span(),
+            "Invalid attribute #[validate] on field `{}`: invalid argument for `{}`: only strings are allowed",
+            field,
+            name
+        ),
+    };
+
+    let condition = quote_self_predicate(&expr);
+
+    if name == "skip_if" {
+        quote!(!(#condition))
+    } else {
+        quote!((#condition))
+    }
+}
+
+/// A predicate on the struct: a path to a function taking `&Self` or a closure are called with `self`,
+/// any other expression is used as is
+pub fn quote_self_predicate(expr: &syn::Expr) -> proc_macro2::TokenStream {
+    match expr {
+        syn::Expr::Path(ref path) => quote!(#path(self)),
+        syn::Expr::Closure(ref closure) => quote!((#closure)(self)),
+        ref expr => quote!(#expr),
     }
 }
 
@@ -58,7 +168,7 @@ pub fn extract_length_validation(
     let mut max = None;
     let mut equal = None;
 
-    let (message, code) = extract_message_and_code("length", &field, meta_items);
+    let (message, message_fn, code) = extract_message_and_code("length", &field, meta_items);
 
     let error = |span: Span, msg: &str| -> ! {
         abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
@@ -69,7 +179,7 @@ pub fn extract_length_validation(
             if let syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) = *item {
                 let ident = path.get_ident().unwrap();
                 match ident.to_string().as_ref() {
-                    "message" | "code" => continue,
+                    "message" | "message_fn" | "code" => continue,
                     "min" => {
                         min = match lit_to_u64_or_path(lit) {
                             Some(s) => Some(s),
@@ -119,8 +229,10 @@ pub fn extract_length_validation(
     let validator = Validator::Length { min, max, equal };
     FieldValidation {
         message,
+        message_fn,
         code: code.unwrap_or_else(|| validator.code().to_string()),
         validator,
+        severity: None,
     }
 }
 
@@ -128,6 +240,7 @@ const RANGE_MIN_KEY: &str = "min";
 const RANGE_EXCLUSIVE_MIN_KEY: &str = "exclusive_min";
 const RANGE_MAX_KEY: &str = "max";
 const RANGE_EXCLUSIVE_MAX_KEY: &str = "exclusive_max";
+const RANGE_STEP_KEY: &str = "step";
 
 pub fn extract_range_validation(
     field: String,
@@ -138,8 +251,9 @@ pub fn extract_range_validation(
     let mut max = None;
     let mut exclusive_min = None;
     let mut exclusive_max = None;
+    let mut step = None;
 
-    let (message, code) = extract_message_and_code("range", &field, meta_items);
+    let (message, message_fn, code) = extract_message_and_code("range", &field, meta_items);
 
     let error = |span: Span, msg: &str| -> ! {
         abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
@@ -151,7 +265,7 @@ pub fn extract_range_validation(
                 syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
                     let ident = path.get_ident().unwrap();
                     match ident.to_string().as_ref() {
-                        "message" | "code" => continue,
+                        "message" | "message_fn" | "code" => continue,
                         RANGE_MIN_KEY => {
                             min = match lit_to_f64_or_path(lit) {
                                 Some(s) => Some(s),
@@ -176,8 +290,15 @@ pub fn extract_range_validation(
                                 None => error(lit.span(), &lit_to_f64_error_message(RANGE_EXCLUSIVE_MAX_KEY))
                             };
                         }
+                        RANGE_STEP_KEY => {
+                            step = match lit_to_f64_or_path(lit) {
+                                Some(ValueOrPath::Value(s)) if s <= 0.0 => error(lit.span(), "`step` of `range` validator needs to be a positive number"),
+                                Some(s) => Some(s),
+                                None => error(lit.span(), &lit_to_f64_error_message(RANGE_STEP_KEY))
+                            };
+                        }
                         v => error(path.span(), &format!(
-                            "unknown argument `{}` for validator `range` (it only has `min`, `max`)",
+                            "unknown argument `{}` for validator `range` (it only has `min`, `max`, `step`)",
                             v
                         )),
                     }
@@ -192,12 +313,16 @@ pub fn extract_range_validation(
         }
     }
 
-    if [&min, &max, &exclusive_min, &exclusive_max].iter().all(|x| x.is_none()) {
+    if [&min, &max, &exclusive_min, &exclusive_max, &step].iter().all(|x| x.is_none()) {
         error(
             attr.span(),
             &format!(
-                "Validator `range` requires at least 1 argument out of `{}`, `{}`, `{}` and `{}`",
-                RANGE_MIN_KEY, RANGE_MAX_KEY, RANGE_EXCLUSIVE_MIN_KEY, RANGE_EXCLUSIVE_MAX_KEY
+                "Validator `range` requires at least 1 argument out of `{}`, `{}`, `{}`, `{}` and `{}`",
+                RANGE_MIN_KEY,
+                RANGE_MAX_KEY,
+                RANGE_EXCLUSIVE_MIN_KEY,
+                RANGE_EXCLUSIVE_MAX_KEY,
+                RANGE_STEP_KEY
             ),
         );
     }
@@ -211,11 +336,129 @@ pub fn extract_range_validation(
             )
         )
     }
-    let validator = Validator::Range { min, max, exclusive_min, exclusive_max };
+    let validator = Validator::Range { min, max, exclusive_min, exclusive_max, step };
+    FieldValidation {
+        message,
+        message_fn,
+        code: code.unwrap_or_else(|| validator.code().to_string()),
+        validator,
+        severity: None,
+    }
+}
+
+pub fn extract_bits_within_validation(
+    field: String,
+    attr: &syn::Attribute,
+    meta_items: &[syn::NestedMeta],
+) -> FieldValidation {
+    let mut mask = None;
+
+    let (message, message_fn, code) = extract_message_and_code("bits_within", &field, meta_items);
+
+    let error = |span: Span, msg: &str| -> ! {
+        abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
+    };
+
+    for meta_item in meta_items {
+        match *meta_item {
+            syn::NestedMeta::Meta(ref item) => match *item {
+                syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
+                    let ident = path.get_ident().unwrap();
+                    match ident.to_string().as_ref() {
+                        "message" | "message_fn" | "code" => continue,
+                        "mask" => {
+                            mask = match lit_to_u64_or_path(lit) {
+                                Some(s) => Some(s),
+                                None => error(lit.span(), "invalid argument type for `mask` of `bits_within` validator: only number literals or value paths are allowed"),
+                            };
+                        }
+                        v => error(path.span(), &format!(
+                            "unknown argument `{}` for validator `bits_within` (it only has `mask`)",
+                            v
+                        )),
+                    }
+                }
+                _ => abort!(
+                    item.span(),
+                    "unexpected item {:?} while parsing `bits_within` validator",
+                    item
+                ),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    let validator = match mask {
+        Some(mask) => Validator::BitsWithin(mask),
+        None => error(attr.span(), "Validator `bits_within` requires the `mask` argument"),
+    };
+
+    FieldValidation {
+        message,
+        message_fn,
+        code: code.unwrap_or_else(|| validator.code().to_string()),
+        validator,
+        severity: None,
+    }
+}
+
+/// Extract percentage/ratio field validation with an optional `exclusive` flag, a code or a message
+pub fn extract_percentage_validation(
+    validator_name: String,
+    field: String,
+    meta_items: &[syn::NestedMeta],
+) -> FieldValidation {
+    let mut exclusive = false;
+    let (message, message_fn, code) = extract_message_and_code(&validator_name, &field, meta_items);
+
+    for meta_item in meta_items {
+        match *meta_item {
+            syn::NestedMeta::Meta(ref item) => match *item {
+                syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
+                    let ident = path.get_ident().unwrap();
+                    match ident.to_string().as_ref() {
+                        "message" | "message_fn" | "code" => continue,
+                        "exclusive" => {
+                            exclusive = match lit_to_bool(lit) {
+                                Some(b) => b,
+                                None => abort!(
+                                    lit.span(),
+                                    "Invalid argument type for `exclusive` for validator `{}` on field `{}`: only a bool is allowed",
+                                    validator_name, field
+                                ),
+                            };
+                        }
+                        v => abort!(
+                            path.span(),
+                            "unknown argument `{}` for validator `{}` (it only has `exclusive`)",
+                            v,
+                            validator_name
+                        ),
+                    }
+                }
+                _ => abort!(
+                    item.span(),
+                    "unexpected item {:?} while parsing `{}` validator",
+                    item,
+                    validator_name
+                ),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    let validator = match validator_name.as_ref() {
+        "percentage" => Validator::Percentage { exclusive },
+        "ratio" => Validator::Ratio { exclusive },
+        _ => unreachable!(),
+    };
+
     FieldValidation {
         message,
+        message_fn,
         code: code.unwrap_or_else(|| validator.code().to_string()),
         validator,
+        severity: None,
     }
 }
 
@@ -230,8 +473,9 @@ pub fn extract_custom_validation(
 ) -> FieldValidation {
     let mut function = None;
     let mut argument = None;
+    let mut use_context = false;
 
-    let (message, code) = extract_message_and_code("custom", &field, meta_items);
+    let (message, message_fn, code) = extract_message_and_code("custom", &field, meta_items);
 
     let error = |span: Span, msg: &str| -> ! {
         abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
@@ -243,10 +487,13 @@ pub fn extract_custom_validation(
                 syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
                     let ident = path.get_ident().unwrap();
                     match ident.to_string().as_ref() {
-                        "message" | "code" => continue,
+                        "message" | "message_fn" | "code" => continue,
                         "function" => {
                             function = match lit_to_string(lit) {
-                                Some(s) => Some(s),
+                                Some(s) => {
+                                    assert_custom_function(&lit.span(), &s);
+                                    Some(s)
+                                }
                                 None => error(lit.span(), "invalid argument type for `function` of `custom` validator: expected a string")
                             };
                         }
@@ -270,11 +517,12 @@ pub fn extract_custom_validation(
                             };
                         }
                         v => error(path.span(), &format!(
-                            "unknown argument `{}` for validator `custom` (it only has `function`, `arg`)",
+                            "unknown argument `{}` for validator `custom` (it only has `function`, `arg`, `use_context`)",
                             v
                         )),
                     }
                 }
+                syn::Meta::Path(ref path) if path.is_ident("use_context") => use_context = true,
                 _ => abort!(
                     item.span(),
                     "unexpected item {:?} while parsing `custom` validator",
@@ -289,11 +537,21 @@ pub fn extract_custom_validation(
         error(attr.span(), "The validator `custom` requires the `function` parameter.");
     }
 
-    let validator = Validator::Custom { function: function.unwrap(), argument: Box::new(argument) };
+    if use_context && argument.is_some() {
+        error(attr.span(), "The validator `custom` can't have both `arg` and `use_context`");
+    }
+
+    let validator = Validator::Custom {
+        function: function.unwrap(),
+        argument: Box::new(argument),
+        use_context,
+    };
     FieldValidation {
         message,
+        message_fn,
         code: code.unwrap_or_else(|| validator.code().to_string()),
         validator,
+        severity: None,
     }
 }
 
@@ -303,7 +561,7 @@ pub fn extract_argless_validation(
     field: String,
     meta_items: &[syn::NestedMeta],
 ) -> FieldValidation {
-    let (message, code) = extract_message_and_code(&validator_name, &field, meta_items);
+    let (message, message_fn, code) = extract_message_and_code(&validator_name, &field, meta_items);
 
     for meta_item in meta_items {
         match *meta_item {
@@ -311,7 +569,7 @@ pub fn extract_argless_validation(
                 syn::Meta::NameValue(syn::MetaNameValue { ref path, .. }) => {
                     let ident = path.get_ident().unwrap();
                     match ident.to_string().as_ref() {
-                        "message" | "code" => continue,
+                        "message" | "message_fn" | "code" => continue,
                         v => abort!(
                             meta_item.span(),
                             "Unknown argument `{}` for validator `{}` on field `{}`",
@@ -345,12 +603,92 @@ pub fn extract_argless_validation(
 
     FieldValidation {
         message,
+        message_fn,
         code: code.unwrap_or_else(|| validator.code().to_string()),
         validator,
+        severity: None,
     }
 }
 
-/// For custom, contains, regex, must_match
+/// For regex, taking either the `path` to a `Regex` or a `pattern` that is checked by the derive
+pub fn extract_regex_validation(field: String, meta_items: &[syn::NestedMeta]) -> FieldValidation {
+    let (message, message_fn, code) = extract_message_and_code("regex", &field, meta_items);
+    let mut regex = None;
+
+    for meta_item in meta_items {
+        match *meta_item {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                ref path,
+                ref lit,
+                ..
+            })) => {
+                let ident = path.get_ident().unwrap();
+                let value = match ident.to_string().as_ref() {
+                    "message" | "message_fn" | "code" => continue,
+                    "path" | "pattern" if regex.is_some() => abort!(
+                        path.span(),
+                        "Invalid argument for validator `regex` on field `{}`: only one of `path` and `pattern` is allowed",
+                        field
+                    ),
+                    "path" | "pattern" => match lit_to_string(lit) {
+                        Some(s) => s,
+                        None => abort!(
+                            lit.span(),
+                            "Invalid argument type for `{}` for validator `regex` on field `{}`: only a string is allowed",
+                            ident,
+                            field
+                        ),
+                    },
+                    v => abort!(
+                        path.span(),
+                        "Unknown argument `{}` for validator `regex` on field `{}`",
+                        v,
+                        field
+                    ),
+                };
+
+                if ident == "path" {
+                    assert_regex_path(&lit.span(), &value);
+                    regex = Some(ValueOrPath::Path(value));
+                } else {
+                    if let Err(e) = regex::Regex::new(&value) {
+                        abort!(
+                            lit.span(),
+                            "Invalid `pattern` for validator `regex` on field `{}`: {}",
+                            field,
+                            e
+                        );
+                    }
+                    regex = Some(ValueOrPath::Value(value));
+                }
+            }
+            _ => abort!(
+                meta_item.span(),
+                "unexpected item {:?} while parsing `regex` validator",
+                meta_item
+            ),
+        }
+    }
+
+    let validator = match regex {
+        Some(regex) => Validator::Regex(regex),
+        None => abort!(
+            Span::call_site(),
+            "Missing argument `path` or `pattern` for validator `regex` on field `{}`",
+            field
+        ),
+    };
+
+    FieldValidation {
+        message,
+        message_fn,
+        code: code.unwrap_or_else(|| validator.code().to_string()),
+        validator,
+        severity: None,
+    }
+}
+
+/// For custom, contains, must_match, rule and the comparisons
 pub fn extract_one_arg_validation(
     val_name: &str,
     validator_name: String,
@@ -358,7 +696,7 @@ pub fn extract_one_arg_validation(
     meta_items: &[syn::NestedMeta],
 ) -> FieldValidation {
     let mut value = None;
-    let (message, code) = extract_message_and_code(&validator_name, &field, meta_items);
+    let (message, message_fn, code) = extract_message_and_code(&validator_name, &field, meta_items);
 
     for meta_item in meta_items {
         match *meta_item {
@@ -366,7 +704,7 @@ pub fn extract_one_arg_validation(
                 syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
                     let ident = path.get_ident().unwrap();
                     match ident.to_string().as_ref() {
-                        "message" | "code" => continue,
+                        "message" | "message_fn" | "code" => continue,
                         v if v == val_name => {
                             value = match lit_to_string(lit) {
                                 Some(s) => Some(s),
@@ -407,18 +745,35 @@ pub fn extract_one_arg_validation(
     }
 
     let validator = match validator_name.as_ref() {
-        "custom" => Validator::Custom { function: value.unwrap(), argument: Box::new(None) },
-        "contains" => Validator::Contains(value.unwrap()),
-        "does_not_contain" => Validator::DoesNotContain(value.unwrap()),
+        "custom" => Validator::Custom {
+            function: value.unwrap(),
+            argument: Box::new(None),
+            use_context: false,
+        },
+        #[cfg(feature = "async")]
+        "custom_async" => Validator::CustomAsync { function: value.unwrap() },
+        "contains" if val_name == "words" => Validator::Contains(ValueOrPath::Path(value.unwrap())),
+        "contains" => Validator::Contains(ValueOrPath::Value(value.unwrap())),
+        "does_not_contain" if val_name == "words" => {
+            Validator::DoesNotContain(ValueOrPath::Path(value.unwrap()))
+        }
+        "does_not_contain" => Validator::DoesNotContain(ValueOrPath::Value(value.unwrap())),
         "must_match" => Validator::MustMatch(value.unwrap()),
-        "regex" => Validator::Regex(value.unwrap()),
-        _ => unreachable!(),
+        "rule" => Validator::Rule(value.unwrap()),
+        name => match Comparison::from_name(name) {
+            Some(comparison) => {
+                Validator::Compare { comparison, other: value.unwrap(), other_is_option: false }
+            }
+            None => unreachable!(),
+        },
     };
 
     FieldValidation {
         message,
+        message_fn,
         code: code.unwrap_or_else(|| validator.code().to_string()),
         validator,
+        severity: None,
     }
 }
 
@@ -426,8 +781,10 @@ fn extract_message_and_code(
     validator_name: &str,
     field: &str,
     meta_items: &[syn::NestedMeta],
-) -> (Option<String>, Option<String>) {
+) -> (Option<String>, Option<String>, Option<String>) {
     let mut message = None;
+    let mut message_fn = None;
+    let mut message_fn_span = None;
     let mut code = None;
 
     for meta_item in meta_items {
@@ -459,10 +816,33 @@ fn extract_message_and_code(
                                 ),
                     };
                 }
+                "message_fn" => {
+                    message_fn = match lit_to_string(lit) {
+                        Some(s) => {
+                            assert_message_fn(&lit.span(), &s);
+                            message_fn_span = Some(path.span());
+                            Some(s)
+                        }
+                        None => abort!(
+                                    meta_item.span(),
+                                    "Invalid argument type for `message_fn` for validator `{}` on field `{}`: only a string is allowed",
+                                    validator_name, field
+                                ),
+                    };
+                }
                 _ => continue,
             }
         }
     }
 
-    (message, code)
+    if let (Some(_), Some(span)) = (&message, message_fn_span) {
+        abort!(
+            span,
+            "Invalid attribute for validator `{}` on field `{}`: only one of `message` and `message_fn` is allowed",
+            validator_name,
+            field
+        );
+    }
+
+    (message, message_fn, code)
 }
@@ -0,0 +1,266 @@
+//! Benchmarks of the validators and of the derived validation, run with
+//! `cargo bench --bench validation`.
+//!
+//! The results are printed in nanoseconds per iteration, and can be compared with the ones of
+//! another revision:
+//!
+//! ```text
+//! git checkout master && cargo bench --bench validation -- --save-baseline master
+//! git checkout my-branch && cargo bench --bench validation -- --baseline master
+//! ```
+//!
+//! A benchmark more than `--threshold` percent (5 by default) slower than the baseline is a
+//! regression, which makes the run fail. A name given without dashes only runs the benchmarks
+//! containing it, like `cargo bench --bench validation -- email`.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+use validator::{
+    validate_email, validate_length, validate_non_control_character, validate_url, Validate,
+    ValidateEmail, ValidationError, ValidationErrors,
+};
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 1, max = 100))]
+    street: String,
+    #[validate(length(equal = 5))]
+    zip: String,
+}
+
+#[derive(Debug, Validate)]
+struct User {
+    #[validate(email)]
+    email: String,
+    #[validate(url)]
+    website: String,
+    #[validate(length(min = 1, max = 50))]
+    name: String,
+    #[validate(range(min = 18, max = 150))]
+    age: u32,
+    #[validate]
+    addresses: Vec<Address>,
+}
+
+fn user(email: &str) -> User {
+    User {
+        email: email.to_string(),
+        website: "https://example.com/users/bob".to_string(),
+        name: "Bob".to_string(),
+        age: 42,
+        addresses: (0..3)
+            .map(|_| Address { street: "1 Main Street".to_string(), zip: "12345".to_string() })
+            .collect(),
+    }
+}
+
+struct Options {
+    filter: Option<String>,
+    save_baseline: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+impl Options {
+    fn from_args() -> Options {
+        let mut options =
+            Options { filter: None, save_baseline: None, baseline: None, threshold: 5.0 };
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let mut value =
+                || args.next().unwrap_or_else(|| fail(&format!("{} needs a value", arg)));
+            match arg.as_str() {
+                "--save-baseline" => options.save_baseline = Some(value()),
+                "--baseline" => options.baseline = Some(value()),
+                "--threshold" => {
+                    options.threshold =
+                        value().parse().unwrap_or_else(|_| fail("--threshold needs a number"))
+                }
+                // Passed by `cargo bench`
+                "--bench" => (),
+                _ if arg.starts_with('-') => fail(&format!("unknown option {}", arg)),
+                _ => options.filter = Some(arg),
+            }
+        }
+        options
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("error: {}", message);
+    process::exit(2);
+}
+
+/// Runs the benchmarks and keeps the median time of their samples, in nanoseconds per iteration
+struct Runner {
+    filter: Option<String>,
+    results: BTreeMap<String, f64>,
+}
+
+impl Runner {
+    const SAMPLES: usize = 20;
+    const SAMPLE_TIME: Duration = Duration::from_millis(25);
+
+    fn bench<R>(&mut self, name: &str, mut f: impl FnMut() -> R) {
+        if self.filter.as_ref().is_some_and(|filter| !name.contains(filter.as_str())) {
+            return;
+        }
+
+        // Finds how many iterations take about the time of a sample, which also warms up
+        let mut iterations = 1u64;
+        loop {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                black_box(f());
+            }
+            if start.elapsed() >= Self::SAMPLE_TIME {
+                break;
+            }
+            iterations *= 2;
+        }
+
+        let mut samples: Vec<f64> = (0..Self::SAMPLES)
+            .map(|_| {
+                let start = Instant::now();
+                for _ in 0..iterations {
+                    black_box(f());
+                }
+                start.elapsed().as_nanos() as f64 / iterations as f64
+            })
+            .collect();
+        samples.sort_by(|a, b| a.total_cmp(b));
+        let median = samples[samples.len() / 2];
+        println!("{:<40} {:>12.1} ns/iter", name, median);
+        self.results.insert(name.to_string(), median);
+    }
+}
+
+fn baseline_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../target/validator-bench")
+        .join(format!("{}.txt", name))
+}
+
+fn save_baseline(name: &str, results: &BTreeMap<String, f64>) {
+    let path = baseline_path(name);
+    let content: String =
+        results.iter().map(|(bench, time)| format!("{} {}\n", bench, time)).collect();
+    fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| fs::write(&path, content))
+        .unwrap_or_else(|e| fail(&format!("unable to save {}: {}", path.display(), e)));
+    println!("\nSaved the baseline `{}` in {}", name, path.display());
+}
+
+fn load_baseline(name: &str) -> BTreeMap<String, f64> {
+    let path = baseline_path(name);
+    let content = fs::read_to_string(&path)
+        .unwrap_or_else(|e| fail(&format!("unable to read {}: {}", path.display(), e)));
+    content
+        .lines()
+        .filter_map(|line| {
+            let (bench, time) = line.rsplit_once(' ')?;
+            Some((bench.to_string(), time.parse().ok()?))
+        })
+        .collect()
+}
+
+/// Prints the change of each benchmark from the baseline, returning the number of regressions
+fn compare(
+    results: &BTreeMap<String, f64>,
+    baseline: &BTreeMap<String, f64>,
+    threshold: f64,
+) -> usize {
+    println!();
+    let mut regressions = 0;
+    for (bench, time) in results {
+        let before = match baseline.get(bench) {
+            Some(before) => before,
+            None => {
+                println!("{:<40} not in the baseline", bench);
+                continue;
+            }
+        };
+        let change = (time - before) / before * 100.0;
+        let verdict = if change > threshold {
+            regressions += 1;
+            "regressed"
+        } else if change < -threshold {
+            "improved"
+        } else {
+            "unchanged"
+        };
+        println!("{:<40} {:>+8.1}% {}", bench, change, verdict);
+    }
+    regressions
+}
+
+fn main() {
+    let options = Options::from_args();
+    let mut runner = Runner { filter: options.filter.clone(), results: BTreeMap::new() };
+
+    runner.bench("email/valid", || validate_email(black_box("bob.smith@example.com")));
+    runner.bench("email/invalid", || validate_email(black_box("bob.smith@@example.com")));
+    runner
+        .bench("email/idn", || validate_email(black_box("bob@domain.with.idn.tld.उदाहरण.परीक्षा")));
+    runner.bench("email/literal", || validate_email(black_box("bob@[2001:db8::1]")));
+    let owned: Cow<'static, str> = Cow::from("bob.smith@example.com".to_string());
+    runner.bench("email/cow", || black_box(&owned).validate_email());
+
+    runner.bench("url/valid", || validate_url(black_box("https://example.com/a/b?c=d#e")));
+    runner.bench("url/invalid", || validate_url(black_box("example com")));
+
+    let text = "Lorem ipsum dolor sit amet, société générale. ".repeat(1000);
+    runner.bench("non_control/large", || validate_non_control_character(black_box(text.as_str())));
+
+    let name = "Bob Smith".to_string();
+    let items: Vec<u32> = (0..100).collect();
+    runner.bench("length/string", || validate_length(black_box(&name), Some(1), Some(50), None));
+    runner.bench("length/vec", || validate_length(black_box(&items), Some(1), Some(500), None));
+
+    let valid = user("bob@example.com");
+    let invalid = User {
+        age: 12,
+        addresses: vec![Address { street: String::new(), zip: "1".to_string() }],
+        ..user("bob")
+    };
+    runner.bench("nested/valid", || black_box(&valid).validate());
+    runner.bench("nested/invalid", || black_box(&invalid).validate());
+
+    runner.bench("errors/add", || {
+        let mut errors = ValidationErrors::new();
+        for field in &["email", "website", "name", "age"] {
+            errors.add(*field, ValidationError::new("invalid"));
+        }
+        errors
+    });
+    runner.bench("errors/nested", || {
+        let mut address = ValidationErrors::new();
+        address.add("zip", ValidationError::new("length"));
+        let mut errors = ValidationErrors::new();
+        errors.add("email", ValidationError::new("email"));
+        errors.add_nested("address", address);
+        errors
+    });
+    let errors = invalid.validate().unwrap_err();
+    runner.bench("errors/iter", || black_box(&errors).iter().count());
+
+    if let Some(ref name) = options.save_baseline {
+        save_baseline(name, &runner.results);
+    }
+    if let Some(ref name) = options.baseline {
+        let regressions = compare(&runner.results, &load_baseline(name), options.threshold);
+        if regressions > 0 {
+            println!(
+                "\n{} benchmark(s) regressed by more than {}%",
+                regressions, options.threshold
+            );
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::collections::HashMap;
+
+use validator::{validate_email, Validate, ValidateEmail};
+
+/// Counts the allocations of the thread running the test
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get().map(|count| count + 1)));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    ALLOCATIONS.with(|count| count.set(Some(0)));
+    f();
+    ALLOCATIONS.with(|count| count.replace(None)).unwrap()
+}
+
+#[derive(Debug, Validate)]
+struct Contact {
+    #[validate(email)]
+    email: String,
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+#[derive(Debug, Validate)]
+struct AddressBook {
+    #[validate]
+    contacts: HashMap<String, Contact>,
+}
+
+#[test]
+fn valid_values_are_validated_without_allocating() {
+    let email = Cow::from("bob@example.com".to_string());
+    assert_eq!(allocations(|| assert!(email.validate_email())), 0);
+    assert_eq!(allocations(|| assert!(!validate_email("bob@"))), 0);
+
+    let mut contacts = HashMap::new();
+    for name in &["bob", "alice"] {
+        let contact = Contact { email: format!("{}@example.com", name), name: name.to_string() };
+        contacts.insert(name.to_string(), contact);
+    }
+    let book = AddressBook { contacts };
+    assert_eq!(allocations(|| assert!(book.validate().is_ok())), 0);
+}
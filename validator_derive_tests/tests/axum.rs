@@ -0,0 +1,59 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use validator::{ErrorsShape, Validate};
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(email(message = "Not an email"))]
+    mail: String,
+}
+
+fn errors() -> validator::ValidationErrors {
+    Signup { mail: "bob".to_string() }.validate().unwrap_err()
+}
+
+fn body(response: Response) -> serde_json::Value {
+    let bytes = futures::executor::block_on(axum::body::to_bytes(response.into_body(), usize::MAX))
+        .unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[test]
+fn errors_are_a_422_with_json() {
+    let response = errors().into_response();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(response.headers()["content-type"], "application/json");
+    assert_eq!(body(response)["mail"][0]["message"], "Not an email");
+}
+
+#[test]
+fn can_choose_the_status_and_shape() {
+    let response = errors()
+        .into_response_with(StatusCode::BAD_REQUEST, ErrorsShape::FlatMessages)
+        .into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(body(response), serde_json::json!({"mail": ["Not an email"]}));
+}
+
+#[test]
+fn problem_details_are_problem_json() {
+    let mut problem = errors().to_problem_details();
+    problem.status = 400;
+    let response = problem.into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(response.headers()["content-type"], "application/problem+json");
+    assert_eq!(body(response)["errors"][0]["pointer"], "/mail");
+}
+
+#[test]
+fn handlers_can_return_the_errors() {
+    fn handler(signup: Signup) -> Result<&'static str, validator::ValidationErrors> {
+        signup.validate()?;
+        Ok("Welcome")
+    }
+
+    let response = handler(Signup { mail: "bob".to_string() }).into_response();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let response = handler(Signup { mail: "bob@example.com".to_string() }).into_response();
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -0,0 +1,100 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum::response::Response;
+use axum::routing::{get, post};
+use axum::{Extension, Router};
+use serde::Deserialize;
+use tower::ServiceExt;
+use validator::{
+    ErrorsShape, RejectionConfig, Validate, ValidatedForm, ValidatedJson, ValidatedPath,
+    ValidatedQuery,
+};
+
+#[derive(Debug, Deserialize, Validate)]
+struct Signup {
+    #[validate(email(message = "Not an email"))]
+    mail: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+struct Page {
+    #[validate(range(min = 1))]
+    page: u32,
+}
+
+fn app() -> Router {
+    Router::new()
+        .route(
+            "/json",
+            post(|ValidatedJson(signup): ValidatedJson<Signup>| async move { signup.mail }),
+        )
+        .route(
+            "/form",
+            post(|ValidatedForm(signup): ValidatedForm<Signup>| async move { signup.mail }),
+        )
+        .route(
+            "/query",
+            get(|ValidatedQuery(page): ValidatedQuery<Page>| async move { page.page.to_string() }),
+        )
+        .route(
+            "/pages/{page}",
+            get(|ValidatedPath(page): ValidatedPath<Page>| async move { page.page.to_string() }),
+        )
+}
+
+fn send(app: Router, request: Request<Body>) -> (StatusCode, String) {
+    let response: Response = futures::executor::block_on(app.oneshot(request)).unwrap();
+    let status = response.status();
+    let bytes = futures::executor::block_on(axum::body::to_bytes(response.into_body(), usize::MAX))
+        .unwrap();
+    (status, String::from_utf8(bytes.to_vec()).unwrap())
+}
+
+fn json(body: &str) -> Request<Body> {
+    Request::post("/json")
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn get_request(uri: &str) -> Request<Body> {
+    Request::get(uri).body(Body::empty()).unwrap()
+}
+
+#[test]
+fn valid_payloads_are_extracted() {
+    assert_eq!(send(app(), json(r#"{"mail": "bob@example.com"}"#)).1, "bob@example.com");
+    let form = Request::post("/form")
+        .header("content-type", "application/x-www-form-urlencoded")
+        .body(Body::from("mail=bob%40example.com"))
+        .unwrap();
+    assert_eq!(send(app(), form).1, "bob@example.com");
+    assert_eq!(send(app(), get_request("/query?page=2")).1, "2");
+    assert_eq!(send(app(), get_request("/pages/3")).1, "3");
+}
+
+#[test]
+fn invalid_payloads_are_a_422() {
+    let (status, body) = send(app(), json(r#"{"mail": "bob"}"#));
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["mail"][0]["message"], "Not an email");
+
+    assert_eq!(send(app(), get_request("/query?page=0")).0, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(send(app(), get_request("/pages/0")).0, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[test]
+fn payloads_that_cant_be_extracted_keep_the_axum_rejection() {
+    assert_eq!(send(app(), json("{")).0, StatusCode::BAD_REQUEST);
+    assert_eq!(send(app(), get_request("/query")).0, StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn the_rejection_can_be_configured() {
+    let app = app()
+        .layer(Extension(RejectionConfig::new(StatusCode::BAD_REQUEST, ErrorsShape::FlatMessages)));
+    let (status, body) = send(app, json(r#"{"mail": "bob"}"#));
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body, r#"{"mail":["Not an email"]}"#);
+}
@@ -0,0 +1,84 @@
+use serde::Deserialize;
+use validator::{BatchReport, BatchValidator, RowError, Validate, DESERIALIZE_CODE};
+
+#[derive(Debug, Deserialize, Validate)]
+struct User {
+    #[validate(email(message = "Not an email"))]
+    mail: String,
+    #[validate(range(min = 18))]
+    age: u8,
+}
+
+fn user(mail: &str, age: u8) -> User {
+    User { mail: mail.to_string(), age }
+}
+
+#[test]
+fn reports_the_errors_of_every_row() {
+    let rows = vec![user("bob@example.com", 30), user("bob", 30), user("alice@example.com", 12)];
+    let (valid, report) = BatchValidator::new().validate(rows);
+    assert_eq!(valid.len(), 1);
+    assert_eq!(valid[0].mail, "bob@example.com");
+    assert!(!report.is_valid());
+    assert_eq!(
+        report,
+        BatchReport {
+            rows: 3,
+            invalid_rows: 2,
+            errors: vec![
+                RowError {
+                    row: 1,
+                    path: "mail".to_string(),
+                    code: "email".to_string(),
+                    message: Some("Not an email".to_string()),
+                },
+                RowError {
+                    row: 2,
+                    path: "age".to_string(),
+                    code: "range".to_string(),
+                    message: None
+                },
+            ],
+            aborted: false,
+        }
+    );
+}
+
+#[test]
+fn reports_the_rows_that_cant_be_deserialized() {
+    let ndjson = "{\"mail\": \"bob@example.com\", \"age\": 30}\n{\"mail\": \"bob@example.com\"}\n";
+    let rows = ndjson.lines().map(serde_json::from_str::<User>);
+    let (valid, report) = BatchValidator::new().validate_results(rows);
+    assert_eq!(valid.len(), 1);
+    assert_eq!(report.invalid_rows, 1);
+    assert_eq!(report.errors[0].row, 1);
+    assert_eq!(report.errors[0].code, DESERIALIZE_CODE);
+    assert!(report.errors[0].message.as_ref().unwrap().contains("age"));
+}
+
+#[test]
+fn stops_after_the_threshold() {
+    let rows = (0..10).map(|_| user("bob", 30));
+    let (valid, report) = BatchValidator::new().max_invalid_rows(2).validate(rows);
+    assert!(valid.is_empty());
+    assert!(report.aborted);
+    assert_eq!(report.rows, 3);
+    assert_eq!(report.invalid_rows, 3);
+
+    let (_, report) = BatchValidator::new().max_invalid_rows(2).validate(vec![user("bob", 30)]);
+    assert!(!report.aborted);
+}
+
+#[test]
+fn can_serialize_the_report() {
+    let (_, report) = BatchValidator::new().validate(vec![user("bob", 30)]);
+    assert_eq!(
+        serde_json::to_value(&report).unwrap(),
+        serde_json::json!({
+            "rows": 1,
+            "invalid_rows": 1,
+            "errors": [{"row": 0, "path": "mail", "code": "email", "message": "Not an email"}],
+            "aborted": false,
+        })
+    );
+}
@@ -0,0 +1,105 @@
+use validator::Validate;
+
+const SUPPORTED_FLAGS: u8 = 0b0000_1010;
+
+#[test]
+fn can_validate_bits_within_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(bits_within = 0b0000_1111)]
+        val: u8,
+        #[validate(bits_within = 0xFF)]
+        signed_val: i32,
+        #[validate(bits_within = "SUPPORTED_FLAGS")]
+        path_val: u8,
+        #[validate(bits_within(mask = 0b0000_0011))]
+        opt_val: Option<u64>,
+    }
+
+    let s =
+        TestStruct { val: 0b0000_0101, signed_val: 200, path_val: 0b0000_1000, opt_val: Some(3) };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn value_with_bits_outside_mask_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(bits_within = 0b0000_1111)]
+        val: u16,
+    }
+
+    let s = TestStruct { val: 0b0001_0001 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "bits_within");
+    assert_eq!(errs["val"][0].params["value"], 0b0001_0001);
+    assert_eq!(errs["val"][0].params["mask"], 0b0000_1111);
+}
+
+#[test]
+fn negative_value_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(bits_within = 0xFF)]
+        val: i8,
+    }
+
+    assert!(TestStruct { val: -1 }.validate().is_err());
+}
+
+#[test]
+fn can_validate_bits_within_with_path_mask() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(bits_within(mask = "SUPPORTED_FLAGS"))]
+        val: u8,
+    }
+
+    let res = TestStruct { val: 0b0000_0100 }.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].params["mask"], SUPPORTED_FLAGS);
+}
+
+#[test]
+fn can_specify_code_for_bits_within() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(bits_within(mask = 0b0000_0001, code = "oops"))]
+        val: u32,
+    }
+
+    let s = TestStruct { val: 2 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "oops");
+}
+
+#[test]
+fn can_specify_message_for_bits_within() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(bits_within(mask = 0b0000_0001, message = "unsupported flags"))]
+        val: u32,
+    }
+
+    let s = TestStruct { val: 2 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].clone().message.unwrap(), "unsupported flags");
+}
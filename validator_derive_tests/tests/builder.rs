@@ -0,0 +1,96 @@
+use validator::{Validate, ValidationErrorsKind};
+
+#[derive(Debug, Validate)]
+#[validate(builder)]
+struct Signup {
+    #[validate(email)]
+    mail: String,
+    #[validate(range(min = 18))]
+    age: u8,
+    #[validate(length(min = 1))]
+    nickname: Option<String>,
+}
+
+#[test]
+fn can_build_a_valid_value() {
+    let signup = Signup::builder().mail("bob@bob.com").age(18).build().unwrap();
+    assert_eq!(signup.mail, "bob@bob.com");
+    assert_eq!(signup.age, 18);
+    assert_eq!(signup.nickname, None);
+
+    let signup = Signup::builder().mail("bob@bob.com").age(18).nickname("bob".to_string()).build();
+    assert_eq!(signup.unwrap().nickname, Some("bob".to_string()));
+}
+
+#[test]
+fn cant_build_an_invalid_value() {
+    let res = Signup::builder().mail("bob").age(17).nickname(String::new()).build();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs["mail"][0].code, "email");
+    assert_eq!(errs["age"][0].code, "range");
+    assert_eq!(errs["nickname"][0].code, "length");
+}
+
+#[test]
+fn missing_fields_are_required() {
+    let res = Signup::builder().age(20).build();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs["mail"][0].code, "required");
+}
+
+#[test]
+fn builder_keeps_the_struct_options() {
+    #[derive(Debug, Validate)]
+    #[validate(builder)]
+    #[validate(code_prefix = "item.")]
+    struct Item {
+        #[validate(nested)]
+        parts: Vec<Part>,
+        #[validate(rename = "itemName", length(min = 1))]
+        name: String,
+    }
+
+    #[derive(Debug, Validate)]
+    struct Part {
+        #[validate(range(min = 1))]
+        count: u32,
+    }
+
+    let err = Item::builder().parts(vec![Part { count: 1 }]).build().unwrap_err();
+    assert_eq!(err.field_errors()["itemName"][0].code, "item.required");
+
+    let err = Item::builder().parts(vec![Part { count: 0 }]).name("item").build().unwrap_err();
+    assert!(matches!(err.errors()["parts"], ValidationErrorsKind::List(_)));
+}
+
+#[test]
+fn builder_takes_the_context() {
+    struct Limits {
+        max: usize,
+    }
+
+    fn check_max(value: &[u8], limits: &Limits) -> Result<(), validator::ValidationError> {
+        if value.len() > limits.max {
+            return Err(validator::ValidationError::new("too_big"));
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Validate)]
+    #[validate(builder)]
+    #[validate(context = "Limits")]
+    struct Upload {
+        #[validate(custom(function = "check_max", use_context))]
+        data: Vec<u8>,
+    }
+
+    let limits = Limits { max: 2 };
+    assert!(Upload::builder().data(vec![1, 2]).build(&limits).is_ok());
+    assert!(Upload::builder().data(vec![1, 2, 3]).build(&limits).is_err());
+}
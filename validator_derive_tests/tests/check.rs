@@ -0,0 +1,140 @@
+use serde::Serialize;
+use validator::{
+    check_bits_within, check_contains, check_credit_card, check_does_not_contain, check_email,
+    check_greater_than, check_length, check_must_match, check_non_control_character,
+    check_percentage, check_phone, check_range, check_ratio, check_required, check_url, Validate,
+    ValidationError, ValidationErrors,
+};
+
+#[derive(Debug, Validate)]
+struct Derived {
+    #[validate(email)]
+    mail: String,
+    #[validate(url)]
+    site: String,
+    #[validate(length(min = 2, max = 4))]
+    name: String,
+    #[validate(range(min = 18, exclusive_max = 130))]
+    age: i64,
+    #[validate(percentage)]
+    discount: f64,
+    #[validate(ratio(exclusive = true))]
+    share: f32,
+    #[validate(bits_within = 0b0110)]
+    flags: u8,
+    #[validate(required)]
+    nickname: Option<String>,
+    #[validate(contains = "@")]
+    handle: String,
+    #[validate(does_not_contain = "admin")]
+    login: String,
+    #[validate(must_match(other = "password2"))]
+    password: String,
+    password2: String,
+    #[validate(greater_than(other = "start"))]
+    end: u32,
+    start: u32,
+    #[validate(credit_card)]
+    card: String,
+    #[validate(phone)]
+    phone: String,
+    #[validate(non_control_character)]
+    text: String,
+}
+
+/// The same rules as `Derived`, written by hand
+#[derive(Debug)]
+struct Manual(Derived);
+
+impl Validate for Manual {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let d = &self.0;
+        let mut errors = ValidationErrors::new();
+        let mut add = |field: &'static str, result: Result<(), ValidationError>| {
+            if let Err(err) = result {
+                errors.add(field, err);
+            }
+        };
+        add("mail", check_email(&d.mail));
+        add("site", check_url(&d.site));
+        add("name", check_length(&d.name, Some(2), Some(4), None));
+        add("age", check_range(d.age, Some(18.0), None, None, Some(130.0)));
+        add("discount", check_percentage(d.discount, false));
+        add("share", check_ratio(d.share, true));
+        add("flags", check_bits_within(d.flags, 0b0110));
+        add("nickname", check_required(&d.nickname));
+        add("handle", check_contains(&d.handle, "@"));
+        add("login", check_does_not_contain(&d.login, "admin"));
+        add("password", check_must_match(&d.password, &d.password2));
+        add("end", check_greater_than(&d.end, &d.start));
+        add("card", check_credit_card(&d.card));
+        add("phone", check_phone(&d.phone));
+        add("text", check_non_control_character(&d.text));
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+}
+
+fn invalid() -> Derived {
+    Derived {
+        mail: "bob".to_string(),
+        site: "not a url".to_string(),
+        name: "b".to_string(),
+        age: 130,
+        discount: 100.5,
+        share: 1.0,
+        flags: 0b1001,
+        nickname: None,
+        handle: "bob".to_string(),
+        login: "superadmin".to_string(),
+        password: "hunter2".to_string(),
+        password2: "hunter3".to_string(),
+        end: 1,
+        start: 2,
+        card: "5236313877109141".to_string(),
+        phone: "123".to_string(),
+        text: "\u{0000}".to_string(),
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap()
+}
+
+#[test]
+fn manual_impl_has_the_same_errors_as_the_derive() {
+    let derived = invalid().validate().unwrap_err();
+    let manual = Manual(invalid()).validate().unwrap_err();
+
+    assert_eq!(derived.field_errors().len(), 15);
+    assert_eq!(manual, derived);
+    assert_eq!(to_json(&manual), to_json(&derived));
+}
+
+#[test]
+fn manual_impl_passes_with_valid_values() {
+    let valid = Derived {
+        mail: "bob@example.com".to_string(),
+        site: "https://example.com".to_string(),
+        name: "bob".to_string(),
+        age: 42,
+        discount: 100.0,
+        share: 0.5,
+        flags: 0b0100,
+        nickname: Some("bobby".to_string()),
+        handle: "@bob".to_string(),
+        login: "bob".to_string(),
+        password: "hunter2".to_string(),
+        password2: "hunter2".to_string(),
+        end: 2,
+        start: 1,
+        card: "5236313877109142".to_string(),
+        phone: "+14152370800".to_string(),
+        text: "hello".to_string(),
+    };
+
+    assert!(valid.validate().is_ok());
+    assert!(Manual(valid).validate().is_ok());
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use validator::{Validate, ValidationError, ValidationErrorsKind};
+
+fn taken(_: &str) -> Result<(), ValidationError> {
+    Err(ValidationError::new("taken"))
+}
+
+fn always_fails(_: &User) -> Result<(), ValidationError> {
+    Err(ValidationError::new("invalid_user"))
+}
+
+#[derive(Debug, Serialize, Validate)]
+struct Address {
+    #[validate(length(min = 1))]
+    city: String,
+}
+
+#[derive(Debug, Validate)]
+#[validate(code_prefix = "user.")]
+#[validate(schema(function = "always_fails", skip_on_field_errors = false))]
+struct User {
+    #[validate(email, length(max = 5, code = "too_long"))]
+    mail: String,
+    #[validate(custom = "taken")]
+    username: String,
+    #[validate(keys(length(max = 1)))]
+    tags: HashMap<String, u8>,
+    #[validate]
+    address: Address,
+}
+
+#[test]
+fn code_prefix_is_added_to_all_the_codes_of_the_struct() {
+    let mut tags = HashMap::new();
+    tags.insert("long".to_string(), 1);
+    let user = User {
+        mail: "not an email".to_string(),
+        username: "bob".to_string(),
+        tags,
+        address: Address { city: String::new() },
+    };
+
+    let err = user.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["mail"][0].code, "user.email");
+    assert_eq!(errs["mail"][1].code, "user.too_long");
+    assert_eq!(errs["username"][0].code, "user.taken");
+    assert_eq!(errs["__all__"][0].code, "user.invalid_user");
+
+    match &err.errors()["tags"] {
+        ValidationErrorsKind::Map(tags) => {
+            assert_eq!(tags["long"].field_errors()["__key__"][0].code, "user.length");
+        }
+        _ => panic!("expected the errors of a map"),
+    }
+}
+
+#[test]
+fn code_prefix_is_not_added_to_nested_structs() {
+    let user = User {
+        mail: "a@b.c".to_string(),
+        username: "bob".to_string(),
+        tags: HashMap::new(),
+        address: Address { city: String::new() },
+    };
+
+    let err = user.validate().unwrap_err();
+    match &err.errors()["address"] {
+        ValidationErrorsKind::Struct(address) => {
+            assert_eq!(address.field_errors()["city"][0].code, "length");
+        }
+        _ => panic!("expected the errors of a struct"),
+    }
+}
+
+#[test]
+fn code_prefix_is_used_in_message_placeholders() {
+    #[derive(Debug, Validate)]
+    #[validate(code_prefix = "form.")]
+    struct TestStruct {
+        #[validate(url(message = "{code}"))]
+        site: String,
+    }
+
+    let err = TestStruct { site: "nope".to_string() }.validate().unwrap_err();
+    assert_eq!(err.field_errors()["site"][0].clone().message.unwrap(), "form.url");
+}
+
+#[test]
+fn can_set_code_of_custom_and_schema_validators() {
+    fn check(_: &TestStruct) -> Result<(), ValidationError> {
+        Err(ValidationError::new("meh"))
+    }
+
+    #[derive(Debug, Validate)]
+    #[validate(schema(function = "check", code = "schema_code", skip_on_field_errors = false))]
+    struct TestStruct {
+        #[validate(custom(function = "taken", code = "custom_code"))]
+        name: String,
+    }
+
+    let err = TestStruct { name: "bob".to_string() }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["name"][0].code, "custom_code");
+    assert_eq!(errs["__all__"][0].code, "schema_code");
+}
+
+#[test]
+fn can_set_code_of_required_nested() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(required_nested(code = "missing_address"))]
+        address: Option<Address>,
+    }
+
+    let err = TestStruct { address: None }.validate().unwrap_err();
+    assert_eq!(err.field_errors()["address"][0].code, "missing_address");
+
+    let err = TestStruct { address: Some(Address { city: String::new() }) }.validate().unwrap_err();
+    assert!(err.errors().contains_key("address"));
+}
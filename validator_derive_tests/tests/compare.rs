@@ -0,0 +1,139 @@
+use validator::Validate;
+
+#[derive(Debug, Validate)]
+struct Period {
+    start: u32,
+    #[validate(greater_than = "start")]
+    end: u32,
+}
+
+#[test]
+fn can_validate_valid_greater_than() {
+    assert!(Period { start: 1, end: 2 }.validate().is_ok());
+}
+
+#[test]
+fn not_greater_fails_validation() {
+    let s = Period { start: 2, end: 2 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("end"));
+    assert_eq!(errs["end"].len(), 1);
+    assert_eq!(errs["end"][0].code, "greater_than");
+    assert_eq!(errs["end"][0].params["value"], 2);
+    assert_eq!(errs["end"][0].params["other"], 2);
+}
+
+#[test]
+fn can_validate_or_equal_comparisons() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        min: f64,
+        #[validate(greater_than_or_equal = "min", less_than_or_equal = "max")]
+        val: f64,
+        max: f64,
+    }
+
+    assert!(TestStruct { min: 1.0, val: 1.0, max: 1.0 }.validate().is_ok());
+    assert!(TestStruct { min: 1.0, val: 1.5, max: 2.0 }.validate().is_ok());
+
+    let err = TestStruct { min: 1.0, val: 2.5, max: 2.0 }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "less_than_or_equal");
+}
+
+#[test]
+fn can_validate_before_and_after_on_dates() {
+    // ISO 8601 dates compare like the dates they represent
+    #[derive(Debug, Validate)]
+    struct Booking<'a> {
+        #[validate(before = "check_out")]
+        check_in: &'a str,
+        #[validate(after = "check_in")]
+        check_out: &'a str,
+    }
+
+    assert!(Booking { check_in: "2022-06-27", check_out: "2022-06-29" }.validate().is_ok());
+
+    let err = Booking { check_in: "2022-06-29", check_out: "2022-06-27" }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["check_in"][0].code, "before");
+    assert_eq!(errs["check_out"][0].code, "after");
+}
+
+#[test]
+fn comparison_is_skipped_when_a_field_is_none() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        start: Option<u64>,
+        #[validate(greater_than = "start")]
+        end: Option<u64>,
+    }
+
+    assert!(TestStruct { start: None, end: Some(1) }.validate().is_ok());
+    assert!(TestStruct { start: Some(2), end: None }.validate().is_ok());
+    assert!(TestStruct { start: Some(2), end: Some(1) }.validate().is_err());
+
+    #[derive(Debug, Validate)]
+    struct Mixed {
+        start: u64,
+        #[validate(less_than(other = "start"))]
+        end: Option<u64>,
+    }
+
+    assert!(Mixed { start: 2, end: None }.validate().is_ok());
+    assert!(Mixed { start: 2, end: Some(1) }.validate().is_ok());
+    assert!(Mixed { start: 2, end: Some(2) }.validate().is_err());
+}
+
+#[test]
+fn can_compare_to_a_path_into_a_nested_struct() {
+    #[derive(Debug)]
+    struct Period {
+        start: String,
+    }
+
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        period: Period,
+        #[validate(after = "period.start")]
+        published_at: String,
+    }
+
+    let s = TestStruct {
+        period: Period { start: "2022-06-27".to_string() },
+        published_at: "2022-07-01".to_string(),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        period: Period { start: "2022-06-27".to_string() },
+        published_at: "2022-06-01".to_string(),
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["published_at"][0].code, "after");
+    assert_eq!(errs["published_at"][0].params["other"], "2022-06-27");
+}
+
+#[test]
+fn can_specify_code_and_message_for_comparisons() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        start: i32,
+        #[validate(greater_than(
+            other = "start",
+            code = "end_before_start",
+            message = "{field} must be after {other}"
+        ))]
+        end: i32,
+    }
+
+    let err = TestStruct { start: 3, end: 1 }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["end"][0].code, "end_before_start");
+    assert_eq!(errs["end"][0].clone().message.unwrap(), "end must be after 3");
+}
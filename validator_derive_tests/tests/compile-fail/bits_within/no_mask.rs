@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(bits_within(code = "flags"))]
+    s: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(bits_within = 0b0000_1111)]
+    s: f64,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use validator::Validate;
+
+#[derive(Validate)]
+#[validate(builder)]
+struct Test(#[validate(length(min = 1))] String);
+
+fn main() {}
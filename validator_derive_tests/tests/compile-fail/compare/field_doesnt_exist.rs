@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(greater_than = "start")]
+    end: i32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    start: String,
+    #[validate(after = "start")]
+    end: Option<i32>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    is_draft: bool,
+    #[validate(skip_if = "self.is_draft &&", length(min = 1))]
+    s: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    is_draft: bool,
+    #[validate(skip_if = "self.is_draft")]
+    s: String,
+}
+
+fn main() {}
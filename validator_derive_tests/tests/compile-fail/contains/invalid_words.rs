@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Post {
+    #[validate(does_not_contain(words = "a list"))]
+    body: String,
+}
+
+fn main() {}
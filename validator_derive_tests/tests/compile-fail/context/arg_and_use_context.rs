@@ -0,0 +1,14 @@
+use validator::{Validate, ValidationError};
+
+fn check(_: &str, _: i64) -> Result<(), ValidationError> {
+    Ok(())
+}
+
+#[derive(Validate)]
+#[validate(context = "String")]
+struct Test {
+    #[validate(custom(function = "check", arg = "i64", use_context))]
+    s: String,
+}
+
+fn main() {}
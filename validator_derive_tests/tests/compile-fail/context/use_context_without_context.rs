@@ -0,0 +1,13 @@
+use validator::{Validate, ValidationError};
+
+fn check(_: &str, _: &()) -> Result<(), ValidationError> {
+    Ok(())
+}
+
+#[derive(Validate)]
+struct Test {
+    #[validate(custom(function = "check", use_context))]
+    s: String,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use validator::{Validate, ValidationError};
+
+fn check(_: &str, _: &String) -> Result<(), ValidationError> {
+    Ok(())
+}
+
+#[derive(Validate)]
+#[validate(context = "String")]
+struct Test {
+    #[validate(custom(function = "check", use_context))]
+    s: String,
+}
+
+fn main() {
+    let test = Test { s: "test".to_string() };
+    test.validate();
+}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(custom = "1 + 1")]
+    s: String,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use validator::FromValidationErrors;
+
+#[derive(FromValidationErrors)]
+struct SignupError {
+    message: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use validator::{FromValidationErrors, ValidationErrors};
+
+#[derive(FromValidationErrors)]
+enum SignupError {
+    #[validation_error(fallback)]
+    Invalid(ValidationErrors),
+    #[validation_error(fallback)]
+    Other,
+}
+
+fn main() {}
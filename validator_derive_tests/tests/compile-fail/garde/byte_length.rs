@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[garde(length(bytes, max = 10))]
+    name: String,
+}
+
+fn main() {}
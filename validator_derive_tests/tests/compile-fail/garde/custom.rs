@@ -0,0 +1,13 @@
+use validator::Validate;
+
+fn check_name(_: &str, _: &()) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(Validate)]
+struct Test {
+    #[garde(custom(check_name))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(inner(nested))]
+    s: Vec<String>,
+}
+
+fn main() {}
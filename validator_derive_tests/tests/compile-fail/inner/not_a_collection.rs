@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(inner(length(min = 1)))]
+    s: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(keys(length(min = 1)))]
+    s: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(length(min = 1, message = "too short", message_fn = "crate::message"))]
+    s: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Normalize;
+
+#[derive(Normalize)]
+struct Test {
+    #[normalize(trim, reverse)]
+    s: String,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+
+use validator::Validate;
+
+#[derive(Validate)]
+struct Row {
+    #[validate(range(min = 1))]
+    value: u32,
+}
+
+#[derive(Validate)]
+struct Import {
+    #[validate(nested, parallel)]
+    rows: HashMap<String, Row>,
+}
+
+fn main() {}
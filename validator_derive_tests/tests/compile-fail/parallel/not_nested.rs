@@ -0,0 +1,15 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Row {
+    #[validate(range(min = 1))]
+    value: u32,
+}
+
+#[derive(Validate)]
+struct Import {
+    #[validate(length(min = 1), parallel)]
+    rows: Vec<Row>,
+}
+
+fn main() {}
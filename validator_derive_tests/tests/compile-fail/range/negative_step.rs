@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(range(min = 0, step = -5))]
+    s: i32,
+}
+
+fn main() {}
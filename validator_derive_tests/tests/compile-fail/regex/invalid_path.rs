@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(regex(path = "RE +"))]
+    s: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+#[validate(expr = "self.start <")]
+struct Test {
+    start: u32,
+}
+
+fn main() {}
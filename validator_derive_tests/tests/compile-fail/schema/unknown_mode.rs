@@ -0,0 +1,10 @@
+use validator::Validate;
+
+#[derive(Validate)]
+#[validate(mode = "lazy")]
+struct Test {
+    #[validate(length(min = 1))]
+    s: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(length(min = 1), severity = "fatal")]
+    s: String,
+}
+
+fn main() {}
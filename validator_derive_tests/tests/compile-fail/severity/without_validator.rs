@@ -0,0 +1,10 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(length(min = 1))]
+    #[validate(severity = "warning")]
+    s: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(skip)]
+    #[validate(length(min = 1))]
+    s: String,
+}
+
+fn main() {}
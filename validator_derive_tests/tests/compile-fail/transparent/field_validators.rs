@@ -0,0 +1,7 @@
+use validator::Validate;
+
+#[derive(Validate)]
+#[validate(transparent)]
+struct Test(#[validate(email)] String);
+
+fn main() {}
@@ -1,6 +1,7 @@
 use validator::Validate;
 
 #[derive(Validate)]
-struct TupleStruct(String);
+#[validate(transparent)]
+struct Test(String, String);
 
 fn main() {}
@@ -0,0 +1,82 @@
+use validator::Validate;
+
+#[derive(Debug, Validate)]
+struct Article {
+    is_draft: bool,
+    #[validate(skip_if = "self.is_draft", length(min = 1))]
+    title: String,
+    #[validate(validate_if = "Self::is_published", length(min = 10))]
+    body: String,
+    #[validate(validate_if = "|a: &Self| !a.is_draft", range(min = 1))]
+    pages: u32,
+}
+
+impl Article {
+    fn is_published(&self) -> bool {
+        !self.is_draft
+    }
+}
+
+#[test]
+fn skips_validation_when_condition_does_not_hold() {
+    let a = Article { is_draft: true, title: String::new(), body: String::new(), pages: 0 };
+
+    assert!(a.validate().is_ok());
+}
+
+#[test]
+fn runs_validation_when_condition_holds() {
+    let a = Article { is_draft: false, title: String::new(), body: String::new(), pages: 0 };
+
+    let res = a.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs["title"][0].code, "length");
+    assert_eq!(errs["body"][0].code, "length");
+    assert_eq!(errs["pages"][0].code, "range");
+}
+
+fn has_children(p: &Parent) -> bool {
+    p.has_child
+}
+
+#[derive(Debug, Validate)]
+struct Child {
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+#[derive(Debug, Validate)]
+struct Parent {
+    has_child: bool,
+    #[validate(validate_if = "has_children")]
+    #[validate]
+    child: Child,
+}
+
+#[test]
+fn condition_applies_to_nested_validation() {
+    let p = Parent { has_child: false, child: Child { name: String::new() } };
+    assert!(p.validate().is_ok());
+
+    let p = Parent { has_child: true, child: Child { name: String::new() } };
+    assert!(p.validate().is_err());
+}
+
+#[test]
+fn can_combine_skip_if_and_validate_if() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        enabled: bool,
+        locked: bool,
+        #[validate(validate_if = "self.enabled", skip_if = "self.locked", length(max = 2))]
+        val: String,
+    }
+
+    let val = "abc".to_string();
+    assert!(TestStruct { enabled: false, locked: false, val: val.clone() }.validate().is_ok());
+    assert!(TestStruct { enabled: true, locked: true, val: val.clone() }.validate().is_ok());
+    assert!(TestStruct { enabled: true, locked: false, val }.validate().is_err());
+}
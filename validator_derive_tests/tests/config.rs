@@ -0,0 +1,73 @@
+use validator::{Validate, ValidationConfig};
+
+#[derive(Debug, Validate)]
+struct Batch {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(nested)]
+    items: Vec<Item>,
+}
+
+#[derive(Debug, Validate)]
+struct Item {
+    #[validate(length(min = 1))]
+    sku: String,
+    #[validate(range(min = 1))]
+    quantity: u32,
+}
+
+fn batch(items: usize) -> Batch {
+    Batch {
+        name: String::new(),
+        items: (0..items).map(|_| Item { sku: String::new(), quantity: 0 }).collect(),
+    }
+}
+
+#[test]
+fn default_config_collects_all_the_errors() {
+    let batch = batch(10);
+    let errors = batch.validate_with_config(&ValidationConfig::default()).unwrap_err();
+    assert_eq!(errors, batch.validate().unwrap_err());
+    assert_eq!(errors.iter().count(), 21);
+}
+
+#[test]
+fn stops_at_max_errors() {
+    let config = ValidationConfig::new().max_errors(4);
+    let errors = batch(1000).validate_with_config(&config).unwrap_err();
+    assert_eq!(errors.iter().count(), 4);
+    assert!(errors.field_has_code("name", "length"));
+    assert!(errors.field_has_code("items[0].sku", "length"));
+    assert!(errors.field_has_code("items[0].quantity", "range"));
+    assert!(errors.field_has_code("items[1].sku", "length"));
+    assert!(errors.at("items[1].quantity").is_none());
+    assert!(errors.at("items[2]").is_none());
+}
+
+#[test]
+fn a_limit_of_zero_is_one() {
+    let config = ValidationConfig::new().max_errors(0);
+    let errors = batch(3).validate_with_config(&config).unwrap_err();
+    assert_eq!(errors.iter().count(), 1);
+}
+
+#[test]
+fn stops_on_first_nested_failure() {
+    let mut batch = batch(100);
+    batch.name = "Batch".to_string();
+    batch.items[0] = Item { sku: "A1".to_string(), quantity: 1 };
+    let config = ValidationConfig::new().stop_on_first_nested_failure(true);
+    let errors = batch.validate_with_config(&config).unwrap_err();
+    assert_eq!(errors.iter().count(), 2);
+    assert!(errors.at("items[0]").is_none());
+    assert!(errors.field_has_code("items[1].sku", "length"));
+    assert!(errors.field_has_code("items[1].quantity", "range"));
+}
+
+#[test]
+fn the_limits_only_apply_to_validate_with_config() {
+    let batch = batch(5);
+    let config = ValidationConfig::new().max_errors(1);
+    assert!(batch.validate_with_config(&config).is_err());
+    assert_eq!(batch.validate().unwrap_err().iter().count(), 11);
+}
@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use validator::{load_config, ConfigFileError, Validate};
+
+#[derive(Debug, Deserialize, Validate)]
+struct ServerConfig {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(nested)]
+    servers: Vec<Server>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+struct Server {
+    #[validate(range(min = 1024, message = "Not a port"))]
+    port: u16,
+}
+
+/// Writes the config to a file of the temp dir, removed when dropped
+struct ConfigFile(PathBuf);
+
+impl ConfigFile {
+    fn new(name: &str, source: &str) -> ConfigFile {
+        let path = std::env::temp_dir().join(format!("validator-{}-{}", std::process::id(), name));
+        std::fs::write(&path, source).unwrap();
+        ConfigFile(path)
+    }
+}
+
+impl Drop for ConfigFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn can_load_a_valid_config() {
+    let file = ConfigFile::new("valid.toml", "name = \"api\"\n\n[[servers]]\nport = 8080\n");
+    let config: ServerConfig = load_config(&file.0).unwrap();
+    assert_eq!(config.name, "api");
+    assert_eq!(config.servers[0].port, 8080);
+
+    let file = ConfigFile::new("valid.json", r#"{"name": "api", "servers": []}"#);
+    let config: ServerConfig = load_config(&file.0).unwrap();
+    assert!(config.servers.is_empty());
+}
+
+#[test]
+fn reports_the_errors_with_the_file_and_the_key() {
+    let file = ConfigFile::new(
+        "invalid.toml",
+        "name = \"api\"\n\n[[servers]]\nport = 8080\n\n[[servers]]\nport = 80\n",
+    );
+    let err = load_config::<ServerConfig>(&file.0).unwrap_err();
+    assert!(matches!(err, ConfigFileError::Invalid { .. }));
+    assert_eq!(err.file(), file.0.as_path());
+    assert_eq!(
+        err.to_string(),
+        format!("{}: servers[1].port: Not a port (range)", file.0.display())
+    );
+}
+
+#[test]
+fn reports_parse_and_io_errors() {
+    let file = ConfigFile::new("broken.json", r#"{"name": "api"}"#);
+    match load_config::<ServerConfig>(&file.0).unwrap_err() {
+        ConfigFileError::Parse { message, .. } => assert!(message.contains("servers")),
+        err => panic!("Unexpected error {:?}", err),
+    }
+
+    let missing = std::env::temp_dir().join("validator-missing-config.toml");
+    match load_config::<ServerConfig>(&missing).unwrap_err() {
+        ConfigFileError::Io { file, .. } => assert_eq!(file, missing),
+        err => panic!("Unexpected error {:?}", err),
+    }
+}
@@ -0,0 +1,77 @@
+use validator::Validate;
+
+#[derive(Debug, Validate)]
+#[validate(constructor)]
+struct Signup {
+    #[validate(email)]
+    mail: String,
+    #[validate(range(min = 18))]
+    age: u8,
+}
+
+#[test]
+fn can_create_a_valid_value() {
+    let signup = Signup::new_validated("bob@bob.com".to_string(), 18).unwrap();
+    assert_eq!(signup.mail, "bob@bob.com");
+    assert_eq!(signup.age, 18);
+}
+
+#[test]
+fn cant_create_an_invalid_value() {
+    let res = Signup::new_validated("bob".to_string(), 17);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs["mail"][0].code, "email");
+    assert_eq!(errs["age"][0].code, "range");
+}
+
+#[test]
+fn works_on_tuple_structs() {
+    #[derive(Debug, Validate)]
+    #[validate(constructor)]
+    struct Range(#[validate(range(min = 1))] u8, #[validate(range(max = 10))] u8);
+
+    let range = Range::new_validated(1, 10).unwrap();
+    assert_eq!((range.0, range.1), (1, 10));
+    assert!(Range::new_validated(0, 10).is_err());
+}
+
+#[test]
+fn works_on_transparent_newtypes() {
+    #[derive(Debug, Validate)]
+    #[validate(transparent)]
+    #[validate(constructor)]
+    struct Wrapper(Signup);
+
+    let signup = Signup { mail: "bob".to_string(), age: 18 };
+    let err = Wrapper::new_validated(signup).unwrap_err();
+    assert_eq!(err.field_errors()["mail"][0].code, "email");
+}
+
+#[test]
+fn constructor_takes_the_context() {
+    struct Limits {
+        max: usize,
+    }
+
+    fn check_max(value: &[u8], limits: &Limits) -> Result<(), validator::ValidationError> {
+        if value.len() > limits.max {
+            return Err(validator::ValidationError::new("too_big"));
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Validate)]
+    #[validate(constructor)]
+    #[validate(context = "Limits")]
+    struct Upload {
+        #[validate(custom(function = "check_max", use_context))]
+        data: Vec<u8>,
+    }
+
+    let limits = Limits { max: 2 };
+    assert!(Upload::new_validated(vec![1, 2], &limits).is_ok());
+    assert!(Upload::new_validated(vec![1, 2, 3], &limits).is_err());
+}
@@ -1,4 +1,11 @@
-use validator::Validate;
+use std::sync::OnceLock;
+
+use validator::{Validate, WordList};
+
+fn keywords() -> &'static WordList {
+    static KEYWORDS: OnceLock<WordList> = OnceLock::new();
+    KEYWORDS.get_or_init(|| WordList::from_words(vec!["rust", "serde"], false))
+}
 
 #[test]
 fn can_validate_contains_ok() {
@@ -66,3 +73,18 @@ fn can_specify_message_for_contains() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+#[test]
+fn value_must_contain_a_word_of_the_list() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(contains(words = "keywords()", code = "keyword"))]
+        val: String,
+    }
+
+    assert!(TestStruct { val: "serde_json".to_string() }.validate().is_ok());
+    let err = TestStruct { val: "Rust".to_string() }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "keyword");
+    assert_eq!(errs["val"][0].params["value"], "Rust");
+}
@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+
+use validator::{Validate, ValidateArgs, ValidateWithContext, ValidationError};
+
+struct Context {
+    taken_usernames: HashSet<&'static str>,
+    max_tags: usize,
+}
+
+impl Context {
+    fn new() -> Self {
+        Context { taken_usernames: vec!["bob", "alice"].into_iter().collect(), max_tags: 2 }
+    }
+}
+
+fn username_available(username: &str, context: &Context) -> Result<(), ValidationError> {
+    if context.taken_usernames.contains(username) {
+        return Err(ValidationError::new("taken"));
+    }
+    Ok(())
+}
+
+fn not_too_many_tags(tags: &[String], context: &Context) -> Result<(), ValidationError> {
+    if tags.len() > context.max_tags {
+        return Err(ValidationError::new("too_many_tags"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Validate)]
+#[validate(context = "Context")]
+struct SignupData {
+    #[validate(length(min = 1), custom(function = "username_available", use_context))]
+    username: String,
+    #[validate(custom(function = "not_too_many_tags", use_context))]
+    tags: Vec<String>,
+}
+
+#[test]
+fn can_validate_with_context_ok() {
+    let s = SignupData { username: "jane".to_string(), tags: vec!["rust".to_string()] };
+
+    assert!(s.validate_with_context(&Context::new()).is_ok());
+}
+
+#[test]
+fn can_validate_with_context_err() {
+    let s = SignupData {
+        username: "bob".to_string(),
+        tags: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+    };
+
+    let res = s.validate_with_context(&Context::new());
+    assert!(res.is_err());
+    let errs = res.unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs["username"].len(), 1);
+    assert_eq!(errs["username"][0].code, "taken");
+    assert_eq!(errs["tags"].len(), 1);
+    assert_eq!(errs["tags"][0].code, "too_many_tags");
+}
+
+#[test]
+fn context_is_the_argument_of_validate_args() {
+    let s = SignupData { username: "alice".to_string(), tags: vec![] };
+
+    let context = Context::new();
+    assert!(s.validate_args(&context).is_err());
+}
+
+#[test]
+fn context_is_passed_to_option_fields() {
+    #[derive(Debug, Validate)]
+    #[validate(context = "Context")]
+    struct TestStruct {
+        #[validate(custom(function = "username_available", use_context))]
+        username: Option<String>,
+    }
+
+    let context = Context::new();
+    assert!(TestStruct { username: None }.validate_with_context(&context).is_ok());
+    assert!(TestStruct { username: Some("jane".to_string()) }
+        .validate_with_context(&context)
+        .is_ok());
+    assert!(TestStruct { username: Some("bob".to_string()) }
+        .validate_with_context(&context)
+        .is_err());
+}
+
+#[test]
+fn context_is_passed_to_schema_validations() {
+    fn not_reserved(data: &TestStruct, context: &Context) -> Result<(), ValidationError> {
+        if context.taken_usernames.contains(data.first.as_str())
+            && context.taken_usernames.contains(data.second.as_str())
+        {
+            return Err(ValidationError::new("both_taken"));
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Validate)]
+    #[validate(context = "Context")]
+    #[validate(schema(function = "not_reserved", use_context))]
+    struct TestStruct {
+        first: String,
+        second: String,
+    }
+
+    let context = Context::new();
+    let s = TestStruct { first: "bob".to_string(), second: "jane".to_string() };
+    assert!(s.validate_with_context(&context).is_ok());
+
+    let s = TestStruct { first: "bob".to_string(), second: "alice".to_string() };
+    let errs = s.validate_with_context(&context).unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs["__all__"][0].code, "both_taken");
+}
+
+#[test]
+fn context_can_be_unsized() {
+    fn is_allowed(value: &str, allowed: &[&str]) -> Result<(), ValidationError> {
+        if allowed.contains(&value) {
+            return Ok(());
+        }
+        Err(ValidationError::new("not_allowed"))
+    }
+
+    #[derive(Debug, Validate)]
+    #[validate(context = "[&'static str]")]
+    struct TestStruct {
+        #[validate(custom(function = "is_allowed", use_context))]
+        value: String,
+    }
+
+    let allowed = ["red", "green"];
+    assert!(TestStruct { value: "red".to_string() }.validate_with_context(&allowed[..]).is_ok());
+    assert!(TestStruct { value: "blue".to_string() }.validate_with_context(&allowed[..]).is_err());
+}
+
+#[test]
+fn validators_without_context_still_run() {
+    let s = SignupData { username: "".to_string(), tags: vec![] };
+
+    let errs = s.validate_with_context(&Context::new()).unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs["username"][0].code, "length");
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use validator::{Validate, ValidationError};
 
 fn valid_custom_fn(_: &str) -> Result<(), ValidationError> {
@@ -56,3 +58,135 @@ fn can_specify_message_for_custom_fn() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+#[test]
+fn can_validate_with_inline_closure_returning_bool() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(custom = "|v: &str| v.chars().all(char::is_alphanumeric)")]
+        val: String,
+    }
+
+    assert!(TestStruct { val: "abc123".to_string() }.validate().is_ok());
+
+    let s = TestStruct { val: "abc 123".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "custom");
+    assert_eq!(errs["val"][0].params["value"], "abc 123");
+}
+
+#[test]
+fn can_validate_with_inline_closure_returning_result() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(custom(
+            function = "|v: &str| if v.is_empty() { Err(ValidationError::new(\"empty\")) } else { Ok(()) }"
+        ))]
+        val: String,
+    }
+
+    assert!(TestStruct { val: "hello".to_string() }.validate().is_ok());
+
+    let s = TestStruct { val: String::new() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "empty");
+}
+
+#[test]
+fn can_set_code_and_message_of_inline_closure() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(custom(
+            function = "|v: u32| v % 2 == 0",
+            code = "odd",
+            message = "must be even"
+        ))]
+        val: Option<u32>,
+    }
+
+    assert!(TestStruct { val: None }.validate().is_ok());
+    assert!(TestStruct { val: Some(2) }.validate().is_ok());
+
+    let s = TestStruct { val: Some(3) };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "odd");
+    assert_eq!(errs["val"][0].clone().message.unwrap(), "must be even");
+}
+
+fn validate_date(value: &str) -> Result<(), Vec<ValidationError>> {
+    let mut errors = vec![];
+    let mut parts = value.split('-');
+    if !matches!(parts.next().map(|y| y.len()), Some(4)) {
+        errors.push(ValidationError::new("invalid_year"));
+    }
+    if !matches!(parts.next().and_then(|m| m.parse::<u8>().ok()), Some(1..=12)) {
+        errors.push(ValidationError::new("invalid_month"));
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[test]
+fn custom_function_can_return_several_errors() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(custom = "validate_date")]
+        date: String,
+        #[validate(custom(function = "validate_date", message = "Invalid date"))]
+        other_date: Option<String>,
+    }
+
+    let s = TestStruct { date: "2022-05".to_string(), other_date: Some("2022-01".to_string()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { date: "22-13".to_string(), other_date: Some("2022-00".to_string()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["date"].len(), 2);
+    assert_eq!(errs["date"][0].code, "invalid_year");
+    assert_eq!(errs["date"][1].code, "invalid_month");
+    assert_eq!(errs["date"][1].params["value"], "22-13");
+    assert_eq!(errs["other_date"].len(), 1);
+    assert_eq!(errs["other_date"][0].code, "invalid_month");
+    assert_eq!(errs["other_date"][0].message, Some(Cow::from("Invalid date")));
+}
+
+fn validate_display_name(value: &str) -> Result<(), ValidationError> {
+    use validator::combinators::{all_of, map_err_code, not, predicate, Rule};
+
+    let rule = map_err_code(
+        all_of((
+            predicate("empty", |name: &str| !name.is_empty()),
+            not("admin", predicate("admin", |name: &str| name == "admin")),
+        )),
+        "display_name",
+    );
+    rule.check(value)
+}
+
+#[test]
+fn can_use_composed_rules_as_custom_fn() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(custom = "validate_display_name")]
+        name: String,
+    }
+
+    assert!(TestStruct { name: "bob".to_string() }.validate().is_ok());
+
+    let err = TestStruct { name: "admin".to_string() }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["name"][0].code, "display_name");
+    assert_eq!(errs["name"][0].params["value"], "admin");
+}
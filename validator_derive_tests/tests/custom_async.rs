@@ -0,0 +1,208 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use futures::executor::block_on;
+use validator::{Validate, ValidateAsync, ValidationError};
+
+async fn check_unique_email(email: &str) -> Result<(), ValidationError> {
+    if email == "taken@example.com" {
+        return Err(ValidationError::new("email_taken"));
+    }
+
+    Ok(())
+}
+
+async fn check_unique_username(username: &str) -> Result<(), ValidationError> {
+    if username == "admin" {
+        return Err(ValidationError::new("username_taken"));
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Validate, ValidateAsync)]
+struct SignupData {
+    #[validate(email, custom_async = "check_unique_email")]
+    mail: String,
+    #[validate(custom_async(function = "check_unique_username", message = "Already taken"))]
+    username: Option<String>,
+}
+
+#[test]
+fn can_validate_async_ok() {
+    let s = SignupData { mail: "bob@example.com".to_string(), username: Some("bob".to_string()) };
+
+    assert!(s.validate().is_ok());
+    assert!(block_on(s.validate_async()).is_ok());
+}
+
+#[test]
+fn sync_validation_does_not_run_async_validators() {
+    let s = SignupData { mail: "taken@example.com".to_string(), username: None };
+
+    assert!(s.validate().is_ok());
+    assert!(block_on(s.validate_async()).is_err());
+}
+
+#[test]
+fn async_validation_fails() {
+    let s =
+        SignupData { mail: "taken@example.com".to_string(), username: Some("admin".to_string()) };
+
+    let res = block_on(s.validate_async());
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs["mail"].len(), 1);
+    assert_eq!(errs["mail"][0].code, "email_taken");
+    assert_eq!(errs["mail"][0].params["value"], "taken@example.com");
+    assert_eq!(errs["username"][0].code, "username_taken");
+    assert_eq!(errs["username"][0].clone().message.unwrap(), "Already taken");
+}
+
+#[test]
+fn async_validation_also_has_sync_errors() {
+    let s = SignupData { mail: "not an email".to_string(), username: Some("admin".to_string()) };
+
+    let res = block_on(s.validate_async());
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["mail"][0].code, "email");
+    assert_eq!(errs["username"][0].code, "username_taken");
+}
+
+async fn check_not_leaked(password: &str) -> Result<(), ValidationError> {
+    if password == "hunter2" {
+        return Err(ValidationError::new("leaked"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn async_errors_of_redacted_fields_have_no_value() {
+    #[derive(Debug, Validate, ValidateAsync)]
+    struct Credentials {
+        #[validate(redact, length(min = 8), custom_async = "check_not_leaked")]
+        password: String,
+    }
+
+    let c = Credentials { password: "hunter2".to_string() };
+    let err = block_on(c.validate_async()).unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["password"].len(), 2);
+    assert_eq!(errs["password"][1].code, "leaked");
+    assert!(errs["password"].iter().all(|e| !e.params.contains_key("value")));
+}
+
+#[derive(Debug, Validate, ValidateAsync)]
+struct Profile {
+    #[validate(length(min = 1), custom_async = "check_unique_username")]
+    username: String,
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 1))]
+    city: String,
+}
+
+#[derive(Debug, Validate, ValidateAsync)]
+struct Account {
+    #[validate(nested)]
+    profile: Profile,
+    #[validate(nested)]
+    backup: Option<Profile>,
+    #[validate(nested)]
+    aliases: Vec<Profile>,
+    #[validate(nested)]
+    address: Address,
+}
+
+fn profile(username: &str) -> Profile {
+    Profile { username: username.to_string() }
+}
+
+#[test]
+fn async_validation_awaits_nested_validators() {
+    let a = Account {
+        profile: profile("admin"),
+        backup: None,
+        aliases: vec![],
+        address: Address { city: "Paris".to_string() },
+    };
+
+    assert!(a.validate().is_ok());
+    let err = block_on(a.validate_async()).unwrap_err();
+    assert!(err.field_has_code("profile.username", "username_taken"));
+}
+
+#[test]
+fn nested_async_errors_are_added_once_to_the_sync_ones() {
+    let a = Account {
+        profile: profile("admin"),
+        backup: Some(profile("admin")),
+        aliases: vec![profile("bob"), profile("admin"), profile("")],
+        address: Address { city: String::new() },
+    };
+
+    assert!(a.validate().is_err());
+    let err = block_on(a.validate_async()).unwrap_err();
+    let codes = |path: &str| {
+        err.field_errors_at(path).unwrap().iter().map(|e| e.code.to_string()).collect::<Vec<_>>()
+    };
+    assert_eq!(codes("profile.username"), ["username_taken"]);
+    assert_eq!(codes("backup.username"), ["username_taken"]);
+    assert!(err.field_errors_at("aliases[0].username").is_none());
+    assert_eq!(codes("aliases[1].username"), ["username_taken"]);
+    assert_eq!(codes("aliases[2].username"), ["length"]);
+    assert_eq!(codes("address.city"), ["length"]);
+}
+
+#[test]
+fn nested_generic_values_without_async_bound_only_run_sync_validators() {
+    #[derive(Debug, Validate, ValidateAsync)]
+    struct Wrapper<T: Validate + Sync> {
+        #[validate(nested)]
+        inner: T,
+    }
+
+    let w = Wrapper { inner: profile("admin") };
+    assert!(block_on(w.validate_async()).is_ok());
+}
+
+static SYNC_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+fn count_sync_run(_: &str) -> Result<(), ValidationError> {
+    SYNC_RUNS.fetch_add(1, Ordering::SeqCst);
+    Err(ValidationError::new("taken"))
+}
+
+async fn check_taken(_: &str) -> Result<(), ValidationError> {
+    Err(ValidationError::new("taken"))
+}
+
+#[derive(Debug, Validate, ValidateAsync)]
+struct Tag {
+    #[validate(custom(function = "count_sync_run"), custom_async = "check_taken")]
+    name: String,
+}
+
+#[derive(Debug, Validate, ValidateAsync)]
+struct Post {
+    #[validate(nested)]
+    tag: Tag,
+}
+
+#[test]
+fn nested_sync_validators_run_once_and_equal_async_errors_are_kept() {
+    let post = Post { tag: Tag { name: "rust".to_string() } };
+
+    let before = SYNC_RUNS.load(Ordering::SeqCst);
+    let err = block_on(post.validate_async()).unwrap_err();
+    assert_eq!(SYNC_RUNS.load(Ordering::SeqCst) - before, 1);
+    // The sync and the async validators both found the same error
+    let codes = err.field_errors_at("tag.name").unwrap().iter().map(|e| e.code.to_string());
+    assert_eq!(codes.collect::<Vec<_>>(), ["taken", "taken"]);
+}
@@ -0,0 +1,57 @@
+use validator::{Validate, ValidationErrors, ValidationErrorsKind, DEFAULT_MAX_NESTING_DEPTH};
+
+#[derive(Debug, Validate)]
+struct Tree {
+    #[validate(range(min = 1))]
+    value: u32,
+    #[validate(nested)]
+    child: Option<Box<Tree>>,
+}
+
+fn tree(depth: usize, leaf_value: u32) -> Tree {
+    let mut tree = Tree { value: leaf_value, child: None };
+    for _ in 1..depth {
+        tree = Tree { value: 1, child: Some(Box::new(tree)) };
+    }
+    tree
+}
+
+/// Follows the errors of the children and returns the depth of the deepest one with its errors
+fn deepest_errors(errors: &ValidationErrors) -> (usize, &ValidationErrors) {
+    match errors.errors().get("child") {
+        Some(ValidationErrorsKind::Struct(child)) => {
+            let (depth, errors) = deepest_errors(child);
+            (depth + 1, errors)
+        }
+        _ => (0, errors),
+    }
+}
+
+#[test]
+fn nesting_depth_is_limited() {
+    assert!(tree(DEFAULT_MAX_NESTING_DEPTH, 1).validate().is_ok());
+
+    let err = tree(DEFAULT_MAX_NESTING_DEPTH, 0).validate().unwrap_err();
+    let (depth, errors) = deepest_errors(&err);
+    assert_eq!(depth, DEFAULT_MAX_NESTING_DEPTH - 1);
+    assert_eq!(errors.field_errors()["value"][0].code, "range");
+
+    // The value at the top and `DEFAULT_MAX_NESTING_DEPTH` levels of nested values are validated
+    assert!(tree(DEFAULT_MAX_NESTING_DEPTH + 1, 1).validate().is_ok());
+    let err = tree(DEFAULT_MAX_NESTING_DEPTH + 100, 1).validate().unwrap_err();
+    let (depth, errors) = deepest_errors(&err);
+    assert_eq!(depth, DEFAULT_MAX_NESTING_DEPTH + 1);
+    let all = &errors.field_errors()["__all__"];
+    assert_eq!(all[0].code, "max_depth");
+    assert_eq!(all[0].params["max_depth"], DEFAULT_MAX_NESTING_DEPTH);
+
+    // The depth is back to 0 after a validation stopped by the limit
+    assert!(tree(10, 1).validate().is_ok());
+
+    validator::set_max_nesting_depth(3);
+    assert_eq!(validator::max_nesting_depth(), 3);
+    assert!(tree(4, 1).validate().is_ok());
+    let err = tree(5, 1).validate().unwrap_err();
+    assert_eq!(deepest_errors(&err).0, 4);
+    validator::set_max_nesting_depth(DEFAULT_MAX_NESTING_DEPTH);
+}
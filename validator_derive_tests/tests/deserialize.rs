@@ -0,0 +1,99 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use validator::{Severity, Validate, ValidationErrors};
+
+#[derive(Debug, Validate)]
+struct Order {
+    #[validate(length(min = 1), email)]
+    customer: String,
+    #[validate(nested)]
+    address: Address,
+    #[validate(nested)]
+    lines: Vec<Line>,
+    #[validate(nested)]
+    extras: HashMap<String, Line>,
+    #[validate(range(min = 1), severity = "warning")]
+    priority: u8,
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 1))]
+    street: String,
+}
+
+#[derive(Debug, Validate)]
+struct Line {
+    #[validate(range(min = 1))]
+    quantity: u32,
+}
+
+fn order() -> Order {
+    let mut extras = HashMap::new();
+    extras.insert("gift".to_string(), Line { quantity: 0 });
+    Order {
+        customer: String::new(),
+        address: Address { street: String::new() },
+        lines: vec![Line { quantity: 1 }, Line { quantity: 0 }],
+        extras,
+        priority: 0,
+    }
+}
+
+#[test]
+fn round_trips_through_json() {
+    let errors = order().validate_report().issues().clone();
+    let json = serde_json::to_string(&errors).unwrap();
+    let deserialized: ValidationErrors = serde_json::from_str(&json).unwrap();
+    // The params are a `HashMap`, so only the values are compared
+    assert_eq!(
+        serde_json::to_value(&deserialized).unwrap(),
+        serde_json::from_str::<serde_json::Value>(&json).unwrap()
+    );
+    assert_eq!(
+        deserialized.iter_fields().map(|(field, _)| field).collect::<Vec<_>>(),
+        errors.iter_fields().map(|(field, _)| field).collect::<Vec<_>>()
+    );
+
+    assert_eq!(deserialized.field_errors()["customer"], errors.field_errors()["customer"]);
+    assert_eq!(deserialized.at("address"), errors.at("address"));
+    assert_eq!(deserialized.at("lines"), errors.at("lines"));
+    assert_eq!(deserialized.field_errors()["priority"][0].severity, Severity::Warning);
+    // Maps of nested structs come back as nested structs
+    assert!(deserialized.field_has_code("extras.gift.quantity", "range"));
+}
+
+#[test]
+fn keeps_the_order_of_the_fields() {
+    let json =
+        r#"{"b": [{"code": "length", "message": null, "params": {}}], "a": {"0": {"c": []}}}"#;
+    let errors: ValidationErrors = serde_json::from_str(json).unwrap();
+    assert_eq!(errors.iter_fields().map(|(field, _)| field).collect::<Vec<_>>(), vec!["b", "a"]);
+    assert!(errors.at("a[0]").is_some());
+}
+
+#[test]
+fn merges_with_local_errors() {
+    let json = r#"{"remote": [{"code": "taken", "params": {}}]}"#;
+    let remote: ValidationErrors = serde_json::from_str(json).unwrap();
+    let mut errors = order().validate().unwrap_err();
+    errors.extend(remote);
+    assert!(errors.field_has_code("remote", "taken"));
+    assert!(errors.field_has_code("customer", "length"));
+}
+
+#[test]
+fn owns_the_deserialized_field_names() {
+    let json = r#"{"user-supplied": [{"code": "taken", "params": {}}]}"#;
+    let errors: ValidationErrors = serde_json::from_str(json).unwrap();
+    let (field, _) = errors.into_errors().into_iter().next().unwrap();
+    assert!(matches!(field, Cow::Owned(ref name) if name == "user-supplied"));
+}
+
+#[test]
+fn rejects_invalid_errors() {
+    assert!(serde_json::from_str::<ValidationErrors>("[]").is_err());
+    assert!(serde_json::from_str::<ValidationErrors>(r#"{"a": [{"message": "no code"}]}"#).is_err());
+    assert!(serde_json::from_str::<ValidationErrors>(r#"{"a": [], "a": []}"#).is_err());
+}
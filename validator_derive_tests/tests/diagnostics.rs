@@ -0,0 +1,78 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+struct Config {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(nested)]
+    servers: Vec<Server>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+struct Server {
+    #[validate(range(min = 1024, message = "Must not be a privileged port"))]
+    port: u16,
+    #[validate(required)]
+    host: Option<String>,
+}
+
+const SOURCE: &str = r#"{
+  "name": "",
+  "servers": [
+    {"port": 8080, "host": "a"},
+    {"port": 80}
+  ]
+}"#;
+
+fn errors() -> validator::ValidationErrors {
+    let config: Config = serde_json::from_str(SOURCE).unwrap();
+    config.validate().unwrap_err()
+}
+
+#[test]
+fn locates_the_errors_in_the_source() {
+    let errors = errors();
+    let labels = errors.diagnostics(SOURCE).labels();
+    assert_eq!(labels.len(), 3);
+    assert_eq!(labels[0].pointer, "/name");
+    assert_eq!(&SOURCE[labels[0].span.clone().unwrap()], r#""""#);
+    assert_eq!(labels[1].pointer, "/servers/1/port");
+    assert_eq!(labels[1].message, "Must not be a privileged port");
+    assert_eq!(&SOURCE[labels[1].span.clone().unwrap()], "80");
+    // The missing field points at its object
+    assert_eq!(labels[2].pointer, "/servers/1/host");
+    assert_eq!(labels[2].code, "required");
+    assert_eq!(&SOURCE[labels[2].span.clone().unwrap()], r#"{"port": 80}"#);
+}
+
+#[test]
+fn shows_the_lines_of_the_errors() {
+    let errors = errors();
+    let expected = r#"error: length
+ --> config.json:2:11
+  |
+2 |   "name": "",
+  |           ^^
+
+error: Must not be a privileged port (range)
+ --> config.json:5:14
+  |
+5 |     {"port": 80}
+  |              ^^
+
+error: required
+ --> config.json:5:5
+  |
+5 |     {"port": 80}
+  |     ^^^^^^^^^^^^
+"#;
+    assert_eq!(errors.diagnostics(SOURCE).with_name("config.json").to_string(), expected);
+}
+
+#[test]
+fn errors_without_position_have_their_pointer() {
+    let errors = errors();
+    let rendered = errors.diagnostics("not json").to_string();
+    assert!(rendered.starts_with("error: length\n --> <input>#/name\n"));
+}
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
-use validator::Validate;
+use validator::{Validate, WordList};
+
+static BANNED: WordList = WordList::ignoring_ascii_case(&["spam", "free money"]);
 
 #[test]
 fn can_validate_does_not_contain_ok() {
@@ -91,3 +93,27 @@ fn can_specify_message_for_does_not_contain() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+#[test]
+fn string_containing_a_word_of_the_list_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(does_not_contain(words = "BANNED", message = "No spam"))]
+        val: String,
+        #[validate(does_not_contain(words = "BANNED"), redact)]
+        secret: Option<String>,
+    }
+
+    let s = TestStruct { val: "Hello".to_string(), secret: None };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "Get FREE MONEY".to_string(), secret: Some("spam".to_string()) };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "does_not_contain");
+    assert_eq!(errs["val"][0].message.as_deref(), Some("No spam"));
+    assert_eq!(errs["val"][0].params["word"], "free money");
+    assert_eq!(errs["val"][0].params["value"], "Get FREE MONEY");
+    assert_eq!(errs["secret"][0].code, "does_not_contain");
+    assert!(!errs["secret"][0].params.contains_key("word"));
+}
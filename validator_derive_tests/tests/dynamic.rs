@@ -0,0 +1,202 @@
+use regex::Regex;
+use serde_json::json;
+use validator::field::{email, length, must_match, nested, range, regex, required};
+use validator::{DynamicRulesError, DynamicValidator, StructValidator, ValidationErrorsKind};
+
+fn validator(rules: serde_json::Value) -> DynamicValidator {
+    DynamicValidator::from_value(&rules).unwrap()
+}
+
+#[test]
+fn can_validate_a_document() {
+    let validator = validator(json!({
+        "username": [{"kind": "required"}, {"kind": "length", "min": 3, "max": 10}],
+        "age": [{"kind": "range", "min": 18, "step": 1}],
+        "site": [{"kind": "url"}],
+        "password": [{"kind": "required"}],
+        "confirmation": [{"kind": "must_match", "other": "password"}],
+        "tags": [{"kind": "does_not_contain", "needle": "admin"}],
+    }));
+
+    assert!(validator
+        .validate(&json!({"username": "bob", "age": 20, "password": "x", "confirmation": "x"}))
+        .is_ok());
+
+    let errors = validator
+        .validate(&json!({
+            "username": "bo",
+            "age": 17.5,
+            "site": null,
+            "confirmation": "y",
+            "tags": {"admin": true},
+        }))
+        .unwrap_err();
+    let errors = errors.field_errors();
+    assert_eq!(errors["username"][0].code, "length");
+    assert_eq!(errors["username"][0].params["min"], 3);
+    assert_eq!(errors["username"][0].params["value"], "bo");
+    assert_eq!(errors["age"].len(), 1);
+    assert_eq!(errors["password"][0].code, "required");
+    assert_eq!(errors["confirmation"][0].code, "must_match");
+    assert_eq!(errors["tags"][0].code, "does_not_contain");
+    assert!(!errors.contains_key("site"));
+}
+
+#[test]
+fn wrong_types_fail_the_rules() {
+    let validator = validator(json!({
+        "email": [{"kind": "email"}],
+        "count": [{"kind": "range", "max": 10}],
+    }));
+    let errors = validator.validate(&json!({"email": 1, "count": "2"})).unwrap_err();
+    assert_eq!(errors.field_errors().len(), 2);
+}
+
+#[test]
+fn can_set_the_code_and_message() {
+    let validator = validator(json!({
+        "zip": [{"kind": "regex", "pattern": "^[0-9]{5}$", "code": "zip", "message": "Not a zip code"}],
+    }));
+    let errors = validator.validate(&json!({"zip": "1234"})).unwrap_err();
+    let error = &errors.field_errors()["zip"][0];
+    assert_eq!(error.code, "zip");
+    assert_eq!(error.message.as_deref(), Some("Not a zip code"));
+    assert_eq!(error.params["pattern"], "^[0-9]{5}$");
+}
+
+#[test]
+fn can_validate_nested_objects_and_lists() {
+    let validator = validator(json!({
+        "address": [{"kind": "nested", "rules": {"city": [{"kind": "required"}]}}],
+        "items": [
+            {"kind": "length", "min": 1},
+            {"kind": "nested", "rules": {"quantity": [{"kind": "range", "min": 1}]}},
+        ],
+    }));
+
+    let errors = validator
+        .validate(&json!({
+            "address": {},
+            "items": [{"quantity": 1}, {"quantity": 0}],
+        }))
+        .unwrap_err();
+    match &errors.errors()["address"] {
+        ValidationErrorsKind::Struct(errors) => {
+            assert_eq!(errors.field_errors()["city"][0].code, "required")
+        }
+        kind => panic!("Unexpected errors {:?}", kind),
+    }
+    let items = errors.list_errors("items").unwrap();
+    assert_eq!(items.keys().collect::<Vec<_>>(), vec![&1]);
+
+    // The nested rules don't run when the list itself is invalid
+    let errors =
+        validator.validate(&json!({"address": {"city": "Paris"}, "items": []})).unwrap_err();
+    assert_eq!(errors.field_errors()["items"][0].code, "length");
+}
+
+#[test]
+fn reports_invalid_rules() {
+    match DynamicValidator::from_json(r#"{"name": [{"kind": "lenght"}]}"#).unwrap_err() {
+        DynamicRulesError::UnknownRule { field, kind } => {
+            assert_eq!((field.as_str(), kind.as_str()), ("name", "lenght"))
+        }
+        err => panic!("Unexpected error {:?}", err),
+    }
+
+    let err = DynamicValidator::from_value(&json!({"name": [{"kind": "length", "min": "3"}]}))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid rule on `name`: `min` of `length` must be a non-negative integer"
+    );
+
+    for step in [json!(0), json!(-5), json!(-0.5)] {
+        let err = DynamicValidator::from_value(&json!({"age": [{"kind": "range", "step": step}]}))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid rule on `age`: `step` of `range` must be greater than 0"
+        );
+    }
+
+    let err = DynamicValidator::from_value(&json!({
+        "address": [{"kind": "nested", "rules": {"zip": [{"kind": "regex"}]}}],
+    }))
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Invalid rule on `address.zip`: `regex` needs a `pattern` string");
+
+    assert!(matches!(DynamicValidator::from_json("{"), Err(DynamicRulesError::Json(_))));
+    assert!(DynamicValidator::from_value(&json!({"name": {"kind": "email"}})).is_err());
+}
+
+#[test]
+fn can_build_ranges_of_any_integer_type() {
+    let max_size: u64 = 5_000_000_000;
+    let validator = StructValidator::new()
+        .field("size", range(0..=max_size))
+        .field("offset", range(i64::MIN..0i64))
+        .field("index", range(..10usize));
+
+    assert!(validator
+        .validate(&json!({"size": 4_000_000_000u64, "offset": -1, "index": 9}))
+        .is_ok());
+    let errors = validator
+        .validate(&json!({"size": 6_000_000_000u64, "offset": 0, "index": 10}))
+        .unwrap_err();
+    let field_errors = errors.field_errors();
+    assert_eq!(field_errors["size"][0].params["max"], 5_000_000_000u64);
+    assert_eq!(field_errors["offset"][0].params["exclusive_max"], 0);
+    assert_eq!(field_errors["index"][0].params["exclusive_max"], 10);
+}
+
+#[test]
+fn can_build_the_rules_in_code() {
+    let validator = StructValidator::new()
+        .field("username", required().length(3..=10))
+        .field("age", range(18..=120))
+        .field("zip", regex(Regex::new("^[0-9]{5}$").unwrap()).code("zip").message("Not a zip"))
+        .field("password", required())
+        .field("confirmation", must_match("password"))
+        .field("address", nested(StructValidator::new().field("city", required())));
+
+    assert!(validator
+        .validate(&json!({"username": "bob", "age": 20, "password": "x", "confirmation": "x"}))
+        .is_ok());
+
+    let errors = validator
+        .validate(&json!({
+            "username": "bo",
+            "age": 120.5,
+            "zip": "1",
+            "confirmation": "y",
+            "address": {},
+        }))
+        .unwrap_err();
+    let field_errors = errors.field_errors();
+    assert_eq!(field_errors["username"][0].code, "length");
+    assert_eq!(field_errors["username"][0].params["min"], 3);
+    assert_eq!(field_errors["age"][0].params["max"], 120);
+    assert_eq!(field_errors["zip"][0].code, "zip");
+    assert_eq!(field_errors["zip"][0].message.as_deref(), Some("Not a zip"));
+    assert_eq!(field_errors["password"][0].code, "required");
+    assert_eq!(field_errors["confirmation"][0].code, "must_match");
+    assert!(matches!(errors.errors()["address"], ValidationErrorsKind::Struct(_)));
+}
+
+#[test]
+fn built_rules_match_the_loaded_ones() {
+    let built = StructValidator::new()
+        .field("email", email().required())
+        .field("name", length(..=3))
+        .field("name", length(1..));
+    let loaded = validator(json!({
+        "email": [{"kind": "email"}, {"kind": "required"}],
+        "name": [{"kind": "length", "max": 3}, {"kind": "length", "min": 1}],
+    }));
+
+    for document in [json!({}), json!({"email": "bob", "name": ""}), json!({"name": "abcd"})] {
+        assert_eq!(built.validate(&document), loaded.validate(&document), "{}", document);
+    }
+    assert_eq!(built.validate_map([("email", "bob")]), loaded.validate(&json!({"email": "bob"})));
+}
@@ -0,0 +1,65 @@
+use validator::{bail_validation, err_if, Severity, Validate, ValidationError};
+
+#[test]
+fn can_build_an_error() {
+    let error = ValidationError::builder("too_short")
+        .message("Too short")
+        .param("min", 3)
+        .param("field", "name")
+        .severity(Severity::Warning)
+        .build();
+
+    assert_eq!(error.code, "too_short");
+    assert_eq!(error.message.as_deref(), Some("Too short"));
+    assert_eq!(error.param_i64("min"), Some(3));
+    assert_eq!(error.param_str("field"), Some("name"));
+    assert_eq!(error.severity, Severity::Warning);
+
+    let mut expected = ValidationError::new("too_short");
+    expected.message = Some("Too short".into());
+    expected.add_param("min".into(), &3);
+    expected.add_param("field".into(), &"name");
+    expected.severity = Severity::Warning;
+    assert_eq!(error, expected);
+}
+
+fn validate_username(value: &str) -> Result<(), ValidationError> {
+    err_if!(value.len() < 3, "too_short", min = 3, actual = value.len());
+    err_if!(value == "admin", "reserved", message = "This username is reserved");
+    Ok(())
+}
+
+fn validate_pairs(value: &[(u8, u8)]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if value.is_empty() {
+        bail_validation!("empty");
+    }
+    Ok(())
+}
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(custom = "validate_username")]
+    username: String,
+}
+
+#[test]
+fn macros_return_the_errors() {
+    let error = validate_username("ab").unwrap_err();
+    assert_eq!(error.code, "too_short");
+    assert_eq!(error.message, None);
+    assert_eq!(error.param_i64("min"), Some(3));
+    assert_eq!(error.param_i64("actual"), Some(2));
+
+    let error = validate_username("admin").unwrap_err();
+    assert_eq!(error.code, "reserved");
+    assert_eq!(error.message.as_deref(), Some("This username is reserved"));
+
+    assert!(validate_username("bob").is_ok());
+    assert_eq!(validate_pairs(&[]).unwrap_err().to_string(), "Validation error: empty [{}]");
+}
+
+#[test]
+fn macros_can_be_used_in_custom_validators() {
+    let errors = Signup { username: "admin".to_string() }.validate().unwrap_err();
+    assert!(errors.field_has_code("username", "reserved"));
+}
@@ -0,0 +1,57 @@
+use validator::{ErrorCode, Validate, ValidationError};
+
+#[derive(Debug, Validate)]
+#[validate(schema(function = "validate_signup", skip_on_field_errors = false))]
+struct Signup {
+    #[validate(length(min = 3), email)]
+    mail: String,
+    #[validate(range(min = 18))]
+    age: u8,
+    #[validate(length(min = 1, code = "empty_name"))]
+    name: String,
+}
+
+fn validate_signup(_: &Signup) -> Result<(), ValidationError> {
+    Err(ValidationError::with_code(ErrorCode::Custom("closed".into())))
+}
+
+#[test]
+fn can_match_on_the_codes() {
+    let signup = Signup { mail: "a".to_string(), age: 12, name: String::new() };
+    let errors = signup.validate().unwrap_err();
+    let codes = errors.iter().map(|(_, error)| error.error_code()).collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        vec![
+            ErrorCode::Length,
+            ErrorCode::Email,
+            ErrorCode::Range,
+            ErrorCode::Custom("empty_name".into()),
+            ErrorCode::Custom("closed".into()),
+        ]
+    );
+
+    let messages = errors
+        .iter()
+        .map(|(_, error)| match error.error_code() {
+            ErrorCode::Length | ErrorCode::Email => "Invalid email",
+            ErrorCode::Range => "Too young",
+            ErrorCode::Custom(code) if code == "closed" => "Signups are closed",
+            _ => "Invalid",
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec!["Invalid email", "Invalid email", "Too young", "Invalid", "Signups are closed"]
+    );
+}
+
+#[test]
+fn codes_compare_with_strings() {
+    let error = ValidationError::with_code(ErrorCode::Length);
+    assert_eq!(error.code, "length");
+    assert_eq!(error.code, ErrorCode::Length);
+    assert_eq!(ErrorCode::Required, "required");
+    assert_eq!(ErrorCode::GreaterThanOrEqual.to_string(), "greater_than_or_equal");
+    assert_eq!(ErrorCode::from_code("unknown"), ErrorCode::Custom("unknown".into()));
+}
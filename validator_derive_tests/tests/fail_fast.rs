@@ -0,0 +1,100 @@
+use validator::{Validate, ValidationError};
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(email)]
+    mail: String,
+    #[validate(range(min = 18))]
+    age: u8,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 1))]
+    street: String,
+    #[validate(length(min = 1))]
+    city: String,
+}
+
+fn invalid_signup() -> Signup {
+    Signup {
+        mail: "bob".to_string(),
+        age: 17,
+        address: Address { street: String::new(), city: String::new() },
+    }
+}
+
+#[test]
+fn validate_collects_all_the_errors() {
+    let err = invalid_signup().validate().unwrap_err();
+    assert_eq!(err.errors().len(), 3);
+}
+
+#[test]
+fn validate_fail_fast_stops_at_the_first_error() {
+    let err = invalid_signup().validate_fail_fast().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(err.errors().len(), 1);
+    assert_eq!(errs["mail"].len(), 1);
+    assert_eq!(errs["mail"][0].code, "email");
+
+    let mut signup = invalid_signup();
+    signup.mail = "bob@bob.com".to_string();
+    signup.age = 18;
+    let err = signup.validate_fail_fast().unwrap_err();
+    assert!(has_one_nested_error(&err));
+}
+
+fn has_one_nested_error(err: &validator::ValidationErrors) -> bool {
+    match &err.errors()["address"] {
+        validator::ValidationErrorsKind::Struct(errs) => errs.errors().len() == 1,
+        _ => false,
+    }
+}
+
+#[test]
+fn fail_fast_mode_applies_to_validate() {
+    fn never_called(_: &Check) -> Result<(), ValidationError> {
+        panic!("The schema validation shouldn't run after the first error");
+    }
+
+    #[derive(Debug, Validate)]
+    #[validate(mode = "fail_fast")]
+    #[validate(schema(function = "never_called", skip_on_field_errors = false))]
+    struct Check {
+        #[validate(length(min = 1), contains = "a")]
+        name: String,
+        #[validate(email)]
+        mail: String,
+    }
+
+    let check = Check { name: String::new(), mail: "bob".to_string() };
+    let err = check.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs["name"].len(), 1);
+    assert_eq!(errs["name"][0].code, "length");
+}
+
+#[test]
+fn fail_fast_stops_at_the_first_invalid_element() {
+    let signups = vec![invalid_signup(), invalid_signup()];
+    assert_eq!(signups.validate_fail_fast().unwrap_err().errors().len(), 1);
+    match &signups.validate_fail_fast().unwrap_err().errors()["__all__"] {
+        validator::ValidationErrorsKind::List(errors) => assert_eq!(errors.len(), 1),
+        kind => panic!("Expected a list, got {:?}", kind),
+    }
+}
+
+#[derive(Debug, Validate)]
+#[validate(transparent)]
+struct SignupRequest(Signup);
+
+#[test]
+fn transparent_forwards_fail_fast() {
+    let request = SignupRequest(invalid_signup());
+    assert_eq!(request.validate().unwrap_err().errors().len(), 3);
+    assert_eq!(request.validate_fail_fast().unwrap_err().errors().len(), 1);
+}
@@ -0,0 +1,73 @@
+use validator::{Validate, ValidationError, ValidationErrors};
+
+fn always_invalid(_: &Signup) -> Result<(), ValidationError> {
+    Err(ValidationError::new("invalid"))
+}
+
+#[derive(Debug, Validate)]
+#[validate(schema(function = "always_invalid", skip_on_field_errors = false))]
+struct Signup {
+    #[validate(nested)]
+    address: Address,
+    #[validate(length(min = 1))]
+    zip: String,
+    #[validate(email)]
+    mail: String,
+    #[validate(range(min = 18))]
+    age: u8,
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 1))]
+    street: String,
+    #[validate(length(min = 1))]
+    city: String,
+}
+
+fn invalid_signup() -> Signup {
+    Signup {
+        address: Address { street: String::new(), city: String::new() },
+        zip: String::new(),
+        mail: "bob".to_string(),
+        age: 17,
+    }
+}
+
+#[test]
+fn errors_are_in_declaration_order() {
+    let err = invalid_signup().validate().unwrap_err();
+    let fields = err.iter_fields().map(|(field, _)| field).collect::<Vec<_>>();
+    assert_eq!(fields, vec!["address", "zip", "mail", "age", "__all__"]);
+
+    let paths = err.iter().map(|(path, _)| path.to_string()).collect::<Vec<_>>();
+    assert_eq!(paths, vec!["address.street", "address.city", "zip", "mail", "age", "__all__"]);
+}
+
+#[test]
+fn serialization_is_in_declaration_order() {
+    let err = invalid_signup().validate().unwrap_err();
+    let json = serde_json::to_string(&err).unwrap();
+    let positions =
+        ["\"address\"", "\"street\"", "\"city\"", "\"zip\"", "\"mail\"", "\"age\"", "\"__all__\""]
+            .iter()
+            .map(|key| json.find(key).unwrap())
+            .collect::<Vec<_>>();
+    let mut sorted = positions.clone();
+    sorted.sort_unstable();
+    assert_eq!(positions, sorted);
+}
+
+#[test]
+fn field_order_can_be_set() {
+    let mut errors = ValidationErrors::new();
+    errors.add("b", ValidationError::new("invalid"));
+    errors.add("a", ValidationError::new("invalid"));
+    errors.add("c", ValidationError::new("invalid"));
+    let fields = errors.iter_fields().map(|(field, _)| field).collect::<Vec<_>>();
+    assert_eq!(fields, vec!["b", "a", "c"]);
+
+    errors.set_field_order(&["c", "missing", "a"]);
+    let fields = errors.iter_fields().map(|(field, _)| field).collect::<Vec<_>>();
+    assert_eq!(fields, vec!["c", "a", "b"]);
+}
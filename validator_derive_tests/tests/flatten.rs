@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use validator::{PathSegment, Validate};
+
+#[derive(Debug, Validate)]
+struct User {
+    #[validate(email)]
+    mail: String,
+    #[validate(nested)]
+    addresses: Vec<Address>,
+    #[validate(nested)]
+    links: HashMap<String, Link>,
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 1))]
+    street: String,
+    #[validate(nested)]
+    country: Country,
+}
+
+#[derive(Debug, Validate)]
+struct Country {
+    #[validate(length(equal = 2))]
+    code: String,
+}
+
+#[derive(Debug, Validate)]
+struct Link {
+    #[validate(url)]
+    url: String,
+}
+
+fn invalid_user() -> User {
+    let valid_address =
+        Address { street: "Main St".to_string(), country: Country { code: "FR".to_string() } };
+    let invalid_address =
+        Address { street: String::new(), country: Country { code: "FRA".to_string() } };
+    let mut links = HashMap::new();
+    links.insert("a/b".to_string(), Link { url: "nope".to_string() });
+    User { mail: "bob".to_string(), addresses: vec![valid_address, invalid_address], links }
+}
+
+#[test]
+fn flattens_to_dotted_paths() {
+    let err = invalid_user().validate().unwrap_err();
+    let flat = err.flatten();
+    assert_eq!(
+        flat.keys().map(String::as_str).collect::<Vec<_>>(),
+        vec!["addresses[1].country.code", "addresses[1].street", "links[a/b].url", "mail"]
+    );
+    assert_eq!(flat["mail"][0].code, "email");
+    assert_eq!(flat["addresses[1].country.code"][0].code, "length");
+}
+
+#[test]
+fn flattens_to_json_pointers() {
+    let err = invalid_user().validate().unwrap_err();
+    let flat = err.flatten_pointers();
+    assert_eq!(
+        flat.keys().map(String::as_str).collect::<Vec<_>>(),
+        vec!["/addresses/1/country/code", "/addresses/1/street", "/links/a~1b/url", "/mail"]
+    );
+    assert_eq!(flat["/links/a~1b/url"][0].code, "url");
+}
+
+#[test]
+fn can_query_errors_by_path() {
+    let err = invalid_user().validate().unwrap_err();
+    assert!(err.at("addresses[1]").is_some());
+    assert!(err.at("addresses[0]").is_none());
+    assert!(err.at("addresses[1].country").is_some());
+    assert!(err.at("links[a/b]").is_some());
+    assert!(err.at("mail").is_none());
+
+    assert!(err.field_has_code("mail", "email"));
+    assert!(!err.field_has_code("mail", "length"));
+    assert!(err.field_has_code("addresses[1].street", "length"));
+    assert!(err.field_has_code("addresses[1].country.code", "length"));
+    assert!(err.field_has_code("links[a/b].url", "url"));
+    assert_eq!(err.field_errors_at("addresses[1].street").unwrap().len(), 1);
+    assert!(err.field_errors_at("addresses[1]").is_none());
+    assert!(err.field_errors_at("addresses[x].street").is_none());
+    assert!(err.field_errors_at("addresses[1.street").is_none());
+}
+
+#[test]
+fn can_iterate_over_all_the_errors() {
+    let err = invalid_user().validate().unwrap_err();
+    let leaves = err
+        .iter()
+        .map(|(path, error)| (path.to_string(), path.to_pointer(), error.code.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        leaves,
+        vec![
+            ("mail".to_string(), "/mail".to_string(), "email".to_string()),
+            (
+                "addresses[1].street".to_string(),
+                "/addresses/1/street".to_string(),
+                "length".to_string()
+            ),
+            (
+                "addresses[1].country.code".to_string(),
+                "/addresses/1/country/code".to_string(),
+                "length".to_string()
+            ),
+            ("links[a/b].url".to_string(), "/links/a~1b/url".to_string(), "url".to_string()),
+        ]
+    );
+
+    let (path, _) = err.iter().nth(2).unwrap();
+    assert_eq!(path.field(), Some("code"));
+    assert_eq!(
+        path.segments(),
+        &[
+            PathSegment::Field("addresses"),
+            PathSegment::Index(1),
+            PathSegment::Field("country"),
+            PathSegment::Field("code"),
+        ]
+    );
+}
+
+#[test]
+fn can_group_the_errors_by_code() {
+    let err = invalid_user().validate().unwrap_err();
+    let groups = err.by_code();
+    assert_eq!(groups.keys().copied().collect::<Vec<_>>(), vec!["email", "length", "url"]);
+    assert_eq!(
+        groups["length"].iter().map(|(path, _)| path.to_string()).collect::<Vec<_>>(),
+        vec!["addresses[1].street", "addresses[1].country.code"]
+    );
+    assert_eq!(groups["length"][1].1.param_i64("equal"), Some(2));
+    assert_eq!(groups["email"].len(), 1);
+}
@@ -0,0 +1,63 @@
+use serde::Serialize;
+use validator::forms::{field_rules, validate_field};
+use validator::{Validate, ValidationError};
+
+fn check_team(_: &str) -> Result<(), ValidationError> {
+    Err(ValidationError::new("team"))
+}
+
+#[derive(Serialize, Validate)]
+struct Signup {
+    #[serde(rename = "email")]
+    #[validate(email(message = "Not an email"), length(max = 20))]
+    mail: String,
+    #[validate(range(min = 18))]
+    age: u32,
+    #[validate(custom = "check_team")]
+    team: String,
+    #[validate(required, length(min = 2))]
+    nickname: Option<String>,
+    #[validate(inner(length(max = 5)))]
+    tags: Vec<String>,
+    newsletter: bool,
+}
+
+fn codes(errors: Vec<ValidationError>) -> Vec<String> {
+    errors.into_iter().map(|error| error.code.to_string()).collect()
+}
+
+#[test]
+fn can_validate_a_single_field() {
+    assert_eq!(validate_field::<Signup, _>("email", "bob@example.com"), Ok(()));
+    let errors = validate_field::<Signup, _>("email", "bob-at-a-very-long-domain").unwrap_err();
+    assert_eq!(errors[0].message.as_deref(), Some("Not an email"));
+    assert_eq!(errors[0].params["value"], "bob-at-a-very-long-domain");
+    assert_eq!(codes(errors), ["email", "length"]);
+
+    assert_eq!(validate_field::<Signup, _>("age", &21), Ok(()));
+    assert_eq!(codes(validate_field::<Signup, _>("age", &17).unwrap_err()), ["range"]);
+    assert_eq!(
+        codes(validate_field::<Signup, _>("nickname", &None::<String>).unwrap_err()),
+        ["required"]
+    );
+    assert_eq!(codes(validate_field::<Signup, _>("nickname", "b").unwrap_err()), ["length"]);
+    assert_eq!(
+        codes(validate_field::<Signup, _>("tags", &["new", "too long"]).unwrap_err()),
+        ["length"]
+    );
+}
+
+#[test]
+fn leaves_the_rules_needing_more_than_the_value_to_the_struct() {
+    assert_eq!(validate_field::<Signup, _>("team", "blue"), Ok(()));
+    assert_eq!(validate_field::<Signup, _>("newsletter", &true), Ok(()));
+    assert_eq!(validate_field::<Signup, _>("mail", "bob"), Ok(()));
+}
+
+#[test]
+fn exposes_the_rules_of_a_field() {
+    let rules = field_rules::<Signup>("email").unwrap();
+    assert_eq!(rules.rules[1].kind, "length");
+    assert_eq!(rules.rules[1].params["max"], 20);
+    assert!(field_rules::<Signup>("newsletter").is_none());
+}
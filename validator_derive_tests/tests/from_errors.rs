@@ -0,0 +1,85 @@
+use validator::{FromValidationErrors, Validate, ValidationError, ValidationErrors};
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(email)]
+    mail: String,
+    #[validate(length(min = 8))]
+    password: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 2, code = "unknown_country"))]
+    country: String,
+}
+
+#[derive(Debug, PartialEq, FromValidationErrors)]
+enum SignupError {
+    #[validation_error(field = "mail", code = "email")]
+    InvalidMail,
+    #[validation_error(field = "password")]
+    WeakPassword(ValidationError),
+    #[validation_error(code = "unknown_country")]
+    UnknownCountry,
+    #[validation_error(fallback)]
+    Invalid(ValidationErrors),
+    #[allow(dead_code)]
+    Storage(String),
+}
+
+#[derive(Debug, PartialEq, FromValidationErrors)]
+enum PasswordError {
+    #[validation_error(field = "password", code = "length")]
+    TooShort,
+}
+
+fn signup(mail: &str, password: &str, country: &str) -> Signup {
+    Signup {
+        mail: mail.to_string(),
+        password: password.to_string(),
+        address: Address { country: country.to_string() },
+    }
+}
+
+fn register(signup: &Signup) -> Result<(), SignupError> {
+    signup.validate()?;
+    Ok(())
+}
+
+#[test]
+fn variants_are_tried_in_order() {
+    assert_eq!(register(&signup("bob", "short", "")), Err(SignupError::InvalidMail));
+    assert_eq!(
+        register(&signup("bob@example.com", "hunter22", "")),
+        Err(SignupError::UnknownCountry)
+    );
+    assert!(register(&signup("bob@example.com", "hunter22", "FR")).is_ok());
+}
+
+#[test]
+fn variants_can_have_the_error() {
+    match register(&signup("bob@example.com", "short", "FR")) {
+        Err(SignupError::WeakPassword(error)) => assert_eq!(error.param_i64("min"), Some(8)),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn fallback_has_the_unmapped_errors() {
+    let mut errors = ValidationErrors::new();
+    errors.add("other", ValidationError::new("other"));
+    assert_eq!(SignupError::from(errors.clone()), SignupError::Invalid(errors));
+}
+
+#[test]
+fn errors_are_returned_without_fallback() {
+    let errors = signup("bob", "short", "FR").validate().unwrap_err();
+    assert_eq!(PasswordError::from_validation_errors(errors), Ok(PasswordError::TooShort));
+
+    let errors = signup("bob", "hunter22", "FR").validate().unwrap_err();
+    let unmapped = PasswordError::from_validation_errors(errors.clone()).unwrap_err();
+    assert_eq!(unmapped, errors);
+}
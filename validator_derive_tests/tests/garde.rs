@@ -0,0 +1,93 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Serialize;
+use validator::Validate;
+
+const MAX_NAME: usize = 20;
+
+lazy_static! {
+    static ref SKU: Regex = Regex::new(r"^[A-Z]{3}-[0-9]{3}$").unwrap();
+}
+
+#[derive(Debug, Validate)]
+#[garde(allow_unvalidated)]
+struct Order {
+    #[garde(email, length(max = 40))]
+    mail: String,
+    #[garde(length(chars, min = 1, max = MAX_NAME), ascii)]
+    name: String,
+    #[garde(range(min = 1, max = 10))]
+    quantity: u32,
+    #[garde(pattern(SKU))]
+    sku: String,
+    #[garde(prefix("https://"), contains("."))]
+    site: String,
+    #[garde(matches(mail))]
+    mail_confirmation: String,
+    #[garde(length(max = 3), inner(alphanumeric))]
+    tags: Vec<String>,
+    #[garde(required, dive)]
+    address: Option<Address>,
+    #[garde(skip)]
+    _notes: String,
+}
+
+#[derive(Debug, Serialize, Validate)]
+struct Address {
+    #[garde(pattern("^[0-9]{5}$"), suffix("0"))]
+    zip: String,
+}
+
+fn order() -> Order {
+    Order {
+        mail: "bob@example.com".to_string(),
+        name: "Bob".to_string(),
+        quantity: 2,
+        sku: "ABC-123".to_string(),
+        site: "https://example.com".to_string(),
+        mail_confirmation: "bob@example.com".to_string(),
+        tags: vec!["new".to_string()],
+        address: Some(Address { zip: "75010".to_string() }),
+        _notes: String::new(),
+    }
+}
+
+#[test]
+fn can_validate_garde_rules() {
+    assert!(order().validate().is_ok());
+}
+
+#[test]
+fn garde_rules_fail_like_the_validators_they_translate_to() {
+    let order = Order {
+        mail: "bob".to_string(),
+        name: "Bøb".to_string(),
+        quantity: 0,
+        sku: "abc".to_string(),
+        site: "http://example.com".to_string(),
+        mail_confirmation: "bob@example.org".to_string(),
+        tags: vec!["new!".to_string()],
+        address: Some(Address { zip: "75011".to_string() }),
+        ..order()
+    };
+    let errors = order.validate().unwrap_err();
+    let mut codes: Vec<_> =
+        errors.iter().map(|(path, error)| format!("{} {}", path, error.code)).collect();
+    codes.sort();
+    assert_eq!(
+        codes,
+        [
+            "address.zip suffix",
+            "mail email",
+            "mail_confirmation must_match",
+            "name ascii",
+            "quantity range",
+            "site prefix",
+            "sku regex",
+            "tags[0].__value__ alphanumeric",
+        ]
+    );
+
+    let order = Order { address: None, ..self::order() };
+    assert_eq!(order.validate().unwrap_err().to_string(), "address: required (value: null)");
+}
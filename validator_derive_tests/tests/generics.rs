@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use validator::Validate;
+
+#[derive(Debug, Clone, Validate)]
+struct Child {
+    #[validate(length(min = 1))]
+    value: String,
+}
+
+fn valid() -> Child {
+    Child { value: "valid".to_string() }
+}
+
+fn invalid() -> Child {
+    Child { value: String::new() }
+}
+
+#[test]
+fn nested_generic_fields_get_a_validate_bound() {
+    #[derive(Debug, Validate)]
+    struct Wrapper<T> {
+        #[validate]
+        inner: T,
+    }
+
+    assert!(Wrapper { inner: valid() }.validate().is_ok());
+    let err = Wrapper { inner: invalid() }.validate().unwrap_err();
+    assert!(err.errors().contains_key("inner"));
+}
+
+#[test]
+fn nested_generic_collections_get_a_bound_on_their_elements() {
+    #[derive(Debug, Validate)]
+    struct Collections<T, U, V> {
+        #[validate]
+        list: Vec<T>,
+        #[validate]
+        maybe: Option<U>,
+        #[validate]
+        map: HashMap<String, V>,
+    }
+
+    let mut map = HashMap::new();
+    map.insert("a".to_string(), valid());
+    let s = Collections { list: vec![valid()], maybe: Some(valid()), map };
+    assert!(s.validate().is_ok());
+
+    let mut map = HashMap::new();
+    map.insert("a".to_string(), invalid());
+    let s = Collections { list: vec![valid(), invalid()], maybe: Some(invalid()), map };
+    let err = s.validate().unwrap_err();
+    assert_eq!(err.errors().len(), 3);
+    assert!(err.errors_at("list", 1).is_some());
+}
+
+#[test]
+fn generic_fields_that_are_not_nested_get_no_bound() {
+    struct NotValidate;
+
+    #[derive(Validate)]
+    struct TestStruct<T> {
+        #[validate(length(min = 1))]
+        name: String,
+        _data: T,
+    }
+
+    assert!(TestStruct { name: "bob".to_string(), _data: NotValidate }.validate().is_ok());
+    assert!(TestStruct { name: String::new(), _data: NotValidate }.validate().is_err());
+}
+
+#[test]
+fn can_validate_structs_with_where_clauses_and_const_generics() {
+    #[derive(Debug, Validate)]
+    struct TestStruct<'a, T, const N: usize>
+    where
+        T: Clone,
+    {
+        #[validate(length(min = 1))]
+        name: &'a str,
+        #[validate]
+        items: [T; N],
+    }
+
+    let s = TestStruct { name: "bob", items: [valid(), valid()] };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { name: "", items: [valid(), invalid(), valid()] };
+    let err = s.validate().unwrap_err();
+    assert!(err.errors().contains_key("name"));
+    assert!(err.errors_at("items", 1).is_some());
+}
+
+#[test]
+fn transparent_generic_newtypes_get_a_validate_bound() {
+    #[derive(Debug, Validate)]
+    #[validate(transparent)]
+    struct Transparent<T>(T);
+
+    assert!(Transparent(valid()).validate().is_ok());
+    assert!(Transparent(invalid()).validate().is_err());
+}
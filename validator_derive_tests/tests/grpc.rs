@@ -0,0 +1,88 @@
+use serde::Serialize;
+use validator::{GrpcStatus, Validate, BAD_REQUEST_TYPE_URL, INVALID_ARGUMENT};
+
+#[derive(Validate)]
+struct CreateOrder {
+    #[validate(email(message = "Not an email"))]
+    mail: String,
+    #[validate(nested)]
+    items: Vec<Item>,
+}
+
+#[derive(Serialize, Validate)]
+struct Item {
+    #[validate(range(min = 1))]
+    quantity: u32,
+}
+
+/// The fields of a protobuf message, as their number and their varint or bytes
+fn decode(mut bytes: &[u8]) -> Vec<(u64, Result<u64, Vec<u8>>)> {
+    fn varint(bytes: &mut &[u8]) -> u64 {
+        let mut value = 0;
+        for shift in (0..).step_by(7) {
+            let byte = bytes[0];
+            *bytes = &bytes[1..];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte < 0x80 {
+                break;
+            }
+        }
+        value
+    }
+
+    let mut fields = vec![];
+    while !bytes.is_empty() {
+        let key = varint(&mut bytes);
+        let value = match key & 7 {
+            0 => Ok(varint(&mut bytes)),
+            2 => {
+                let length = varint(&mut bytes) as usize;
+                let (value, rest) = bytes.split_at(length);
+                bytes = rest;
+                Err(value.to_vec())
+            }
+            wire_type => panic!("Unexpected wire type {}", wire_type),
+        };
+        fields.push((key >> 3, value));
+    }
+    fields
+}
+
+fn string(value: &Result<u64, Vec<u8>>) -> &str {
+    std::str::from_utf8(value.as_ref().unwrap_err()).unwrap()
+}
+
+#[test]
+fn describes_the_errors_as_a_grpc_status() {
+    let order = CreateOrder {
+        mail: "bob".to_string(),
+        items: vec![Item { quantity: 1 }, Item { quantity: 0 }],
+    };
+    let status = GrpcStatus::new(&order.validate().unwrap_err());
+    assert_eq!(status.code, INVALID_ARGUMENT);
+    assert_eq!(status.violations.len(), 2);
+    assert_eq!(status.violations[0].field, "mail");
+    assert_eq!(status.violations[0].description, "Not an email");
+    assert_eq!(status.violations[1].field, "items[1].quantity");
+    assert_eq!(status.violations[1].reason, "range");
+
+    let encoded = decode(&status.to_bytes());
+    assert_eq!(encoded[0], (1, Ok(3)));
+    assert_eq!((encoded[1].0, string(&encoded[1].1)), (2, "Validation failed"));
+    let any = decode(encoded[2].1.as_ref().unwrap_err());
+    assert_eq!(string(&any[0].1), BAD_REQUEST_TYPE_URL);
+
+    let violations = decode(any[1].1.as_ref().unwrap_err());
+    let violations: Vec<Vec<_>> = violations
+        .iter()
+        .map(|(_, violation)| {
+            decode(violation.as_ref().unwrap_err())
+                .iter()
+                .map(|(_, v)| string(v).to_string())
+                .collect()
+        })
+        .collect();
+    assert_eq!(violations[0], ["mail", "Not an email", "email"]);
+    assert_eq!(violations[1][0], "items[1].quantity");
+    assert_eq!(violations[1][2], "range");
+}
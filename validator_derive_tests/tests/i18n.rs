@@ -0,0 +1,147 @@
+use validator::{MessageBundle, ParamFormatter, Validate};
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(email)]
+    mail: String,
+    #[validate(length(min = 2, max = 10))]
+    name: String,
+    #[validate(range(min = 18))]
+    age: u8,
+    #[validate(custom(function = "validate_username", message = "Terrible username"))]
+    username: String,
+    #[validate(nested)]
+    addresses: Vec<Address>,
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 1))]
+    street: String,
+}
+
+fn validate_username(_: &str) -> Result<(), validator::ValidationError> {
+    Err(validator::ValidationError::new("terrible_username"))
+}
+
+fn invalid_signup() -> Signup {
+    Signup {
+        mail: "bob".to_string(),
+        name: "b".to_string(),
+        age: 17,
+        username: "bob".to_string(),
+        addresses: vec![Address { street: String::new() }],
+    }
+}
+
+#[test]
+fn can_localize_the_builtin_messages() {
+    let mut err = invalid_signup().validate().unwrap_err();
+    err.localize(&MessageBundle::builtin("fr").unwrap());
+
+    let errs = err.field_errors();
+    assert_eq!(errs["mail"][0].message.as_deref(), Some("Doit être une adresse email valide"));
+    assert_eq!(
+        errs["name"][0].message.as_deref(),
+        Some("Doit avoir une longueur comprise entre 2 et 10")
+    );
+    assert_eq!(errs["age"][0].message.as_deref(), Some("Doit être au moins 18"));
+    // There is no message for that code
+    assert_eq!(errs["username"][0].message.as_deref(), Some("Terrible username"));
+    assert_eq!(
+        err.errors_at("addresses", 0).unwrap().field_errors()["street"][0].message.as_deref(),
+        Some("Doit avoir une longueur d'au moins 1")
+    );
+}
+
+#[test]
+fn can_add_messages_for_custom_codes() {
+    let mut bundle = MessageBundle::builtin("en-US").unwrap();
+    bundle.add_resource("terrible_username = { $field } is a terrible username").unwrap();
+    bundle.add_resource("email = Not an email").unwrap();
+
+    let mut err = invalid_signup().validate().unwrap_err();
+    err.localize(&bundle);
+
+    let errs = err.field_errors();
+    assert_eq!(errs["username"][0].message.as_deref(), Some("username is a terrible username"));
+    assert_eq!(errs["mail"][0].message.as_deref(), Some("Not an email"));
+    assert_eq!(errs["age"][0].message.as_deref(), Some("Must be at least 18"));
+}
+
+#[derive(Debug, Validate)]
+#[validate(code_prefix = "user.")]
+struct User {
+    #[validate(length(min = 2))]
+    name: String,
+}
+
+#[test]
+fn can_localize_prefixed_codes() {
+    let mut bundle = MessageBundle::builtin("en").unwrap();
+    bundle.add_resource("user.length = A user name needs at least { $min } characters").unwrap();
+
+    let mut err = User { name: "b".to_string() }.validate().unwrap_err();
+    err.localize(&bundle);
+    assert_eq!(
+        err.field_errors()["name"][0].message.as_deref(),
+        Some("A user name needs at least 2 characters")
+    );
+}
+
+#[derive(Debug, Validate)]
+struct Transfer {
+    #[validate(range(max = 1000000))]
+    amount: u64,
+    #[validate(range(min = 0.5))]
+    fee: f64,
+    #[validate(custom = "validate_date")]
+    date: String,
+}
+
+fn validate_date(_: &str) -> Result<(), validator::ValidationError> {
+    let mut error = validator::ValidationError::new("too_late");
+    error.add_param("latest".into(), &"2024-01-31");
+    Err(error)
+}
+
+fn invalid_transfer() -> Transfer {
+    Transfer { amount: 2500000, fee: 0.25, date: "2024-02-01".to_string() }
+}
+
+#[test]
+fn can_format_numbers_for_a_locale() {
+    let mut bundle = MessageBundle::new("de-DE");
+    bundle
+        .add_resource("range = Muss zwischen { $min } und { $max } liegen, nicht { $value }\n    .min = Muss mindestens { $min } sein\n    .max = Darf höchstens { $max } sein")
+        .unwrap();
+    bundle.set_formatter(ParamFormatter::for_locale(bundle.locale()).unwrap());
+
+    let mut err = invalid_transfer().validate().unwrap_err();
+    err.localize(&bundle);
+    let errs = err.field_errors();
+    assert_eq!(errs["amount"][0].message.as_deref(), Some("Darf höchstens 1.000.000 sein"));
+    assert_eq!(errs["fee"][0].message.as_deref(), Some("Muss mindestens 0,5 sein"));
+
+    assert!(ParamFormatter::for_locale("xx").is_none());
+}
+
+#[test]
+fn can_format_params_with_a_function() {
+    let mut bundle = MessageBundle::new("de");
+    bundle.add_resource("too_late = Muss spätestens am { $latest } sein").unwrap();
+    bundle.set_formatter(ParamFormatter::new(|name, value| match (name, value.as_str()) {
+        ("latest", Some(date)) => {
+            let parts = date.split('-').collect::<Vec<_>>();
+            Some(format!("{}.{}.{}", parts[2], parts[1], parts[0]))
+        }
+        _ => None,
+    }));
+
+    let mut err = invalid_transfer().validate().unwrap_err();
+    err.localize(&bundle);
+    assert_eq!(
+        err.field_errors()["date"][0].message.as_deref(),
+        Some("Muss spätestens am 31.01.2024 sein")
+    );
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use validator::{Validate, ValidationError, ValidationErrorsKind};
+
+#[test]
+fn can_validate_elements_of_a_vec() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(inner(length(min = 1), email))]
+        mails: Vec<String>,
+    }
+
+    let s = TestStruct { mails: vec!["bob@bob.com".to_string(), "alice@alice.com".to_string()] };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        mails: vec!["bob@bob.com".to_string(), "".to_string(), "not a mail".to_string()],
+    };
+    let err = s.validate().unwrap_err();
+    assert!(err.errors_at("mails", 0).is_none());
+
+    let second = err.errors_at("mails", 1).unwrap().field_errors();
+    assert_eq!(second["__value__"].len(), 2);
+    assert_eq!(second["__value__"][0].code, "length");
+    assert_eq!(second["__value__"][1].code, "email");
+
+    let third = err.errors_at("mails", 2).unwrap().field_errors();
+    assert_eq!(third["__value__"].len(), 1);
+    assert_eq!(third["__value__"][0].code, "email");
+    assert_eq!(third["__value__"][0].params["value"], "not a mail");
+}
+
+#[test]
+fn can_validate_elements_of_an_optional_vec() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(inner(range(max = 10)))]
+        scores: Option<Vec<u8>>,
+    }
+
+    assert!(TestStruct { scores: None }.validate().is_ok());
+    assert!(TestStruct { scores: Some(vec![1, 10]) }.validate().is_ok());
+
+    let err = TestStruct { scores: Some(vec![1, 11]) }.validate().unwrap_err();
+    let errs = err.errors_at("scores", 1).unwrap().field_errors();
+    assert_eq!(errs["__value__"][0].code, "range");
+}
+
+#[test]
+fn can_validate_values_of_a_map() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(keys(length(max = 3)), inner(url))]
+        sites: HashMap<String, String>,
+    }
+
+    let mut sites = HashMap::new();
+    sites.insert("doc".to_string(), "https://docs.rs".to_string());
+    assert!(TestStruct { sites }.validate().is_ok());
+
+    let mut sites = HashMap::new();
+    sites.insert("doc".to_string(), "docs".to_string());
+    sites.insert("crates".to_string(), "crates".to_string());
+    let err = TestStruct { sites }.validate().unwrap_err();
+    match &err.errors()["sites"] {
+        ValidationErrorsKind::Map(sites) => {
+            let doc = sites["doc"].field_errors();
+            assert!(!doc.contains_key("__key__"));
+            assert_eq!(doc["__value__"][0].code, "url");
+
+            let crates = sites["crates"].field_errors();
+            assert_eq!(crates["__key__"][0].code, "length");
+            assert_eq!(crates["__value__"][0].code, "url");
+        }
+        _ => panic!("expected the errors of a map"),
+    }
+}
+
+#[test]
+fn inner_errors_are_kept_next_to_nested_errors() {
+    #[derive(Debug, Serialize, Validate)]
+    struct Child {
+        #[validate(length(min = 1))]
+        value: String,
+    }
+
+    fn not_reserved(child: &Child) -> Result<(), ValidationError> {
+        if child.value == "admin" {
+            return Err(ValidationError::new("reserved"));
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Validate)]
+    struct Parent {
+        #[validate(nested, inner(custom = "not_reserved"))]
+        children: Vec<Child>,
+    }
+
+    let s = Parent {
+        children: vec![Child { value: "admin".to_string() }, Child { value: "".to_string() }],
+    };
+    let err = s.validate().unwrap_err();
+
+    let first = err.errors_at("children", 0).unwrap().field_errors();
+    assert_eq!(first["__value__"][0].code, "reserved");
+    assert!(!first.contains_key("value"));
+
+    let second = err.errors_at("children", 1).unwrap().field_errors();
+    assert!(!second.contains_key("__value__"));
+    assert_eq!(second["value"][0].code, "length");
+}
+
+#[test]
+fn inner_errors_get_the_code_prefix() {
+    #[derive(Debug, Validate)]
+    #[validate(code_prefix = "list.")]
+    struct TestStruct {
+        #[validate(inner(length(max = 1)))]
+        values: Vec<String>,
+    }
+
+    let err = TestStruct { values: vec!["ab".to_string()] }.validate().unwrap_err();
+    let errs = err.errors_at("values", 0).unwrap().field_errors();
+    assert_eq!(errs["__value__"][0].code, "list.length");
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use validator::{to_json_schema, Validate};
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(email)]
+    mail: String,
+    #[validate(url)]
+    site: Option<String>,
+    #[validate(length(min = 2, max = 20), regex(pattern = "^[a-z]+$"), does_not_contain = "admin")]
+    username: String,
+    #[validate(regex(path = "USERNAME"))]
+    nickname: String,
+    #[validate(range(min = 18, max = 120))]
+    age: u8,
+    #[validate(range(exclusive_min = 0.0, step = 0.5))]
+    height: f64,
+    #[validate(percentage)]
+    progress: f32,
+    #[validate(length(equal = 3), inner(length(min = 1)))]
+    tags: Vec<String>,
+    #[validate(keys(length(max = 10)), inner(range(min = 0)))]
+    scores: HashMap<String, i64>,
+    #[validate(required)]
+    bio: Option<String>,
+    #[validate(custom = "validate_team")]
+    team: String,
+}
+
+lazy_static::lazy_static! {
+    static ref USERNAME: regex::Regex = regex::Regex::new("^[a-z]+$").unwrap();
+}
+
+fn validate_team(_: &str) -> Result<(), validator::ValidationError> {
+    Ok(())
+}
+
+#[test]
+fn can_export_a_json_schema() {
+    let schema = to_json_schema::<Signup>();
+    assert_eq!(
+        schema,
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "mail": {"type": "string", "format": "email"},
+                "site": {"type": "string", "format": "uri"},
+                "username": {
+                    "type": "string",
+                    "minLength": 2,
+                    "maxLength": 20,
+                    "pattern": "^[a-z]+$",
+                    "not": {"pattern": "admin"},
+                },
+                // The regex given by path isn't known
+                "nickname": {"type": "string"},
+                "age": {"type": "integer", "minimum": 18.0, "maximum": 120.0},
+                "height": {"type": "number", "exclusiveMinimum": 0.0, "multipleOf": 0.5},
+                "progress": {"type": "number", "minimum": 0, "maximum": 100},
+                "tags": {
+                    "type": "array",
+                    "minItems": 3,
+                    "maxItems": 3,
+                    "items": {"type": "string", "minLength": 1},
+                },
+                "scores": {
+                    "type": "object",
+                    "propertyNames": {"type": "string", "maxLength": 10},
+                    "additionalProperties": {"type": "integer", "minimum": 0.0},
+                },
+                "bio": {"type": "string"},
+                "team": {"type": "string"},
+            },
+            "required": ["mail", "username", "nickname", "age", "height", "progress", "tags", "scores", "bio", "team"],
+        })
+    );
+}
+
+#[test]
+fn patterns_are_combined() {
+    #[derive(Validate)]
+    struct Code {
+        #[validate(regex(pattern = "^[a-z.]+$"), contains = "a.b")]
+        code: String,
+    }
+
+    let schema = to_json_schema::<Code>();
+    assert_eq!(
+        schema["properties"]["code"],
+        json!({"type": "string", "pattern": "^[a-z.]+$", "allOf": [{"pattern": "a\\.b"}]})
+    );
+}
+
+#[test]
+fn can_enrich_the_schema_of_another_tool() {
+    // Like the schema `utoipa` generates for the struct
+    let mut schema = json!({
+        "type": "object",
+        "properties": {
+            "mail": {"type": "string", "description": "Where to contact the user"},
+            "age": {"type": "integer", "format": "int32", "minimum": 0},
+            "unknown": {"type": "string"},
+        },
+        "required": ["mail", "age"],
+    });
+    validator::enrich_json_schema::<Signup>(&mut schema);
+
+    assert_eq!(
+        schema,
+        json!({
+            "type": "object",
+            "properties": {
+                "mail": {"type": "string", "description": "Where to contact the user", "format": "email"},
+                "age": {"type": "integer", "format": "int32", "minimum": 18.0, "maximum": 120.0},
+                "unknown": {"type": "string"},
+            },
+            "required": ["mail", "age"],
+        })
+    );
+}
@@ -0,0 +1,26 @@
+use futures::executor::block_on;
+use futures::io::Cursor;
+use validator::{validate_length_async_reader, LengthUnit};
+
+#[test]
+fn can_validate_the_length_of_an_async_reader() {
+    let text = "日本語".repeat(10_000);
+
+    let valid = block_on(validate_length_async_reader(
+        Cursor::new(text.as_bytes()),
+        LengthUnit::Chars,
+        None,
+        Some(30_000),
+        None,
+    ));
+    assert!(valid.unwrap());
+
+    let valid = block_on(validate_length_async_reader(
+        Cursor::new(text.as_bytes()),
+        LengthUnit::Bytes,
+        None,
+        Some(30_000),
+        None,
+    ));
+    assert!(!valid.unwrap());
+}
@@ -0,0 +1,62 @@
+use validator::Validate;
+
+#[derive(Debug, Validate)]
+#[validate(mode = "fail_fast")]
+struct Signup {
+    #[validate(email)]
+    mail: String,
+    #[validate(range(min = 18))]
+    age: u8,
+    #[validate(length(min = 3), severity = "warning")]
+    nickname: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[derive(Debug, Validate)]
+#[validate(mode = "fail_fast")]
+struct Address {
+    #[validate(length(min = 1))]
+    street: String,
+    #[validate(length(min = 1))]
+    city: String,
+}
+
+fn invalid_signup() -> Signup {
+    Signup {
+        mail: "bob".to_string(),
+        age: 17,
+        nickname: "b".to_string(),
+        address: Address { street: String::new(), city: String::new() },
+    }
+}
+
+#[test]
+fn lenient_validation_collects_all_the_errors() {
+    assert_eq!(invalid_signup().validate().unwrap_err().errors().len(), 1);
+
+    let errors = invalid_signup().validate_lenient();
+    assert!(errors.field_has_code("mail", "email"));
+    assert!(errors.field_has_code("age", "range"));
+    assert!(errors.field_has_code("address.street", "length"));
+    assert!(errors.field_has_code("address.city", "length"));
+    // Only what `validate` rejects
+    assert!(errors.field_errors_at("nickname").is_none());
+}
+
+#[test]
+fn lenient_validation_of_a_valid_value_is_empty() {
+    let signup = Signup {
+        mail: "bob@example.com".to_string(),
+        age: 18,
+        nickname: "bobby".to_string(),
+        address: Address { street: "Main St".to_string(), city: "Paris".to_string() },
+    };
+    assert!(signup.validate_lenient().is_empty());
+}
+
+#[test]
+fn fail_fast_is_restored_after_the_lenient_validation() {
+    assert!(!invalid_signup().validate_lenient().is_empty());
+    assert_eq!(invalid_signup().validate().unwrap_err().errors().len(), 1);
+}
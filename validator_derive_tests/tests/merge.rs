@@ -0,0 +1,108 @@
+use validator::{Validate, ValidationError, ValidationErrors, ValidationResultExt};
+
+#[derive(Debug, Validate)]
+struct Body {
+    #[validate(email)]
+    mail: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 1))]
+    street: String,
+    #[validate(length(min = 1))]
+    city: String,
+}
+
+#[derive(Debug, Validate)]
+struct Query {
+    #[validate(range(min = 1))]
+    page: u32,
+    #[validate(email)]
+    mail: String,
+}
+
+fn invalid_body() -> Body {
+    Body {
+        mail: "bob".to_string(),
+        address: Address { street: String::new(), city: "Paris".to_string() },
+    }
+}
+
+#[test]
+fn merge_results_combines_the_errors_of_several_sources() {
+    let query = Query { page: 0, mail: "alice".to_string() };
+    let err =
+        ValidationErrors::merge_results(vec![invalid_body().validate(), query.validate(), Ok(())])
+            .unwrap_err();
+
+    assert_eq!(err.field_errors()["mail"].len(), 2);
+    assert!(err.field_has_code("page", "range"));
+    assert!(err.field_has_code("address.street", "length"));
+
+    assert!(ValidationErrors::merge_results(vec![Ok(()), Ok(())]).is_ok());
+}
+
+#[test]
+fn add_nested_merges_with_the_existing_errors() {
+    let mut errors = ValidationErrors::new();
+    errors.add_nested(
+        "address",
+        Address { street: String::new(), city: "Paris".to_string() }.validate().unwrap_err(),
+    );
+    errors.add_nested(
+        "address",
+        Address { street: "Main St".to_string(), city: String::new() }.validate().unwrap_err(),
+    );
+    errors.add_nested("other", ValidationErrors::new());
+
+    assert!(errors.field_has_code("address.street", "length"));
+    assert!(errors.field_has_code("address.city", "length"));
+    assert!(!errors.errors().contains_key("other"));
+}
+
+#[test]
+#[should_panic(expected = "Attempt to merge different kinds of ValidationErrorsKind instances")]
+fn add_nested_panics_on_field_errors() {
+    let mut errors = ValidationErrors::new();
+    errors.add("address", ValidationError::new("invalid"));
+    errors.add_nested("address", invalid_body().validate().unwrap_err());
+}
+
+#[test]
+fn can_compose_results() {
+    let query = Query { page: 1, mail: "alice@example.com".to_string() };
+    assert!(query.validate().and_merge(Ok(())).is_ok());
+
+    let err = query
+        .validate()
+        .and_validate(|| invalid_body().validate())
+        .and_nested("query", Query { page: 0, mail: "alice@example.com".to_string() }.validate())
+        .unwrap_err();
+    assert!(err.field_has_code("mail", "email"));
+    assert!(err.field_has_code("address.street", "length"));
+    assert!(err.field_has_code("query.page", "range"));
+}
+
+#[test]
+fn dedup_removes_the_errors_merged_twice() {
+    let query = Query { page: 0, mail: "alice".to_string() };
+    let mut err = ValidationErrors::merge_results(vec![
+        invalid_body().validate(),
+        query.validate(),
+        invalid_body().validate(),
+    ])
+    .unwrap_err();
+    assert_eq!(err.field_errors()["mail"].len(), 3);
+    assert_eq!(err.field_errors_at("address.street").unwrap().len(), 2);
+
+    err.dedup();
+    // The errors of different values have different params
+    assert_eq!(err.field_errors()["mail"].len(), 2);
+    assert_eq!(err.field_errors()["mail"][0].param_str("value"), Some("bob"));
+    assert_eq!(err.field_errors()["mail"][1].param_str("value"), Some("alice"));
+    assert_eq!(err.field_errors_at("address.street").unwrap().len(), 1);
+    assert_eq!(err.field_errors()["page"].len(), 1);
+}
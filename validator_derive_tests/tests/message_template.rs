@@ -0,0 +1,173 @@
+use std::borrow::Cow;
+
+use validator::{Validate, ValidationError};
+
+#[test]
+fn can_interpolate_params_in_message() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(range(
+            min = 1,
+            max = 10,
+            message = "{field} must be between {min} and {max}, got {value}"
+        ))]
+        age: u8,
+    }
+
+    let s = TestStruct { age: 11 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["age"][0].message, Some(Cow::from("age must be between 1.0 and 10.0, got 11")));
+}
+
+#[test]
+fn string_params_are_interpolated_without_quotes() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(length(min = 5, message = "`{value}` is shorter than {min} characters"))]
+        name: String,
+    }
+
+    let s = TestStruct { name: "bob".to_string() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["name"][0].message, Some(Cow::from("`bob` is shorter than 5 characters")));
+}
+
+#[test]
+fn can_interpolate_code_and_option_fields() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(email(code = "invalid_email", message = "{field}: {code}"))]
+        mail: Option<String>,
+    }
+
+    let s = TestStruct { mail: Some("bob".to_string()) };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["mail"][0].message, Some(Cow::from("mail: invalid_email")));
+}
+
+#[test]
+fn can_interpolate_params_of_custom_validators() {
+    fn not_reserved(value: &str) -> Result<(), ValidationError> {
+        let mut err = ValidationError::new("reserved");
+        err.add_param(Cow::from("reason"), &"it is reserved");
+        if value == "admin" {
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(custom(
+            function = "not_reserved",
+            message = "{value} can't be used: {reason}"
+        ))]
+        username: String,
+    }
+
+    let s = TestStruct { username: "admin".to_string() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["username"][0].message, Some(Cow::from("admin can't be used: it is reserved")));
+}
+
+#[test]
+fn can_interpolate_schema_messages() {
+    fn check(s: &TestStruct) -> Result<(), ValidationError> {
+        if s.value == "hey" {
+            return Err(ValidationError::new("meh"));
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Validate)]
+    #[validate(schema(function = "check", message = "{field} failed with {code}"))]
+    struct TestStruct {
+        value: String,
+    }
+
+    let s = TestStruct { value: "hey".to_string() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["__all__"][0].message, Some(Cow::from("__all__ failed with meh")));
+}
+
+#[test]
+fn unknown_placeholders_are_kept() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(length(max = 1, message = "{unknown} {max} {} {not a param} {max"))]
+        value: String,
+    }
+
+    let s = TestStruct { value: "hey".to_string() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["value"][0].message, Some(Cow::from("{unknown} 1 {} {not a param} {max")));
+}
+
+#[test]
+fn can_interpolate_message_of_error_built_by_hand() {
+    let mut err = ValidationError::new("length");
+    err.message = Some(Cow::from("{field} is too long, at most {max}"));
+    err.add_param(Cow::from("max"), &5);
+    err.interpolate_message("name");
+
+    assert_eq!(err.message, Some(Cow::from("name is too long, at most 5")));
+}
+
+fn length_message(err: &ValidationError) -> String {
+    let min = err.params["min"].as_u64().unwrap();
+    let value = err.params["value"].as_str().unwrap();
+    let missing = min - value.chars().count() as u64;
+    format!("{} more character{} needed", missing, if missing == 1 { " is" } else { "s are" })
+}
+
+#[test]
+fn can_compute_message_with_message_fn() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(length(min = 5, message_fn = "length_message"))]
+        name: String,
+        #[validate(email(code = "mail", message_fn = "|e: &ValidationError| format!(\"bad {}\", e.code)"))]
+        mail: Option<String>,
+    }
+
+    let s = TestStruct { name: "bob".to_string(), mail: Some("bob".to_string()) };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["name"][0].message, Some(Cow::from("2 more characters are needed")));
+    assert_eq!(errs["mail"][0].message, Some(Cow::from("bad mail")));
+
+    let s = TestStruct { name: "mark".to_string(), mail: None };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["name"][0].message, Some(Cow::from("1 more character is needed")));
+}
+
+#[test]
+fn message_fn_sees_the_prefixed_code() {
+    fn static_message(err: &ValidationError) -> &'static str {
+        if err.code == "user_range" {
+            "out of range"
+        } else {
+            "unexpected code"
+        }
+    }
+
+    #[derive(Debug, Validate)]
+    #[validate(code_prefix = "user_")]
+    struct TestStruct {
+        #[validate(range(min = 1, message_fn = "static_message"))]
+        age: u8,
+    }
+
+    let err = TestStruct { age: 0 }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["age"][0].message, Some(Cow::from("out of range")));
+}
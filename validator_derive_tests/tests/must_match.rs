@@ -71,3 +71,33 @@ fn can_specify_message_for_must_match() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+#[test]
+fn can_match_a_path_into_a_nested_struct() {
+    #[derive(Debug)]
+    struct Credentials {
+        password: String,
+    }
+
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(must_match = "credentials.password")]
+        password_confirmation: String,
+        credentials: Credentials,
+    }
+
+    let s = TestStruct {
+        password_confirmation: "hunter2".to_string(),
+        credentials: Credentials { password: "hunter2".to_string() },
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        password_confirmation: "hunter3".to_string(),
+        credentials: Credentials { password: "hunter2".to_string() },
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["password_confirmation"][0].code, "must_match");
+    assert_eq!(errs["password_confirmation"][0].params["other"], "hunter2");
+}
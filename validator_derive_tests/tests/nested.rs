@@ -287,6 +287,30 @@ fn test_can_validate_vector_fields() {
     }
 }
 
+#[test]
+fn test_can_query_vector_errors_by_index() {
+    let instance = ParentWithVectorOfChildren {
+        child: vec![
+            Child { value: "valid".to_string() },
+            Child { value: String::new() },
+            Child { value: "valid".to_string() },
+            Child { value: String::new() },
+        ],
+    };
+
+    let err = instance.validate().unwrap_err();
+    let indices: Vec<_> = err.list_errors("child").unwrap().keys().copied().collect();
+    assert_eq!(indices, vec![1, 3]);
+    assert!(err.errors_at("child", 0).is_none());
+    assert_eq!(err.errors_at("child", 1).unwrap().field_errors()["value"][0].code, "length");
+    assert!(err.list_errors("value").is_none());
+    assert!(err.errors_at("other", 1).is_none());
+
+    let display = err.to_string();
+    assert!(display.contains("child[1].value: "));
+    assert!(display.contains("child[3].value: "));
+}
+
 #[test]
 fn test_can_validate_slice_fields() {
     let child = vec![
@@ -433,9 +457,9 @@ fn test_can_validate_map_fields() {
     let errs = err.errors();
     assert_eq!(errs.len(), 1);
     assert!(errs.contains_key("child"));
-    if let ValidationErrorsKind::List(ref errs) = errs["child"] {
-        assert!(errs.contains_key(&0));
-        unwrap_map(&errs[&0], |errs| {
+    if let ValidationErrorsKind::Map(ref errs) = errs["child"] {
+        assert!(errs.contains_key("0"));
+        unwrap_map(&errs["0"], |errs| {
             assert_eq!(errs.len(), 1);
             assert!(errs.contains_key("value"));
             if let ValidationErrorsKind::Field(ref errs) = errs["value"] {
@@ -446,7 +470,7 @@ fn test_can_validate_map_fields() {
             }
         });
     } else {
-        panic!("Expected list validation errors");
+        panic!("Expected map validation errors");
     }
 }
 
@@ -461,9 +485,9 @@ fn test_can_validate_ref_map_fields() {
     let errs = err.errors();
     assert_eq!(errs.len(), 1);
     assert!(errs.contains_key("child"));
-    if let ValidationErrorsKind::List(ref errs) = errs["child"] {
-        assert!(errs.contains_key(&0));
-        unwrap_map(&errs[&0], |errs| {
+    if let ValidationErrorsKind::Map(ref errs) = errs["child"] {
+        assert!(errs.contains_key("0"));
+        unwrap_map(&errs["0"], |errs| {
             assert_eq!(errs.len(), 1);
             assert!(errs.contains_key("value"));
             if let ValidationErrorsKind::Field(ref errs) = errs["value"] {
@@ -474,7 +498,7 @@ fn test_can_validate_ref_map_fields() {
             }
         });
     } else {
-        panic!("Expected list validation errors");
+        panic!("Expected map validation errors");
     }
 }
 
@@ -490,9 +514,9 @@ fn test_can_validate_option_map_fields() {
     let errs = err.errors();
     assert_eq!(errs.len(), 1);
     assert!(errs.contains_key("child"));
-    if let ValidationErrorsKind::List(ref errs) = errs["child"] {
-        assert!(errs.contains_key(&0));
-        unwrap_map(&errs[&0], |errs| {
+    if let ValidationErrorsKind::Map(ref errs) = errs["child"] {
+        assert!(errs.contains_key("0"));
+        unwrap_map(&errs["0"], |errs| {
             assert_eq!(errs.len(), 1);
             assert!(errs.contains_key("value"));
             if let ValidationErrorsKind::Field(ref errs) = errs["value"] {
@@ -503,7 +527,7 @@ fn test_can_validate_option_map_fields() {
             }
         });
     } else {
-        panic!("Expected list validation errors");
+        panic!("Expected map validation errors");
     }
 }
 
@@ -613,7 +637,7 @@ fn test_field_validations_take_priority_over_nested_validations() {
 
 #[test]
 #[should_panic(expected = "Attempt to replace non-empty ValidationErrors entry")]
-#[allow(unused)]
+#[allow(unused, deprecated)]
 fn test_field_validation_errors_replaced_with_nested_validations_fails() {
     #[derive(Debug)]
     struct ParentWithOverridingStructValidations {
@@ -661,7 +685,7 @@ fn test_field_validation_errors_replaced_with_nested_validations_fails() {
 #[should_panic(
     expected = "Attempt to add field validation to a non-Field ValidationErrorsKind instance"
 )]
-#[allow(unused)]
+#[allow(unused, deprecated)]
 fn test_field_validations_evaluated_after_nested_validations_fails() {
     #[derive(Debug)]
     struct ParentWithStructValidationsFirst {
@@ -708,8 +732,8 @@ fn test_field_validations_evaluated_after_nested_validations_fails() {
 
 fn unwrap_map<F>(errors: &ValidationErrors, f: F)
 where
-    F: FnOnce(HashMap<&'static str, ValidationErrorsKind>),
+    F: FnOnce(HashMap<Cow<'static, str>, ValidationErrorsKind>),
 {
     let errors = errors.clone();
-    f(errors.errors().clone());
+    f(errors.errors().clone().into());
 }
@@ -0,0 +1,109 @@
+use std::collections::{BTreeMap, HashMap};
+
+use validator::{Validate, ValidationErrorsKind};
+
+#[derive(Debug, Validate)]
+struct Child {
+    #[validate(length(min = 1))]
+    value: String,
+}
+
+#[derive(Debug, Validate)]
+struct Parent {
+    #[validate(nested, keys(length(max = 5)))]
+    children: BTreeMap<String, Child>,
+}
+
+#[derive(Debug, Validate)]
+struct ParentWithOnlyKeys {
+    #[validate(keys(range(max = 10)))]
+    scores: Option<HashMap<u8, String>>,
+}
+
+fn child(value: &str) -> Child {
+    Child { value: value.to_string() }
+}
+
+#[test]
+fn can_validate_map_ok() {
+    let mut children = BTreeMap::new();
+    children.insert("bob".to_string(), child("hello"));
+    let p = Parent { children };
+
+    assert!(p.validate().is_ok());
+}
+
+#[test]
+fn map_errors_are_keyed_by_map_key() {
+    let mut children = BTreeMap::new();
+    children.insert("alice".to_string(), child("hello"));
+    children.insert("bob".to_string(), child(""));
+    let p = Parent { children };
+
+    let res = p.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.errors();
+    assert_eq!(errs.len(), 1);
+    if let ValidationErrorsKind::Map(ref errs) = errs["children"] {
+        assert_eq!(errs.len(), 1);
+        let entry = errs["bob"].field_errors();
+        assert_eq!(entry["value"][0].code, "length");
+    } else {
+        panic!("Expected map validation errors");
+    }
+    assert!(err.to_string().starts_with("children[bob].value: "));
+}
+
+#[test]
+fn key_errors_are_in_the_entry_of_the_key() {
+    let mut children = BTreeMap::new();
+    children.insert("too long key".to_string(), child(""));
+    let p = Parent { children };
+
+    let res = p.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.errors();
+    if let ValidationErrorsKind::Map(ref errs) = errs["children"] {
+        let entry = errs["too long key"].field_errors();
+        assert_eq!(entry.len(), 2);
+        assert_eq!(entry["__key__"][0].code, "length");
+        assert_eq!(entry["__key__"][0].params["value"], "too long key");
+        assert_eq!(entry["value"][0].code, "length");
+    } else {
+        panic!("Expected map validation errors");
+    }
+}
+
+#[test]
+fn can_validate_keys_without_nested() {
+    let mut scores = HashMap::new();
+    scores.insert(3, "a".to_string());
+    scores.insert(42, "b".to_string());
+    let p = ParentWithOnlyKeys { scores: Some(scores) };
+
+    let res = p.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.errors();
+    if let ValidationErrorsKind::Map(ref errs) = errs["scores"] {
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs["42"].field_errors()["__key__"][0].code, "range");
+    } else {
+        panic!("Expected map validation errors");
+    }
+
+    assert!(ParentWithOnlyKeys { scores: None }.validate().is_ok());
+}
+
+#[test]
+fn map_errors_serialize_as_object() {
+    let mut children = BTreeMap::new();
+    children.insert("bob".to_string(), child(""));
+    let p = Parent { children };
+
+    let err = p.validate().unwrap_err();
+    let json = serde_json::to_value(&err).unwrap();
+    assert_eq!(json["children"]["bob"]["value"][0]["code"], "length");
+}
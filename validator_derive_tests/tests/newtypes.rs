@@ -0,0 +1,49 @@
+use serde::Deserialize;
+use validator::types::{BoundedString, BoundedVec, NonEmptyString, Port};
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+struct Upstream {
+    host: NonEmptyString,
+    port: Port,
+    #[validate(length(max = 8))]
+    tag: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+struct Proxy {
+    name: BoundedString<1, 16>,
+    #[validate(nested)]
+    upstreams: BoundedVec<Upstream, 1, 4>,
+}
+
+#[test]
+fn wrappers_are_checked_when_deserialized() {
+    let proxy: Proxy = serde_json::from_str(
+        r#"{"name": "edge", "upstreams": [{"host": "a.internal", "port": 8080, "tag": "x"}]}"#,
+    )
+    .unwrap();
+    assert_eq!(&*proxy.name, "edge");
+    assert_eq!(&*proxy.upstreams[0].host, "a.internal");
+    assert_eq!(proxy.upstreams[0].port.get(), 8080);
+    assert!(proxy.validate().is_ok());
+
+    let err = serde_json::from_str::<Proxy>(r#"{"name": "edge", "upstreams": []}"#).unwrap_err();
+    assert!(err.to_string().contains("length"), "{}", err);
+    let err = serde_json::from_str::<Proxy>(
+        r#"{"name": "edge", "upstreams": [{"host": "", "port": 80, "tag": "x"}]}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("length"), "{}", err);
+}
+
+#[test]
+fn bounded_vec_validates_its_elements() {
+    let proxy: Proxy = serde_json::from_str(
+        r#"{"name": "edge", "upstreams": [{"host": "a.internal", "port": 8080, "tag": "too long tag"}]}"#,
+    )
+    .unwrap();
+    let errors = proxy.validate().unwrap_err();
+    // Like the errors of a `Vec` validated on its own rather than as a field
+    assert_eq!(errors.field_errors_at("upstreams.__all__[0].tag").unwrap()[0].code, "length");
+}
@@ -0,0 +1,158 @@
+use validator::{Normalize, Validate};
+
+#[derive(Debug, Clone, Normalize, Validate)]
+struct Signup {
+    #[normalize(trim, lowercase)]
+    #[validate(email)]
+    email: String,
+    #[normalize(trim)]
+    #[validate(length(min = 3))]
+    username: String,
+    #[normalize(trim)]
+    nickname: Option<String>,
+    #[normalize(lowercase)]
+    tags: Vec<String>,
+}
+
+fn signup() -> Signup {
+    Signup {
+        email: "  Bob@Example.COM ".to_string(),
+        username: " bob ".to_string(),
+        nickname: Some(" bobby".to_string()),
+        tags: vec!["Rust".to_string()],
+    }
+}
+
+#[test]
+fn can_normalize_fields() {
+    let mut s = signup();
+    s.normalize();
+
+    assert_eq!(s.email, "bob@example.com");
+    assert_eq!(s.username, "bob");
+    assert_eq!(s.nickname, Some("bobby".to_string()));
+    assert_eq!(s.tags, ["rust"]);
+}
+
+#[test]
+fn validates_the_normalized_values() {
+    let mut s = signup();
+    assert!(s.validify().is_ok());
+
+    let mut s = Signup { username: " b ".to_string(), ..signup() };
+    let err = s.validify().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["username"][0].code, "length");
+    assert_eq!(errs["username"][0].params["value"], "b");
+}
+
+#[test]
+fn can_normalize_a_copy() {
+    let s = signup();
+    let normalized = s.clone().normalized();
+
+    assert_eq!(s.username, " bob ");
+    assert_eq!(normalized.username, "bob");
+}
+
+#[test]
+fn can_normalize_nested_structs_and_nfc() {
+    #[derive(Debug, Normalize)]
+    struct Name(#[normalize(nfc, uppercase)] String);
+
+    #[derive(Debug, Normalize)]
+    struct Person {
+        #[normalize(nested)]
+        name: Name,
+        #[normalize(nested)]
+        aliases: Vec<Name>,
+    }
+
+    let mut p = Person {
+        name: Name("jose\u{301}".to_string()),
+        aliases: vec![Name("pepe".to_string())],
+    };
+    p.normalize();
+
+    assert_eq!(p.name.0, "JOS\u{c9}");
+    assert_eq!(p.aliases[0].0, "PEPE");
+}
+
+fn slugify(value: &mut String) {
+    *value = value.replace(' ', "-");
+}
+
+#[test]
+fn can_capitalize_truncate_and_use_custom_normalizations() {
+    #[derive(Debug, Normalize)]
+    struct Article {
+        #[normalize(trim, capitalize)]
+        title: String,
+        #[normalize(truncate(max = 10))]
+        summary: Option<String>,
+        #[normalize(lowercase, custom = "slugify")]
+        slug: String,
+        #[normalize(custom = "|v: &mut u32| *v = (*v).min(100)")]
+        priority: u32,
+    }
+
+    let a = Article {
+        title: " hello world".to_string(),
+        summary: Some("A very long summary".to_string()),
+        slug: "Hello World".to_string(),
+        priority: 250,
+    }
+    .normalized();
+
+    assert_eq!(a.title, "Hello world");
+    assert_eq!(a.summary, Some("A very lon".to_string()));
+    assert_eq!(a.slug, "hello-world");
+    assert_eq!(a.priority, 100);
+}
+
+#[test]
+fn validify_normalizes_and_validates_nested_structs() {
+    #[derive(Debug, Normalize, Validate)]
+    struct Address {
+        #[normalize(trim)]
+        #[validate(length(min = 1))]
+        city: String,
+    }
+
+    #[derive(Debug, Normalize, Validate)]
+    struct Customer {
+        #[normalize(nested)]
+        #[validate(nested)]
+        address: Address,
+    }
+
+    let mut c = Customer { address: Address { city: " Paris ".to_string() } };
+    assert!(c.validify().is_ok());
+    assert_eq!(c.address.city, "Paris");
+
+    let mut c = Customer { address: Address { city: "   ".to_string() } };
+    let err = c.validify().unwrap_err();
+    assert!(err.errors().contains_key("address"));
+}
+
+#[test]
+fn can_require_the_normalization_form_of_identifiers() {
+    fn nfkc(handle: &str) -> Result<(), validator::ValidationError> {
+        validator::check_nfkc(handle)
+    }
+
+    #[derive(Debug, Normalize, Validate)]
+    struct Account {
+        #[normalize(nfkc)]
+        #[validate(custom(function = "nfkc"))]
+        handle: String,
+    }
+
+    let mut a = Account { handle: "\u{fb01}\u{ff4c}e".to_string() };
+    let err = a.validate().unwrap_err();
+    assert_eq!(err.field_errors()["handle"][0].code, "nfkc");
+
+    a.validify().unwrap();
+    assert_eq!(a.handle, "file");
+    assert!(validator::validate_nfc(&a.handle));
+}
@@ -0,0 +1,104 @@
+use std::sync::{Arc, Mutex};
+
+use validator::{
+    remove_validation_observer, set_validation_observer, FieldPath, Validate, ValidationObserver,
+};
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(email)]
+    mail: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 1))]
+    city: String,
+}
+
+#[derive(Debug, Validate)]
+struct Comment {
+    #[validate(length(max = 5))]
+    text: String,
+}
+
+/// Records the outcomes as text, for the types of the test only as the global observer also sees
+/// the validations of the other tests
+#[derive(Clone)]
+struct Recorder {
+    types: &'static [&'static str],
+    outcomes: Arc<Mutex<Vec<String>>>,
+}
+
+impl Recorder {
+    fn new(types: &'static [&'static str]) -> Recorder {
+        Recorder { types, outcomes: Arc::default() }
+    }
+
+    fn outcomes(&self) -> Vec<String> {
+        self.outcomes.lock().unwrap().clone()
+    }
+
+    fn push(&self, type_name: &str, outcome: String) {
+        if self.types.iter().any(|name| type_name.ends_with(name)) {
+            self.outcomes.lock().unwrap().push(outcome);
+        }
+    }
+}
+
+impl ValidationObserver for Recorder {
+    fn on_success(&self, type_name: &'static str) {
+        self.push(type_name, format!("{} valid", type_name));
+    }
+
+    fn on_failure(&self, type_name: &'static str, field: &FieldPath<'_>, code: &str) {
+        self.push(type_name, format!("{} {} {}", type_name, field, code));
+    }
+}
+
+fn signup(mail: &str, city: &str) -> Signup {
+    Signup { mail: mail.to_string(), address: Address { city: city.to_string() } }
+}
+
+#[test]
+fn the_global_observer_sees_every_validation() {
+    let recorder = Recorder::new(&["::Signup", "::Address"]);
+    set_validation_observer(recorder.clone());
+
+    assert!(signup("bob@example.com", "Paris").validate().is_ok());
+    assert!(signup("bob", "").validate().is_err());
+    assert!(signup("bob", "Paris").validate_fail_fast().is_err());
+    // Only the outcome of the value validated is reported, not of the structs nested in it
+    assert!(Address { city: String::new() }.validate().is_err());
+
+    remove_validation_observer();
+    assert!(signup("bob", "").validate().is_err());
+
+    assert_eq!(
+        recorder.outcomes(),
+        vec![
+            "observer::Signup valid",
+            "observer::Signup mail email",
+            "observer::Signup address.city length",
+            "observer::Signup mail email",
+            "observer::Address city length",
+        ]
+    );
+}
+
+#[test]
+fn can_observe_a_single_validation() {
+    let recorder = Recorder::new(&["::Comment"]);
+    let comment = Comment { text: "Too long".to_string() };
+    assert!(comment.validate_observed(&recorder).is_err());
+    assert!(comment.validate().is_err());
+    let comment = Comment { text: "Ok".to_string() };
+    assert!(comment.validate_observed(&recorder).is_ok());
+
+    assert_eq!(
+        recorder.outcomes(),
+        vec!["observer::Comment text length", "observer::Comment valid"]
+    );
+}
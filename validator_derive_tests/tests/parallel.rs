@@ -0,0 +1,78 @@
+use validator::{validate_all_par, Validate, ValidationConfig, ValidationErrors};
+
+#[derive(Debug, Validate)]
+struct Row {
+    #[validate(email)]
+    mail: String,
+    #[validate(range(min = 18))]
+    age: u8,
+}
+
+#[derive(Debug, Validate)]
+struct Import {
+    #[validate(nested, parallel)]
+    rows: Vec<Row>,
+}
+
+#[derive(Debug, Validate)]
+struct SequentialImport {
+    #[validate(nested)]
+    rows: Vec<Row>,
+}
+
+#[derive(Debug, Validate)]
+struct OptionalImport {
+    #[validate(nested, parallel)]
+    rows: Option<Vec<Row>>,
+}
+
+/// Every seventh row has an invalid age and every eleventh an invalid mail
+fn rows(count: usize) -> Vec<Row> {
+    (0..count)
+        .map(|i| Row {
+            mail: if i % 11 == 0 { "bob".to_string() } else { format!("user{}@example.com", i) },
+            age: if i % 7 == 0 { 12 } else { 30 },
+        })
+        .collect()
+}
+
+#[test]
+fn validates_all_the_values_in_their_order() {
+    let rows = rows(1000);
+    let results = validate_all_par(&rows);
+    assert_eq!(results.len(), rows.len());
+    for (row, result) in rows.iter().zip(&results) {
+        assert_eq!(result, &row.validate());
+    }
+    assert!(validate_all_par::<Row>(&[]).is_empty());
+}
+
+#[test]
+fn nested_lists_have_the_errors_of_the_sequential_validation() {
+    let import = Import { rows: rows(1000) };
+    let errors = import.validate().unwrap_err();
+    let sequential = SequentialImport { rows: import.rows }.validate().unwrap_err();
+    assert_eq!(errors, sequential);
+    assert_eq!(errors.list_errors("rows").unwrap().len(), 1000 / 7 + 1000 / 11 - 1000 / 77 + 1);
+
+    assert!(Import { rows: rows(1000).into_iter().filter(|row| row.validate().is_ok()).collect() }
+        .validate()
+        .is_ok());
+}
+
+#[test]
+fn optional_nested_lists_can_be_validated_in_parallel() {
+    assert!(OptionalImport { rows: None }.validate().is_ok());
+    let errors = OptionalImport { rows: Some(rows(200)) }.validate().unwrap_err();
+    assert!(errors.list_errors("rows").unwrap().contains_key(&0));
+}
+
+#[test]
+fn limits_of_a_config_are_kept() {
+    let import = Import { rows: rows(1000) };
+    let config = ValidationConfig::new().max_errors(3);
+    let errors: ValidationErrors = import.validate_with_config(&config).unwrap_err();
+    let sequential = SequentialImport { rows: import.rows }.validate_with_config(&config);
+    assert_eq!(errors.iter().count(), 3);
+    assert_eq!(Err(errors), sequential);
+}
@@ -0,0 +1,68 @@
+use std::borrow::Cow;
+
+use validator::{ParamValue, Validate, ValidationError};
+
+#[derive(Debug, Validate)]
+struct Item {
+    #[validate(range(min = 1, max = 10))]
+    quantity: i32,
+    #[validate(range(min = 0.5))]
+    weight: f64,
+    #[validate(contains = "@")]
+    handle: String,
+}
+
+#[test]
+fn can_read_the_params_as_typed_values() {
+    let err = Item { quantity: 0, weight: 0.1, handle: "bob".to_string() }.validate().unwrap_err();
+    let errs = err.field_errors();
+
+    let quantity = &errs["quantity"][0];
+    assert_eq!(quantity.param_i64("min"), Some(1));
+    assert_eq!(quantity.param_i64("max"), Some(10));
+    assert_eq!(quantity.param_f64("min"), Some(1.0));
+    assert_eq!(quantity.param("min"), Some(ParamValue::Float(1.0)));
+    assert_eq!(quantity.param_str("min"), None);
+    assert_eq!(quantity.param_i64("missing"), None);
+
+    let weight = &errs["weight"][0];
+    assert_eq!(weight.param_f64("min"), Some(0.5));
+    assert_eq!(weight.param_i64("min"), None);
+    assert_eq!(weight.param("min"), Some(ParamValue::Float(0.5)));
+
+    let handle = &errs["handle"][0];
+    assert_eq!(handle.param_str("needle"), Some("@"));
+    assert_eq!(handle.param("needle"), Some(ParamValue::Str(Cow::from("@"))));
+}
+
+#[test]
+fn can_add_typed_params() {
+    let mut err = ValidationError::new("choice");
+    err.add_param(
+        Cow::from("choices"),
+        &ParamValue::List(vec![ParamValue::Str(Cow::from("a")), ParamValue::Int(2)]),
+    );
+    err.add_param(Cow::from("strict"), &ParamValue::Bool(true));
+    err.add_param(Cow::from("nothing"), &None::<i32>);
+
+    assert_eq!(
+        err.param("choices"),
+        Some(ParamValue::List(vec![ParamValue::Str(Cow::from("a")), ParamValue::Int(2)]))
+    );
+    assert_eq!(err.param_bool("strict"), Some(true));
+    assert_eq!(err.param("nothing"), None);
+}
+
+#[test]
+fn static_params_are_borrowed() {
+    let err = Item { quantity: 1, weight: 1.0, handle: "bob".to_string() }.validate().unwrap_err();
+    let handle = &err.field_errors()["handle"][0];
+    assert!(matches!(handle.params["needle"], ParamValue::Str(Cow::Borrowed("@"))));
+    assert_eq!(handle.params["value"], "bob");
+
+    let mut err = ValidationError::new("choice");
+    err.add_param_value(Cow::from("choice"), "a");
+    err.add_param_value(Cow::from("max"), 3u8);
+    assert!(matches!(err.params["choice"], ParamValue::Str(Cow::Borrowed("a"))));
+    assert_eq!(err.params["max"], ParamValue::Int(3));
+}
@@ -0,0 +1,99 @@
+use validator::Validate;
+
+// Loose floating point comparison using EPSILON error bound
+macro_rules! assert_float {
+    ($e1:expr, $e2:expr) => {
+        assert!(($e2 - $e1).abs() < f64::EPSILON);
+    };
+}
+
+#[test]
+fn can_validate_percentage_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(percentage)]
+        val: f64,
+        #[validate(percentage)]
+        int_val: u8,
+        #[validate(percentage)]
+        opt_val: Option<f32>,
+    }
+
+    let s = TestStruct { val: 100.0, int_val: 0, opt_val: Some(42.5) };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn value_out_of_percentage_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(percentage)]
+        val: f64,
+    }
+
+    let s = TestStruct { val: 100.5 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "percentage");
+    assert_eq!(errs["val"][0].params["value"], 100.5);
+    assert_float!(errs["val"][0].params["min"].as_f64().unwrap(), 0.0);
+    assert_float!(errs["val"][0].params["max"].as_f64().unwrap(), 100.0);
+    assert_eq!(errs["val"][0].params["exclusive"], false);
+}
+
+#[test]
+fn can_validate_exclusive_percentage() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(percentage(exclusive = true))]
+        val: u32,
+    }
+
+    assert!(TestStruct { val: 50 }.validate().is_ok());
+
+    let res = TestStruct { val: 100 }.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "percentage");
+    assert_eq!(errs["val"][0].params["exclusive"], true);
+}
+
+#[test]
+fn can_specify_code_for_percentage() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(percentage(code = "oops"))]
+        val: i32,
+    }
+    let s = TestStruct { val: -1 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "oops");
+}
+
+#[test]
+fn can_specify_message_for_percentage() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(percentage(message = "oops"))]
+        val: i32,
+    }
+    let s = TestStruct { val: 101 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
+}
@@ -0,0 +1,66 @@
+use serde_json::json;
+use validator::Validate;
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(email(message = "Not an email"))]
+    mail: String,
+    #[validate(range(min = 18))]
+    age: u8,
+    #[validate(nested)]
+    addresses: Vec<Address>,
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 1), contains = " ")]
+    street: String,
+}
+
+#[test]
+fn serializes_to_problem_json() {
+    let signup = Signup {
+        mail: "bob".to_string(),
+        age: 17,
+        addresses: vec![Address { street: String::new() }],
+    };
+    let problem = signup.validate().unwrap_err().to_problem_details();
+
+    assert_eq!(
+        serde_json::to_value(&problem).unwrap(),
+        json!({
+            "type": "about:blank",
+            "title": "Validation failed",
+            "status": 422,
+            "errors": [
+                {"pointer": "/addresses/0/street", "code": "length"},
+                {"pointer": "/addresses/0/street", "code": "contains"},
+                {"pointer": "/age", "code": "range"},
+                {"pointer": "/mail", "code": "email", "detail": "Not an email"},
+            ],
+        })
+    );
+}
+
+#[test]
+fn can_change_the_problem_members() {
+    let signup = Signup { mail: "bob".to_string(), age: 18, addresses: vec![] };
+    let mut problem = signup.validate().unwrap_err().to_problem_details();
+    problem.type_ = "https://example.com/probs/invalid".to_string();
+    problem.status = 400;
+    problem.detail = Some("The signup is invalid".to_string());
+    problem.instance = Some("/signups".to_string());
+
+    assert_eq!(
+        serde_json::to_value(&problem).unwrap(),
+        json!({
+            "type": "https://example.com/probs/invalid",
+            "title": "Validation failed",
+            "status": 400,
+            "detail": "The signup is invalid",
+            "instance": "/signups",
+            "errors": [{"pointer": "/mail", "code": "email", "detail": "Not an email"}],
+        })
+    );
+    assert_eq!(validator::PROBLEM_JSON_CONTENT_TYPE, "application/problem+json");
+}
@@ -0,0 +1,74 @@
+use validator::{ProtoRules, Validate};
+
+fn pair(path: &str, attribute: &str) -> (String, String) {
+    (path.to_string(), attribute.to_string())
+}
+
+#[test]
+fn can_build_the_attributes() {
+    let mut rules = ProtoRules::new();
+    rules
+        .field(".shop.Order", "email", "email")
+        .field(".shop.Order", "items", "length(min = 1)")
+        .field(".shop.Order", "items", "nested")
+        .message(".shop.Item");
+
+    assert_eq!(
+        rules.type_attributes(),
+        vec![
+            pair(".shop.Item", "#[derive(::validator::Validate)]"),
+            pair(".shop.Order", "#[derive(::validator::Validate)]"),
+        ]
+    );
+    assert_eq!(
+        rules.field_attributes(),
+        vec![
+            pair(".shop.Order.email", "#[validate(email)]"),
+            pair(".shop.Order.items", "#[validate(length(min = 1), nested)]"),
+        ]
+    );
+}
+
+#[test]
+fn can_read_the_rules_from_json() {
+    let rules = ProtoRules::from_json(
+        r#"{
+            ".shop.Order": { "email": ["email"], "items": ["length(min = 1)", "nested"] },
+            ".shop.Item": {}
+        }"#,
+    )
+    .unwrap();
+    let mut expected = ProtoRules::new();
+    expected
+        .field(".shop.Order", "email", "email")
+        .field(".shop.Order", "items", "length(min = 1)")
+        .field(".shop.Order", "items", "nested")
+        .message(".shop.Item");
+    assert_eq!(rules, expected);
+
+    assert!(ProtoRules::from_json(r#"{".shop.Order": {"email": "email"}}"#).is_err());
+}
+
+// What prost generates for messages with such attributes
+#[derive(Clone, PartialEq, Validate)]
+struct Order {
+    #[validate(email)]
+    email: String,
+    #[validate(nested)]
+    items: Vec<Item>,
+}
+
+#[derive(Clone, PartialEq, Validate)]
+struct Item {
+    #[validate(length(equal = 8))]
+    sku: String,
+}
+
+#[test]
+fn the_attributes_validate_the_messages() {
+    let order =
+        Order { email: "a@b.com".to_string(), items: vec![Item { sku: "1234".to_string() }] };
+    let errors = order.validate().unwrap_err();
+    assert!(errors.errors().contains_key("items"));
+    assert!(!errors.errors().contains_key("email"));
+}
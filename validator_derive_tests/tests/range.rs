@@ -6,7 +6,7 @@ const MIN_CONST: usize = 0;
 // Loose floating point comparison using EPSILON error bound
 macro_rules! assert_float {
     ($e1:expr, $e2:expr) => {
-        assert!(($e2 - $e1).abs() < std::f64::EPSILON);
+        assert!(($e2 - $e1).abs() < f64::EPSILON);
     };
 }
 
@@ -178,6 +178,73 @@ fn value_out_of_range_fails_validation_with_crate_path() {
     assert_eq!(errs["val"][0].code, "range");
 }
 
+#[test]
+fn can_validate_range_step_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(range(min = 0, max = 100, step = 5))]
+        val: u32,
+        #[validate(range(min = 0.99, step = 0.5))]
+        price: f64,
+        #[validate(range(step = "MAX_CONST"))]
+        only_step: i64,
+    }
+
+    let s = TestStruct { val: 35, price: 19.99, only_step: -20 };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn value_off_step_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(range(min = 0, max = 100, step = 5))]
+        val: u32,
+    }
+
+    let s = TestStruct { val: 36 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "range");
+    assert_eq!(errs["val"][0].params["value"], 36);
+    assert_float!(errs["val"][0].params["step"].as_f64().unwrap(), 5.0);
+}
+
+#[test]
+fn range_step_starts_at_exclusive_min() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(range(exclusive_min = 1, step = 2))]
+        val: u32,
+    }
+
+    assert!(TestStruct { val: 3 }.validate().is_ok());
+    assert!(TestStruct { val: 4 }.validate().is_err());
+}
+
+#[test]
+fn range_step_is_exact_for_large_integers() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(range(step = 5))]
+        val: u64,
+        #[validate(range(step = 2))]
+        opt: Option<i64>,
+    }
+
+    assert!(TestStruct { val: 5_000_000_000, opt: Some(i64::MAX - 7) }.validate().is_ok());
+    let errs = TestStruct { val: 5_000_000_003, opt: Some(i64::MAX - 8) }.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs["val"][0].code, "range");
+    assert_eq!(errs["opt"][0].code, "range");
+    assert!(TestStruct { val: u64::MAX - 1, opt: None }.validate().is_err());
+}
+
 #[test]
 fn can_specify_code_for_range() {
     #[derive(Debug, Validate)]
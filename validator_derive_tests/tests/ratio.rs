@@ -0,0 +1,79 @@
+use validator::Validate;
+
+// Loose floating point comparison using EPSILON error bound
+macro_rules! assert_float {
+    ($e1:expr, $e2:expr) => {
+        assert!(($e2 - $e1).abs() < f64::EPSILON);
+    };
+}
+
+#[test]
+fn can_validate_ratio_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(ratio)]
+        val: f64,
+        #[validate(ratio)]
+        opt_val: Option<f32>,
+    }
+
+    let s = TestStruct { val: 1.0, opt_val: Some(0.25) };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn value_out_of_ratio_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(ratio)]
+        val: f64,
+    }
+
+    let s = TestStruct { val: 1.5 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "ratio");
+    assert_eq!(errs["val"][0].params["value"], 1.5);
+    assert_float!(errs["val"][0].params["min"].as_f64().unwrap(), 0.0);
+    assert_float!(errs["val"][0].params["max"].as_f64().unwrap(), 1.0);
+}
+
+#[test]
+fn can_validate_exclusive_ratio() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(ratio(exclusive = true))]
+        val: f64,
+    }
+
+    assert!(TestStruct { val: 0.5 }.validate().is_ok());
+
+    let res = TestStruct { val: 0.0 }.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "ratio");
+    assert_eq!(errs["val"][0].params["exclusive"], true);
+}
+
+#[test]
+fn can_specify_code_for_ratio() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(ratio(code = "oops"))]
+        val: f64,
+    }
+    let s = TestStruct { val: -0.1 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "oops");
+}
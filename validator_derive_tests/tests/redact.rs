@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use validator::Validate;
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(length(min = 3))]
+    username: String,
+    #[validate(length(min = 8), redact)]
+    password: String,
+    #[validate(must_match(other = "password"), redact)]
+    password_confirmation: String,
+    #[validate(redact, inner(length(equal = 4)))]
+    pins: Vec<String>,
+    #[validate(redact, keys(length(min = 1)))]
+    secrets: HashMap<String, String>,
+}
+
+fn signup() -> Signup {
+    let mut secrets = HashMap::new();
+    secrets.insert(String::new(), "hunter2".to_string());
+    Signup {
+        username: "b".to_string(),
+        password: "hunter2".to_string(),
+        password_confirmation: "hunter3".to_string(),
+        pins: vec!["12345".to_string()],
+        secrets,
+    }
+}
+
+#[test]
+fn values_are_captured_by_default() {
+    let errors = signup().validate().unwrap_err();
+    assert_eq!(errors.field_errors()["username"][0].params["value"], "b");
+}
+
+#[test]
+fn redacted_fields_dont_capture_their_values() {
+    let errors = signup().validate().unwrap_err();
+    let password = &errors.field_errors()["password"][0];
+    assert_eq!(password.code, "length");
+    assert_eq!(password.params["min"], 8);
+    assert!(!password.params.contains_key("value"));
+
+    let confirmation = &errors.field_errors()["password_confirmation"][0];
+    assert_eq!(confirmation.code, "must_match");
+    assert!(!confirmation.params.contains_key("value"));
+    assert!(!confirmation.params.contains_key("other"));
+
+    assert!(!errors.to_string().contains("hunter"));
+}
+
+#[test]
+fn redacted_collections_dont_capture_their_elements() {
+    let errors = signup().validate().unwrap_err();
+    let pin = &errors.field_errors_at("pins[0].__value__").unwrap()[0];
+    assert_eq!(pin.code, "length");
+    assert!(!pin.params.contains_key("value"));
+
+    assert!(!serde_json::to_string(&errors).unwrap().contains("hunter"));
+    assert!(!serde_json::to_string(&errors).unwrap().contains("12345"));
+}
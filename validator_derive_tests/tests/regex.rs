@@ -71,3 +71,69 @@ fn can_specify_message_for_regex() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+#[test]
+fn can_validate_inline_regex_pattern() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(regex(pattern = r"^[a-z]+$"))]
+        val: String,
+        #[validate(regex(pattern = r"^\d{4}$", code = "year"))]
+        year: Option<String>,
+    }
+
+    assert!(TestStruct { val: "abc".to_string(), year: Some("2022".to_string()) }.validate().is_ok());
+    assert!(TestStruct { val: "abc".to_string(), year: None }.validate().is_ok());
+
+    let s = TestStruct { val: "Abc".to_string(), year: Some("22".to_string()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "regex");
+    assert_eq!(errs["val"][0].params["value"], "Abc");
+    assert_eq!(errs["year"][0].code, "year");
+}
+
+mod regexes {
+    use std::sync::OnceLock;
+
+    use regex::Regex;
+
+    pub static USERNAME: OnceLock<Regex> = OnceLock::new();
+
+    pub fn username() -> &'static Regex {
+        USERNAME.get_or_init(|| Regex::new(r"^[a-z_]{3,}$").unwrap())
+    }
+
+    pub fn username_lock() -> &'static OnceLock<Regex> {
+        username();
+        &USERNAME
+    }
+}
+
+#[test]
+fn can_validate_regex_returned_by_function() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(regex(path = "crate::regexes::username()"))]
+        name: String,
+        #[validate(regex = "crate::regexes::username_lock()")]
+        alias: String,
+        #[validate(regex(path = "crate::regexes::USERNAME"))]
+        login: String,
+    }
+
+    let s = TestStruct { name: "bob".to_string(), alias: "bob".to_string(), login: "bob".to_string() };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { name: "B".to_string(), alias: "B".to_string(), login: "B".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs["name"][0].code, "regex");
+    assert_eq!(errs["alias"][0].code, "regex");
+    assert_eq!(errs["login"][0].code, "regex");
+}
@@ -0,0 +1,76 @@
+use std::borrow::Cow;
+
+use validator::{register_validator, Validate, ValidationError};
+
+fn validate_iban(value: &str) -> Result<(), ValidationError> {
+    if value.starts_with("FR") {
+        Ok(())
+    } else {
+        Err(ValidationError::new("iban"))
+    }
+}
+
+fn register() {
+    register_validator!("iban_custom", validate_iban);
+    register_validator!("not_bob", |value: &str| {
+        if value == "bob" {
+            return Err(ValidationError::new("bob"));
+        }
+        Ok(())
+    });
+}
+
+#[derive(Debug, Validate)]
+struct Account {
+    #[validate(rule = "iban_custom")]
+    iban: String,
+    #[validate(rule(name = "iban_custom", code = "backup_iban", message = "Invalid backup"))]
+    backup: Option<String>,
+    #[validate(rule = "not_bob", length(min = 2))]
+    owner: Cow<'static, str>,
+}
+
+#[test]
+fn can_validate_with_registered_rule() {
+    register();
+    let account = Account {
+        iban: "FR76".to_string(),
+        backup: Some("FR12".to_string()),
+        owner: Cow::from("alice"),
+    };
+    assert!(account.validate().is_ok());
+
+    let account = Account { iban: "FR76".to_string(), backup: None, owner: Cow::from("alice") };
+    assert!(account.validate().is_ok());
+}
+
+#[test]
+fn registered_rule_errors_keep_their_code() {
+    register();
+    let account = Account {
+        iban: "DE89".to_string(),
+        backup: Some("DE12".to_string()),
+        owner: Cow::from("bob"),
+    };
+    let res = account.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["iban"][0].code, "iban");
+    assert_eq!(errs["iban"][0].params["value"], "DE89");
+    assert_eq!(errs["backup"][0].code, "backup_iban");
+    assert_eq!(errs["backup"][0].message, Some(Cow::from("Invalid backup")));
+    assert_eq!(errs["owner"][0].code, "bob");
+}
+
+#[test]
+#[should_panic(expected = "No validator is registered as `never_registered`")]
+fn unregistered_rule_panics() {
+    #[derive(Debug, Validate)]
+    struct Test {
+        #[validate(rule = "never_registered")]
+        value: String,
+    }
+
+    let _ = Test { value: String::new() }.validate();
+}
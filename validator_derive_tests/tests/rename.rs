@@ -0,0 +1,98 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[test]
+fn errors_use_serde_rename_all() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TestStruct {
+        #[validate(length(min = 1))]
+        first_name: String,
+        #[validate(range(min = 18))]
+        age_in_years: u8,
+    }
+
+    let s: TestStruct = serde_json::from_str(r#"{"firstName": "", "ageInYears": 12}"#).unwrap();
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("firstName"));
+    assert!(errs.contains_key("ageInYears"));
+}
+
+#[test]
+fn field_rename_wins_over_rename_all() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "SCREAMING-KEBAB-CASE")]
+    struct TestStruct {
+        #[validate(length(min = 1))]
+        first_name: String,
+        #[validate(length(min = 1))]
+        #[serde(rename = "surname")]
+        last_name: String,
+    }
+
+    let s = TestStruct { first_name: String::new(), last_name: String::new() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("FIRST-NAME"));
+    assert!(errs.contains_key("surname"));
+}
+
+#[test]
+fn errors_use_deserialize_name_of_serde_rename() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all(serialize = "PascalCase", deserialize = "kebab-case"))]
+    struct TestStruct {
+        #[validate(length(min = 1))]
+        first_name: String,
+        #[validate(length(min = 1))]
+        #[serde(rename(serialize = "Last", deserialize = "last"))]
+        last_name: String,
+    }
+
+    let s = TestStruct { first_name: String::new(), last_name: String::new() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("first-name"));
+    assert!(errs.contains_key("last"));
+}
+
+#[test]
+fn validate_rename_wins_over_serde() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TestStruct {
+        #[validate(rename = "user.name", length(min = 1))]
+        #[serde(rename = "name")]
+        user_name: String,
+        #[validate(rename = "mail")]
+        #[validate(email)]
+        email_address: String,
+    }
+
+    let s = TestStruct { user_name: String::new(), email_address: "bob".to_string() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("user.name"));
+    assert!(errs.contains_key("mail"));
+}
+
+#[test]
+fn rename_is_used_for_nested_errors() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct Child {
+        #[validate(length(min = 1))]
+        value: String,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct TestStruct {
+        #[validate]
+        child_value: Child,
+    }
+
+    let s = TestStruct { child_value: Child { value: String::new() } };
+    let err = s.validate().unwrap_err();
+    assert!(err.errors().contains_key("ChildValue"));
+}
@@ -1,5 +1,5 @@
 use serde::Serialize;
-use validator::Validate;
+use validator::{Validate, ValidationErrors, ValidationErrorsKind};
 
 #[derive(Serialize)]
 struct ObjectRef {
@@ -119,4 +119,115 @@ fn can_validate_custom_impl_for_required() {
 
     assert!(something.validate().is_ok());
     assert!(nothing.validate().is_err());
-}
\ No newline at end of file
+}
+#[test]
+fn required_runs_the_other_validators_on_the_value() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(required, length(min = 3))]
+        val: Option<String>,
+    }
+
+    let missing = TestStruct { val: None };
+    let err = missing.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "required");
+
+    let too_short = TestStruct { val: Some("ab".to_string()) };
+    let err = too_short.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "length");
+
+    assert!(TestStruct { val: Some("abc".to_string()) }.validate().is_ok());
+}
+
+#[test]
+fn required_nested_reports_the_errors_of_the_value() {
+    let s = RequiredNested { val: Some(CheckedObjectRef { id: 0, name: String::new() }) };
+    let res = s.validate();
+    assert!(ValidationErrors::has_error(&res, "val"));
+    let err = res.unwrap_err();
+    let errs = err.errors();
+    match errs["val"] {
+        ValidationErrorsKind::Struct(ref nested) => {
+            assert_eq!(nested.field_errors()["id"][0].code, "range");
+            assert_eq!(nested.field_errors()["name"][0].code, "length");
+        }
+        _ => panic!("Expected struct validation errors"),
+    }
+}
+
+#[test]
+fn can_require_only_in_some_flows() {
+    #[derive(Validate)]
+    struct UpdateObject {
+        publish: bool,
+        #[validate(validate_if = "self.publish", required_nested)]
+        val: Option<CheckedObjectRef>,
+    }
+
+    assert!(UpdateObject { publish: false, val: None }.validate().is_ok());
+
+    let err = UpdateObject { publish: true, val: None }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "required");
+
+    let s = UpdateObject {
+        publish: true,
+        val: Some(CheckedObjectRef { id: 1, name: String::from("Reference") }),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[derive(Validate)]
+struct Patch {
+    #[validate(required, length(min = 1))]
+    name: Option<Option<String>>,
+    #[validate(required_nested)]
+    val: Option<Option<CheckedObjectRef>>,
+}
+
+#[test]
+fn absent_double_option_is_valid() {
+    let patch = Patch { name: None, val: None };
+    assert!(patch.validate().is_ok());
+
+    let patch = Patch {
+        name: Some(Some("Bob".to_string())),
+        val: Some(Some(CheckedObjectRef { id: 1, name: "Bob".to_string() })),
+    };
+    assert!(patch.validate().is_ok());
+}
+
+#[test]
+fn null_double_option_fails_required() {
+    let patch = Patch { name: Some(None), val: Some(None) };
+    let res = patch.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["name"].len(), 1);
+    assert_eq!(errs["name"][0].code, "required");
+    assert_eq!(errs["name"][0].params["value"], serde_json::Value::Null);
+    assert_eq!(errs["val"][0].code, "required");
+}
+
+#[test]
+fn present_double_option_runs_the_other_validations() {
+    let patch = Patch {
+        name: Some(Some(String::new())),
+        val: Some(Some(CheckedObjectRef { id: 0, name: "Bob".to_string() })),
+    };
+    let res = patch.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.field_errors()["name"][0].code, "length");
+    match &err.errors()["val"] {
+        ValidationErrorsKind::Struct(errs) => {
+            assert_eq!(errs.field_errors()["id"][0].code, "range");
+        }
+        kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::json;
+use validator::{Validate, ValidationError, ValidationRules};
+
+const MAX_NAME: u64 = 20;
+
+fn check_team(_: &Signup) -> Result<(), ValidationError> {
+    Ok(())
+}
+
+#[derive(Serialize, Validate)]
+#[validate(schema(function = "check_team", code = "team"))]
+struct Signup {
+    #[validate(email(message = "Not an email"))]
+    mail: String,
+    #[validate(length(min = 1, max = "MAX_NAME"), does_not_contain = "admin")]
+    name: String,
+    #[serde(rename = "userAge")]
+    #[validate(range(min = 18, max = 100, code = "age"))]
+    age: u32,
+    #[validate(keys(length(max = 10)), inner(range(min = 0)))]
+    scores: HashMap<String, i64>,
+    draft: bool,
+    #[validate(skip_if = "self.draft", required)]
+    bio: Option<String>,
+    team: String,
+}
+
+#[test]
+fn describes_the_rules_of_each_field() {
+    let rules = Signup::validation_rules();
+    let fields: Vec<_> = rules.iter().map(|r| r.field).collect();
+    assert_eq!(fields, ["mail", "name", "userAge", "scores", "bio", "__all__"]);
+
+    let mail = &rules[0].rules[0];
+    assert_eq!(mail.kind, "email");
+    assert_eq!(mail.code, "email");
+    assert_eq!(mail.message.as_deref(), Some("Not an email"));
+    assert!(mail.params.is_empty());
+
+    let name = &rules[1].rules;
+    assert_eq!(name[0].kind, "length");
+    assert_eq!(name[0].params["min"], 1);
+    assert_eq!(name[0].params["max"], 20);
+    assert_eq!(name[1].kind, "does_not_contain");
+    assert_eq!(name[1].params["needle"], "admin");
+
+    let age = &rules[2].rules[0];
+    assert_eq!(age.kind, "range");
+    assert_eq!(age.code, "age");
+    assert_eq!(age.params["min"], 18.0);
+    assert_eq!(age.params["max"], 100.0);
+
+    assert!(rules[3].rules.is_empty());
+    assert_eq!(rules[3].keys[0].kind, "length");
+    assert_eq!(rules[3].inner[0].kind, "range");
+
+    assert!(rules[4].conditional);
+    assert_eq!(rules[4].rules[0].kind, "required");
+
+    let schema = &rules[5].rules[0];
+    assert_eq!(schema.kind, "schema");
+    assert_eq!(schema.code, "team");
+    assert_eq!(schema.params["function"], "check_team");
+}
+
+#[test]
+fn can_serialize_rules() {
+    #[derive(Validate)]
+    #[validate(code_prefix = "user.")]
+    #[validate(expr = "self.min <= self.max")]
+    struct Limits {
+        #[validate(less_than_or_equal = "max")]
+        min: i32,
+        max: i32,
+    }
+
+    assert_eq!(
+        serde_json::to_value(Limits::validation_rules()).unwrap(),
+        json!([
+            {
+                "field": "min",
+                "rules": [{
+                    "kind": "less_than_or_equal",
+                    "code": "user.less_than_or_equal",
+                    "message": null,
+                    "params": {"other": "max"},
+                }],
+                "keys": [],
+                "inner": [],
+                "conditional": false,
+            },
+            {
+                "field": "__all__",
+                "rules": [{
+                    "kind": "expr",
+                    "code": "user.expr",
+                    "message": null,
+                    "params": {"expr": "self.min <= self.max"},
+                }],
+                "keys": [],
+                "inner": [],
+                "conditional": false,
+            },
+        ])
+    );
+}
@@ -0,0 +1,137 @@
+use std::collections::{BTreeSet, HashMap};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::json;
+use validator::{SampleError, Samples, Validate, ValidationErrors};
+
+lazy_static! {
+    static ref TEAM_CODE: Regex = Regex::new(r"^[A-Z]{3}$").unwrap();
+}
+
+#[derive(Debug, Deserialize, Validate)]
+struct Signup {
+    #[validate(email, length(max = 40))]
+    mail: String,
+    #[validate(length(min = 2, max = 10), does_not_contain = "admin")]
+    name: String,
+    #[validate(range(min = 18, max = 100))]
+    age: u8,
+    #[validate(range(exclusive_min = 0.0, max = 5.0))]
+    rating: f64,
+    #[validate(url, contains = "example")]
+    site: String,
+    #[validate(length(max = 3), inner(length(min = 1)))]
+    tags: Vec<String>,
+    #[validate(keys(length(max = 5)), inner(range(min = 0)))]
+    scores: HashMap<String, i64>,
+    #[validate(required)]
+    bio: Option<String>,
+    #[validate(inner(range(min = 1, max = 9)))]
+    levels: BTreeSet<u8>,
+    #[validate(regex = "crate::TEAM_CODE")]
+    team: String,
+    #[allow(dead_code)]
+    newsletter: bool,
+}
+
+fn samples(seed: u64) -> Samples {
+    Samples::new(seed).base(json!({"team": "RED", "newsletter": false}))
+}
+
+fn has_error(errors: &ValidationErrors, field: &str, code: &str) -> bool {
+    errors.iter().any(|(path, error)| path.to_string().starts_with(field) && error.code == code)
+}
+
+#[test]
+fn generates_valid_values() {
+    for seed in 0..200 {
+        let value = samples(seed).valid::<Signup>().unwrap();
+        let signup: Signup = serde_json::from_value(value.clone()).unwrap();
+        assert!(signup.validate().is_ok(), "{} is invalid: {:?}", value, signup.validate());
+        assert_eq!(value["team"], "RED");
+    }
+}
+
+#[test]
+fn the_same_seed_generates_the_same_values() {
+    assert_eq!(samples(7).valid::<Signup>().unwrap(), samples(7).valid::<Signup>().unwrap());
+    assert_ne!(samples(7).valid::<Signup>().unwrap(), samples(8).valid::<Signup>().unwrap());
+}
+
+#[test]
+fn generates_values_breaking_each_rule() {
+    for seed in 0..50 {
+        let invalid = samples(seed).invalid::<Signup>().unwrap();
+        for sample in &invalid {
+            let signup: Signup = serde_json::from_value(sample.value.clone()).unwrap();
+            let errors = signup.validate().unwrap_err();
+            assert!(
+                has_error(&errors, sample.field, &sample.code),
+                "{} doesn't break {} of {}: {}",
+                sample.value,
+                sample.code,
+                sample.field,
+                errors
+            );
+        }
+
+        let mut broken: Vec<_> =
+            invalid.iter().map(|sample| (sample.field, &*sample.code)).collect();
+        broken.dedup();
+        assert_eq!(
+            broken,
+            [
+                ("mail", "email"),
+                ("mail", "length"),
+                ("name", "length"),
+                ("name", "does_not_contain"),
+                ("age", "range"),
+                ("rating", "range"),
+                ("site", "url"),
+                ("site", "contains"),
+                ("tags", "length"),
+                ("scores", "range"),
+                ("scores", "length"),
+                ("bio", "required"),
+                ("levels", "range"),
+            ]
+        );
+    }
+}
+
+#[test]
+fn needs_the_fields_that_cant_be_generated_in_the_base() {
+    assert_eq!(
+        Samples::new(0).valid::<Signup>(),
+        Err(SampleError::Unsupported { field: "team", kind: "regex" })
+    );
+
+    #[derive(Debug, Validate)]
+    struct Contradiction {
+        #[validate(length(min = 5, max = 2))]
+        name: String,
+    }
+    assert_eq!(
+        Samples::new(0).valid::<Contradiction>(),
+        Err(SampleError::Unsatisfiable { field: "name" })
+    );
+}
+
+#[test]
+fn generates_instances() {
+    let signups: Vec<Signup> = samples(3).instances(20).unwrap();
+    assert_eq!(signups.len(), 20);
+    for signup in &signups {
+        assert!(signup.validate().is_ok());
+        assert!(signup.mail.ends_with("@example.com"));
+        assert!((18..=100).contains(&signup.age));
+    }
+    assert_ne!(signups[0].mail, signups[1].mail);
+
+    let missing = Samples::new(0).base(json!({"team": "RED"})).instance::<Signup>().unwrap_err();
+    assert!(
+        matches!(missing, SampleError::Deserialize { message } if message.contains("newsletter"))
+    );
+}
@@ -1,4 +1,4 @@
-use validator::{Validate, ValidationError};
+use validator::{Validate, ValidationError, ValidationErrors};
 
 #[test]
 fn can_validate_schema_fn_ok() {
@@ -18,6 +18,7 @@ fn can_validate_schema_fn_ok() {
     assert!(s.validate().is_ok());
 }
 
+#[allow(dead_code)]
 mod some_defining_mod {
     use validator::Validate;
 
@@ -192,3 +193,102 @@ fn can_choose_to_run_schema_validation_even_after_field_errors() {
     assert_eq!(errs["num"].len(), 1);
     assert_eq!(errs["num"][0].clone().code, "range");
 }
+
+#[test]
+fn skips_schema_validation_after_field_errors_by_default() {
+    fn invalid_schema_fn(_: &TestStruct) -> Result<(), ValidationError> {
+        Err(ValidationError::new("meh"))
+    }
+    #[allow(dead_code)]
+    #[derive(Debug, Validate)]
+    #[validate(schema(function = "invalid_schema_fn"))]
+    struct TestStruct {
+        #[validate(range(min = 1, max = 10))]
+        num: usize,
+    }
+
+    let err = TestStruct { num: 0 }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert!(!errs.contains_key("__all__"));
+    assert_eq!(errs["num"][0].code, "range");
+
+    let err = TestStruct { num: 1 }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["__all__"][0].code, "meh");
+}
+
+#[test]
+fn runs_schema_validations_by_order() {
+    fn first(_: &TestStruct) -> Result<(), ValidationError> {
+        Err(ValidationError::new("first"))
+    }
+    fn second(_: &TestStruct) -> Result<(), ValidationError> {
+        Err(ValidationError::new("second"))
+    }
+    fn last(_: &TestStruct) -> Result<(), ValidationError> {
+        Err(ValidationError::new("last"))
+    }
+    #[allow(dead_code, clippy::duplicated_attributes)]
+    #[derive(Debug, Validate)]
+    #[validate(schema(function = "last"))]
+    #[validate(schema(function = "second", order = 2))]
+    #[validate(schema(function = "first", order = 1))]
+    struct TestStruct {
+        val: String,
+    }
+
+    let err = TestStruct { val: String::new() }.validate().unwrap_err();
+    let errs = err.field_errors();
+    let codes: Vec<_> = errs["__all__"].iter().map(|e| e.code.clone()).collect();
+    assert_eq!(codes, ["first", "second", "last"]);
+}
+
+#[test]
+fn can_return_errors_for_specific_fields_from_schema_fn() {
+    fn check_passwords(s: &TestStruct) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if s.password != s.password_confirmation {
+            errors.add("password", ValidationError::new("mismatch"));
+            errors.add("password_confirmation", ValidationError::new("mismatch"));
+        }
+        if s.password.contains(&s.username) {
+            errors.add("password", ValidationError::new("contains_username"));
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+    #[derive(Debug, Validate)]
+    #[validate(schema(function = "check_passwords", message = "Check {field}"))]
+    struct TestStruct {
+        username: String,
+        password: String,
+        password_confirmation: String,
+    }
+
+    let s = TestStruct {
+        username: "bob".to_string(),
+        password: "hunter2".to_string(),
+        password_confirmation: "hunter2".to_string(),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        username: "bob".to_string(),
+        password: "bob1".to_string(),
+        password_confirmation: "bob2".to_string(),
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert!(!errs.contains_key("__all__"));
+    assert_eq!(errs["password"].len(), 2);
+    assert_eq!(errs["password"][0].code, "mismatch");
+    assert_eq!(errs["password"][1].code, "contains_username");
+    assert_eq!(errs["password_confirmation"][0].code, "mismatch");
+    assert_eq!(
+        errs["password_confirmation"][0].clone().message.unwrap(),
+        "Check password_confirmation"
+    );
+}
@@ -0,0 +1,82 @@
+use validator::Validate;
+
+#[derive(Debug, Validate)]
+#[validate(expr = "self.start < self.end", message = "start must be before end", code = "range")]
+struct Period {
+    start: u32,
+    end: u32,
+}
+
+#[test]
+fn can_validate_valid_expr() {
+    assert!(Period { start: 1, end: 2 }.validate().is_ok());
+}
+
+#[test]
+fn false_expr_fails_validation() {
+    let err = Period { start: 2, end: 1 }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("__all__"));
+    assert_eq!(errs["__all__"].len(), 1);
+    assert_eq!(errs["__all__"][0].code, "range");
+    assert_eq!(errs["__all__"][0].clone().message.unwrap(), "start must be before end");
+}
+
+#[test]
+fn expr_code_defaults_to_expr() {
+    #[derive(Debug, Validate)]
+    #[validate(expr = "self.a.len() + self.b.len() <= 5")]
+    struct TestStruct {
+        a: String,
+        b: String,
+    }
+
+    assert!(TestStruct { a: "ab".to_string(), b: "cde".to_string() }.validate().is_ok());
+
+    let err = TestStruct { a: "abc".to_string(), b: "def".to_string() }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["__all__"][0].code, "expr");
+    assert_eq!(errs["__all__"][0].message, None);
+}
+
+#[test]
+fn can_use_several_exprs_and_functions() {
+    #[derive(Debug, Validate)]
+    #[validate(expr = "Self::has_name", code = "name")]
+    #[validate(expr = "|s: &Self| s.min <= s.max", code = "bounds")]
+    struct TestStruct {
+        name: String,
+        min: i32,
+        max: i32,
+    }
+
+    impl TestStruct {
+        fn has_name(&self) -> bool {
+            !self.name.is_empty()
+        }
+    }
+
+    assert!(TestStruct { name: "a".to_string(), min: 1, max: 2 }.validate().is_ok());
+
+    let err = TestStruct { name: String::new(), min: 3, max: 2 }.validate().unwrap_err();
+    let errs = err.field_errors();
+    let mut codes: Vec<_> = errs["__all__"].iter().map(|e| e.code.to_string()).collect();
+    codes.sort();
+    assert_eq!(codes, ["bounds", "name"]);
+}
+
+#[test]
+fn expr_runs_alongside_field_validators() {
+    #[derive(Debug, Validate)]
+    #[validate(expr = "self.confirmed", code = "unconfirmed", skip_on_field_errors = false)]
+    struct TestStruct {
+        #[validate(length(min = 1))]
+        name: String,
+        confirmed: bool,
+    }
+
+    let err = TestStruct { name: String::new(), confirmed: false }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["name"][0].code, "length");
+    assert_eq!(errs["__all__"][0].code, "unconfirmed");
+}
@@ -0,0 +1,90 @@
+use validator::{Severity, Validate};
+
+#[derive(Debug, Validate)]
+struct Account {
+    #[validate(length(min = 4))]
+    #[validate(length(min = 12), severity = "warning")]
+    password: String,
+    #[validate(email)]
+    #[validate(contains(pattern = "+", message = "Consider a tagged address"), severity = "info")]
+    mail: String,
+    #[validate(nested)]
+    profile: Profile,
+}
+
+#[derive(Debug, Validate)]
+struct Profile {
+    #[validate(length(max = 10), severity = "warning")]
+    bio: String,
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+fn account(password: &str, bio: &str) -> Account {
+    Account {
+        password: password.to_string(),
+        mail: "bob@example.com".to_string(),
+        profile: Profile { bio: bio.to_string(), name: "Bob".to_string() },
+    }
+}
+
+#[test]
+fn warnings_dont_fail_the_validation() {
+    assert!(account("secret", "A long enough bio").validate().is_ok());
+    assert!(account("abc", "").validate().is_err());
+}
+
+#[test]
+fn report_collects_all_the_severities() {
+    let report = account("secret", "A long enough bio").validate_report();
+    assert!(report.is_valid());
+    assert!(!report.is_empty());
+    assert!(report.warnings().field_has_code("password", "length"));
+    assert!(report.warnings().field_has_code("profile.bio", "length"));
+    assert!(report.infos().field_has_code("mail", "contains"));
+    assert!(report.errors().is_empty());
+    assert_eq!(report.issues().field_errors()["password"][0].severity, Severity::Warning);
+    assert!(report.into_result().is_ok());
+}
+
+#[test]
+fn report_has_the_errors_too() {
+    let report = account("abc", "A long enough bio").validate_report();
+    assert!(!report.is_valid());
+    let password_errors = report.issues().field_errors()["password"].clone();
+    assert_eq!(password_errors.len(), 2);
+    assert_eq!(password_errors[0].severity, Severity::Error);
+    assert_eq!(password_errors[1].severity, Severity::Warning);
+
+    let err = report.into_result().unwrap_err();
+    assert_eq!(err.field_errors()["password"].len(), 1);
+    assert_eq!(err.field_errors()["password"][0].severity, Severity::Error);
+}
+
+#[test]
+fn severity_is_serialized_when_not_error() {
+    let report = account("abc", "A long enough bio").validate_report();
+    let json = serde_json::to_value(report.issues()).unwrap();
+    assert!(json["password"][0].get("severity").is_none());
+    assert_eq!(json["password"][1]["severity"], "warning");
+}
+
+#[derive(Debug, Validate)]
+#[validate(mode = "fail_fast")]
+struct Signup {
+    #[validate(length(min = 3), severity = "warning")]
+    nickname: String,
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(length(min = 1))]
+    city: String,
+}
+
+#[test]
+fn warnings_dont_stop_a_fail_fast_validation() {
+    let signup = Signup { nickname: "b".to_string(), name: String::new(), city: String::new() };
+    let report = signup.validate_report();
+    assert!(report.warnings().field_has_code("nickname", "length"));
+    assert!(report.errors().field_has_code("name", "length"));
+    assert!(!report.errors().field_errors().contains_key("city"));
+}
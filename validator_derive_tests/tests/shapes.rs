@@ -0,0 +1,60 @@
+use serde_json::json;
+use validator::{ErrorsShape, Validate};
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(email(message = "Not an email"))]
+    mail: String,
+    #[validate(nested)]
+    addresses: Vec<Address>,
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 1))]
+    street: String,
+}
+
+fn invalid_signup() -> Signup {
+    Signup { mail: "bob".to_string(), addresses: vec![Address { street: String::new() }] }
+}
+
+#[test]
+fn can_serialize_the_nested_shape() {
+    let err = invalid_signup().validate().unwrap_err();
+    assert_eq!(
+        serde_json::to_value(err.with_shape(ErrorsShape::Nested)).unwrap(),
+        serde_json::to_value(&err).unwrap()
+    );
+}
+
+#[test]
+fn can_serialize_the_flat_messages_shape() {
+    let err = invalid_signup().validate().unwrap_err();
+    assert_eq!(
+        serde_json::to_value(err.with_shape(ErrorsShape::FlatMessages)).unwrap(),
+        json!({"addresses[0].street": ["length"], "mail": ["Not an email"]})
+    );
+}
+
+#[test]
+fn can_serialize_the_list_shape() {
+    let err = invalid_signup().validate().unwrap_err();
+    assert_eq!(
+        serde_json::to_value(err.with_shape(ErrorsShape::List)).unwrap(),
+        json!([
+            {
+                "field": "addresses[0].street",
+                "code": "length",
+                "message": null,
+                "params": {"min": 1, "value": ""},
+            },
+            {
+                "field": "mail",
+                "code": "email",
+                "message": "Not an email",
+                "params": {"value": "bob"},
+            },
+        ])
+    );
+}
@@ -0,0 +1,39 @@
+use validator::Validate;
+
+#[derive(Debug, Validate)]
+struct Child {
+    #[validate(length(min = 1))]
+    value: String,
+}
+
+#[test]
+fn skipped_fields_are_not_validated() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(length(min = 1))]
+        name: String,
+        #[validate(skip)]
+        cache: Child,
+    }
+
+    let s = TestStruct { name: "bob".to_string(), cache: Child { value: String::new() } };
+    assert!(s.cache.validate().is_err());
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { name: String::new(), cache: Child { value: String::new() } };
+    let err = s.validate().unwrap_err();
+    assert_eq!(err.errors().len(), 1);
+    assert!(err.errors().contains_key("name"));
+}
+
+#[test]
+fn skipped_fields_of_tuple_structs_keep_their_position() {
+    #[derive(Debug, Validate)]
+    struct TestStruct(#[validate(skip)] Child, #[validate(length(min = 1))] String);
+
+    let s = TestStruct(Child { value: String::new() }, String::new());
+    assert!(s.0.validate().is_err());
+    let err = s.validate().unwrap_err();
+    assert_eq!(err.errors().len(), 1);
+    assert!(err.errors().contains_key("1"));
+}
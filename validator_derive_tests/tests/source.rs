@@ -0,0 +1,68 @@
+use std::error::Error;
+use std::net::Ipv4Addr;
+
+use validator::{Validate, ValidationError};
+
+fn valid_port(port: &str) -> Result<(), ValidationError> {
+    port.parse::<u16>().map(|_| ()).map_err(|e| ValidationError::new("port").with_source(e))
+}
+
+fn valid_address(address: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    address.parse::<Ipv4Addr>()?;
+    Ok(())
+}
+
+#[derive(Debug, Validate)]
+struct Server {
+    #[validate(custom = "valid_address")]
+    address: String,
+    #[validate(custom = "valid_port")]
+    port: String,
+}
+
+#[derive(Debug, Validate)]
+struct Config {
+    #[validate(nested)]
+    servers: Vec<Server>,
+}
+
+#[test]
+fn custom_errors_keep_their_source() {
+    let server = Server { address: "127.0.0.1".to_string(), port: "http".to_string() };
+    let errors = server.validate().unwrap_err();
+    let error = &errors.field_errors()["port"][0];
+    assert_eq!(error.code, "port");
+    assert_eq!(error.source().unwrap().to_string(), "invalid digit found in string");
+}
+
+#[test]
+fn boxed_errors_become_the_source() {
+    let server = Server { address: "localhost".to_string(), port: "80".to_string() };
+    let errors = server.validate().unwrap_err();
+    let error = &errors.field_errors()["address"][0];
+    assert_eq!(error.code, "custom");
+    assert_eq!(error.source().unwrap().to_string(), "invalid IPv4 address syntax");
+}
+
+#[test]
+fn nested_errors_chain_to_the_first_error() {
+    let config = Config {
+        servers: vec![
+            Server { address: "127.0.0.1".to_string(), port: "80".to_string() },
+            Server { address: "127.0.0.1".to_string(), port: "99999".to_string() },
+        ],
+    };
+    let errors = config.validate().unwrap_err();
+
+    let mut chain = vec![];
+    let mut source: Option<&dyn Error> = Some(&errors);
+    while let Some(error) = source {
+        chain.push(error.to_string());
+        source = error.source();
+    }
+    assert_eq!(chain.len(), 4);
+    assert!(chain[0].starts_with("servers[1].port: port"));
+    assert!(chain[1].starts_with("port: port"));
+    assert!(chain[2].starts_with("Validation error: port"));
+    assert_eq!(chain[3], "number too large to fit in target type");
+}
@@ -0,0 +1,47 @@
+use std::borrow::Cow;
+
+use validator::Validate;
+
+#[derive(Debug, Validate)]
+struct Profile {
+    #[validate(email, spans)]
+    mail: String,
+    #[validate(url, spans)]
+    site: Option<String>,
+    #[validate(regex = "DIGITS", spans)]
+    code: Cow<'static, str>,
+    #[validate(non_control_character, spans, redact)]
+    bio: String,
+    #[validate(email)]
+    backup_mail: String,
+}
+
+lazy_static::lazy_static! {
+    static ref DIGITS: regex::Regex = regex::Regex::new(r"^\d+$").unwrap();
+}
+
+fn span(errors: &validator::ValidationErrors, field: &str) -> (Option<i64>, Option<i64>) {
+    let error = &errors.field_errors_at(field).unwrap()[0];
+    (error.param_i64("offset"), error.param_i64("length"))
+}
+
+#[test]
+fn string_validators_can_add_the_span_of_the_invalid_part() {
+    let profile = Profile {
+        mail: "bob smith@example.com".to_string(),
+        site: Some("https://exa mple.com".to_string()),
+        code: Cow::Borrowed("12a"),
+        bio: "Hello\u{0007}".to_string(),
+        backup_mail: "bob".to_string(),
+    };
+    let errors = profile.validate().unwrap_err();
+
+    assert_eq!(span(&errors, "mail"), (Some(3), Some(1)));
+    assert_eq!(span(&errors, "site"), (Some(11), Some(1)));
+    assert_eq!(span(&errors, "code"), (Some(0), Some(3)));
+    // The span is kept without the value
+    assert_eq!(span(&errors, "bio"), (Some(5), Some(1)));
+    assert_eq!(errors.field_errors_at("bio").unwrap()[0].param("value"), None);
+    // Only with `spans`
+    assert_eq!(span(&errors, "backup_mail"), (None, None));
+}
@@ -0,0 +1,61 @@
+use std::convert::Infallible;
+
+use axum::body::Body;
+use axum::http::{Request, Response, StatusCode};
+use bytes::Bytes;
+use http_body_util::Full;
+use serde::Deserialize;
+use tower::{service_fn, Layer, ServiceExt};
+use validator::{ErrorsShape, Validate, ValidateJsonLayer};
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+struct Signup {
+    #[validate(email(message = "Not an email"))]
+    mail: String,
+}
+
+async fn handle(request: Request<Full<Bytes>>) -> Result<Response<Body>, Infallible> {
+    let signup = request.extensions().get::<Signup>().unwrap();
+    Ok(Response::new(Body::from(format!("Welcome {}", signup.mail))))
+}
+
+fn send(layer: ValidateJsonLayer<Signup>, body: &str) -> (StatusCode, String) {
+    let service = layer.layer(service_fn(handle));
+    let request = Request::post("/").body(Body::from(body.to_string())).unwrap();
+    let response = futures::executor::block_on(service.oneshot(request)).unwrap();
+    let status = response.status();
+    let bytes = futures::executor::block_on(axum::body::to_bytes(response.into_body(), usize::MAX))
+        .unwrap();
+    (status, String::from_utf8(bytes.to_vec()).unwrap())
+}
+
+#[test]
+fn valid_bodies_reach_the_service() {
+    let (status, body) = send(ValidateJsonLayer::new(), r#"{"mail": "bob@example.com"}"#);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "Welcome bob@example.com");
+}
+
+#[test]
+fn invalid_bodies_are_a_422() {
+    let (status, body) = send(ValidateJsonLayer::new(), r#"{"mail": "bob"}"#);
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["mail"][0]["message"], "Not an email");
+}
+
+#[test]
+fn bodies_that_arent_a_payload_are_a_400() {
+    let (status, body) = send(ValidateJsonLayer::new(), r#"{"name": "bob"}"#);
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body.contains("missing field `mail`"), "{}", body);
+}
+
+#[test]
+fn the_response_can_be_configured() {
+    let layer =
+        ValidateJsonLayer::new().with_response(StatusCode::BAD_REQUEST, ErrorsShape::FlatMessages);
+    let (status, body) = send(layer, r#"{"mail": "bob"}"#);
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body, r#"{"mail":["Not an email"]}"#);
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use validator::Validate;
+
+#[derive(Debug, Validate)]
+struct Order {
+    #[validate(email)]
+    mail: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 1))]
+    city: String,
+}
+
+/// Records the spans and the events as text
+#[derive(Clone, Default)]
+struct Recorder {
+    next_id: Arc<AtomicU64>,
+    lines: Arc<Mutex<Vec<String>>>,
+}
+
+struct Fields(String);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push_str(&format!(" {}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields(format!("span {}", span.metadata().name()));
+        span.record(&mut fields);
+        self.lines.lock().unwrap().push(fields.0);
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+
+    fn record(&self, _: &Id, values: &Record<'_>) {
+        let mut fields = Fields("record".to_string());
+        values.record(&mut fields);
+        self.lines.lock().unwrap().push(fields.0);
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields("event".to_string());
+        event.record(&mut fields);
+        self.lines.lock().unwrap().push(fields.0);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn record<F: FnOnce()>(f: F) -> Vec<String> {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), f);
+    let lines = recorder.lines.lock().unwrap().clone();
+    lines
+}
+
+#[test]
+fn traces_the_validation() {
+    let order = Order { mail: "bob".to_string(), address: Address { city: String::new() } };
+    let lines = record(|| assert!(order.validate_traced().is_err()));
+    assert_eq!(
+        lines,
+        vec![
+            "span validate type=\"tracing::Order\"",
+            "span validate type=\"tracing::Address\"",
+            "record valid=false",
+            "record valid=false",
+            "event message=validation failed field=mail code=email",
+            "event message=validation failed field=address.city code=length",
+        ]
+    );
+}
+
+#[test]
+fn only_traces_when_asked() {
+    let order = Order { mail: "bob".to_string(), address: Address { city: String::new() } };
+    assert!(record(|| assert!(order.validate().is_err())).is_empty());
+
+    let order = Order {
+        mail: "bob@example.com".to_string(),
+        address: Address { city: "Paris".to_string() },
+    };
+    assert_eq!(
+        record(|| assert!(order.validate_traced().is_ok())),
+        vec![
+            "span validate type=\"tracing::Order\"",
+            "span validate type=\"tracing::Address\"",
+            "record valid=true",
+            "record valid=true",
+        ]
+    );
+}
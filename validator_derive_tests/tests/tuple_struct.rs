@@ -0,0 +1,74 @@
+use validator::{Validate, ValidationErrorsKind};
+
+#[derive(Debug, Validate)]
+struct Meters(#[validate(range(min = 0.0))] f64);
+
+#[derive(Debug, Validate)]
+struct Pair(
+    #[validate(length(min = 1))] String,
+    #[validate(range(max = 10))] u8,
+    #[validate(must_match = "0")] String,
+);
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(email)]
+    mail: String,
+}
+
+#[derive(Debug, Validate)]
+#[validate(transparent)]
+struct ValidatedSignup(Signup);
+
+#[derive(Debug, Validate)]
+struct Wrapper(#[validate] Signup, #[validate] Option<Meters>);
+
+#[test]
+fn can_validate_tuple_struct_ok() {
+    assert!(Meters(1.5).validate().is_ok());
+    assert!(Pair("a".to_string(), 3, "a".to_string()).validate().is_ok());
+}
+
+#[test]
+fn tuple_struct_errors_are_keyed_by_position() {
+    let res = Pair(String::new(), 30, "b".to_string()).validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs["0"][0].code, "length");
+    assert_eq!(errs["1"][0].code, "range");
+    assert_eq!(errs["1"][0].params["value"], 30);
+    assert_eq!(errs["2"][0].code, "must_match");
+
+    let err = Meters(-1.0).validate().unwrap_err();
+    assert_eq!(err.field_errors()["0"][0].code, "range");
+}
+
+#[test]
+fn can_validate_nested_tuple_struct_fields() {
+    let w = Wrapper(Signup { mail: "bob".to_string() }, Some(Meters(-2.0)));
+    let res = w.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.errors();
+    assert_eq!(errs.len(), 2);
+    if let ValidationErrorsKind::Struct(ref errs) = errs["0"] {
+        assert_eq!(errs.field_errors()["mail"][0].code, "email");
+    } else {
+        panic!("Expected struct validation errors");
+    }
+    assert!(matches!(errs["1"], ValidationErrorsKind::Struct(_)));
+}
+
+#[test]
+fn transparent_newtype_uses_the_inner_validation() {
+    assert!(ValidatedSignup(Signup { mail: "bob@example.com".to_string() }).validate().is_ok());
+
+    let res = ValidatedSignup(Signup { mail: "bob".to_string() }).validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs["mail"][0].code, "email");
+}
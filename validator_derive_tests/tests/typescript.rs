@@ -0,0 +1,62 @@
+use validator::{to_zod_schema, TypeScriptGenerator, Validate};
+
+#[derive(Debug, Validate)]
+struct Signup {
+    #[validate(email)]
+    mail: String,
+    #[validate(url)]
+    site: Option<String>,
+    #[validate(
+        length(min = 2, max = 20),
+        regex(pattern = "^[a-z/]+$"),
+        does_not_contain = "admin"
+    )]
+    username: String,
+    #[validate(range(min = 18, exclusive_max = 120))]
+    age: u8,
+    #[validate(length(max = 3), inner(length(min = 1)))]
+    tags: Vec<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Validate)]
+struct Empty {
+    name: String,
+}
+
+const SIGNUP: &str = r#"z.object({
+  "age": z.number().int().gte(18.0).lt(120.0),
+  "mail": z.string().email(),
+  "site": z.string().url().optional(),
+  "tags": z.array(z.string().min(1)).max(3),
+  "username": z.string().min(2).max(20).regex(new RegExp("^[a-z/]+$")).refine((value) => !new RegExp("admin").test(value)),
+}).passthrough()"#;
+
+#[test]
+fn can_generate_a_zod_schema() {
+    assert_eq!(to_zod_schema::<Signup>(), SIGNUP);
+    assert_eq!(to_zod_schema::<Empty>(), "z.object({}).passthrough()");
+}
+
+#[test]
+fn can_generate_a_module() {
+    let source =
+        TypeScriptGenerator::new().add::<Signup>("Signup").add::<Empty>("Empty").generate();
+    assert_eq!(
+        source,
+        format!(
+            "// Generated from the validation rules of the server, do not edit\nimport {{ z }} from \"zod\";\n\nexport const SignupSchema = {};\n\nexport const EmptySchema = z.object({{}}).passthrough();\n",
+            SIGNUP
+        )
+    );
+}
+
+#[test]
+fn can_write_the_module() {
+    let path = std::env::temp_dir().join(format!("validator-{}.ts", std::process::id()));
+    let mut generator = TypeScriptGenerator::new();
+    generator.add::<Signup>("Signup");
+    generator.write_to(&path).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), generator.generate());
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use validator::{Valid, Validate};
+
+#[derive(Debug, Deserialize, Validate)]
+struct Config {
+    #[validate(range(min = 1))]
+    workers: u32,
+    #[validate(nested)]
+    servers: Vec<Server>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+struct Server {
+    #[validate(url)]
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct App {
+    name: String,
+    config: Valid<Config>,
+}
+
+#[test]
+fn valid_values_are_deserialized() {
+    let config: Valid<Config> =
+        serde_json::from_str(r#"{"workers": 4, "servers": [{"url": "https://example.com"}]}"#)
+            .unwrap();
+    assert_eq!(config.workers, 4);
+    assert_eq!(config.into_inner().servers[0].url, "https://example.com");
+}
+
+#[test]
+fn invalid_values_fail_the_deserialization() {
+    let err =
+        serde_json::from_str::<Valid<Config>>(r#"{"workers": 0, "servers": [{"url": "example"}]}"#)
+            .unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("workers: range"), "{}", message);
+    assert!(message.contains("servers[0].url: url"), "{}", message);
+
+    // Deep in a document
+    let err =
+        serde_json::from_str::<App>(r#"{"name": "app", "config": {"workers": 0, "servers": []}}"#)
+            .unwrap_err();
+    assert!(err.to_string().contains("workers: range"), "{}", err);
+}
+
+#[test]
+fn can_validate_a_value() {
+    let config = Config { workers: 1, servers: vec![] };
+    assert_eq!(Valid::new(config).unwrap().workers, 1);
+    assert!(Valid::new(Config { workers: 0, servers: vec![] }).is_err());
+
+    let app: App =
+        serde_json::from_str(r#"{"name": "app", "config": {"workers": 2, "servers": []}}"#)
+            .unwrap();
+    assert_eq!(app.name, "app");
+    assert!(app.config.servers.is_empty());
+}
+
+#[test]
+fn valid_values_serialize_like_the_value() {
+    let server = Valid::new(Server { url: "https://example.com".to_string() }).unwrap();
+    assert_eq!(serde_json::to_string(&server).unwrap(), r#"{"url":"https://example.com"}"#);
+}
@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use validator::{Validate, ValidationErrorsKind};
+
+#[derive(Debug, Clone, Validate)]
+struct Item {
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+fn valid() -> Item {
+    Item { name: "item".to_string() }
+}
+
+fn invalid() -> Item {
+    Item { name: String::new() }
+}
+
+#[test]
+fn can_validate_through_pointers() {
+    assert!(Box::new(valid()).validate().is_ok());
+    assert!(Rc::new(valid()).validate().is_ok());
+    assert!(Arc::new(valid()).validate().is_ok());
+    assert!(Cow::Owned::<Item>(valid()).validate().is_ok());
+
+    let item = invalid();
+    assert!(Box::new(item.clone()).validate().is_err());
+    assert!(Rc::new(item.clone()).validate().is_err());
+    assert!(Arc::new(item.clone()).validate().is_err());
+    assert!(Cow::Borrowed(&item).validate().is_err());
+}
+
+#[test]
+fn none_is_valid() {
+    assert!(None::<Item>.validate().is_ok());
+    assert!(Some(valid()).validate().is_ok());
+    assert!(Some(invalid()).validate().is_err());
+}
+
+#[test]
+fn errors_of_a_vec_are_keyed_by_index() {
+    assert!(vec![valid(), valid()].validate().is_ok());
+
+    let err = vec![valid(), invalid()].validate().unwrap_err();
+    match &err.errors()["__all__"] {
+        ValidationErrorsKind::List(errors) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[&1].field_errors()["name"][0].code, "length");
+        }
+        kind => panic!("Expected a list, got {:?}", kind),
+    }
+}
+
+#[test]
+fn errors_of_a_hashmap_are_keyed_by_key() {
+    let mut items = HashMap::new();
+    items.insert(1, valid());
+    assert!(items.validate().is_ok());
+
+    items.insert(2, invalid());
+    let err = items.validate().unwrap_err();
+    match &err.errors()["__all__"] {
+        ValidationErrorsKind::Map(errors) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors["2"].field_errors()["name"][0].code, "length");
+        }
+        kind => panic!("Expected a map, got {:?}", kind),
+    }
+}
@@ -14,19 +14,47 @@ pub enum Validator {
         function: String,
         /// This is the argument type that can be passed in with a macro
         argument: Box<Option<CustomArgument>>,
+        /// Whether the context of the struct is passed to the function
+        use_context: bool,
     },
-    // String is the name of the field to match
+    #[cfg(feature = "async")]
+    CustomAsync {
+        /// This is the name of the async function that should be awaited
+        function: String,
+    },
+    // String is the name of the field to match, or a path into a nested struct
     MustMatch(String),
-    // value is a &str or a HashMap<String, ..>
-    Contains(String),
-    // No implementation in this crate, it's all in validator_derive
-    Regex(String),
+    // Compares the value to another field, or a path into a nested struct
+    Compare {
+        comparison: Comparison,
+        other: String,
+        /// Whether the other field is an `Option`, the comparison only runs when it is `Some`
+        other_is_option: bool,
+    },
+    // The name of a function registered at runtime with `register_validator!`
+    Rule(String),
+    // value is a &str or a HashMap<String, ..>, or the path to a WordList if the value is a string
+    Contains(ValueOrPath<String>),
+    // No implementation in this crate, it's all in validator_derive.
+    // Either an inline pattern, compiled once by the generated code, or the path to a Regex
+    Regex(ValueOrPath<String>),
     Range {
         min: Option<ValueOrPath<f64>>,
         max: Option<ValueOrPath<f64>>,
         exclusive_min: Option<ValueOrPath<f64>>,
         exclusive_max: Option<ValueOrPath<f64>>,
+        step: Option<ValueOrPath<f64>>,
+    },
+    // A number between 0 and 100
+    Percentage {
+        exclusive: bool,
     },
+    // A number between 0.0 and 1.0
+    Ratio {
+        exclusive: bool,
+    },
+    // An integer that can only set the bits of the mask
+    BitsWithin(ValueOrPath<u64>),
     // Any value that impl HasLen can be validated with Length
     Length {
         min: Option<ValueOrPath<u64>>,
@@ -42,7 +70,46 @@ pub enum Validator {
     NonControlCharacter,
     Required,
     RequiredNested,
-    DoesNotContain(String),
+    DoesNotContain(ValueOrPath<String>),
+}
+
+/// How a field is compared to another one by `Validator::Compare`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    // Same as `LessThan`, under a name that reads better for dates
+    Before,
+    // Same as `GreaterThan`, under a name that reads better for dates
+    After,
+}
+
+impl Comparison {
+    /// The comparison for the name of its validator, if it is one
+    pub fn from_name(name: &str) -> Option<Comparison> {
+        match name {
+            "greater_than" => Some(Comparison::GreaterThan),
+            "greater_than_or_equal" => Some(Comparison::GreaterThanOrEqual),
+            "less_than" => Some(Comparison::LessThan),
+            "less_than_or_equal" => Some(Comparison::LessThanOrEqual),
+            "before" => Some(Comparison::Before),
+            "after" => Some(Comparison::After),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Comparison::GreaterThan => "greater_than",
+            Comparison::GreaterThanOrEqual => "greater_than_or_equal",
+            Comparison::LessThan => "less_than",
+            Comparison::LessThanOrEqual => "less_than_or_equal",
+            Comparison::Before => "before",
+            Comparison::After => "after",
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -76,12 +143,19 @@ impl Validator {
     pub fn code(&self) -> &'static str {
         match *self {
             Validator::MustMatch(_) => "must_match",
+            Validator::Compare { comparison, .. } => comparison.code(),
             Validator::Email => "email",
             Validator::Url => "url",
             Validator::Custom { .. } => "custom",
+            #[cfg(feature = "async")]
+            Validator::CustomAsync { .. } => "custom_async",
+            Validator::Rule(_) => "rule",
             Validator::Contains(_) => "contains",
             Validator::Regex(_) => "regex",
             Validator::Range { .. } => "range",
+            Validator::Percentage { .. } => "percentage",
+            Validator::Ratio { .. } => "ratio",
+            Validator::BitsWithin(_) => "bits_within",
             Validator::Length { .. } => "length",
             #[cfg(feature = "card")]
             Validator::CreditCard => "credit_card",